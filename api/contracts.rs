@@ -1,13 +1,25 @@
 mod common;
+mod couple;
+mod cross_border;
 mod document;
+mod donations;
 mod errors;
 mod estate_documents;
+mod liquidation_account;
+mod rule_publishing;
 mod rules;
 mod scenario;
+mod strategies;
 
 pub use common::*;
+pub use couple::*;
+pub use cross_border::*;
 pub use document::*;
+pub use donations::*;
 pub use errors::*;
 pub use estate_documents::*;
+pub use liquidation_account::*;
+pub use rule_publishing::*;
 pub use rules::*;
 pub use scenario::*;
+pub use strategies::*;
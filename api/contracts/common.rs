@@ -1,4 +1,4 @@
-use crate::core::rules::tax_rules::Jurisdiction;
+use crate::core::rules::tax_rules::{Currency, Jurisdiction};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -37,3 +37,34 @@ impl From<ApiJurisdiction> for Jurisdiction {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ApiCurrency {
+    Zar,
+    Usd,
+    Gbp,
+    Eur,
+}
+
+impl From<Currency> for ApiCurrency {
+    fn from(value: Currency) -> Self {
+        match value {
+            Currency::Zar => ApiCurrency::Zar,
+            Currency::Usd => ApiCurrency::Usd,
+            Currency::Gbp => ApiCurrency::Gbp,
+            Currency::Eur => ApiCurrency::Eur,
+        }
+    }
+}
+
+impl From<ApiCurrency> for Currency {
+    fn from(value: ApiCurrency) -> Self {
+        match value {
+            ApiCurrency::Zar => Currency::Zar,
+            ApiCurrency::Usd => Currency::Usd,
+            ApiCurrency::Gbp => Currency::Gbp,
+            ApiCurrency::Eur => Currency::Eur,
+        }
+    }
+}
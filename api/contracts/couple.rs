@@ -0,0 +1,85 @@
+use super::{ApiEstateScenarioInput, ApiScenarioResult};
+use crate::core::domain::models::{
+    CoupleScenarioInput, CoupleScenarioResult, DeathOrder, EstateScenarioInput,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiDeathOrder {
+    SpouseAFirst,
+    SpouseBFirst,
+}
+
+impl From<DeathOrder> for ApiDeathOrder {
+    fn from(value: DeathOrder) -> Self {
+        match value {
+            DeathOrder::SpouseAFirst => ApiDeathOrder::SpouseAFirst,
+            DeathOrder::SpouseBFirst => ApiDeathOrder::SpouseBFirst,
+        }
+    }
+}
+
+impl From<ApiDeathOrder> for DeathOrder {
+    fn from(value: ApiDeathOrder) -> Self {
+        match value {
+            ApiDeathOrder::SpouseAFirst => DeathOrder::SpouseAFirst,
+            ApiDeathOrder::SpouseBFirst => DeathOrder::SpouseBFirst,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCoupleScenarioInput {
+    pub spouse_a: ApiEstateScenarioInput,
+    pub spouse_b: ApiEstateScenarioInput,
+    pub order_of_death: ApiDeathOrder,
+}
+
+impl From<ApiCoupleScenarioInput> for CoupleScenarioInput {
+    fn from(value: ApiCoupleScenarioInput) -> Self {
+        CoupleScenarioInput {
+            spouse_a: EstateScenarioInput::from(value.spouse_a),
+            spouse_b: EstateScenarioInput::from(value.spouse_b),
+            order_of_death: value.order_of_death.into(),
+        }
+    }
+}
+
+impl From<CoupleScenarioInput> for ApiCoupleScenarioInput {
+    fn from(value: CoupleScenarioInput) -> Self {
+        ApiCoupleScenarioInput {
+            spouse_a: value.spouse_a.into(),
+            spouse_b: value.spouse_b.into(),
+            order_of_death: value.order_of_death.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCoupleScenarioResult {
+    pub order_of_death: ApiDeathOrder,
+    pub first_death: ApiScenarioResult,
+    pub rolled_over_to_survivor_amount: Decimal,
+    pub ported_exemption_amount: Decimal,
+    pub second_death: ApiScenarioResult,
+    pub combined_estate_transfer_tax_amount: Decimal,
+    pub combined_tax_liability_amount: Decimal,
+    pub combined_liquidity_gap_amount: Decimal,
+}
+
+impl From<CoupleScenarioResult> for ApiCoupleScenarioResult {
+    fn from(value: CoupleScenarioResult) -> Self {
+        ApiCoupleScenarioResult {
+            order_of_death: value.order_of_death.into(),
+            first_death: value.first_death.into(),
+            rolled_over_to_survivor_amount: value.rolled_over_to_survivor_amount,
+            ported_exemption_amount: value.ported_exemption_amount,
+            second_death: value.second_death.into(),
+            combined_estate_transfer_tax_amount: value.combined_estate_transfer_tax_amount,
+            combined_tax_liability_amount: value.combined_tax_liability_amount,
+            combined_liquidity_gap_amount: value.combined_liquidity_gap_amount,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use super::{ApiCurrency, ApiEstateScenarioInput, ApiJurisdiction, ApiScenarioResult};
+use crate::core::domain::models::{
+    ConsolidatedLiabilityAndLiquidity, CrossBorderScenarioInput, CrossBorderScenarioResult,
+    EstateScenarioInput, ForeignJurisdictionFiling, ForeignJurisdictionResult,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiForeignJurisdictionFilingInput {
+    pub jurisdiction: ApiJurisdiction,
+    pub tax_year: u16,
+    #[serde(default)]
+    pub administration_costs_amount: Decimal,
+}
+
+impl From<ApiForeignJurisdictionFilingInput> for ForeignJurisdictionFiling {
+    fn from(value: ApiForeignJurisdictionFilingInput) -> Self {
+        ForeignJurisdictionFiling {
+            jurisdiction: value.jurisdiction.into(),
+            tax_year: value.tax_year,
+            administration_costs_amount: value.administration_costs_amount,
+        }
+    }
+}
+
+impl From<ForeignJurisdictionFiling> for ApiForeignJurisdictionFilingInput {
+    fn from(value: ForeignJurisdictionFiling) -> Self {
+        ApiForeignJurisdictionFilingInput {
+            jurisdiction: value.jurisdiction.into(),
+            tax_year: value.tax_year,
+            administration_costs_amount: value.administration_costs_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCrossBorderScenarioInput {
+    pub estate: ApiEstateScenarioInput,
+    #[serde(default)]
+    pub foreign_filings: Vec<ApiForeignJurisdictionFilingInput>,
+}
+
+impl From<ApiCrossBorderScenarioInput> for CrossBorderScenarioInput {
+    fn from(value: ApiCrossBorderScenarioInput) -> Self {
+        CrossBorderScenarioInput {
+            estate: EstateScenarioInput::from(value.estate),
+            foreign_filings: value
+                .foreign_filings
+                .into_iter()
+                .map(ForeignJurisdictionFiling::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<CrossBorderScenarioInput> for ApiCrossBorderScenarioInput {
+    fn from(value: CrossBorderScenarioInput) -> Self {
+        ApiCrossBorderScenarioInput {
+            estate: value.estate.into(),
+            foreign_filings: value
+                .foreign_filings
+                .into_iter()
+                .map(ApiForeignJurisdictionFilingInput::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiForeignJurisdictionResult {
+    pub jurisdiction: ApiJurisdiction,
+    pub tax_year: u16,
+    pub reporting_currency: ApiCurrency,
+    pub rate_to_home_currency: Decimal,
+    pub result: ApiScenarioResult,
+    pub foreign_death_duty_amount: Decimal,
+    pub foreign_tax_liability_amount: Decimal,
+    pub administration_costs_amount: Decimal,
+    pub home_duty_on_situs_assets_amount: Decimal,
+    pub death_duty_credit_amount: Decimal,
+}
+
+impl From<ForeignJurisdictionResult> for ApiForeignJurisdictionResult {
+    fn from(value: ForeignJurisdictionResult) -> Self {
+        ApiForeignJurisdictionResult {
+            jurisdiction: value.jurisdiction.into(),
+            tax_year: value.tax_year,
+            reporting_currency: value.reporting_currency.into(),
+            rate_to_home_currency: value.rate_to_home_currency,
+            result: value.result.into(),
+            foreign_death_duty_amount: value.foreign_death_duty_amount,
+            foreign_tax_liability_amount: value.foreign_tax_liability_amount,
+            administration_costs_amount: value.administration_costs_amount,
+            home_duty_on_situs_assets_amount: value.home_duty_on_situs_assets_amount,
+            death_duty_credit_amount: value.death_duty_credit_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiConsolidatedLiabilityAndLiquidity {
+    pub reporting_currency: ApiCurrency,
+    pub home_tax_liability_amount: Decimal,
+    pub foreign_death_duty_credit_amount: Decimal,
+    pub foreign_tax_liability_amount: Decimal,
+    pub foreign_administration_costs_amount: Decimal,
+    pub total_tax_liability_amount: Decimal,
+    pub total_available_liquidity_amount: Decimal,
+    pub immediate_cash_requirements_amount: Decimal,
+    pub liquidity_gap_amount: Decimal,
+    pub liquidity_surplus_amount: Decimal,
+}
+
+impl From<ConsolidatedLiabilityAndLiquidity> for ApiConsolidatedLiabilityAndLiquidity {
+    fn from(value: ConsolidatedLiabilityAndLiquidity) -> Self {
+        ApiConsolidatedLiabilityAndLiquidity {
+            reporting_currency: value.reporting_currency.into(),
+            home_tax_liability_amount: value.home_tax_liability_amount,
+            foreign_death_duty_credit_amount: value.foreign_death_duty_credit_amount,
+            foreign_tax_liability_amount: value.foreign_tax_liability_amount,
+            foreign_administration_costs_amount: value.foreign_administration_costs_amount,
+            total_tax_liability_amount: value.total_tax_liability_amount,
+            total_available_liquidity_amount: value.total_available_liquidity_amount,
+            immediate_cash_requirements_amount: value.immediate_cash_requirements_amount,
+            liquidity_gap_amount: value.liquidity_gap_amount,
+            liquidity_surplus_amount: value.liquidity_surplus_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCrossBorderScenarioResult {
+    pub home: ApiScenarioResult,
+    pub foreign: Vec<ApiForeignJurisdictionResult>,
+    pub consolidated: ApiConsolidatedLiabilityAndLiquidity,
+}
+
+impl From<CrossBorderScenarioResult> for ApiCrossBorderScenarioResult {
+    fn from(value: CrossBorderScenarioResult) -> Self {
+        ApiCrossBorderScenarioResult {
+            home: value.home.into(),
+            foreign: value
+                .foreign
+                .into_iter()
+                .map(ApiForeignJurisdictionResult::from)
+                .collect(),
+            consolidated: value.consolidated.into(),
+        }
+    }
+}
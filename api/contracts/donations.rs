@@ -0,0 +1,175 @@
+use super::{ApiJurisdiction, ApiTaxPayerClass};
+use crate::core::domain::models::{
+    DonationTaxLine, DonationsTaxInput, DonationsTaxResult, DonationsTaxYearSummary,
+    LifetimeDonation,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiLifetimeDonationInput {
+    pub donee: String,
+    pub donated_on: String,
+    pub amount: Decimal,
+    #[serde(default)]
+    pub exempt_from_donations_tax: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDonationsTaxInput {
+    pub jurisdiction: ApiJurisdiction,
+    pub taxpayer_class: ApiTaxPayerClass,
+    #[serde(default)]
+    pub prior_cumulative_taxable_donations_amount: Decimal,
+    pub donations: Vec<ApiLifetimeDonationInput>,
+}
+
+impl From<ApiLifetimeDonationInput> for LifetimeDonation {
+    fn from(value: ApiLifetimeDonationInput) -> Self {
+        LifetimeDonation {
+            donee: value.donee,
+            donated_on: value.donated_on,
+            amount: value.amount,
+            exempt_from_donations_tax: value.exempt_from_donations_tax,
+        }
+    }
+}
+
+impl From<LifetimeDonation> for ApiLifetimeDonationInput {
+    fn from(value: LifetimeDonation) -> Self {
+        ApiLifetimeDonationInput {
+            donee: value.donee,
+            donated_on: value.donated_on,
+            amount: value.amount,
+            exempt_from_donations_tax: value.exempt_from_donations_tax,
+        }
+    }
+}
+
+impl From<ApiDonationsTaxInput> for DonationsTaxInput {
+    fn from(value: ApiDonationsTaxInput) -> Self {
+        DonationsTaxInput {
+            jurisdiction: value.jurisdiction.into(),
+            taxpayer_class: value.taxpayer_class.into(),
+            prior_cumulative_taxable_donations_amount: value
+                .prior_cumulative_taxable_donations_amount,
+            donations: value
+                .donations
+                .into_iter()
+                .map(LifetimeDonation::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<DonationsTaxInput> for ApiDonationsTaxInput {
+    fn from(value: DonationsTaxInput) -> Self {
+        ApiDonationsTaxInput {
+            jurisdiction: value.jurisdiction.into(),
+            taxpayer_class: value.taxpayer_class.into(),
+            prior_cumulative_taxable_donations_amount: value
+                .prior_cumulative_taxable_donations_amount,
+            donations: value
+                .donations
+                .into_iter()
+                .map(ApiLifetimeDonationInput::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDonationTaxLine {
+    pub donation_index: usize,
+    pub donee: String,
+    pub donated_on: String,
+    pub tax_year: u16,
+    pub amount: Decimal,
+    pub exempt_amount: Decimal,
+    pub annual_exemption_used_amount: Decimal,
+    pub taxable_amount: Decimal,
+    pub primary_band_amount: Decimal,
+    pub secondary_band_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+    pub cumulative_taxable_donations_amount: Decimal,
+}
+
+impl From<DonationTaxLine> for ApiDonationTaxLine {
+    fn from(value: DonationTaxLine) -> Self {
+        ApiDonationTaxLine {
+            donation_index: value.donation_index,
+            donee: value.donee,
+            donated_on: value.donated_on,
+            tax_year: value.tax_year,
+            amount: value.amount,
+            exempt_amount: value.exempt_amount,
+            annual_exemption_used_amount: value.annual_exemption_used_amount,
+            taxable_amount: value.taxable_amount,
+            primary_band_amount: value.primary_band_amount,
+            secondary_band_amount: value.secondary_band_amount,
+            tax_payable_amount: value.tax_payable_amount,
+            cumulative_taxable_donations_amount: value.cumulative_taxable_donations_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDonationsTaxYearSummary {
+    pub tax_year: u16,
+    pub rule_version_id: String,
+    pub total_donations_amount: Decimal,
+    pub exempt_donations_amount: Decimal,
+    pub annual_exemption_available_amount: Decimal,
+    pub annual_exemption_used_amount: Decimal,
+    pub taxable_donations_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+    pub cumulative_taxable_donations_amount: Decimal,
+}
+
+impl From<DonationsTaxYearSummary> for ApiDonationsTaxYearSummary {
+    fn from(value: DonationsTaxYearSummary) -> Self {
+        ApiDonationsTaxYearSummary {
+            tax_year: value.tax_year,
+            rule_version_id: value.rule_version_id.to_string(),
+            total_donations_amount: value.total_donations_amount,
+            exempt_donations_amount: value.exempt_donations_amount,
+            annual_exemption_available_amount: value.annual_exemption_available_amount,
+            annual_exemption_used_amount: value.annual_exemption_used_amount,
+            taxable_donations_amount: value.taxable_donations_amount,
+            tax_payable_amount: value.tax_payable_amount,
+            cumulative_taxable_donations_amount: value.cumulative_taxable_donations_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDonationsTaxResult {
+    pub donations: Vec<ApiDonationTaxLine>,
+    pub tax_years: Vec<ApiDonationsTaxYearSummary>,
+    pub total_donations_amount: Decimal,
+    pub total_taxable_donations_amount: Decimal,
+    pub total_tax_payable_amount: Decimal,
+    pub cumulative_taxable_donations_amount: Decimal,
+}
+
+impl From<DonationsTaxResult> for ApiDonationsTaxResult {
+    fn from(value: DonationsTaxResult) -> Self {
+        ApiDonationsTaxResult {
+            donations: value
+                .donations
+                .into_iter()
+                .map(ApiDonationTaxLine::from)
+                .collect(),
+            tax_years: value
+                .tax_years
+                .into_iter()
+                .map(ApiDonationsTaxYearSummary::from)
+                .collect(),
+            total_donations_amount: value.total_donations_amount,
+            total_taxable_donations_amount: value.total_taxable_donations_amount,
+            total_tax_payable_amount: value.total_tax_payable_amount,
+            cumulative_taxable_donations_amount: value.cumulative_taxable_donations_amount,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::ApiJurisdiction;
+use crate::core::domain::models::{
+    AccountEntry, AccountReconciliation, DistributionAccount, LiquidationAccountSection,
+    LiquidationAndDistributionAccount,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiAccountEntry {
+    pub description: String,
+    pub amount: Decimal,
+}
+
+impl From<AccountEntry> for ApiAccountEntry {
+    fn from(value: AccountEntry) -> Self {
+        ApiAccountEntry {
+            description: value.description,
+            amount: value.amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiLiquidationAccountSection {
+    pub receipts: Vec<ApiAccountEntry>,
+    pub payments: Vec<ApiAccountEntry>,
+    pub total_receipts_amount: Decimal,
+    pub total_payments_amount: Decimal,
+    pub balance_amount: Decimal,
+}
+
+impl From<LiquidationAccountSection> for ApiLiquidationAccountSection {
+    fn from(value: LiquidationAccountSection) -> Self {
+        ApiLiquidationAccountSection {
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(ApiAccountEntry::from)
+                .collect(),
+            payments: value
+                .payments
+                .into_iter()
+                .map(ApiAccountEntry::from)
+                .collect(),
+            total_receipts_amount: value.total_receipts_amount,
+            total_payments_amount: value.total_payments_amount,
+            balance_amount: value.balance_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDistributionAccount {
+    pub balance_for_distribution_amount: Decimal,
+    pub awards: Vec<ApiAccountEntry>,
+    pub total_awarded_amount: Decimal,
+}
+
+impl From<DistributionAccount> for ApiDistributionAccount {
+    fn from(value: DistributionAccount) -> Self {
+        ApiDistributionAccount {
+            balance_for_distribution_amount: value.balance_for_distribution_amount,
+            awards: value
+                .awards
+                .into_iter()
+                .map(ApiAccountEntry::from)
+                .collect(),
+            total_awarded_amount: value.total_awarded_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiAccountReconciliation {
+    pub estate_duty_per_breakdown_amount: Decimal,
+    pub estate_duty_per_account_amount: Decimal,
+    pub executor_fee_per_breakdown_amount: Decimal,
+    pub executor_fee_per_account_amount: Decimal,
+    pub cash_requirements_per_liquidity_amount: Decimal,
+    pub payments_per_account_amount: Decimal,
+    pub reconciled: bool,
+}
+
+impl From<AccountReconciliation> for ApiAccountReconciliation {
+    fn from(value: AccountReconciliation) -> Self {
+        ApiAccountReconciliation {
+            estate_duty_per_breakdown_amount: value.estate_duty_per_breakdown_amount,
+            estate_duty_per_account_amount: value.estate_duty_per_account_amount,
+            executor_fee_per_breakdown_amount: value.executor_fee_per_breakdown_amount,
+            executor_fee_per_account_amount: value.executor_fee_per_account_amount,
+            cash_requirements_per_liquidity_amount: value.cash_requirements_per_liquidity_amount,
+            payments_per_account_amount: value.payments_per_account_amount,
+            reconciled: value.reconciled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiLiquidationAndDistributionAccount {
+    pub jurisdiction: ApiJurisdiction,
+    pub tax_year: u16,
+    pub capital_account: ApiLiquidationAccountSection,
+    pub income_account: ApiLiquidationAccountSection,
+    pub distribution_account: ApiDistributionAccount,
+    pub reconciliation: ApiAccountReconciliation,
+    pub balanced: bool,
+}
+
+impl From<LiquidationAndDistributionAccount> for ApiLiquidationAndDistributionAccount {
+    fn from(value: LiquidationAndDistributionAccount) -> Self {
+        ApiLiquidationAndDistributionAccount {
+            jurisdiction: value.jurisdiction.into(),
+            tax_year: value.tax_year,
+            capital_account: value.capital_account.into(),
+            income_account: value.income_account.into(),
+            distribution_account: value.distribution_account.into(),
+            reconciliation: value.reconciliation.into(),
+            balanced: value.balanced,
+        }
+    }
+}
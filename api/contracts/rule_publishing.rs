@@ -0,0 +1,107 @@
+use super::{ApiJurisdiction, ApiVersionedJurisdictionTaxRuleSet};
+use crate::core::rules::rule_publishing::{
+    TaxRuleVersionRecord, TaxRuleVersionReview, TaxRuleVersionStatus,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiTaxRuleVersionStatus {
+    Draft,
+    Published,
+    Retired,
+}
+
+impl From<TaxRuleVersionStatus> for ApiTaxRuleVersionStatus {
+    fn from(value: TaxRuleVersionStatus) -> Self {
+        match value {
+            TaxRuleVersionStatus::Draft => ApiTaxRuleVersionStatus::Draft,
+            TaxRuleVersionStatus::Published => ApiTaxRuleVersionStatus::Published,
+            TaxRuleVersionStatus::Retired => ApiTaxRuleVersionStatus::Retired,
+        }
+    }
+}
+
+impl From<ApiTaxRuleVersionStatus> for TaxRuleVersionStatus {
+    fn from(value: ApiTaxRuleVersionStatus) -> Self {
+        match value {
+            ApiTaxRuleVersionStatus::Draft => TaxRuleVersionStatus::Draft,
+            ApiTaxRuleVersionStatus::Published => TaxRuleVersionStatus::Published,
+            ApiTaxRuleVersionStatus::Retired => TaxRuleVersionStatus::Retired,
+        }
+    }
+}
+
+// The author, reviewer and publisher are the authenticated admin principal, not request fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCreateTaxRuleDraftRequest {
+    pub jurisdiction: ApiJurisdiction,
+    pub rule_set: ApiVersionedJurisdictionTaxRuleSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiReviewTaxRuleVersionRequest {
+    pub approved: bool,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiPublishTaxRuleVersionRequest {
+    // Published version ids whose window this version takes over. Those starting earlier are
+    // closed the year before it starts; those it covers entirely are retired.
+    #[serde(default)]
+    pub supersedes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiTaxRuleVersionReview {
+    pub reviewer: String,
+    pub approved: bool,
+    pub notes: Option<String>,
+    pub reviewed_at: String,
+}
+
+impl From<TaxRuleVersionReview> for ApiTaxRuleVersionReview {
+    fn from(value: TaxRuleVersionReview) -> Self {
+        ApiTaxRuleVersionReview {
+            reviewer: value.reviewer,
+            approved: value.approved,
+            notes: value.notes,
+            reviewed_at: value.reviewed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiTaxRuleVersionRecord {
+    pub id: i64,
+    pub jurisdiction: ApiJurisdiction,
+    pub status: ApiTaxRuleVersionStatus,
+    pub author: String,
+    pub created_at: String,
+    pub review: Option<ApiTaxRuleVersionReview>,
+    pub published_by: Option<String>,
+    pub published_at: Option<String>,
+    pub retired_by: Option<String>,
+    pub retired_at: Option<String>,
+    pub rule_set: ApiVersionedJurisdictionTaxRuleSet,
+}
+
+impl From<TaxRuleVersionRecord<ApiVersionedJurisdictionTaxRuleSet>> for ApiTaxRuleVersionRecord {
+    fn from(value: TaxRuleVersionRecord<ApiVersionedJurisdictionTaxRuleSet>) -> Self {
+        ApiTaxRuleVersionRecord {
+            id: value.id,
+            jurisdiction: value.jurisdiction.into(),
+            status: value.status.into(),
+            author: value.author,
+            created_at: value.created_at,
+            review: value.review.map(ApiTaxRuleVersionReview::from),
+            published_by: value.published_by,
+            published_at: value.published_at,
+            retired_by: value.retired_by,
+            retired_at: value.retired_at,
+            rule_set: value.versioned,
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use super::ApiJurisdiction;
+use crate::core::rules::rule_catalog::{intern_rule_text, TaxRuleCatalog};
+use crate::core::rules::rule_diff::{TaxRuleChangeKind, TaxRuleFieldChange, TaxRuleVersionDiff};
 use crate::core::rules::tax_rules::{
-    CapitalGainsAtDeathRule, DonationsTaxRule, EstateDutyRule, Jurisdiction,
-    JurisdictionTaxRuleSet, TaxRuleRegistryEntry, TaxRuleVersion, VersionedJurisdictionTaxRuleSet,
+    CapitalGainsAtDeathRule, DonationsTaxRule, EstateDutyRule, EstateTaxApportionment,
+    ExecutorFeeRule, IncomeTaxRebate, IncomeTaxRule, IncomeTaxTable, Jurisdiction,
+    JurisdictionTaxRuleSet, LifePolicyInclusion, RateBracket, RetirementFundRule, ReturnRounding,
+    RoundingRule, StateEstateTaxRule, TaxRuleRegistryEntry, TaxRuleVersion, VatRate, VatRule,
+    VersionedJurisdictionTaxRuleSet,
 };
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -38,6 +44,19 @@ impl From<TaxRuleVersion> for ApiTaxRuleVersion {
     }
 }
 
+impl From<ApiTaxRuleVersion> for TaxRuleVersion {
+    fn from(value: ApiTaxRuleVersion) -> Self {
+        TaxRuleVersion {
+            version_id: intern_rule_text(&value.version_id),
+            tax_year_from: value.tax_year_from,
+            tax_year_to: value.tax_year_to,
+            effective_from: intern_rule_text(&value.effective_from),
+            effective_to: value.effective_to.as_deref().map(intern_rule_text),
+            source_last_verified_on: intern_rule_text(&value.source_last_verified_on),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct ApiTaxRuleRegistryEntry {
     pub jurisdiction: ApiJurisdiction,
@@ -78,15 +97,106 @@ impl From<JurisdictionTaxRuleRegistryResponse> for ApiJurisdictionTaxRuleRegistr
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRateBracket {
+    pub from_amount: Decimal,
+    pub rate: Decimal,
+}
+
+impl From<RateBracket> for ApiRateBracket {
+    fn from(value: RateBracket) -> Self {
+        ApiRateBracket {
+            from_amount: value.from_amount,
+            rate: value.rate,
+        }
+    }
+}
+
+impl From<ApiRateBracket> for RateBracket {
+    fn from(value: ApiRateBracket) -> Self {
+        RateBracket {
+            from_amount: value.from_amount,
+            rate: value.rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiLifePolicyInclusion {
+    DeemedPropertyWithExemptions,
+    IncidentsOfOwnership,
+}
+
+impl From<LifePolicyInclusion> for ApiLifePolicyInclusion {
+    fn from(value: LifePolicyInclusion) -> Self {
+        match value {
+            LifePolicyInclusion::DeemedPropertyWithExemptions => {
+                ApiLifePolicyInclusion::DeemedPropertyWithExemptions
+            }
+            LifePolicyInclusion::IncidentsOfOwnership => {
+                ApiLifePolicyInclusion::IncidentsOfOwnership
+            }
+        }
+    }
+}
+
+impl From<ApiLifePolicyInclusion> for LifePolicyInclusion {
+    fn from(value: ApiLifePolicyInclusion) -> Self {
+        match value {
+            ApiLifePolicyInclusion::DeemedPropertyWithExemptions => {
+                LifePolicyInclusion::DeemedPropertyWithExemptions
+            }
+            ApiLifePolicyInclusion::IncidentsOfOwnership => {
+                LifePolicyInclusion::IncidentsOfOwnership
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiEstateTaxApportionment {
+    BorneByResidue,
+    ProRataAmongDutiableHeirs,
+}
+
+impl From<EstateTaxApportionment> for ApiEstateTaxApportionment {
+    fn from(value: EstateTaxApportionment) -> Self {
+        match value {
+            EstateTaxApportionment::BorneByResidue => ApiEstateTaxApportionment::BorneByResidue,
+            EstateTaxApportionment::ProRataAmongDutiableHeirs => {
+                ApiEstateTaxApportionment::ProRataAmongDutiableHeirs
+            }
+        }
+    }
+}
+
+impl From<ApiEstateTaxApportionment> for EstateTaxApportionment {
+    fn from(value: ApiEstateTaxApportionment) -> Self {
+        match value {
+            ApiEstateTaxApportionment::BorneByResidue => EstateTaxApportionment::BorneByResidue,
+            ApiEstateTaxApportionment::ProRataAmongDutiableHeirs => {
+                EstateTaxApportionment::ProRataAmongDutiableHeirs
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiEstateDutyRule {
     #[serde(alias = "section_4a_abatement_zar")]
-    pub exemption_amount: f64,
-    pub primary_rate: f64,
-    #[serde(alias = "primary_rate_cap_zar")]
-    pub primary_rate_cap_amount: f64,
-    pub secondary_rate: f64,
+    pub exemption_amount: Decimal,
+    pub rate_schedule: Vec<ApiRateBracket>,
+    #[serde(default)]
+    pub exemption_as_unified_credit: bool,
+    #[serde(default)]
+    pub non_resident_exemption_amount: Option<Decimal>,
     pub spouse_deduction_unlimited: bool,
+    pub life_policy_inclusion: ApiLifePolicyInclusion,
+    pub default_tax_apportionment: ApiEstateTaxApportionment,
+    #[serde(default)]
+    pub foreign_death_duty_credit: bool,
+    #[serde(default)]
+    pub exemption_portable_from: Option<String>,
     pub effective_from: String,
     pub source: String,
     pub source_url: String,
@@ -96,10 +206,18 @@ impl From<EstateDutyRule> for ApiEstateDutyRule {
     fn from(value: EstateDutyRule) -> Self {
         ApiEstateDutyRule {
             exemption_amount: value.exemption_amount,
-            primary_rate: value.primary_rate,
-            primary_rate_cap_amount: value.primary_rate_cap_amount,
-            secondary_rate: value.secondary_rate,
+            rate_schedule: value
+                .rate_schedule
+                .into_iter()
+                .map(ApiRateBracket::from)
+                .collect(),
+            exemption_as_unified_credit: value.exemption_as_unified_credit,
+            non_resident_exemption_amount: value.non_resident_exemption_amount,
             spouse_deduction_unlimited: value.spouse_deduction_unlimited,
+            life_policy_inclusion: value.life_policy_inclusion.into(),
+            default_tax_apportionment: value.default_tax_apportionment.into(),
+            foreign_death_duty_credit: value.foreign_death_duty_credit,
+            exemption_portable_from: value.exemption_portable_from.map(str::to_string),
             effective_from: value.effective_from.to_string(),
             source: value.source.to_string(),
             source_url: value.source_url.to_string(),
@@ -107,16 +225,89 @@ impl From<EstateDutyRule> for ApiEstateDutyRule {
     }
 }
 
+impl From<ApiEstateDutyRule> for EstateDutyRule {
+    fn from(value: ApiEstateDutyRule) -> Self {
+        EstateDutyRule {
+            exemption_amount: value.exemption_amount,
+            rate_schedule: value
+                .rate_schedule
+                .into_iter()
+                .map(RateBracket::from)
+                .collect(),
+            exemption_as_unified_credit: value.exemption_as_unified_credit,
+            non_resident_exemption_amount: value.non_resident_exemption_amount,
+            spouse_deduction_unlimited: value.spouse_deduction_unlimited,
+            life_policy_inclusion: value.life_policy_inclusion.into(),
+            default_tax_apportionment: value.default_tax_apportionment.into(),
+            foreign_death_duty_credit: value.foreign_death_duty_credit,
+            exemption_portable_from: value
+                .exemption_portable_from
+                .as_deref()
+                .map(intern_rule_text),
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiStateEstateTaxRule {
+    pub exemption_amount: Decimal,
+    pub rate_schedule: Vec<ApiRateBracket>,
+    pub credit_phase_out_ceiling_ratio: Option<Decimal>,
+    pub deductible_for_federal_estate_tax: bool,
+    pub effective_from: String,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<StateEstateTaxRule> for ApiStateEstateTaxRule {
+    fn from(value: StateEstateTaxRule) -> Self {
+        ApiStateEstateTaxRule {
+            exemption_amount: value.exemption_amount,
+            rate_schedule: value
+                .rate_schedule
+                .into_iter()
+                .map(ApiRateBracket::from)
+                .collect(),
+            credit_phase_out_ceiling_ratio: value.credit_phase_out_ceiling_ratio,
+            deductible_for_federal_estate_tax: value.deductible_for_federal_estate_tax,
+            effective_from: value.effective_from.to_string(),
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+impl From<ApiStateEstateTaxRule> for StateEstateTaxRule {
+    fn from(value: ApiStateEstateTaxRule) -> Self {
+        StateEstateTaxRule {
+            exemption_amount: value.exemption_amount,
+            rate_schedule: value
+                .rate_schedule
+                .into_iter()
+                .map(RateBracket::from)
+                .collect(),
+            credit_phase_out_ceiling_ratio: value.credit_phase_out_ceiling_ratio,
+            deductible_for_federal_estate_tax: value.deductible_for_federal_estate_tax,
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiDonationsTaxRule {
     #[serde(alias = "annual_exemption_natural_person_zar")]
-    pub annual_exemption_natural_person_amount: f64,
+    pub annual_exemption_natural_person_amount: Decimal,
     #[serde(alias = "annual_exemption_non_natural_casual_gifts_zar")]
-    pub annual_exemption_non_natural_casual_gifts_amount: f64,
-    pub primary_rate: f64,
+    pub annual_exemption_non_natural_casual_gifts_amount: Decimal,
+    pub primary_rate: Decimal,
     #[serde(alias = "primary_rate_cap_cumulative_zar")]
-    pub primary_rate_cap_cumulative_amount: f64,
-    pub secondary_rate: f64,
+    pub primary_rate_cap_cumulative_amount: Decimal,
+    pub secondary_rate: Decimal,
     pub effective_from: String,
     pub source: String,
     pub source_url: String,
@@ -138,14 +329,35 @@ impl From<DonationsTaxRule> for ApiDonationsTaxRule {
     }
 }
 
+impl From<ApiDonationsTaxRule> for DonationsTaxRule {
+    fn from(value: ApiDonationsTaxRule) -> Self {
+        DonationsTaxRule {
+            annual_exemption_natural_person_amount: value.annual_exemption_natural_person_amount,
+            annual_exemption_non_natural_casual_gifts_amount: value
+                .annual_exemption_non_natural_casual_gifts_amount,
+            primary_rate: value.primary_rate,
+            primary_rate_cap_cumulative_amount: value.primary_rate_cap_cumulative_amount,
+            secondary_rate: value.secondary_rate,
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiCapitalGainsAtDeathRule {
     #[serde(alias = "annual_exclusion_in_year_of_death_zar")]
-    pub annual_exclusion_in_year_of_death_amount: f64,
-    pub inclusion_rate_natural_person: f64,
-    pub inclusion_rate_company: f64,
-    pub inclusion_rate_trust: f64,
+    pub annual_exclusion_in_year_of_death_amount: Decimal,
+    #[serde(default)]
+    pub annual_exclusion_amount: Option<Decimal>,
+    pub inclusion_rate_natural_person: Decimal,
+    pub inclusion_rate_company: Decimal,
+    pub inclusion_rate_trust: Decimal,
+    pub primary_residence_exclusion_amount: Decimal,
     pub base_cost_step_up_to_market_value_on_death: bool,
+    pub spousal_rollover_on_death: bool,
+    pub pbo_bequest_gain_disregarded: bool,
     pub effective_from: String,
     pub source: String,
     pub source_url: String,
@@ -156,11 +368,15 @@ impl From<CapitalGainsAtDeathRule> for ApiCapitalGainsAtDeathRule {
         ApiCapitalGainsAtDeathRule {
             annual_exclusion_in_year_of_death_amount: value
                 .annual_exclusion_in_year_of_death_amount,
+            annual_exclusion_amount: value.annual_exclusion_amount,
             inclusion_rate_natural_person: value.inclusion_rate_natural_person,
             inclusion_rate_company: value.inclusion_rate_company,
             inclusion_rate_trust: value.inclusion_rate_trust,
+            primary_residence_exclusion_amount: value.primary_residence_exclusion_amount,
             base_cost_step_up_to_market_value_on_death: value
                 .base_cost_step_up_to_market_value_on_death,
+            spousal_rollover_on_death: value.spousal_rollover_on_death,
+            pbo_bequest_gain_disregarded: value.pbo_bequest_gain_disregarded,
             effective_from: value.effective_from.to_string(),
             source: value.source.to_string(),
             source_url: value.source_url.to_string(),
@@ -168,11 +384,367 @@ impl From<CapitalGainsAtDeathRule> for ApiCapitalGainsAtDeathRule {
     }
 }
 
+impl From<ApiCapitalGainsAtDeathRule> for CapitalGainsAtDeathRule {
+    fn from(value: ApiCapitalGainsAtDeathRule) -> Self {
+        CapitalGainsAtDeathRule {
+            annual_exclusion_in_year_of_death_amount: value
+                .annual_exclusion_in_year_of_death_amount,
+            annual_exclusion_amount: value.annual_exclusion_amount,
+            inclusion_rate_natural_person: value.inclusion_rate_natural_person,
+            inclusion_rate_company: value.inclusion_rate_company,
+            inclusion_rate_trust: value.inclusion_rate_trust,
+            primary_residence_exclusion_amount: value.primary_residence_exclusion_amount,
+            base_cost_step_up_to_market_value_on_death: value
+                .base_cost_step_up_to_market_value_on_death,
+            spousal_rollover_on_death: value.spousal_rollover_on_death,
+            pbo_bequest_gain_disregarded: value.pbo_bequest_gain_disregarded,
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRetirementFundRule {
+    pub fund_benefits_in_estate: bool,
+    pub living_annuity_in_estate: bool,
+    pub disallowed_contributions_in_estate: bool,
+    pub disregarded_for_cgt_on_death: bool,
+    pub fund_benefits_allocated_by_trustees: bool,
+    pub effective_from: String,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<RetirementFundRule> for ApiRetirementFundRule {
+    fn from(value: RetirementFundRule) -> Self {
+        ApiRetirementFundRule {
+            fund_benefits_in_estate: value.fund_benefits_in_estate,
+            living_annuity_in_estate: value.living_annuity_in_estate,
+            disallowed_contributions_in_estate: value.disallowed_contributions_in_estate,
+            disregarded_for_cgt_on_death: value.disregarded_for_cgt_on_death,
+            fund_benefits_allocated_by_trustees: value.fund_benefits_allocated_by_trustees,
+            effective_from: value.effective_from.to_string(),
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+impl From<ApiRetirementFundRule> for RetirementFundRule {
+    fn from(value: ApiRetirementFundRule) -> Self {
+        RetirementFundRule {
+            fund_benefits_in_estate: value.fund_benefits_in_estate,
+            living_annuity_in_estate: value.living_annuity_in_estate,
+            disallowed_contributions_in_estate: value.disallowed_contributions_in_estate,
+            disregarded_for_cgt_on_death: value.disregarded_for_cgt_on_death,
+            fund_benefits_allocated_by_trustees: value.fund_benefits_allocated_by_trustees,
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiIncomeTaxRebate {
+    pub from_age: u8,
+    pub amount: Decimal,
+}
+
+impl From<IncomeTaxRebate> for ApiIncomeTaxRebate {
+    fn from(value: IncomeTaxRebate) -> Self {
+        ApiIncomeTaxRebate {
+            from_age: value.from_age,
+            amount: value.amount,
+        }
+    }
+}
+
+impl From<ApiIncomeTaxRebate> for IncomeTaxRebate {
+    fn from(value: ApiIncomeTaxRebate) -> Self {
+        IncomeTaxRebate {
+            from_age: value.from_age,
+            amount: value.amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiIncomeTaxTable {
+    pub tax_year_from: u16,
+    pub tax_year_to: Option<u16>,
+    pub rate_schedule: Vec<ApiRateBracket>,
+    pub rebates: Vec<ApiIncomeTaxRebate>,
+    pub trust_rate_schedule: Vec<ApiRateBracket>,
+    pub company_rate_schedule: Vec<ApiRateBracket>,
+}
+
+impl From<IncomeTaxTable> for ApiIncomeTaxTable {
+    fn from(value: IncomeTaxTable) -> Self {
+        ApiIncomeTaxTable {
+            tax_year_from: value.tax_year_from,
+            tax_year_to: value.tax_year_to,
+            rate_schedule: value
+                .rate_schedule
+                .into_iter()
+                .map(ApiRateBracket::from)
+                .collect(),
+            rebates: value
+                .rebates
+                .into_iter()
+                .map(ApiIncomeTaxRebate::from)
+                .collect(),
+            trust_rate_schedule: value
+                .trust_rate_schedule
+                .into_iter()
+                .map(ApiRateBracket::from)
+                .collect(),
+            company_rate_schedule: value
+                .company_rate_schedule
+                .into_iter()
+                .map(ApiRateBracket::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<ApiIncomeTaxTable> for IncomeTaxTable {
+    fn from(value: ApiIncomeTaxTable) -> Self {
+        IncomeTaxTable {
+            tax_year_from: value.tax_year_from,
+            tax_year_to: value.tax_year_to,
+            rate_schedule: value
+                .rate_schedule
+                .into_iter()
+                .map(RateBracket::from)
+                .collect(),
+            rebates: value
+                .rebates
+                .into_iter()
+                .map(IncomeTaxRebate::from)
+                .collect(),
+            trust_rate_schedule: value
+                .trust_rate_schedule
+                .into_iter()
+                .map(RateBracket::from)
+                .collect(),
+            company_rate_schedule: value
+                .company_rate_schedule
+                .into_iter()
+                .map(RateBracket::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiIncomeTaxRule {
+    pub tables: Vec<ApiIncomeTaxTable>,
+    pub effective_from: String,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<IncomeTaxRule> for ApiIncomeTaxRule {
+    fn from(value: IncomeTaxRule) -> Self {
+        ApiIncomeTaxRule {
+            tables: value
+                .tables
+                .into_iter()
+                .map(ApiIncomeTaxTable::from)
+                .collect(),
+            effective_from: value.effective_from.to_string(),
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+impl From<ApiIncomeTaxRule> for IncomeTaxRule {
+    fn from(value: ApiIncomeTaxRule) -> Self {
+        IncomeTaxRule {
+            tables: value.tables.into_iter().map(IncomeTaxTable::from).collect(),
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiExecutorFeeRule {
+    pub asset_rate_schedule: Vec<ApiRateBracket>,
+    pub income_rate: Option<Decimal>,
+    pub minimum_fee_amount: Decimal,
+    pub vat_applies: bool,
+    pub effective_from: String,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<ExecutorFeeRule> for ApiExecutorFeeRule {
+    fn from(value: ExecutorFeeRule) -> Self {
+        ApiExecutorFeeRule {
+            asset_rate_schedule: value
+                .asset_rate_schedule
+                .into_iter()
+                .map(ApiRateBracket::from)
+                .collect(),
+            income_rate: value.income_rate,
+            minimum_fee_amount: value.minimum_fee_amount,
+            vat_applies: value.vat_applies,
+            effective_from: value.effective_from.to_string(),
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+impl From<ApiExecutorFeeRule> for ExecutorFeeRule {
+    fn from(value: ApiExecutorFeeRule) -> Self {
+        ExecutorFeeRule {
+            asset_rate_schedule: value
+                .asset_rate_schedule
+                .into_iter()
+                .map(RateBracket::from)
+                .collect(),
+            income_rate: value.income_rate,
+            minimum_fee_amount: value.minimum_fee_amount,
+            vat_applies: value.vat_applies,
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiVatRate {
+    pub tax_year_from: u16,
+    pub tax_year_to: Option<u16>,
+    pub standard_rate: Decimal,
+}
+
+impl From<VatRate> for ApiVatRate {
+    fn from(value: VatRate) -> Self {
+        ApiVatRate {
+            tax_year_from: value.tax_year_from,
+            tax_year_to: value.tax_year_to,
+            standard_rate: value.standard_rate,
+        }
+    }
+}
+
+impl From<ApiVatRate> for VatRate {
+    fn from(value: ApiVatRate) -> Self {
+        VatRate {
+            tax_year_from: value.tax_year_from,
+            tax_year_to: value.tax_year_to,
+            standard_rate: value.standard_rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiVatRule {
+    pub rates: Vec<ApiVatRate>,
+    pub effective_from: String,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<VatRule> for ApiVatRule {
+    fn from(value: VatRule) -> Self {
+        ApiVatRule {
+            rates: value.rates.into_iter().map(ApiVatRate::from).collect(),
+            effective_from: value.effective_from.to_string(),
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+impl From<ApiVatRule> for VatRule {
+    fn from(value: ApiVatRule) -> Self {
+        VatRule {
+            rates: value.rates.into_iter().map(VatRate::from).collect(),
+            effective_from: intern_rule_text(&value.effective_from),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiReturnRounding {
+    HalfUp,
+    Down,
+}
+
+impl From<ReturnRounding> for ApiReturnRounding {
+    fn from(value: ReturnRounding) -> Self {
+        match value {
+            ReturnRounding::HalfUp => ApiReturnRounding::HalfUp,
+            ReturnRounding::Down => ApiReturnRounding::Down,
+        }
+    }
+}
+
+impl From<ApiReturnRounding> for ReturnRounding {
+    fn from(value: ApiReturnRounding) -> Self {
+        match value {
+            ApiReturnRounding::HalfUp => ReturnRounding::HalfUp,
+            ApiReturnRounding::Down => ReturnRounding::Down,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRoundingRule {
+    pub return_decimal_places: u32,
+    pub return_rounding: ApiReturnRounding,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<RoundingRule> for ApiRoundingRule {
+    fn from(value: RoundingRule) -> Self {
+        ApiRoundingRule {
+            return_decimal_places: value.return_decimal_places,
+            return_rounding: value.return_rounding.into(),
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+impl From<ApiRoundingRule> for RoundingRule {
+    fn from(value: ApiRoundingRule) -> Self {
+        RoundingRule {
+            return_decimal_places: value.return_decimal_places,
+            return_rounding: value.return_rounding.into(),
+            source: intern_rule_text(&value.source),
+            source_url: intern_rule_text(&value.source_url),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiJurisdictionTaxRuleSet {
     pub estate_duty: ApiEstateDutyRule,
     pub donations_tax: ApiDonationsTaxRule,
     pub cgt_on_death: ApiCapitalGainsAtDeathRule,
+    pub retirement_funds: ApiRetirementFundRule,
+    pub income_tax: ApiIncomeTaxRule,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executor_fee: Option<ApiExecutorFeeRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vat: Option<ApiVatRule>,
+    pub rounding: ApiRoundingRule,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_estate_tax: Option<ApiStateEstateTaxRule>,
 }
 
 impl From<JurisdictionTaxRuleSet> for ApiJurisdictionTaxRuleSet {
@@ -181,6 +753,28 @@ impl From<JurisdictionTaxRuleSet> for ApiJurisdictionTaxRuleSet {
             estate_duty: value.estate_duty.into(),
             donations_tax: value.donations_tax.into(),
             cgt_on_death: value.cgt_on_death.into(),
+            retirement_funds: value.retirement_funds.into(),
+            income_tax: value.income_tax.into(),
+            executor_fee: value.executor_fee.map(ApiExecutorFeeRule::from),
+            vat: value.vat.map(ApiVatRule::from),
+            rounding: value.rounding.into(),
+            state_estate_tax: value.state_estate_tax.map(ApiStateEstateTaxRule::from),
+        }
+    }
+}
+
+impl From<ApiJurisdictionTaxRuleSet> for JurisdictionTaxRuleSet {
+    fn from(value: ApiJurisdictionTaxRuleSet) -> Self {
+        JurisdictionTaxRuleSet {
+            estate_duty: value.estate_duty.into(),
+            donations_tax: value.donations_tax.into(),
+            cgt_on_death: value.cgt_on_death.into(),
+            retirement_funds: value.retirement_funds.into(),
+            income_tax: value.income_tax.into(),
+            executor_fee: value.executor_fee.map(ExecutorFeeRule::from),
+            vat: value.vat.map(VatRule::from),
+            rounding: value.rounding.into(),
+            state_estate_tax: value.state_estate_tax.map(StateEstateTaxRule::from),
         }
     }
 }
@@ -199,3 +793,134 @@ impl From<VersionedJurisdictionTaxRuleSet> for ApiVersionedJurisdictionTaxRuleSe
         }
     }
 }
+
+impl From<ApiVersionedJurisdictionTaxRuleSet> for VersionedJurisdictionTaxRuleSet {
+    fn from(value: ApiVersionedJurisdictionTaxRuleSet) -> Self {
+        VersionedJurisdictionTaxRuleSet {
+            version: value.version.into(),
+            rules: value.rules.into(),
+        }
+    }
+}
+
+// A rule catalog data file: every version for one jurisdiction, in the `/v1/rules` schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiTaxRuleCatalog {
+    pub jurisdiction: ApiJurisdiction,
+    pub versions: Vec<ApiVersionedJurisdictionTaxRuleSet>,
+}
+
+impl From<ApiTaxRuleCatalog> for TaxRuleCatalog {
+    fn from(value: ApiTaxRuleCatalog) -> Self {
+        TaxRuleCatalog {
+            jurisdiction: value.jurisdiction.into(),
+            versions: value
+                .versions
+                .into_iter()
+                .map(VersionedJurisdictionTaxRuleSet::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<TaxRuleCatalog> for ApiTaxRuleCatalog {
+    fn from(value: TaxRuleCatalog) -> Self {
+        ApiTaxRuleCatalog {
+            jurisdiction: value.jurisdiction.into(),
+            versions: value
+                .versions
+                .into_iter()
+                .map(ApiVersionedJurisdictionTaxRuleSet::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiLoadedTaxRuleCatalog {
+    pub jurisdiction: ApiJurisdiction,
+    pub source_file: String,
+    pub version_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiTaxRuleCatalogReloadResponse {
+    pub rules_dir: String,
+    pub loaded: Vec<ApiLoadedTaxRuleCatalog>,
+    // Jurisdictions without a data file, which keep their compiled-in catalog.
+    pub compiled_in: Vec<ApiJurisdiction>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiTaxRuleChangeKind {
+    Rate,
+    Threshold,
+    Flag,
+    Treatment,
+    Source,
+    EffectiveDate,
+}
+
+impl From<TaxRuleChangeKind> for ApiTaxRuleChangeKind {
+    fn from(value: TaxRuleChangeKind) -> Self {
+        match value {
+            TaxRuleChangeKind::Rate => ApiTaxRuleChangeKind::Rate,
+            TaxRuleChangeKind::Threshold => ApiTaxRuleChangeKind::Threshold,
+            TaxRuleChangeKind::Flag => ApiTaxRuleChangeKind::Flag,
+            TaxRuleChangeKind::Treatment => ApiTaxRuleChangeKind::Treatment,
+            TaxRuleChangeKind::Source => ApiTaxRuleChangeKind::Source,
+            TaxRuleChangeKind::EffectiveDate => ApiTaxRuleChangeKind::EffectiveDate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiTaxRuleFieldChange {
+    pub field: String,
+    pub kind: ApiTaxRuleChangeKind,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub description: String,
+}
+
+impl From<TaxRuleFieldChange> for ApiTaxRuleFieldChange {
+    fn from(value: TaxRuleFieldChange) -> Self {
+        ApiTaxRuleFieldChange {
+            description: value.description(),
+            field: value.field,
+            kind: value.kind.into(),
+            from: value.from,
+            to: value.to,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JurisdictionTaxRuleVersionDiff {
+    pub jurisdiction: Jurisdiction,
+    pub diff: TaxRuleVersionDiff,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiTaxRuleVersionDiff {
+    pub jurisdiction: ApiJurisdiction,
+    pub from_version_id: String,
+    pub to_version_id: String,
+    pub changes: Vec<ApiTaxRuleFieldChange>,
+}
+
+impl From<JurisdictionTaxRuleVersionDiff> for ApiTaxRuleVersionDiff {
+    fn from(value: JurisdictionTaxRuleVersionDiff) -> Self {
+        ApiTaxRuleVersionDiff {
+            jurisdiction: value.jurisdiction.into(),
+            from_version_id: value.diff.from_version_id.to_string(),
+            to_version_id: value.diff.to_version_id.to_string(),
+            changes: value
+                .diff
+                .changes
+                .into_iter()
+                .map(ApiTaxRuleFieldChange::from)
+                .collect(),
+        }
+    }
+}
@@ -1,11 +1,19 @@
-use super::ApiJurisdiction;
+use super::{ApiCurrency, ApiEstateTaxApportionment, ApiJurisdiction};
 use crate::core::domain::models::{
-    CapitalGainsTaxBreakdown, CombinedTaxLiability, EstateAsset, EstateDutyBreakdown,
-    EstateScenarioInput, LiquidityGapOutput, ResidencyStatus, ScenarioResult,
+    AssetTaxAttributionLine, Beneficiary, CapitalGainsTaxBreakdown, CombinedTaxLiability,
+    CurrencyConversionBreakdown, CurrencyConversionLine, CurrencyLiability, DefaultedField,
+    DistributionBreakdown, EstateAsset, EstateDutyBreakdown, EstateScenarioInput, ExecutorFeeBasis,
+    ExecutorFeeBreakdown, ExplainStage, ExplainStep, ExplainTrace, ExplainValue, FxRate,
+    FxRateTable, HeirCategory, HeirDistributionLine, IncomeTaxBreakdown, LifeInsurancePolicy,
+    LifePolicyLine, LifePolicyTreatment, LiquidityGapOutput, MaritalPropertyBreakdown,
+    MaritalPropertyInput, MaritalPropertyRegime, PolicyParty, RateBracketTaxLine, ResidencyStatus,
+    RetirementBenefitRecipient, RetirementFundInterest, RetirementFundLine, RetirementFundType,
+    RuleCitation, ScenarioResult, StateEstateTaxBreakdown, SteppedUpBasisLine,
 };
 use crate::core::engine::optimizer::OptimizedScenario;
 use crate::core::engine::scoring::{LiquidityRiskBand, ScenarioScore};
 use crate::core::rules::tax_rules::TaxPayerClass;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -13,17 +21,96 @@ use utoipa::ToSchema;
 pub struct ApiEstateAssetInput {
     pub name: String,
     #[serde(alias = "market_value_zar")]
-    pub market_value_amount: f64,
+    pub market_value_amount: Decimal,
     #[serde(alias = "base_cost_zar")]
-    pub base_cost_amount: f64,
+    pub base_cost_amount: Decimal,
     pub is_liquid: bool,
     #[serde(alias = "situs_in_south_africa")]
     pub situs_in_jurisdiction: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub situs_jurisdiction: Option<ApiJurisdiction>,
     pub included_in_estate_duty: bool,
     pub included_in_cgt_deemed_disposal: bool,
     pub bequeathed_to_surviving_spouse: bool,
     pub bequeathed_to_pbo: bool,
     pub qualifies_primary_residence_exclusion: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<ApiCurrency>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCurrencyLiabilityInput {
+    pub description: String,
+    pub currency: ApiCurrency,
+    pub amount: Decimal,
+}
+
+impl From<ApiCurrencyLiabilityInput> for CurrencyLiability {
+    fn from(value: ApiCurrencyLiabilityInput) -> Self {
+        CurrencyLiability {
+            description: value.description,
+            currency: value.currency.into(),
+            amount: value.amount,
+        }
+    }
+}
+
+impl From<CurrencyLiability> for ApiCurrencyLiabilityInput {
+    fn from(value: CurrencyLiability) -> Self {
+        ApiCurrencyLiabilityInput {
+            description: value.description,
+            currency: value.currency.into(),
+            amount: value.amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiFxRateInput {
+    pub currency: ApiCurrency,
+    pub rate: Decimal,
+}
+
+impl From<ApiFxRateInput> for FxRate {
+    fn from(value: ApiFxRateInput) -> Self {
+        FxRate {
+            currency: value.currency.into(),
+            rate: value.rate,
+        }
+    }
+}
+
+impl From<FxRate> for ApiFxRateInput {
+    fn from(value: FxRate) -> Self {
+        ApiFxRateInput {
+            currency: value.currency.into(),
+            rate: value.rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiFxRateTableInput {
+    pub valuation_date: String,
+    pub rates: Vec<ApiFxRateInput>,
+}
+
+impl From<ApiFxRateTableInput> for FxRateTable {
+    fn from(value: ApiFxRateTableInput) -> Self {
+        FxRateTable {
+            valuation_date: value.valuation_date,
+            rates: value.rates.into_iter().map(FxRate::from).collect(),
+        }
+    }
+}
+
+impl From<FxRateTable> for ApiFxRateTableInput {
+    fn from(value: FxRateTable) -> Self {
+        ApiFxRateTableInput {
+            valuation_date: value.valuation_date,
+            rates: value.rates.into_iter().map(ApiFxRateInput::from).collect(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -83,41 +170,348 @@ impl From<ApiResidencyStatus> for ResidencyStatus {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiEstateScenarioInput {
     pub jurisdiction: ApiJurisdiction,
-    pub tax_year: u16,
+    // Optional when `date_of_death` is set; it is then derived from the date.
+    #[serde(default)]
+    pub tax_year: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_of_death: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valuation_date: Option<String>,
     pub taxpayer_class: ApiTaxPayerClass,
     pub residency_status: ApiResidencyStatus,
-    pub marginal_income_tax_rate: f64,
+    #[serde(default)]
+    pub taxable_income_in_year_of_death_amount: Decimal,
+    pub age_at_tax_year_end: Option<u8>,
+    pub marginal_income_tax_rate: Option<Decimal>,
     pub assets: Vec<ApiEstateAssetInput>,
     #[serde(alias = "debts_and_loans_zar")]
-    pub debts_and_loans_amount: f64,
+    pub debts_and_loans_amount: Decimal,
     #[serde(alias = "funeral_costs_zar")]
-    pub funeral_costs_amount: f64,
+    pub funeral_costs_amount: Decimal,
     #[serde(alias = "administration_costs_zar")]
-    pub administration_costs_amount: f64,
+    pub administration_costs_amount: Decimal,
     #[serde(alias = "masters_office_fees_zar")]
-    pub masters_office_fees_amount: f64,
+    pub masters_office_fees_amount: Decimal,
     #[serde(alias = "conveyancing_costs_zar")]
-    pub conveyancing_costs_amount: f64,
+    pub conveyancing_costs_amount: Decimal,
     #[serde(alias = "other_settlement_costs_zar")]
-    pub other_settlement_costs_amount: f64,
+    pub other_settlement_costs_amount: Decimal,
     #[serde(alias = "final_income_tax_due_zar")]
-    pub final_income_tax_due_amount: f64,
+    pub final_income_tax_due_amount: Option<Decimal>,
     #[serde(alias = "ongoing_estate_income_tax_provision_zar")]
-    pub ongoing_estate_income_tax_provision_amount: f64,
+    pub ongoing_estate_income_tax_provision_amount: Decimal,
     #[serde(alias = "additional_allowable_estate_duty_deductions_zar")]
-    pub additional_allowable_estate_transfer_tax_deductions_amount: f64,
+    pub additional_allowable_estate_transfer_tax_deductions_amount: Decimal,
     #[serde(alias = "ported_section_4a_abatement_zar")]
-    pub ported_estate_tax_exemption_amount: f64,
-    #[serde(alias = "primary_residence_cgt_exclusion_cap_zar")]
-    pub primary_residence_cgt_exclusion_cap_amount: f64,
-    pub executor_fee_rate: f64,
-    pub vat_rate: f64,
+    pub ported_estate_tax_exemption_amount: Decimal,
+    #[serde(default, alias = "primary_residence_cgt_exclusion_cap_zar")]
+    pub primary_residence_cgt_exclusion_cap_amount: Option<Decimal>,
+    pub executor_fee_rate: Option<Decimal>,
+    #[serde(default)]
+    pub income_accrued_after_death_amount: Decimal,
+    #[serde(default)]
+    pub vat_rate: Option<Decimal>,
     #[serde(alias = "explicit_executor_fee_zar")]
-    pub explicit_executor_fee_amount: Option<f64>,
+    pub explicit_executor_fee_amount: Option<Decimal>,
     #[serde(alias = "external_liquidity_proceeds_zar")]
-    pub external_liquidity_proceeds_amount: f64,
+    pub external_liquidity_proceeds_amount: Decimal,
     #[serde(alias = "cash_reserve_zar")]
-    pub cash_reserve_amount: f64,
+    pub cash_reserve_amount: Decimal,
+    #[serde(default)]
+    pub marital_property: ApiMaritalPropertyInput,
+    #[serde(default)]
+    pub life_policies: Vec<ApiLifeInsurancePolicyInput>,
+    #[serde(default)]
+    pub retirement_funds: Vec<ApiRetirementFundInput>,
+    #[serde(default)]
+    pub beneficiaries: Vec<ApiBeneficiaryInput>,
+    #[serde(default)]
+    pub tax_apportionment: Option<ApiEstateTaxApportionment>,
+    #[serde(default)]
+    pub liabilities: Vec<ApiCurrencyLiabilityInput>,
+    #[serde(default)]
+    pub fx_rates: Option<ApiFxRateTableInput>,
+    #[serde(default)]
+    pub explain: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiBeneficiaryInput {
+    pub name: String,
+    pub category: ApiHeirCategory,
+    #[serde(default)]
+    pub specific_bequest_asset_names: Vec<String>,
+    #[serde(default)]
+    pub legacy_amount: Decimal,
+    #[serde(default)]
+    pub residue_share: Decimal,
+}
+
+impl From<ApiBeneficiaryInput> for Beneficiary {
+    fn from(value: ApiBeneficiaryInput) -> Self {
+        Beneficiary {
+            name: value.name,
+            category: value.category.into(),
+            specific_bequest_asset_names: value.specific_bequest_asset_names,
+            legacy_amount: value.legacy_amount,
+            residue_share: value.residue_share,
+        }
+    }
+}
+
+impl From<Beneficiary> for ApiBeneficiaryInput {
+    fn from(value: Beneficiary) -> Self {
+        ApiBeneficiaryInput {
+            name: value.name,
+            category: value.category.into(),
+            specific_bequest_asset_names: value.specific_bequest_asset_names,
+            legacy_amount: value.legacy_amount,
+            residue_share: value.residue_share,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiRetirementFundType {
+    PensionFund,
+    ProvidentFund,
+    PreservationFund,
+    RetirementAnnuityFund,
+    LivingAnnuity,
+}
+
+impl From<RetirementFundType> for ApiRetirementFundType {
+    fn from(value: RetirementFundType) -> Self {
+        match value {
+            RetirementFundType::PensionFund => ApiRetirementFundType::PensionFund,
+            RetirementFundType::ProvidentFund => ApiRetirementFundType::ProvidentFund,
+            RetirementFundType::PreservationFund => ApiRetirementFundType::PreservationFund,
+            RetirementFundType::RetirementAnnuityFund => {
+                ApiRetirementFundType::RetirementAnnuityFund
+            }
+            RetirementFundType::LivingAnnuity => ApiRetirementFundType::LivingAnnuity,
+        }
+    }
+}
+
+impl From<ApiRetirementFundType> for RetirementFundType {
+    fn from(value: ApiRetirementFundType) -> Self {
+        match value {
+            ApiRetirementFundType::PensionFund => RetirementFundType::PensionFund,
+            ApiRetirementFundType::ProvidentFund => RetirementFundType::ProvidentFund,
+            ApiRetirementFundType::PreservationFund => RetirementFundType::PreservationFund,
+            ApiRetirementFundType::RetirementAnnuityFund => {
+                RetirementFundType::RetirementAnnuityFund
+            }
+            ApiRetirementFundType::LivingAnnuity => RetirementFundType::LivingAnnuity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiRetirementBenefitRecipient {
+    SurvivingSpouse,
+    Dependants,
+    Estate,
+}
+
+impl From<RetirementBenefitRecipient> for ApiRetirementBenefitRecipient {
+    fn from(value: RetirementBenefitRecipient) -> Self {
+        match value {
+            RetirementBenefitRecipient::SurvivingSpouse => {
+                ApiRetirementBenefitRecipient::SurvivingSpouse
+            }
+            RetirementBenefitRecipient::Dependants => ApiRetirementBenefitRecipient::Dependants,
+            RetirementBenefitRecipient::Estate => ApiRetirementBenefitRecipient::Estate,
+        }
+    }
+}
+
+impl From<ApiRetirementBenefitRecipient> for RetirementBenefitRecipient {
+    fn from(value: ApiRetirementBenefitRecipient) -> Self {
+        match value {
+            ApiRetirementBenefitRecipient::SurvivingSpouse => {
+                RetirementBenefitRecipient::SurvivingSpouse
+            }
+            ApiRetirementBenefitRecipient::Dependants => RetirementBenefitRecipient::Dependants,
+            ApiRetirementBenefitRecipient::Estate => RetirementBenefitRecipient::Estate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRetirementFundInput {
+    pub name: String,
+    pub fund_type: ApiRetirementFundType,
+    pub value_amount: Decimal,
+    #[serde(default)]
+    pub disallowed_contributions_amount: Decimal,
+    pub nominated_recipient: ApiRetirementBenefitRecipient,
+}
+
+impl From<ApiRetirementFundInput> for RetirementFundInterest {
+    fn from(value: ApiRetirementFundInput) -> Self {
+        RetirementFundInterest {
+            name: value.name,
+            fund_type: value.fund_type.into(),
+            value_amount: value.value_amount,
+            disallowed_contributions_amount: value.disallowed_contributions_amount,
+            nominated_recipient: value.nominated_recipient.into(),
+        }
+    }
+}
+
+impl From<RetirementFundInterest> for ApiRetirementFundInput {
+    fn from(value: RetirementFundInterest) -> Self {
+        ApiRetirementFundInput {
+            name: value.name,
+            fund_type: value.fund_type.into(),
+            value_amount: value.value_amount,
+            disallowed_contributions_amount: value.disallowed_contributions_amount,
+            nominated_recipient: value.nominated_recipient.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiPolicyParty {
+    Deceased,
+    Estate,
+    SurvivingSpouse,
+    NamedPerson,
+    Company,
+}
+
+impl From<PolicyParty> for ApiPolicyParty {
+    fn from(value: PolicyParty) -> Self {
+        match value {
+            PolicyParty::Deceased => ApiPolicyParty::Deceased,
+            PolicyParty::Estate => ApiPolicyParty::Estate,
+            PolicyParty::SurvivingSpouse => ApiPolicyParty::SurvivingSpouse,
+            PolicyParty::NamedPerson => ApiPolicyParty::NamedPerson,
+            PolicyParty::Company => ApiPolicyParty::Company,
+        }
+    }
+}
+
+impl From<ApiPolicyParty> for PolicyParty {
+    fn from(value: ApiPolicyParty) -> Self {
+        match value {
+            ApiPolicyParty::Deceased => PolicyParty::Deceased,
+            ApiPolicyParty::Estate => PolicyParty::Estate,
+            ApiPolicyParty::SurvivingSpouse => PolicyParty::SurvivingSpouse,
+            ApiPolicyParty::NamedPerson => PolicyParty::NamedPerson,
+            ApiPolicyParty::Company => PolicyParty::Company,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiLifeInsurancePolicyInput {
+    pub name: String,
+    pub owner: ApiPolicyParty,
+    pub life_insured: ApiPolicyParty,
+    pub beneficiary: ApiPolicyParty,
+    pub sum_assured_amount: Decimal,
+    pub premiums_paid_by: ApiPolicyParty,
+    #[serde(default)]
+    pub held_under_buy_and_sell_agreement: bool,
+}
+
+impl From<ApiLifeInsurancePolicyInput> for LifeInsurancePolicy {
+    fn from(value: ApiLifeInsurancePolicyInput) -> Self {
+        LifeInsurancePolicy {
+            name: value.name,
+            owner: value.owner.into(),
+            life_insured: value.life_insured.into(),
+            beneficiary: value.beneficiary.into(),
+            sum_assured_amount: value.sum_assured_amount,
+            premiums_paid_by: value.premiums_paid_by.into(),
+            held_under_buy_and_sell_agreement: value.held_under_buy_and_sell_agreement,
+        }
+    }
+}
+
+impl From<LifeInsurancePolicy> for ApiLifeInsurancePolicyInput {
+    fn from(value: LifeInsurancePolicy) -> Self {
+        ApiLifeInsurancePolicyInput {
+            name: value.name,
+            owner: value.owner.into(),
+            life_insured: value.life_insured.into(),
+            beneficiary: value.beneficiary.into(),
+            sum_assured_amount: value.sum_assured_amount,
+            premiums_paid_by: value.premiums_paid_by.into(),
+            held_under_buy_and_sell_agreement: value.held_under_buy_and_sell_agreement,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub enum ApiMaritalPropertyRegime {
+    #[default]
+    NotMarried,
+    OutOfCommunity,
+    InCommunity,
+    Accrual,
+}
+
+impl From<MaritalPropertyRegime> for ApiMaritalPropertyRegime {
+    fn from(value: MaritalPropertyRegime) -> Self {
+        match value {
+            MaritalPropertyRegime::NotMarried => ApiMaritalPropertyRegime::NotMarried,
+            MaritalPropertyRegime::OutOfCommunity => ApiMaritalPropertyRegime::OutOfCommunity,
+            MaritalPropertyRegime::InCommunity => ApiMaritalPropertyRegime::InCommunity,
+            MaritalPropertyRegime::Accrual => ApiMaritalPropertyRegime::Accrual,
+        }
+    }
+}
+
+impl From<ApiMaritalPropertyRegime> for MaritalPropertyRegime {
+    fn from(value: ApiMaritalPropertyRegime) -> Self {
+        match value {
+            ApiMaritalPropertyRegime::NotMarried => MaritalPropertyRegime::NotMarried,
+            ApiMaritalPropertyRegime::OutOfCommunity => MaritalPropertyRegime::OutOfCommunity,
+            ApiMaritalPropertyRegime::InCommunity => MaritalPropertyRegime::InCommunity,
+            ApiMaritalPropertyRegime::Accrual => MaritalPropertyRegime::Accrual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct ApiMaritalPropertyInput {
+    pub regime: ApiMaritalPropertyRegime,
+    pub deceased_commencement_value_amount: Decimal,
+    pub deceased_excluded_assets_amount: Decimal,
+    pub spouse_net_estate_amount: Decimal,
+    pub spouse_commencement_value_amount: Decimal,
+    pub spouse_excluded_assets_amount: Decimal,
+}
+
+impl From<ApiMaritalPropertyInput> for MaritalPropertyInput {
+    fn from(value: ApiMaritalPropertyInput) -> Self {
+        MaritalPropertyInput {
+            regime: value.regime.into(),
+            deceased_commencement_value_amount: value.deceased_commencement_value_amount,
+            deceased_excluded_assets_amount: value.deceased_excluded_assets_amount,
+            spouse_net_estate_amount: value.spouse_net_estate_amount,
+            spouse_commencement_value_amount: value.spouse_commencement_value_amount,
+            spouse_excluded_assets_amount: value.spouse_excluded_assets_amount,
+        }
+    }
+}
+
+impl From<MaritalPropertyInput> for ApiMaritalPropertyInput {
+    fn from(value: MaritalPropertyInput) -> Self {
+        ApiMaritalPropertyInput {
+            regime: value.regime.into(),
+            deceased_commencement_value_amount: value.deceased_commencement_value_amount,
+            deceased_excluded_assets_amount: value.deceased_excluded_assets_amount,
+            spouse_net_estate_amount: value.spouse_net_estate_amount,
+            spouse_commencement_value_amount: value.spouse_commencement_value_amount,
+            spouse_excluded_assets_amount: value.spouse_excluded_assets_amount,
+        }
+    }
 }
 
 impl From<ApiEstateAssetInput> for EstateAsset {
@@ -128,11 +522,13 @@ impl From<ApiEstateAssetInput> for EstateAsset {
             base_cost_amount: value.base_cost_amount,
             is_liquid: value.is_liquid,
             situs_in_jurisdiction: value.situs_in_jurisdiction,
+            situs_jurisdiction: value.situs_jurisdiction.map(Into::into),
             included_in_estate_duty: value.included_in_estate_duty,
             included_in_cgt_deemed_disposal: value.included_in_cgt_deemed_disposal,
             bequeathed_to_surviving_spouse: value.bequeathed_to_surviving_spouse,
             bequeathed_to_pbo: value.bequeathed_to_pbo,
             qualifies_primary_residence_exclusion: value.qualifies_primary_residence_exclusion,
+            currency: value.currency.map(Into::into),
         }
     }
 }
@@ -145,11 +541,13 @@ impl From<EstateAsset> for ApiEstateAssetInput {
             base_cost_amount: value.base_cost_amount,
             is_liquid: value.is_liquid,
             situs_in_jurisdiction: value.situs_in_jurisdiction,
+            situs_jurisdiction: value.situs_jurisdiction.map(Into::into),
             included_in_estate_duty: value.included_in_estate_duty,
             included_in_cgt_deemed_disposal: value.included_in_cgt_deemed_disposal,
             bequeathed_to_surviving_spouse: value.bequeathed_to_surviving_spouse,
             bequeathed_to_pbo: value.bequeathed_to_pbo,
             qualifies_primary_residence_exclusion: value.qualifies_primary_residence_exclusion,
+            currency: value.currency.map(Into::into),
         }
     }
 }
@@ -158,9 +556,14 @@ impl From<ApiEstateScenarioInput> for EstateScenarioInput {
     fn from(value: ApiEstateScenarioInput) -> Self {
         EstateScenarioInput {
             jurisdiction: value.jurisdiction.into(),
-            tax_year: value.tax_year,
+            // Left at 0 when omitted; the engine derives it from the date of death.
+            tax_year: value.tax_year.unwrap_or_default(),
+            date_of_death: value.date_of_death,
+            valuation_date: value.valuation_date,
             taxpayer_class: value.taxpayer_class.into(),
             residency_status: value.residency_status.into(),
+            taxable_income_in_year_of_death_amount: value.taxable_income_in_year_of_death_amount,
+            age_at_tax_year_end: value.age_at_tax_year_end,
             marginal_income_tax_rate: value.marginal_income_tax_rate,
             assets: value.assets.into_iter().map(EstateAsset::from).collect(),
             debts_and_loans_amount: value.debts_and_loans_amount,
@@ -178,10 +581,35 @@ impl From<ApiEstateScenarioInput> for EstateScenarioInput {
             primary_residence_cgt_exclusion_cap_amount: value
                 .primary_residence_cgt_exclusion_cap_amount,
             executor_fee_rate: value.executor_fee_rate,
+            income_accrued_after_death_amount: value.income_accrued_after_death_amount,
             vat_rate: value.vat_rate,
             explicit_executor_fee_amount: value.explicit_executor_fee_amount,
             external_liquidity_proceeds_amount: value.external_liquidity_proceeds_amount,
             cash_reserve_amount: value.cash_reserve_amount,
+            marital_property: value.marital_property.into(),
+            life_policies: value
+                .life_policies
+                .into_iter()
+                .map(LifeInsurancePolicy::from)
+                .collect(),
+            retirement_funds: value
+                .retirement_funds
+                .into_iter()
+                .map(RetirementFundInterest::from)
+                .collect(),
+            beneficiaries: value
+                .beneficiaries
+                .into_iter()
+                .map(Beneficiary::from)
+                .collect(),
+            tax_apportionment: value.tax_apportionment.map(Into::into),
+            liabilities: value
+                .liabilities
+                .into_iter()
+                .map(CurrencyLiability::from)
+                .collect(),
+            fx_rates: value.fx_rates.map(Into::into),
+            explain: value.explain,
         }
     }
 }
@@ -190,9 +618,13 @@ impl From<EstateScenarioInput> for ApiEstateScenarioInput {
     fn from(value: EstateScenarioInput) -> Self {
         ApiEstateScenarioInput {
             jurisdiction: value.jurisdiction.into(),
-            tax_year: value.tax_year,
+            tax_year: Some(value.tax_year),
+            date_of_death: value.date_of_death,
+            valuation_date: value.valuation_date,
             taxpayer_class: value.taxpayer_class.into(),
             residency_status: value.residency_status.into(),
+            taxable_income_in_year_of_death_amount: value.taxable_income_in_year_of_death_amount,
+            age_at_tax_year_end: value.age_at_tax_year_end,
             marginal_income_tax_rate: value.marginal_income_tax_rate,
             assets: value
                 .assets
@@ -214,74 +646,683 @@ impl From<EstateScenarioInput> for ApiEstateScenarioInput {
             primary_residence_cgt_exclusion_cap_amount: value
                 .primary_residence_cgt_exclusion_cap_amount,
             executor_fee_rate: value.executor_fee_rate,
+            income_accrued_after_death_amount: value.income_accrued_after_death_amount,
             vat_rate: value.vat_rate,
             explicit_executor_fee_amount: value.explicit_executor_fee_amount,
             external_liquidity_proceeds_amount: value.external_liquidity_proceeds_amount,
             cash_reserve_amount: value.cash_reserve_amount,
+            marital_property: value.marital_property.into(),
+            life_policies: value
+                .life_policies
+                .into_iter()
+                .map(ApiLifeInsurancePolicyInput::from)
+                .collect(),
+            retirement_funds: value
+                .retirement_funds
+                .into_iter()
+                .map(ApiRetirementFundInput::from)
+                .collect(),
+            beneficiaries: value
+                .beneficiaries
+                .into_iter()
+                .map(ApiBeneficiaryInput::from)
+                .collect(),
+            tax_apportionment: value.tax_apportionment.map(Into::into),
+            liabilities: value
+                .liabilities
+                .into_iter()
+                .map(ApiCurrencyLiabilityInput::from)
+                .collect(),
+            fx_rates: value.fx_rates.map(Into::into),
+            explain: value.explain,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiHeirCategory {
+    SurvivingSpouse,
+    PublicBenefitOrganisation,
+    OtherHeirs,
+}
+
+impl From<HeirCategory> for ApiHeirCategory {
+    fn from(value: HeirCategory) -> Self {
+        match value {
+            HeirCategory::SurvivingSpouse => ApiHeirCategory::SurvivingSpouse,
+            HeirCategory::PublicBenefitOrganisation => ApiHeirCategory::PublicBenefitOrganisation,
+            HeirCategory::OtherHeirs => ApiHeirCategory::OtherHeirs,
+        }
+    }
+}
+
+impl From<ApiHeirCategory> for HeirCategory {
+    fn from(value: ApiHeirCategory) -> Self {
+        match value {
+            ApiHeirCategory::SurvivingSpouse => HeirCategory::SurvivingSpouse,
+            ApiHeirCategory::PublicBenefitOrganisation => HeirCategory::PublicBenefitOrganisation,
+            ApiHeirCategory::OtherHeirs => HeirCategory::OtherHeirs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiSteppedUpBasisLine {
+    pub asset_name: String,
+    pub heir: ApiHeirCategory,
+    pub original_base_cost_amount: Decimal,
+    pub stepped_up_basis_amount: Decimal,
+    pub basis_step_up_amount: Decimal,
+}
+
+impl From<SteppedUpBasisLine> for ApiSteppedUpBasisLine {
+    fn from(value: SteppedUpBasisLine) -> Self {
+        ApiSteppedUpBasisLine {
+            asset_name: value.asset_name,
+            heir: value.heir.into(),
+            original_base_cost_amount: value.original_base_cost_amount,
+            stepped_up_basis_amount: value.stepped_up_basis_amount,
+            basis_step_up_amount: value.basis_step_up_amount,
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiCapitalGainsTaxBreakdown {
-    pub gross_capital_gain_amount: f64,
-    pub primary_residence_exclusion_used_amount: f64,
-    pub annual_exclusion_used_amount: f64,
-    pub inclusion_rate: f64,
-    pub taxable_capital_gain_in_income_amount: f64,
-    pub tax_payable_amount: f64,
+    pub deemed_disposal_applies: bool,
+    pub gross_capital_gain_amount: Decimal,
+    pub primary_residence_exclusion_used_amount: Decimal,
+    pub spousal_rollover_gain_amount: Decimal,
+    pub spouse_inherited_base_cost_amount: Decimal,
+    pub pbo_disregarded_gain_amount: Decimal,
+    pub retirement_fund_disregarded_amount: Decimal,
+    pub retirement_fund_gain_amount: Decimal,
+    pub retirement_fund_cgt_attributed_amount: Decimal,
+    pub annual_exclusion_used_amount: Decimal,
+    pub inclusion_rate: Decimal,
+    pub taxable_capital_gain_in_income_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+    pub stepped_up_basis_total_amount: Decimal,
+    pub stepped_up_basis: Vec<ApiSteppedUpBasisLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRateBracketTaxLine {
+    pub from_amount: Decimal,
+    pub to_amount: Option<Decimal>,
+    pub rate: Decimal,
+    pub taxable_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiIncomeTaxBreakdown {
+    pub taxable_income_excluding_capital_gain_amount: Decimal,
+    pub taxable_capital_gain_amount: Decimal,
+    pub taxable_income_amount: Decimal,
+    pub bracket_taxes: Vec<ApiRateBracketTaxLine>,
+    pub tax_before_rebates_amount: Decimal,
+    pub rebates_amount: Decimal,
+    pub normal_tax_amount: Decimal,
+    pub tax_on_income_excluding_capital_gain_amount: Decimal,
+    pub tax_on_capital_gain_amount: Decimal,
+    pub marginal_rate: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiExecutorFeeBasis {
+    Tariff,
+    FlatRateOverride,
+    ExplicitAmount,
+    NoTariff,
+}
+
+impl From<ExecutorFeeBasis> for ApiExecutorFeeBasis {
+    fn from(value: ExecutorFeeBasis) -> Self {
+        match value {
+            ExecutorFeeBasis::Tariff => ApiExecutorFeeBasis::Tariff,
+            ExecutorFeeBasis::FlatRateOverride => ApiExecutorFeeBasis::FlatRateOverride,
+            ExecutorFeeBasis::ExplicitAmount => ApiExecutorFeeBasis::ExplicitAmount,
+            ExecutorFeeBasis::NoTariff => ApiExecutorFeeBasis::NoTariff,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiExecutorFeeBreakdown {
+    pub basis: ApiExecutorFeeBasis,
+    pub gross_assets_amount: Decimal,
+    pub income_accrued_after_death_amount: Decimal,
+    pub bracket_fees: Vec<ApiRateBracketTaxLine>,
+    pub asset_fee_amount: Decimal,
+    pub income_fee_amount: Decimal,
+    pub minimum_fee_amount: Decimal,
+    pub fee_before_vat_amount: Decimal,
+    pub vat_amount: Decimal,
+    pub executor_fee_amount: Decimal,
+}
+
+impl From<ExecutorFeeBreakdown> for ApiExecutorFeeBreakdown {
+    fn from(value: ExecutorFeeBreakdown) -> Self {
+        ApiExecutorFeeBreakdown {
+            basis: value.basis.into(),
+            gross_assets_amount: value.gross_assets_amount,
+            income_accrued_after_death_amount: value.income_accrued_after_death_amount,
+            bracket_fees: value
+                .bracket_fees
+                .into_iter()
+                .map(ApiRateBracketTaxLine::from)
+                .collect(),
+            asset_fee_amount: value.asset_fee_amount,
+            income_fee_amount: value.income_fee_amount,
+            minimum_fee_amount: value.minimum_fee_amount,
+            fee_before_vat_amount: value.fee_before_vat_amount,
+            vat_amount: value.vat_amount,
+            executor_fee_amount: value.executor_fee_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiMaritalPropertyBreakdown {
+    pub regime: ApiMaritalPropertyRegime,
+    pub spouse_share_of_joint_estate_amount: Decimal,
+    pub deceased_accrual_amount: Decimal,
+    pub spouse_accrual_amount: Decimal,
+    pub accrual_claim_against_estate_amount: Decimal,
+    pub accrual_claim_by_estate_amount: Decimal,
+}
+
+impl From<MaritalPropertyBreakdown> for ApiMaritalPropertyBreakdown {
+    fn from(value: MaritalPropertyBreakdown) -> Self {
+        ApiMaritalPropertyBreakdown {
+            regime: value.regime.into(),
+            spouse_share_of_joint_estate_amount: value.spouse_share_of_joint_estate_amount,
+            deceased_accrual_amount: value.deceased_accrual_amount,
+            spouse_accrual_amount: value.spouse_accrual_amount,
+            accrual_claim_against_estate_amount: value.accrual_claim_against_estate_amount,
+            accrual_claim_by_estate_amount: value.accrual_claim_by_estate_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiLifePolicyTreatment {
+    DeemedProperty,
+    ExemptBuyAndSell,
+    ExemptThirdPartyOwned,
+    NotOnDeceasedLife,
+}
+
+impl From<LifePolicyTreatment> for ApiLifePolicyTreatment {
+    fn from(value: LifePolicyTreatment) -> Self {
+        match value {
+            LifePolicyTreatment::DeemedProperty => ApiLifePolicyTreatment::DeemedProperty,
+            LifePolicyTreatment::ExemptBuyAndSell => ApiLifePolicyTreatment::ExemptBuyAndSell,
+            LifePolicyTreatment::ExemptThirdPartyOwned => {
+                ApiLifePolicyTreatment::ExemptThirdPartyOwned
+            }
+            LifePolicyTreatment::NotOnDeceasedLife => ApiLifePolicyTreatment::NotOnDeceasedLife,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiLifePolicyLine {
+    pub name: String,
+    pub treatment: ApiLifePolicyTreatment,
+    pub sum_assured_amount: Decimal,
+    pub included_in_estate_amount: Decimal,
+    pub spousal_deduction_amount: Decimal,
+    pub liquidity_to_estate_amount: Decimal,
+}
+
+impl From<LifePolicyLine> for ApiLifePolicyLine {
+    fn from(value: LifePolicyLine) -> Self {
+        ApiLifePolicyLine {
+            name: value.name,
+            treatment: value.treatment.into(),
+            sum_assured_amount: value.sum_assured_amount,
+            included_in_estate_amount: value.included_in_estate_amount,
+            spousal_deduction_amount: value.spousal_deduction_amount,
+            liquidity_to_estate_amount: value.liquidity_to_estate_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRetirementFundLine {
+    pub name: String,
+    pub fund_type: ApiRetirementFundType,
+    pub value_amount: Decimal,
+    pub included_in_estate_amount: Decimal,
+    pub spousal_deduction_amount: Decimal,
+    pub liquidity_to_estate_amount: Decimal,
+}
+
+impl From<RetirementFundLine> for ApiRetirementFundLine {
+    fn from(value: RetirementFundLine) -> Self {
+        ApiRetirementFundLine {
+            name: value.name,
+            fund_type: value.fund_type.into(),
+            value_amount: value.value_amount,
+            included_in_estate_amount: value.included_in_estate_amount,
+            spousal_deduction_amount: value.spousal_deduction_amount,
+            liquidity_to_estate_amount: value.liquidity_to_estate_amount,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiEstateDutyBreakdown {
-    pub gross_estate_for_transfer_tax_amount: f64,
-    pub executor_fee_amount: f64,
-    pub spousal_deduction_amount: f64,
-    pub pbo_deduction_amount: f64,
-    pub total_allowable_deductions_amount: f64,
-    pub exemption_used_amount: f64,
-    pub dutiable_estate_after_exemption_amount: f64,
-    pub tax_payable_amount: f64,
+    pub gross_estate_for_transfer_tax_amount: Decimal,
+    pub accrual_claim_asset_amount: Decimal,
+    pub life_policy_deemed_property_amount: Decimal,
+    pub life_policies: Vec<ApiLifePolicyLine>,
+    pub retirement_fund_included_amount: Decimal,
+    pub retirement_funds: Vec<ApiRetirementFundLine>,
+    pub executor_fee: ApiExecutorFeeBreakdown,
+    pub executor_fee_amount: Decimal,
+    pub spousal_deduction_amount: Decimal,
+    pub pbo_deduction_amount: Decimal,
+    pub accrual_claim_deduction_amount: Decimal,
+    pub state_estate_tax_deduction_amount: Decimal,
+    pub total_allowable_deductions_amount: Decimal,
+    pub exemption_used_amount: Decimal,
+    pub dutiable_estate_after_exemption_amount: Decimal,
+    pub bracket_taxes: Vec<ApiRateBracketTaxLine>,
+    pub unified_credit_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiStateEstateTaxBreakdown {
+    pub state_taxable_estate_amount: Decimal,
+    pub exemption_amount: Decimal,
+    pub tentative_tax_amount: Decimal,
+    pub credit_amount: Decimal,
+    pub tax_payable_amount: Decimal,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiCombinedTaxLiability {
-    pub estate_transfer_tax_amount: f64,
-    pub cgt_on_death_amount: f64,
-    pub final_income_tax_amount: f64,
-    pub ongoing_estate_income_tax_provision_amount: f64,
-    pub total_tax_liability_amount: f64,
+    pub estate_transfer_tax_amount: Decimal,
+    pub state_estate_tax_amount: Decimal,
+    pub cgt_on_death_amount: Decimal,
+    pub final_income_tax_amount: Decimal,
+    pub ongoing_estate_income_tax_provision_amount: Decimal,
+    pub total_tax_liability_amount: Decimal,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiLiquidityGapOutput {
-    pub liquid_assets_in_estate_amount: f64,
-    pub external_liquidity_proceeds_amount: f64,
-    pub cash_reserve_amount: f64,
-    pub total_available_liquidity_amount: f64,
-    pub executor_fee_amount: f64,
-    pub immediate_cash_requirements_amount: f64,
-    pub liquidity_gap_amount: f64,
-    pub liquidity_surplus_amount: f64,
+    pub liquid_assets_in_estate_amount: Decimal,
+    pub life_policy_proceeds_to_estate_amount: Decimal,
+    pub retirement_fund_proceeds_to_estate_amount: Decimal,
+    pub external_liquidity_proceeds_amount: Decimal,
+    pub cash_reserve_amount: Decimal,
+    pub total_available_liquidity_amount: Decimal,
+    pub executor_fee_amount: Decimal,
+    pub accrual_claim_payable_amount: Decimal,
+    pub immediate_cash_requirements_amount: Decimal,
+    pub liquidity_gap_amount: Decimal,
+    pub liquidity_surplus_amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiHeirDistributionLine {
+    pub name: String,
+    pub category: ApiHeirCategory,
+    pub specific_bequests_amount: Decimal,
+    pub legacy_amount: Decimal,
+    pub residue_amount: Decimal,
+    pub abatement_amount: Decimal,
+    pub gross_inheritance_amount: Decimal,
+    pub estate_tax_borne_amount: Decimal,
+    pub net_inheritance_amount: Decimal,
+}
+
+impl From<HeirDistributionLine> for ApiHeirDistributionLine {
+    fn from(value: HeirDistributionLine) -> Self {
+        ApiHeirDistributionLine {
+            name: value.name,
+            category: value.category.into(),
+            specific_bequests_amount: value.specific_bequests_amount,
+            legacy_amount: value.legacy_amount,
+            residue_amount: value.residue_amount,
+            abatement_amount: value.abatement_amount,
+            gross_inheritance_amount: value.gross_inheritance_amount,
+            estate_tax_borne_amount: value.estate_tax_borne_amount,
+            net_inheritance_amount: value.net_inheritance_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDistributionBreakdown {
+    pub tax_apportionment: ApiEstateTaxApportionment,
+    pub distributable_assets_amount: Decimal,
+    pub costs_and_liabilities_amount: Decimal,
+    pub estate_taxes_amount: Decimal,
+    pub specific_bequests_amount: Decimal,
+    pub legacies_amount: Decimal,
+    pub abatement_amount: Decimal,
+    pub residue_amount: Decimal,
+    pub undistributed_residue_amount: Decimal,
+    pub heirs: Vec<ApiHeirDistributionLine>,
+    pub total_net_inheritance_amount: Decimal,
+}
+
+impl From<DistributionBreakdown> for ApiDistributionBreakdown {
+    fn from(value: DistributionBreakdown) -> Self {
+        ApiDistributionBreakdown {
+            tax_apportionment: value.tax_apportionment.into(),
+            distributable_assets_amount: value.distributable_assets_amount,
+            costs_and_liabilities_amount: value.costs_and_liabilities_amount,
+            estate_taxes_amount: value.estate_taxes_amount,
+            specific_bequests_amount: value.specific_bequests_amount,
+            legacies_amount: value.legacies_amount,
+            abatement_amount: value.abatement_amount,
+            residue_amount: value.residue_amount,
+            undistributed_residue_amount: value.undistributed_residue_amount,
+            heirs: value
+                .heirs
+                .into_iter()
+                .map(ApiHeirDistributionLine::from)
+                .collect(),
+            total_net_inheritance_amount: value.total_net_inheritance_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiAssetTaxAttributionLine {
+    pub asset_name: String,
+    pub market_value_amount: Decimal,
+    pub capital_gain_amount: Decimal,
+    pub primary_residence_exclusion_used_amount: Decimal,
+    pub gain_disregarded_amount: Decimal,
+    pub annual_exclusion_used_amount: Decimal,
+    pub cgt_attributed_amount: Decimal,
+    pub included_in_estate_amount: Decimal,
+    pub deductions_attributable_amount: Decimal,
+    pub estate_duty_attributed_amount: Decimal,
+    pub state_estate_tax_attributed_amount: Decimal,
+    pub total_tax_attributed_amount: Decimal,
+}
+
+impl From<AssetTaxAttributionLine> for ApiAssetTaxAttributionLine {
+    fn from(value: AssetTaxAttributionLine) -> Self {
+        ApiAssetTaxAttributionLine {
+            asset_name: value.asset_name,
+            market_value_amount: value.market_value_amount,
+            capital_gain_amount: value.capital_gain_amount,
+            primary_residence_exclusion_used_amount: value.primary_residence_exclusion_used_amount,
+            gain_disregarded_amount: value.gain_disregarded_amount,
+            annual_exclusion_used_amount: value.annual_exclusion_used_amount,
+            cgt_attributed_amount: value.cgt_attributed_amount,
+            included_in_estate_amount: value.included_in_estate_amount,
+            deductions_attributable_amount: value.deductions_attributable_amount,
+            estate_duty_attributed_amount: value.estate_duty_attributed_amount,
+            state_estate_tax_attributed_amount: value.state_estate_tax_attributed_amount,
+            total_tax_attributed_amount: value.total_tax_attributed_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCurrencyConversionLine {
+    pub item_name: String,
+    pub field: String,
+    pub currency: ApiCurrency,
+    pub rate: Decimal,
+    pub original_amount: Decimal,
+    pub reporting_amount: Decimal,
+}
+
+impl From<CurrencyConversionLine> for ApiCurrencyConversionLine {
+    fn from(value: CurrencyConversionLine) -> Self {
+        ApiCurrencyConversionLine {
+            item_name: value.item_name,
+            field: value.field,
+            currency: value.currency.into(),
+            rate: value.rate,
+            original_amount: value.original_amount,
+            reporting_amount: value.reporting_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiCurrencyConversionBreakdown {
+    pub reporting_currency: ApiCurrency,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valuation_date: Option<String>,
+    pub lines: Vec<ApiCurrencyConversionLine>,
+}
+
+impl From<CurrencyConversionBreakdown> for ApiCurrencyConversionBreakdown {
+    fn from(value: CurrencyConversionBreakdown) -> Self {
+        ApiCurrencyConversionBreakdown {
+            reporting_currency: value.reporting_currency.into(),
+            valuation_date: value.valuation_date,
+            lines: value
+                .lines
+                .into_iter()
+                .map(ApiCurrencyConversionLine::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiExplainStage {
+    CurrencyConversion,
+    MaritalProperty,
+    CapitalGainsTax,
+    IncomeTax,
+    ExecutorFee,
+    EstateDuty,
+    StateEstateTax,
+    CombinedTax,
+    Liquidity,
+    Distribution,
+}
+
+impl From<ExplainStage> for ApiExplainStage {
+    fn from(value: ExplainStage) -> Self {
+        match value {
+            ExplainStage::CurrencyConversion => ApiExplainStage::CurrencyConversion,
+            ExplainStage::MaritalProperty => ApiExplainStage::MaritalProperty,
+            ExplainStage::CapitalGainsTax => ApiExplainStage::CapitalGainsTax,
+            ExplainStage::IncomeTax => ApiExplainStage::IncomeTax,
+            ExplainStage::ExecutorFee => ApiExplainStage::ExecutorFee,
+            ExplainStage::EstateDuty => ApiExplainStage::EstateDuty,
+            ExplainStage::StateEstateTax => ApiExplainStage::StateEstateTax,
+            ExplainStage::CombinedTax => ApiExplainStage::CombinedTax,
+            ExplainStage::Liquidity => ApiExplainStage::Liquidity,
+            ExplainStage::Distribution => ApiExplainStage::Distribution,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiExplainValue {
+    pub name: String,
+    pub value: Decimal,
+}
+
+impl From<ExplainValue> for ApiExplainValue {
+    fn from(value: ExplainValue) -> Self {
+        ApiExplainValue {
+            name: value.name,
+            value: value.value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRuleCitation {
+    pub rule_field: String,
+    pub source: String,
+    pub source_url: String,
+}
+
+impl From<RuleCitation> for ApiRuleCitation {
+    fn from(value: RuleCitation) -> Self {
+        ApiRuleCitation {
+            rule_field: value.rule_field,
+            source: value.source.to_string(),
+            source_url: value.source_url.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiExplainStep {
+    pub step: usize,
+    pub stage: ApiExplainStage,
+    pub description: String,
+    pub formula: String,
+    pub inputs: Vec<ApiExplainValue>,
+    pub value: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<ApiRuleCitation>,
+}
+
+impl From<ExplainStep> for ApiExplainStep {
+    fn from(value: ExplainStep) -> Self {
+        ApiExplainStep {
+            step: value.step,
+            stage: value.stage.into(),
+            description: value.description,
+            formula: value.formula,
+            inputs: value
+                .inputs
+                .into_iter()
+                .map(ApiExplainValue::from)
+                .collect(),
+            value: value.value,
+            rule: value.rule.map(ApiRuleCitation::from),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiExplainTrace {
+    pub jurisdiction: ApiJurisdiction,
+    pub tax_year: u16,
+    pub rule_version_id: String,
+    pub reporting_currency: ApiCurrency,
+    pub steps: Vec<ApiExplainStep>,
+}
+
+impl From<ExplainTrace> for ApiExplainTrace {
+    fn from(value: ExplainTrace) -> Self {
+        ApiExplainTrace {
+            jurisdiction: value.jurisdiction.into(),
+            tax_year: value.tax_year,
+            rule_version_id: value.rule_version_id.to_string(),
+            reporting_currency: value.reporting_currency.into(),
+            steps: value.steps.into_iter().map(ApiExplainStep::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiDefaultedField {
+    pub field: String,
+    pub value: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<ApiRuleCitation>,
+}
+
+impl From<DefaultedField> for ApiDefaultedField {
+    fn from(value: DefaultedField) -> Self {
+        ApiDefaultedField {
+            field: value.field,
+            value: value.value,
+            rule: value.rule.map(ApiRuleCitation::from),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiScenarioResult {
+    pub marital_property: ApiMaritalPropertyBreakdown,
+    pub income_tax: ApiIncomeTaxBreakdown,
     pub cgt: ApiCapitalGainsTaxBreakdown,
     pub estate_duty: ApiEstateDutyBreakdown,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_estate_tax: Option<ApiStateEstateTaxBreakdown>,
     pub combined_tax: ApiCombinedTaxLiability,
     pub liquidity: ApiLiquidityGapOutput,
+    pub distribution: ApiDistributionBreakdown,
+    pub assets: Vec<ApiAssetTaxAttributionLine>,
+    pub currency: ApiCurrencyConversionBreakdown,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain: Option<ApiExplainTrace>,
+    #[serde(default)]
+    pub defaulted_fields: Vec<ApiDefaultedField>,
 }
 
 impl From<CapitalGainsTaxBreakdown> for ApiCapitalGainsTaxBreakdown {
     fn from(value: CapitalGainsTaxBreakdown) -> Self {
         ApiCapitalGainsTaxBreakdown {
+            deemed_disposal_applies: value.deemed_disposal_applies,
             gross_capital_gain_amount: value.gross_capital_gain_amount,
             primary_residence_exclusion_used_amount: value.primary_residence_exclusion_used_amount,
+            spousal_rollover_gain_amount: value.spousal_rollover_gain_amount,
+            spouse_inherited_base_cost_amount: value.spouse_inherited_base_cost_amount,
+            pbo_disregarded_gain_amount: value.pbo_disregarded_gain_amount,
+            retirement_fund_disregarded_amount: value.retirement_fund_disregarded_amount,
+            retirement_fund_gain_amount: value.retirement_fund_gain_amount,
+            retirement_fund_cgt_attributed_amount: value.retirement_fund_cgt_attributed_amount,
             annual_exclusion_used_amount: value.annual_exclusion_used_amount,
             inclusion_rate: value.inclusion_rate,
             taxable_capital_gain_in_income_amount: value.taxable_capital_gain_in_income_amount,
             tax_payable_amount: value.tax_payable_amount,
+            stepped_up_basis_total_amount: value.stepped_up_basis_total_amount,
+            stepped_up_basis: value
+                .stepped_up_basis
+                .into_iter()
+                .map(ApiSteppedUpBasisLine::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<RateBracketTaxLine> for ApiRateBracketTaxLine {
+    fn from(value: RateBracketTaxLine) -> Self {
+        ApiRateBracketTaxLine {
+            from_amount: value.from_amount,
+            to_amount: value.to_amount,
+            rate: value.rate,
+            taxable_amount: value.taxable_amount,
+            tax_amount: value.tax_amount,
+        }
+    }
+}
+
+impl From<IncomeTaxBreakdown> for ApiIncomeTaxBreakdown {
+    fn from(value: IncomeTaxBreakdown) -> Self {
+        ApiIncomeTaxBreakdown {
+            taxable_income_excluding_capital_gain_amount: value
+                .taxable_income_excluding_capital_gain_amount,
+            taxable_capital_gain_amount: value.taxable_capital_gain_amount,
+            taxable_income_amount: value.taxable_income_amount,
+            bracket_taxes: value
+                .bracket_taxes
+                .into_iter()
+                .map(ApiRateBracketTaxLine::from)
+                .collect(),
+            tax_before_rebates_amount: value.tax_before_rebates_amount,
+            rebates_amount: value.rebates_amount,
+            normal_tax_amount: value.normal_tax_amount,
+            tax_on_income_excluding_capital_gain_amount: value
+                .tax_on_income_excluding_capital_gain_amount,
+            tax_on_capital_gain_amount: value.tax_on_capital_gain_amount,
+            marginal_rate: value.marginal_rate,
         }
     }
 }
@@ -290,12 +1331,46 @@ impl From<EstateDutyBreakdown> for ApiEstateDutyBreakdown {
     fn from(value: EstateDutyBreakdown) -> Self {
         ApiEstateDutyBreakdown {
             gross_estate_for_transfer_tax_amount: value.gross_estate_for_transfer_tax_amount,
+            accrual_claim_asset_amount: value.accrual_claim_asset_amount,
+            life_policy_deemed_property_amount: value.life_policy_deemed_property_amount,
+            life_policies: value
+                .life_policies
+                .into_iter()
+                .map(ApiLifePolicyLine::from)
+                .collect(),
+            retirement_fund_included_amount: value.retirement_fund_included_amount,
+            retirement_funds: value
+                .retirement_funds
+                .into_iter()
+                .map(ApiRetirementFundLine::from)
+                .collect(),
+            executor_fee: value.executor_fee.into(),
             executor_fee_amount: value.executor_fee_amount,
             spousal_deduction_amount: value.spousal_deduction_amount,
             pbo_deduction_amount: value.pbo_deduction_amount,
+            accrual_claim_deduction_amount: value.accrual_claim_deduction_amount,
+            state_estate_tax_deduction_amount: value.state_estate_tax_deduction_amount,
             total_allowable_deductions_amount: value.total_allowable_deductions_amount,
             exemption_used_amount: value.exemption_used_amount,
             dutiable_estate_after_exemption_amount: value.dutiable_estate_after_exemption_amount,
+            bracket_taxes: value
+                .bracket_taxes
+                .into_iter()
+                .map(ApiRateBracketTaxLine::from)
+                .collect(),
+            unified_credit_amount: value.unified_credit_amount,
+            tax_payable_amount: value.tax_payable_amount,
+        }
+    }
+}
+
+impl From<StateEstateTaxBreakdown> for ApiStateEstateTaxBreakdown {
+    fn from(value: StateEstateTaxBreakdown) -> Self {
+        ApiStateEstateTaxBreakdown {
+            state_taxable_estate_amount: value.state_taxable_estate_amount,
+            exemption_amount: value.exemption_amount,
+            tentative_tax_amount: value.tentative_tax_amount,
+            credit_amount: value.credit_amount,
             tax_payable_amount: value.tax_payable_amount,
         }
     }
@@ -305,6 +1380,7 @@ impl From<CombinedTaxLiability> for ApiCombinedTaxLiability {
     fn from(value: CombinedTaxLiability) -> Self {
         ApiCombinedTaxLiability {
             estate_transfer_tax_amount: value.estate_transfer_tax_amount,
+            state_estate_tax_amount: value.state_estate_tax_amount,
             cgt_on_death_amount: value.cgt_on_death_amount,
             final_income_tax_amount: value.final_income_tax_amount,
             ongoing_estate_income_tax_provision_amount: value
@@ -318,10 +1394,14 @@ impl From<LiquidityGapOutput> for ApiLiquidityGapOutput {
     fn from(value: LiquidityGapOutput) -> Self {
         ApiLiquidityGapOutput {
             liquid_assets_in_estate_amount: value.liquid_assets_in_estate_amount,
+            life_policy_proceeds_to_estate_amount: value.life_policy_proceeds_to_estate_amount,
+            retirement_fund_proceeds_to_estate_amount: value
+                .retirement_fund_proceeds_to_estate_amount,
             external_liquidity_proceeds_amount: value.external_liquidity_proceeds_amount,
             cash_reserve_amount: value.cash_reserve_amount,
             total_available_liquidity_amount: value.total_available_liquidity_amount,
             executor_fee_amount: value.executor_fee_amount,
+            accrual_claim_payable_amount: value.accrual_claim_payable_amount,
             immediate_cash_requirements_amount: value.immediate_cash_requirements_amount,
             liquidity_gap_amount: value.liquidity_gap_amount,
             liquidity_surplus_amount: value.liquidity_surplus_amount,
@@ -332,10 +1412,26 @@ impl From<LiquidityGapOutput> for ApiLiquidityGapOutput {
 impl From<ScenarioResult> for ApiScenarioResult {
     fn from(value: ScenarioResult) -> Self {
         ApiScenarioResult {
+            marital_property: value.marital_property.into(),
+            income_tax: value.income_tax.into(),
             cgt: value.cgt.into(),
             estate_duty: value.estate_duty.into(),
+            state_estate_tax: value.state_estate_tax.map(ApiStateEstateTaxBreakdown::from),
             combined_tax: value.combined_tax.into(),
             liquidity: value.liquidity.into(),
+            distribution: value.distribution.into(),
+            assets: value
+                .assets
+                .into_iter()
+                .map(ApiAssetTaxAttributionLine::from)
+                .collect(),
+            currency: value.currency.into(),
+            explain: value.explain.map(ApiExplainTrace::from),
+            defaulted_fields: value
+                .defaulted_fields
+                .into_iter()
+                .map(ApiDefaultedField::from)
+                .collect(),
         }
     }
 }
@@ -364,6 +1460,8 @@ pub struct ApiScenarioScore {
     pub tax_burden_ratio: f64,
     pub liquidity_cover_ratio: f64,
     pub liquidity_risk_band: ApiLiquidityRiskBand,
+    pub net_inheritance_ratio: f64,
+    pub bequest_shortfall_ratio: f64,
     pub composite_score: f64,
 }
 
@@ -373,6 +1471,8 @@ impl From<ScenarioScore> for ApiScenarioScore {
             tax_burden_ratio: value.tax_burden_ratio,
             liquidity_cover_ratio: value.liquidity_cover_ratio,
             liquidity_risk_band: value.liquidity_risk_band.into(),
+            net_inheritance_ratio: value.net_inheritance_ratio,
+            bequest_shortfall_ratio: value.bequest_shortfall_ratio,
             composite_score: value.composite_score,
         }
     }
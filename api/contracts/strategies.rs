@@ -0,0 +1,100 @@
+use super::{ApiEstateScenarioInput, ApiScenarioResult, ApiScenarioScore};
+use crate::core::engine::optimizer::RankedStrategy;
+use crate::core::engine::strategies::{StrategyChange, StrategyLever};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiStrategyLever {
+    ResidueToSpouse,
+    PboBequest,
+    LifeCover,
+    TrustTransfer,
+    AnnualDonations,
+}
+
+impl From<StrategyLever> for ApiStrategyLever {
+    fn from(value: StrategyLever) -> Self {
+        match value {
+            StrategyLever::ResidueToSpouse => ApiStrategyLever::ResidueToSpouse,
+            StrategyLever::PboBequest => ApiStrategyLever::PboBequest,
+            StrategyLever::LifeCover => ApiStrategyLever::LifeCover,
+            StrategyLever::TrustTransfer => ApiStrategyLever::TrustTransfer,
+            StrategyLever::AnnualDonations => ApiStrategyLever::AnnualDonations,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiStrategyOptimizationRequest {
+    pub scenario: ApiEstateScenarioInput,
+    // Years of annual donations before death; `None` uses 5 and 0 leaves that strategy out.
+    #[serde(default)]
+    pub annual_donation_years: Option<u8>,
+    // `YYYY-MM-DD` date lifetime transfers are made; `None` uses the start of the tax year.
+    #[serde(default)]
+    pub planning_date: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiStrategyChange {
+    pub lever: ApiStrategyLever,
+    pub field: String,
+    pub description: String,
+}
+
+impl From<StrategyChange> for ApiStrategyChange {
+    fn from(value: StrategyChange) -> Self {
+        ApiStrategyChange {
+            lever: value.lever.into(),
+            field: value.field,
+            description: value.description,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ApiRankedStrategy {
+    pub rank: usize,
+    pub name: String,
+    pub description: String,
+    pub levers: Vec<ApiStrategyLever>,
+    pub changes: Vec<ApiStrategyChange>,
+    pub tax_saving_amount: Decimal,
+    pub second_death_tax_amount: Option<Decimal>,
+    pub combined_tax_saving_amount: Decimal,
+    pub deferral_only: bool,
+    pub score: ApiScenarioScore,
+    pub input: ApiEstateScenarioInput,
+    pub result: ApiScenarioResult,
+}
+
+impl From<RankedStrategy> for ApiRankedStrategy {
+    fn from(value: RankedStrategy) -> Self {
+        ApiRankedStrategy {
+            rank: value.rank,
+            name: value.strategy.name.to_string(),
+            description: value.strategy.description.clone(),
+            levers: value
+                .strategy
+                .levers()
+                .into_iter()
+                .map(ApiStrategyLever::from)
+                .collect(),
+            changes: value
+                .strategy
+                .changes
+                .into_iter()
+                .map(ApiStrategyChange::from)
+                .collect(),
+            tax_saving_amount: value.tax_saving_amount,
+            second_death_tax_amount: value.second_death_tax_amount,
+            combined_tax_saving_amount: value.combined_tax_saving_amount,
+            deferral_only: value.deferral_only,
+            score: value.score.into(),
+            input: value.strategy.input.into(),
+            result: value.result.into(),
+        }
+    }
+}
@@ -1,28 +1,51 @@
 use crate::api::contracts::{
-    ApiErrorCode, ApiErrorResponse, ApiEstateDocumentAnalysisRequest,
-    ApiEstateDocumentAnalysisResponse, ApiEstateDocumentChecklistItem, ApiEstateDocumentDetection,
-    ApiEstateDocumentInput, ApiEstateDocumentRequirementStatus, ApiEstateDocumentType,
-    ApiEstateScenarioInput, ApiJurisdiction, ApiJurisdictionTaxRuleRegistryResponse,
-    ApiOptimizedScenario, ApiScenarioDocumentCalculateResponse, ApiScenarioDocumentFormat,
+    ApiCoupleScenarioInput, ApiCoupleScenarioResult, ApiCrossBorderScenarioInput,
+    ApiCrossBorderScenarioResult, ApiDonationsTaxInput, ApiDonationsTaxResult, ApiErrorCode,
+    ApiErrorResponse, ApiEstateDocumentAnalysisRequest, ApiEstateDocumentAnalysisResponse,
+    ApiEstateDocumentChecklistItem, ApiEstateDocumentDetection, ApiEstateDocumentInput,
+    ApiEstateDocumentRequirementStatus, ApiEstateDocumentType, ApiEstateScenarioInput,
+    ApiJurisdiction, ApiJurisdictionTaxRuleRegistryResponse, ApiLiquidationAndDistributionAccount,
+    ApiLoadedTaxRuleCatalog, ApiOptimizedScenario, ApiRankedStrategy,
+    ApiScenarioDocumentCalculateResponse, ApiScenarioDocumentFormat,
     ApiScenarioDocumentIngestRequest, ApiScenarioDocumentIngestResponse, ApiScenarioResult,
-    ApiTaxRuleRegistryEntry, ApiValidationIssue, ApiVersionedJurisdictionTaxRuleSet,
-    JurisdictionTaxRuleRegistryResponse,
+    ApiStrategyOptimizationRequest, ApiTaxRuleCatalog, ApiTaxRuleCatalogReloadResponse,
+    ApiTaxRuleRegistryEntry, ApiTaxRuleVersionDiff, ApiValidationIssue,
+    ApiVersionedJurisdictionTaxRuleSet, JurisdictionTaxRuleRegistryResponse,
+    JurisdictionTaxRuleVersionDiff,
+};
+use crate::api::pdf::render_liquidation_account_pdf;
+use crate::core::domain::models::{
+    CalendarDate, CoupleScenarioInput, CoupleScenarioResult, CrossBorderScenarioInput,
+    CrossBorderScenarioResult, DonationsTaxInput, DonationsTaxResult, EstateScenarioInput,
+    LiquidationAndDistributionAccount, ScenarioResult,
+};
+use crate::core::engine::couple::calculate_couple_second_death;
+use crate::core::engine::cross_border::calculate_cross_border_scenario;
+use crate::core::engine::donations::calculate_donations_tax;
+use crate::core::engine::explain::render_explain_text;
+use crate::core::engine::liquidation_account::draft_liquidation_and_distribution_account;
+use crate::core::engine::optimizer::{
+    optimize_scenarios, rank_candidate_strategies, OptimizedScenario, RankedStrategy,
 };
-use crate::core::domain::models::{EstateScenarioInput, ScenarioResult};
-use crate::core::engine::optimizer::{optimize_scenarios, OptimizedScenario};
 use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::engine::strategies::StrategyGeneratorOptions;
 use crate::core::errors::EngineError;
+use crate::core::rules::rule_catalog::{
+    install_tax_rule_catalogs, loaded_tax_rule_jurisdictions, TaxRuleCatalog, TaxRuleCatalogSource,
+};
+use crate::core::rules::rule_diff::diff_tax_rule_versions;
 use crate::core::rules::tax_rules::{
-    latest_tax_rules_for, supported_jurisdictions, supported_tax_year_window, tax_rule_registry,
-    tax_rule_registry_for, tax_rules_for, Jurisdiction, TaxRuleRegistryEntry,
+    latest_tax_rules_for, supported_jurisdictions, supported_tax_year_window, tax_rule_catalog_for,
+    tax_rule_registry, tax_rule_registry_for, tax_rules_for, Jurisdiction, TaxRuleRegistryEntry,
     VersionedJurisdictionTaxRuleSet,
 };
-use crate::core::validation::InputValidationError;
+use crate::core::validation::{InputValidationError, ValidationIssue};
 use base64::Engine;
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::{BTreeMap, BTreeSet};
 use std::io::{Cursor, Read};
+use std::path::Path;
 use zip::ZipArchive;
 
 pub fn list_supported_jurisdictions() -> Vec<Jurisdiction> {
@@ -99,6 +122,201 @@ pub fn resolve_latest_tax_rules_contract(
     resolve_latest_tax_rules(jurisdiction.into()).into()
 }
 
+// Compares two registered versions of one jurisdiction, identified by version id.
+pub fn diff_tax_rule_registry_versions(
+    jurisdiction: Jurisdiction,
+    from_version_id: &str,
+    to_version_id: &str,
+) -> Result<JurisdictionTaxRuleVersionDiff, EngineError> {
+    let versions = tax_rule_catalog_for(jurisdiction);
+    let find = |version_id: &str| {
+        versions
+            .iter()
+            .find(|versioned| versioned.version.version_id == version_id)
+    };
+
+    let mut issues = Vec::new();
+    for (field, version_id) in [("from", from_version_id), ("to", to_version_id)] {
+        if find(version_id).is_none() {
+            issues.push(ValidationIssue::new(
+                field.to_string(),
+                format!(
+                    "Unknown version id '{version_id}' for {jurisdiction:?}; expected one of: {}",
+                    versions
+                        .iter()
+                        .map(|versioned| versioned.version.version_id)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ));
+        }
+    }
+    match (find(from_version_id), find(to_version_id)) {
+        (Some(from), Some(to)) => Ok(JurisdictionTaxRuleVersionDiff {
+            jurisdiction,
+            diff: diff_tax_rule_versions(from, to),
+        }),
+        _ => Err(EngineError::Validation(InputValidationError::new(issues))),
+    }
+}
+
+pub fn diff_tax_rule_registry_versions_contract(
+    jurisdiction: ApiJurisdiction,
+    from_version_id: &str,
+    to_version_id: &str,
+) -> Result<ApiTaxRuleVersionDiff, ApiErrorResponse> {
+    diff_tax_rule_registry_versions(jurisdiction.into(), from_version_id, to_version_id)
+        .map(ApiTaxRuleVersionDiff::from)
+        .map_err(to_api_error_response)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxRuleCatalogFormat {
+    Toml,
+    Json,
+}
+
+impl TaxRuleCatalogFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "toml" => Some(TaxRuleCatalogFormat::Toml),
+            "json" => Some(TaxRuleCatalogFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_tax_rule_catalog(
+    content: &str,
+    format: TaxRuleCatalogFormat,
+) -> Result<TaxRuleCatalog, EngineError> {
+    let parsed = match format {
+        TaxRuleCatalogFormat::Toml => {
+            toml::from_str::<ApiTaxRuleCatalog>(content).map_err(|err| err.to_string())
+        }
+        TaxRuleCatalogFormat::Json => {
+            serde_json::from_str::<ApiTaxRuleCatalog>(content).map_err(|err| err.to_string())
+        }
+    }
+    .map_err(|message| {
+        EngineError::Validation(InputValidationError::new(vec![ValidationIssue::new(
+            "catalog".to_string(),
+            format!("Rule catalog does not match the schema: {message}"),
+        )]))
+    })?;
+
+    let catalog = TaxRuleCatalog::from(parsed);
+    catalog.validate()?;
+    Ok(catalog)
+}
+
+// Every `.toml` and `.json` file in the directory holds one jurisdiction's catalog. The files
+// are read and validated together, so one bad file rejects the whole load.
+pub fn load_tax_rule_catalogs(dir: &Path) -> Result<Vec<(String, TaxRuleCatalog)>, EngineError> {
+    let entries = std::fs::read_dir(dir).map_err(|err| {
+        EngineError::Computation(format!(
+            "Cannot read rule catalog directory {}: {err}",
+            dir.display()
+        ))
+    })?;
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && TaxRuleCatalogFormat::from_path(path).is_some())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut catalogs: Vec<(String, TaxRuleCatalog)> = Vec::new();
+    let mut issues = Vec::new();
+    for path in paths {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let Some(format) = TaxRuleCatalogFormat::from_path(&path) else {
+            continue;
+        };
+        let content = std::fs::read_to_string(&path).map_err(|err| {
+            EngineError::Computation(format!(
+                "Cannot read rule catalog {}: {err}",
+                path.display()
+            ))
+        })?;
+
+        match parse_tax_rule_catalog(&content, format) {
+            Ok(catalog) => {
+                if let Some((earlier_file, _)) = catalogs
+                    .iter()
+                    .find(|(_, earlier)| earlier.jurisdiction == catalog.jurisdiction)
+                {
+                    issues.push(ValidationIssue::new(
+                        format!("{file_name}/jurisdiction"),
+                        format!(
+                            "{:?} is already loaded from {earlier_file}",
+                            catalog.jurisdiction
+                        ),
+                    ));
+                } else {
+                    catalogs.push((file_name, catalog));
+                }
+            }
+            Err(EngineError::Validation(validation)) => {
+                issues.extend(validation.issues.into_iter().map(|mut issue| {
+                    issue.field = format!("{file_name}/{}", issue.field);
+                    issue
+                }));
+            }
+            Err(other) => return Err(other),
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(catalogs)
+    } else {
+        Err(EngineError::Validation(InputValidationError::new(issues)))
+    }
+}
+
+// Loads the directory and swaps it into the registry. On any error the previously loaded
+// catalogs stay in place.
+pub fn reload_tax_rule_catalogs(
+    dir: &Path,
+) -> Result<ApiTaxRuleCatalogReloadResponse, EngineError> {
+    let catalogs = load_tax_rule_catalogs(dir)?;
+    let loaded = catalogs
+        .iter()
+        .map(|(file_name, catalog)| ApiLoadedTaxRuleCatalog {
+            jurisdiction: catalog.jurisdiction.into(),
+            source_file: file_name.clone(),
+            version_ids: catalog
+                .versions
+                .iter()
+                .map(|versioned| versioned.version.version_id.to_string())
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    install_tax_rule_catalogs(
+        TaxRuleCatalogSource::Files,
+        catalogs.into_iter().map(|(_, catalog)| catalog).collect(),
+    );
+
+    let loaded_jurisdictions = loaded_tax_rule_jurisdictions(TaxRuleCatalogSource::Files);
+    Ok(ApiTaxRuleCatalogReloadResponse {
+        rules_dir: dir.display().to_string(),
+        loaded,
+        compiled_in: supported_jurisdictions()
+            .into_iter()
+            .filter(|jurisdiction| !loaded_jurisdictions.contains(jurisdiction))
+            .map(ApiJurisdiction::from)
+            .collect(),
+    })
+}
+
+pub fn reload_tax_rule_catalogs_contract(
+    dir: &Path,
+) -> Result<ApiTaxRuleCatalogReloadResponse, ApiErrorResponse> {
+    reload_tax_rule_catalogs(dir).map_err(to_api_error_response)
+}
+
 pub fn to_api_error_response(error: EngineError) -> ApiErrorResponse {
     match error {
         EngineError::Validation(validation_error) => ApiErrorResponse {
@@ -139,12 +357,77 @@ pub fn calculate_single_scenario_contract(
     calculate_single_scenario_api(&domain_input).map(ApiScenarioResult::from)
 }
 
+pub fn render_scenario_explain_text_contract(
+    input: ApiEstateScenarioInput,
+) -> Result<String, ApiErrorResponse> {
+    let mut domain_input: EstateScenarioInput = input.into();
+    domain_input.explain = true;
+    let result = calculate_single_scenario_api(&domain_input)?;
+    Ok(result
+        .explain
+        .as_ref()
+        .map(render_explain_text)
+        .unwrap_or_default())
+}
+
+pub fn draft_liquidation_account_api(
+    input: &EstateScenarioInput,
+) -> Result<LiquidationAndDistributionAccount, ApiErrorResponse> {
+    draft_liquidation_account(input).map_err(to_api_error_response)
+}
+
+pub fn draft_liquidation_account_contract(
+    input: ApiEstateScenarioInput,
+) -> Result<ApiLiquidationAndDistributionAccount, ApiErrorResponse> {
+    let domain_input: EstateScenarioInput = input.into();
+    draft_liquidation_account_api(&domain_input).map(ApiLiquidationAndDistributionAccount::from)
+}
+
+pub fn render_liquidation_account_pdf_contract(
+    input: ApiEstateScenarioInput,
+) -> Result<Vec<u8>, ApiErrorResponse> {
+    let domain_input: EstateScenarioInput = input.into();
+    draft_liquidation_account(&domain_input)
+        .and_then(|account| render_liquidation_account_pdf(&account))
+        .map_err(to_api_error_response)
+}
+
 pub fn optimize_candidate_scenarios_api(
     candidates: Vec<EstateScenarioInput>,
 ) -> Result<Option<OptimizedScenario>, ApiErrorResponse> {
     optimize_candidate_scenarios(candidates).map_err(to_api_error_response)
 }
 
+pub fn rank_planning_strategies_api(
+    input: &EstateScenarioInput,
+    options: StrategyGeneratorOptions,
+) -> Result<Vec<RankedStrategy>, ApiErrorResponse> {
+    rank_planning_strategies(input, options).map_err(to_api_error_response)
+}
+
+pub fn rank_planning_strategies_contract(
+    request: ApiStrategyOptimizationRequest,
+) -> Result<Vec<ApiRankedStrategy>, ApiErrorResponse> {
+    let mut options = StrategyGeneratorOptions::default();
+    if let Some(annual_donation_years) = request.annual_donation_years {
+        options.annual_donation_years = annual_donation_years;
+    }
+    if let Some(raw) = request.planning_date.as_deref() {
+        let planning_date = CalendarDate::parse(raw).ok_or_else(|| {
+            to_api_error_response(EngineError::Validation(InputValidationError::new(vec![
+                ValidationIssue::new(
+                    "planning_date".to_string(),
+                    "Planning date must be a valid `YYYY-MM-DD` date",
+                ),
+            ])))
+        })?;
+        options.planning_date = Some(planning_date);
+    }
+    let domain_input: EstateScenarioInput = request.scenario.into();
+    rank_planning_strategies_api(&domain_input, options)
+        .map(|ranked| ranked.into_iter().map(ApiRankedStrategy::from).collect())
+}
+
 pub fn optimize_candidate_scenarios_contract(
     candidates: Vec<ApiEstateScenarioInput>,
 ) -> Result<Option<ApiOptimizedScenario>, ApiErrorResponse> {
@@ -157,6 +440,45 @@ pub fn optimize_candidate_scenarios_contract(
         .map(|candidate| candidate.map(ApiOptimizedScenario::from))
 }
 
+pub fn calculate_couple_scenario_api(
+    input: &CoupleScenarioInput,
+) -> Result<CoupleScenarioResult, ApiErrorResponse> {
+    calculate_couple_scenario(input).map_err(to_api_error_response)
+}
+
+pub fn calculate_couple_scenario_contract(
+    input: ApiCoupleScenarioInput,
+) -> Result<ApiCoupleScenarioResult, ApiErrorResponse> {
+    let domain_input: CoupleScenarioInput = input.into();
+    calculate_couple_scenario_api(&domain_input).map(ApiCoupleScenarioResult::from)
+}
+
+pub fn calculate_cross_border_estate_api(
+    input: &CrossBorderScenarioInput,
+) -> Result<CrossBorderScenarioResult, ApiErrorResponse> {
+    calculate_cross_border_estate(input).map_err(to_api_error_response)
+}
+
+pub fn calculate_cross_border_estate_contract(
+    input: ApiCrossBorderScenarioInput,
+) -> Result<ApiCrossBorderScenarioResult, ApiErrorResponse> {
+    let domain_input: CrossBorderScenarioInput = input.into();
+    calculate_cross_border_estate_api(&domain_input).map(ApiCrossBorderScenarioResult::from)
+}
+
+pub fn calculate_donations_tax_api(
+    input: &DonationsTaxInput,
+) -> Result<DonationsTaxResult, ApiErrorResponse> {
+    calculate_lifetime_donations_tax(input).map_err(to_api_error_response)
+}
+
+pub fn calculate_donations_tax_contract(
+    input: ApiDonationsTaxInput,
+) -> Result<ApiDonationsTaxResult, ApiErrorResponse> {
+    let domain_input: DonationsTaxInput = input.into();
+    calculate_donations_tax_api(&domain_input).map(ApiDonationsTaxResult::from)
+}
+
 #[derive(Debug, Deserialize)]
 struct JsonScenarioEnvelope {
     scenarios: Vec<ApiEstateScenarioInput>,
@@ -313,7 +635,35 @@ pub fn calculate_single_scenario(
     input: &EstateScenarioInput,
 ) -> Result<ScenarioResult, EngineError> {
     input.validate().map_err(EngineError::from)?;
-    calculate_combined_tax_and_liquidity(input).map_err(EngineError::from)
+    calculate_combined_tax_and_liquidity(input)
+}
+
+pub fn draft_liquidation_account(
+    input: &EstateScenarioInput,
+) -> Result<LiquidationAndDistributionAccount, EngineError> {
+    input.validate().map_err(EngineError::from)?;
+    draft_liquidation_and_distribution_account(input)
+}
+
+pub fn calculate_couple_scenario(
+    input: &CoupleScenarioInput,
+) -> Result<CoupleScenarioResult, EngineError> {
+    input.validate().map_err(EngineError::from)?;
+    calculate_couple_second_death(input)
+}
+
+pub fn calculate_cross_border_estate(
+    input: &CrossBorderScenarioInput,
+) -> Result<CrossBorderScenarioResult, EngineError> {
+    input.validate().map_err(EngineError::from)?;
+    calculate_cross_border_scenario(input)
+}
+
+pub fn calculate_lifetime_donations_tax(
+    input: &DonationsTaxInput,
+) -> Result<DonationsTaxResult, EngineError> {
+    input.validate().map_err(EngineError::from)?;
+    calculate_donations_tax(input)
 }
 
 pub fn optimize_candidate_scenarios(
@@ -333,7 +683,31 @@ pub fn optimize_candidate_scenarios(
             all_issues,
         )));
     }
-    optimize_scenarios(candidates).map_err(EngineError::from)
+    optimize_scenarios(candidates)
+}
+
+pub fn rank_planning_strategies(
+    input: &EstateScenarioInput,
+    options: StrategyGeneratorOptions,
+) -> Result<Vec<RankedStrategy>, EngineError> {
+    if let Err(mut err) = input.validate() {
+        for issue in &mut err.issues {
+            issue.field = format!("scenario.{}", issue.field);
+        }
+        return Err(EngineError::Validation(err));
+    }
+    let date_of_death = input.date_of_death.as_deref().and_then(CalendarDate::parse);
+    if let (Some(planning_date), Some(date_of_death)) = (options.planning_date, date_of_death) {
+        if planning_date > date_of_death {
+            return Err(EngineError::Validation(InputValidationError::new(vec![
+                ValidationIssue::new(
+                    "planning_date".to_string(),
+                    format!("Planning date cannot be after the date of death {date_of_death}"),
+                ),
+            ])));
+        }
+    }
+    rank_candidate_strategies(input, options)
 }
 
 fn extract_text_for_estate_document_analysis(
@@ -1481,7 +1855,10 @@ fn parse_structured_key_value_scenarios_document(
 
         if let Some((asset_index, asset_field)) = resolve_prefixed_asset_key(&normalized_key) {
             ensure_asset_slot(&mut assets, asset_index);
-            assets[asset_index].insert(asset_field.to_string(), parse_structured_value(raw_value));
+            assets[asset_index].insert(
+                asset_field.to_string(),
+                parse_structured_asset_value(asset_field, raw_value),
+            );
             recognized_fields += 1;
             continue;
         }
@@ -1495,7 +1872,10 @@ fn parse_structured_key_value_scenarios_document(
 
         if let Some(asset_field) = resolve_asset_field(&normalized_key) {
             ensure_asset_slot(&mut assets, 0);
-            assets[0].insert(asset_field.to_string(), parse_structured_value(raw_value));
+            assets[0].insert(
+                asset_field.to_string(),
+                parse_structured_asset_value(asset_field, raw_value),
+            );
             recognized_fields += 1;
             continue;
         }
@@ -1587,6 +1967,10 @@ fn resolve_scenario_key(normalized_key: &str) -> Option<&'static str> {
             Some("taxpayer_class")
         }
         "residency_status" | "residency" | "residence_status" => Some("residency_status"),
+        "taxable_income_in_year_of_death_amount"
+        | "taxable_income_in_year_of_death"
+        | "taxable_income" => Some("taxable_income_in_year_of_death_amount"),
+        "age_at_tax_year_end" | "age" => Some("age_at_tax_year_end"),
         "marginal_income_tax_rate" | "marginal_tax_rate" | "income_tax_rate" => {
             Some("marginal_income_tax_rate")
         }
@@ -1630,6 +2014,9 @@ fn resolve_scenario_key(normalized_key: &str) -> Option<&'static str> {
             Some("primary_residence_cgt_exclusion_cap_amount")
         }
         "executor_fee_rate" => Some("executor_fee_rate"),
+        "income_accrued_after_death_amount"
+        | "income_accrued_after_death"
+        | "income_after_death" => Some("income_accrued_after_death_amount"),
         "vat_rate" => Some("vat_rate"),
         "explicit_executor_fee_amount" | "explicit_executor_fee" | "explicit_executor_fee_zar" => {
             Some("explicit_executor_fee_amount")
@@ -1660,6 +2047,8 @@ fn resolve_asset_field(normalized_key: &str) -> Option<&'static str> {
         "bequeathed_to_surviving_spouse" => Some("bequeathed_to_surviving_spouse"),
         "bequeathed_to_pbo" => Some("bequeathed_to_pbo"),
         "qualifies_primary_residence_exclusion" => Some("qualifies_primary_residence_exclusion"),
+        "currency" | "currency_code" => Some("currency"),
+        "situs_jurisdiction" | "situs_country" => Some("situs_jurisdiction"),
         _ => None,
     }
 }
@@ -1743,6 +2132,19 @@ fn parse_structured_scenario_value(scenario_field: &str, raw_value: &str) -> Val
     }
 }
 
+fn parse_structured_asset_value(asset_field: &str, raw_value: &str) -> Value {
+    match asset_field {
+        "situs_jurisdiction" => normalize_jurisdiction_value(raw_value)
+            .map(|value| Value::String(value.to_string()))
+            .unwrap_or_else(|| parse_structured_value(raw_value)),
+        "currency" => match parse_structured_value(raw_value) {
+            Value::String(code) => Value::String(code.to_ascii_uppercase()),
+            value => value,
+        },
+        _ => parse_structured_value(raw_value),
+    }
+}
+
 fn normalize_jurisdiction_value(raw_value: &str) -> Option<&'static str> {
     match normalize_structured_key(raw_value).as_str() {
         "south_africa" | "southafrica" | "za" => Some("SouthAfrica"),
@@ -2353,7 +2755,10 @@ fn infer_asset_name_from_line(line: &str, amount: &NumericCandidate, next_index:
     if line_contains_any(&lower, &["portfolio", "investment"]) {
         return "Investment Portfolio".to_string();
     }
-    if line_contains_any(&lower, &["market value", "property valuation", "valuation report"]) {
+    if line_contains_any(
+        &lower,
+        &["market value", "property valuation", "valuation report"],
+    ) {
         return "Property Market Value".to_string();
     }
     if line_contains_any(&lower, &["property", "immovable"]) {
@@ -2555,10 +2960,16 @@ fn extract_numeric_candidates(input: &str) -> Vec<NumericCandidate> {
                     start: start_offset,
                     end: end_offset,
                 });
+                index = end_char_index;
+                continue;
             }
         }
 
-        index = end_char_index;
+        // Skip only the leading digit run so later amounts on the same line are still found.
+        index += 1;
+        while index < chars.len() && chars[index].1.is_ascii_digit() {
+            index += 1;
+        }
     }
 
     candidates
@@ -2623,9 +3034,15 @@ fn fragment_looks_identifier_like(fragment: &str, lower_line: &str) -> bool {
         && fragment
             .chars()
             .all(|ch| ch.is_ascii_digit() || ch.is_ascii_whitespace());
+    // Space thousands separators ("1 300 000") read as money rather than an identifier.
+    let groups = fragment.split_whitespace().collect::<Vec<_>>();
+    let has_space_thousands_grouping = groups.len() > 1
+        && (1..=3).contains(&groups[0].len())
+        && groups[1..].iter().all(|group| group.len() == 3);
     let has_money_punctuation = fragment.contains(',')
         || fragment.contains('.')
-        || fragment.to_ascii_uppercase().contains("ZAR");
+        || fragment.to_ascii_uppercase().contains("ZAR")
+        || has_space_thousands_grouping;
     let identifier_context = line_contains_any(
         lower_line,
         &[
@@ -2739,6 +3156,8 @@ fn is_small_property_rate_or_reference(lower_line: &str, amount: f64) -> bool {
                 "sqm",
                 "/m²",
                 "per m²",
+                "/m2",
+                "per m2",
                 "pr. val",
                 "valuer",
                 "deed no",
@@ -1,13 +1,24 @@
 mod routes;
+mod rule_store;
 mod state;
 
+use crate::api::handler::reload_tax_rule_catalogs;
 use axum::Router;
+use rule_store::{prepare_tax_rule_store, refresh_published_tax_rules};
 use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use std::path::PathBuf;
 
-pub use state::AppState;
+use state::parse_admin_tokens;
+
+pub use state::{AdminToken, AppState};
 
 pub fn app() -> Router {
-    app_with_state(AppState { db_pool: None })
+    app_with_state(AppState {
+        db_pool: None,
+        rules_dir: None,
+        admin_tokens: Vec::new(),
+    })
 }
 
 pub fn app_with_state(state: AppState) -> Router {
@@ -28,14 +39,69 @@ pub async fn run(
         .connect(database_url)
         .await?;
 
+    let rules_dir = std::env::var("TAX_RULES_DIR")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .map(PathBuf::from);
+    if let Some(dir) = &rules_dir {
+        reload_tax_rule_catalogs(dir)?;
+    }
+    let admin_tokens = parse_admin_tokens(&std::env::var("ADMIN_API_TOKENS").unwrap_or_default())?;
+    if admin_tokens.is_empty() {
+        eprintln!(
+            "[admin] ADMIN_API_TOKENS is not set; /v1/admin routes will refuse every request"
+        );
+    }
+
+    prepare_tax_rule_store(&db_pool).await?;
+    let published = refresh_published_tax_rules(&db_pool).await?;
+    eprintln!("[rules] serving database versions for {published} jurisdiction(s)");
+    #[cfg(unix)]
+    tokio::spawn(reload_tax_rules_on_sighup(
+        rules_dir.clone(),
+        db_pool.clone(),
+    ));
+
     let listener = tokio::net::TcpListener::bind(bind_addr).await?;
     axum::serve(
         listener,
         app_with_state(AppState {
             db_pool: Some(db_pool),
+            rules_dir,
+            admin_tokens,
         }),
     )
     .await?;
 
     Ok(())
 }
+
+#[cfg(unix)]
+async fn reload_tax_rules_on_sighup(rules_dir: Option<PathBuf>, db_pool: PgPool) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let Ok(mut hangups) = signal(SignalKind::hangup()) else {
+        eprintln!("[rules] SIGHUP handler unavailable; use POST /v1/admin/rules/reload");
+        return;
+    };
+    while hangups.recv().await.is_some() {
+        if let Some(dir) = &rules_dir {
+            match reload_tax_rule_catalogs(dir) {
+                Ok(reloaded) => eprintln!(
+                    "[rules] reloaded {} catalog(s) from {}",
+                    reloaded.loaded.len(),
+                    reloaded.rules_dir
+                ),
+                Err(err) => eprintln!("[rules] reload failed, keeping previous catalogs: {err}"),
+            }
+        }
+        match refresh_published_tax_rules(&db_pool).await {
+            Ok(published) => {
+                eprintln!("[rules] refreshed database versions for {published} jurisdiction(s)")
+            }
+            Err(err) => {
+                eprintln!("[rules] database refresh failed, keeping previous versions: {err}")
+            }
+        }
+    }
+}
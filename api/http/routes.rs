@@ -1,25 +1,47 @@
 use crate::api::contracts::{
-    ApiErrorCode, ApiErrorResponse, ApiEstateDocumentAnalysisRequest,
+    ApiCoupleScenarioInput, ApiCoupleScenarioResult, ApiCreateTaxRuleDraftRequest,
+    ApiCrossBorderScenarioInput, ApiCrossBorderScenarioResult, ApiDonationsTaxInput,
+    ApiDonationsTaxResult, ApiErrorCode, ApiErrorResponse, ApiEstateDocumentAnalysisRequest,
     ApiEstateDocumentAnalysisResponse, ApiEstateDocumentChecklistItem, ApiEstateDocumentDetection,
     ApiEstateDocumentInput, ApiEstateDocumentRequirementStatus, ApiEstateDocumentType,
     ApiEstateScenarioInput, ApiHealthResponse, ApiJurisdiction,
-    ApiJurisdictionTaxRuleRegistryResponse, ApiOptimizedScenario,
-    ApiScenarioDocumentCalculateResponse, ApiScenarioDocumentFormat,
-    ApiScenarioDocumentIngestRequest, ApiScenarioDocumentIngestResponse, ApiScenarioResult,
-    ApiTaxRuleRegistryEntry, ApiValidationIssue, ApiVersionedJurisdictionTaxRuleSet,
+    ApiJurisdictionTaxRuleRegistryResponse, ApiLiquidationAndDistributionAccount,
+    ApiOptimizedScenario, ApiPublishTaxRuleVersionRequest, ApiRankedStrategy,
+    ApiReviewTaxRuleVersionRequest, ApiScenarioDocumentCalculateResponse,
+    ApiScenarioDocumentFormat, ApiScenarioDocumentIngestRequest, ApiScenarioDocumentIngestResponse,
+    ApiScenarioResult, ApiStrategyChange, ApiStrategyLever, ApiStrategyOptimizationRequest,
+    ApiTaxRuleCatalog, ApiTaxRuleCatalogReloadResponse, ApiTaxRuleChangeKind,
+    ApiTaxRuleFieldChange, ApiTaxRuleRegistryEntry, ApiTaxRuleVersionDiff, ApiTaxRuleVersionRecord,
+    ApiTaxRuleVersionReview, ApiTaxRuleVersionStatus, ApiValidationIssue,
+    ApiVersionedJurisdictionTaxRuleSet,
 };
 use crate::api::handler::{
-    analyze_estate_documents_contract, calculate_scenario_document_contract,
-    calculate_single_scenario_contract, get_jurisdiction_tax_rule_registry_contract,
-    ingest_scenario_document_contract, list_supported_jurisdictions_contract,
-    list_tax_rule_registry_entries_contract, optimize_candidate_scenarios_contract,
-    resolve_latest_tax_rules_contract, resolve_tax_rules_for_year_contract,
+    analyze_estate_documents_contract, calculate_couple_scenario_contract,
+    calculate_cross_border_estate_contract, calculate_donations_tax_contract,
+    calculate_scenario_document_contract, calculate_single_scenario_contract,
+    diff_tax_rule_registry_versions_contract, draft_liquidation_account_contract,
+    get_jurisdiction_tax_rule_registry_contract, ingest_scenario_document_contract,
+    list_supported_jurisdictions_contract, list_tax_rule_registry_entries_contract,
+    optimize_candidate_scenarios_contract, rank_planning_strategies_contract,
+    reload_tax_rule_catalogs_contract, render_liquidation_account_pdf_contract,
+    render_scenario_explain_text_contract, resolve_latest_tax_rules_contract,
+    resolve_tax_rules_for_year_contract, to_api_error_response,
 };
-use crate::api::http::state::AppState;
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use crate::api::http::rule_store::{
+    create_tax_rule_draft, fetch_tax_rule_version, list_tax_rule_versions,
+    publish_tax_rule_version, retire_tax_rule_version, review_tax_rule_version,
+    StoredTaxRuleVersionRecord,
+};
+use crate::api::http::state::{admin_principal_for, AppState};
+use crate::core::errors::EngineError;
+use axum::extract::{Extension, Path, Query, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use axum::{Json, Router};
+use serde::Deserialize;
+use sqlx::PgPool;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
@@ -36,8 +58,23 @@ type HttpResult<T> = Result<Json<T>, HttpError>;
         get_registry_for_jurisdiction,
         resolve_latest_rules,
         resolve_rules_for_year,
+        diff_rule_versions,
+        reload_rules,
+        list_rule_versions,
+        create_rule_draft,
+        get_rule_version,
+        review_rule_version,
+        publish_rule_version,
+        retire_rule_version,
         calculate_scenario,
+        explain_scenario_text,
         optimize_scenarios,
+        optimize_strategies,
+        calculate_couple_scenario,
+        calculate_cross_border_scenario,
+        draft_liquidation_account,
+        render_liquidation_account_pdf,
+        calculate_donations,
         ingest_scenario_document,
         calculate_scenario_document,
         analyze_estate_documents
@@ -52,9 +89,31 @@ type HttpResult<T> = Result<Json<T>, HttpError>;
             ApiTaxRuleRegistryEntry,
             ApiJurisdictionTaxRuleRegistryResponse,
             ApiVersionedJurisdictionTaxRuleSet,
+            ApiTaxRuleChangeKind,
+            ApiTaxRuleFieldChange,
+            ApiTaxRuleVersionDiff,
+            ApiTaxRuleCatalog,
+            ApiTaxRuleCatalogReloadResponse,
+            ApiTaxRuleVersionStatus,
+            ApiTaxRuleVersionReview,
+            ApiTaxRuleVersionRecord,
+            ApiCreateTaxRuleDraftRequest,
+            ApiReviewTaxRuleVersionRequest,
+            ApiPublishTaxRuleVersionRequest,
             ApiEstateScenarioInput,
             ApiScenarioResult,
             ApiOptimizedScenario,
+            ApiStrategyOptimizationRequest,
+            ApiStrategyLever,
+            ApiStrategyChange,
+            ApiRankedStrategy,
+            ApiCoupleScenarioInput,
+            ApiCoupleScenarioResult,
+            ApiCrossBorderScenarioInput,
+            ApiCrossBorderScenarioResult,
+            ApiLiquidationAndDistributionAccount,
+            ApiDonationsTaxInput,
+            ApiDonationsTaxResult,
             ApiScenarioDocumentFormat,
             ApiScenarioDocumentIngestRequest,
             ApiScenarioDocumentIngestResponse,
@@ -71,12 +130,68 @@ type HttpResult<T> = Result<Json<T>, HttpError>;
     tags(
         (name = "health", description = "Health and readiness endpoints"),
         (name = "rules", description = "Tax-rule discovery and selection endpoints"),
+        (name = "admin", description = "Rule catalog and rule version administration endpoints"),
         (name = "scenario", description = "Scenario calculation and optimization endpoints"),
+        (name = "donations", description = "Lifetime donations tax calculation endpoints"),
         (name = "estate-documents", description = "Estate legal/tax document intake and checklist analysis")
     )
 )]
 struct ApiDoc;
 
+// Identity of the admin token holder; it is recorded as author, reviewer, publisher or retirer.
+#[derive(Debug, Clone)]
+struct AdminPrincipal(String);
+
+fn admin_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/v1/admin/rules/reload", post(reload_rules))
+        .route(
+            "/v1/admin/rules/versions",
+            get(list_rule_versions).post(create_rule_draft),
+        )
+        .route("/v1/admin/rules/versions/{id}", get(get_rule_version))
+        .route(
+            "/v1/admin/rules/versions/{id}/review",
+            post(review_rule_version),
+        )
+        .route(
+            "/v1/admin/rules/versions/{id}/publish",
+            post(publish_rule_version),
+        )
+        .route(
+            "/v1/admin/rules/versions/{id}/retire",
+            post(retire_rule_version),
+        )
+        .route_layer(middleware::from_fn_with_state(state, require_admin_token))
+}
+
+async fn require_admin_token(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    let principal = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .and_then(|token| admin_principal_for(&state.admin_tokens, token.trim()))
+        .map(|principal| AdminPrincipal(principal.to_string()));
+    let Some(principal) = principal else {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ApiErrorResponse {
+                code: ApiErrorCode::Validation,
+                message: "Admin routes need `Authorization: Bearer <token>` with a token from ADMIN_API_TOKENS".to_string(),
+                validation_issues: Vec::new(),
+            }),
+        ));
+    };
+
+    request.extensions_mut().insert(principal);
+    Ok(next.run(request).await)
+}
+
 pub fn router(state: AppState) -> Router {
     Router::new()
         .merge(crate::web::router())
@@ -94,8 +209,35 @@ pub fn router(state: AppState) -> Router {
             "/v1/rules/{jurisdiction}/{tax_year}",
             get(resolve_rules_for_year),
         )
+        .route("/v1/rules/diff/{jurisdiction}", get(diff_rule_versions))
+        .merge(admin_router(state.clone()))
         .route("/v1/scenario/calculate", post(calculate_scenario))
+        .route(
+            "/v1/scenario/calculate/explain",
+            post(explain_scenario_text),
+        )
         .route("/v1/scenario/optimize", post(optimize_scenarios))
+        .route(
+            "/v1/scenario/optimize/strategies",
+            post(optimize_strategies),
+        )
+        .route(
+            "/v1/scenario/couple/calculate",
+            post(calculate_couple_scenario),
+        )
+        .route(
+            "/v1/scenario/cross-border/calculate",
+            post(calculate_cross_border_scenario),
+        )
+        .route(
+            "/v1/scenario/liquidation-account",
+            post(draft_liquidation_account),
+        )
+        .route(
+            "/v1/scenario/liquidation-account/pdf",
+            post(render_liquidation_account_pdf),
+        )
+        .route("/v1/donations/calculate", post(calculate_donations))
         .route("/v1/scenario/ingest", post(ingest_scenario_document))
         .route(
             "/v1/scenario/document/calculate",
@@ -132,16 +274,7 @@ async fn health() -> Json<ApiHealthResponse> {
     )
 )]
 async fn health_db(State(state): State<AppState>) -> HttpResult<ApiHealthResponse> {
-    let Some(pool) = state.db_pool else {
-        return Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ApiErrorResponse {
-                code: ApiErrorCode::Computation,
-                message: "Database pool is not configured".to_string(),
-                validation_issues: Vec::new(),
-            }),
-        ));
-    };
+    let pool = require_db_pool(state)?;
 
     sqlx::query_scalar::<_, i64>("SELECT 1")
         .fetch_one(&pool)
@@ -254,6 +387,254 @@ async fn resolve_rules_for_year(
         .map_err(api_error_to_http)
 }
 
+#[derive(Debug, Deserialize)]
+struct RuleDiffQuery {
+    from: String,
+    to: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/rules/diff/{jurisdiction}",
+    tag = "rules",
+    params(
+        ("jurisdiction" = String, Path, description = "Jurisdiction path token"),
+        ("from" = String, Query, description = "Version id to compare from"),
+        ("to" = String, Query, description = "Version id to compare to")
+    ),
+    responses(
+        (status = 200, description = "Field-by-field changes between two rule versions", body = ApiTaxRuleVersionDiff),
+        (status = 400, description = "Invalid jurisdiction, missing query parameter or unknown version id", body = ApiErrorResponse)
+    )
+)]
+async fn diff_rule_versions(
+    Path(jurisdiction): Path<String>,
+    Query(query): Query<RuleDiffQuery>,
+) -> HttpResult<ApiTaxRuleVersionDiff> {
+    let jurisdiction = parse_jurisdiction(&jurisdiction).map_err(api_error_to_http)?;
+    diff_tax_rule_registry_versions_contract(jurisdiction, &query.from, &query.to)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/rules/reload",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Rule catalogs reloaded from the rules directory", body = ApiTaxRuleCatalogReloadResponse),
+        (status = 400, description = "A catalog file failed schema or window validation; previous catalogs kept", body = ApiErrorResponse),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 503, description = "No rules directory is configured", body = ApiErrorResponse)
+    )
+)]
+async fn reload_rules(
+    State(state): State<AppState>,
+) -> HttpResult<ApiTaxRuleCatalogReloadResponse> {
+    let Some(rules_dir) = state.rules_dir else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiErrorResponse {
+                code: ApiErrorCode::Computation,
+                message: "Rules directory is not configured (set TAX_RULES_DIR)".to_string(),
+                validation_issues: Vec::new(),
+            }),
+        ));
+    };
+
+    reload_tax_rule_catalogs_contract(&rules_dir)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
+#[derive(Debug, Deserialize)]
+struct RuleVersionListQuery {
+    jurisdiction: Option<String>,
+    status: Option<ApiTaxRuleVersionStatus>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/rules/versions",
+    tag = "admin",
+    params(
+        ("jurisdiction" = Option<String>, Query, description = "Jurisdiction path token"),
+        ("status" = Option<ApiTaxRuleVersionStatus>, Query, description = "Only versions in this state")
+    ),
+    responses(
+        (status = 200, description = "Stored rule versions with review and publication metadata", body = [ApiTaxRuleVersionRecord]),
+        (status = 400, description = "Invalid jurisdiction", body = ApiErrorResponse),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 503, description = "Database is not configured", body = ApiErrorResponse)
+    )
+)]
+async fn list_rule_versions(
+    State(state): State<AppState>,
+    Query(query): Query<RuleVersionListQuery>,
+) -> HttpResult<Vec<ApiTaxRuleVersionRecord>> {
+    let pool = require_db_pool(state)?;
+    let jurisdiction = query
+        .jurisdiction
+        .as_deref()
+        .map(parse_jurisdiction)
+        .transpose()
+        .map_err(api_error_to_http)?;
+
+    list_tax_rule_versions(
+        &pool,
+        jurisdiction.map(Into::into),
+        query.status.map(Into::into),
+    )
+    .await
+    .map(|records| {
+        Json(
+            records
+                .into_iter()
+                .map(ApiTaxRuleVersionRecord::from)
+                .collect(),
+        )
+    })
+    .map_err(engine_error_to_http)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/rules/versions",
+    tag = "admin",
+    request_body = ApiCreateTaxRuleDraftRequest,
+    responses(
+        (status = 200, description = "Draft rule version stored", body = ApiTaxRuleVersionRecord),
+        (status = 400, description = "Rule set failed validation or the version id is taken", body = ApiErrorResponse),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 503, description = "Database is not configured", body = ApiErrorResponse)
+    )
+)]
+async fn create_rule_draft(
+    State(state): State<AppState>,
+    Extension(AdminPrincipal(author)): Extension<AdminPrincipal>,
+    Json(request): Json<ApiCreateTaxRuleDraftRequest>,
+) -> HttpResult<ApiTaxRuleVersionRecord> {
+    let pool = require_db_pool(state)?;
+    create_tax_rule_draft(
+        &pool,
+        request.jurisdiction.into(),
+        &author,
+        request.rule_set.into(),
+    )
+    .await
+    .map(|record| Json(record.into()))
+    .map_err(engine_error_to_http)
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/rules/versions/{id}",
+    tag = "admin",
+    params(
+        ("id" = i64, Path, description = "Stored rule version id")
+    ),
+    responses(
+        (status = 200, description = "Stored rule version", body = ApiTaxRuleVersionRecord),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 404, description = "No stored version with this id", body = ApiErrorResponse),
+        (status = 503, description = "Database is not configured", body = ApiErrorResponse)
+    )
+)]
+async fn get_rule_version(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> HttpResult<ApiTaxRuleVersionRecord> {
+    let pool = require_db_pool(state)?;
+    stored_rule_version_response(fetch_tax_rule_version(&pool, id).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/rules/versions/{id}/review",
+    tag = "admin",
+    params(
+        ("id" = i64, Path, description = "Stored rule version id")
+    ),
+    request_body = ApiReviewTaxRuleVersionRequest,
+    responses(
+        (status = 200, description = "Review recorded on the draft", body = ApiTaxRuleVersionRecord),
+        (status = 400, description = "Version is not a draft or the reviewer is the author", body = ApiErrorResponse),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 404, description = "No stored version with this id", body = ApiErrorResponse),
+        (status = 503, description = "Database is not configured", body = ApiErrorResponse)
+    )
+)]
+async fn review_rule_version(
+    State(state): State<AppState>,
+    Extension(AdminPrincipal(reviewer)): Extension<AdminPrincipal>,
+    Path(id): Path<i64>,
+    Json(request): Json<ApiReviewTaxRuleVersionRequest>,
+) -> HttpResult<ApiTaxRuleVersionRecord> {
+    let pool = require_db_pool(state)?;
+    stored_rule_version_response(
+        review_tax_rule_version(
+            &pool,
+            id,
+            &reviewer,
+            request.approved,
+            request.notes.as_deref(),
+        )
+        .await,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/rules/versions/{id}/publish",
+    tag = "admin",
+    params(
+        ("id" = i64, Path, description = "Stored rule version id")
+    ),
+    request_body = ApiPublishTaxRuleVersionRequest,
+    responses(
+        (status = 200, description = "Version published and served by rule selection", body = ApiTaxRuleVersionRecord),
+        (status = 400, description = "Version is unapproved, not a draft, or overlaps a published version", body = ApiErrorResponse),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 404, description = "No stored version with this id", body = ApiErrorResponse),
+        (status = 503, description = "Database is not configured", body = ApiErrorResponse)
+    )
+)]
+async fn publish_rule_version(
+    State(state): State<AppState>,
+    Extension(AdminPrincipal(published_by)): Extension<AdminPrincipal>,
+    Path(id): Path<i64>,
+    Json(request): Json<ApiPublishTaxRuleVersionRequest>,
+) -> HttpResult<ApiTaxRuleVersionRecord> {
+    let pool = require_db_pool(state)?;
+    stored_rule_version_response(
+        publish_tax_rule_version(&pool, id, &published_by, &request.supersedes).await,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/admin/rules/versions/{id}/retire",
+    tag = "admin",
+    params(
+        ("id" = i64, Path, description = "Stored rule version id")
+    ),
+    responses(
+        (status = 200, description = "Version retired and no longer served", body = ApiTaxRuleVersionRecord),
+        (status = 400, description = "Version is already retired", body = ApiErrorResponse),
+        (status = 401, description = "Missing or unknown admin token", body = ApiErrorResponse),
+        (status = 404, description = "No stored version with this id", body = ApiErrorResponse),
+        (status = 503, description = "Database is not configured", body = ApiErrorResponse)
+    )
+)]
+async fn retire_rule_version(
+    State(state): State<AppState>,
+    Extension(AdminPrincipal(retired_by)): Extension<AdminPrincipal>,
+    Path(id): Path<i64>,
+) -> HttpResult<ApiTaxRuleVersionRecord> {
+    let pool = require_db_pool(state)?;
+    stored_rule_version_response(retire_tax_rule_version(&pool, id, &retired_by).await)
+}
+
 #[utoipa::path(
     post,
     path = "/v1/scenario/calculate",
@@ -274,6 +655,25 @@ async fn calculate_scenario(
         .map_err(api_error_to_http)
 }
 
+#[utoipa::path(
+    post,
+    path = "/v1/scenario/calculate/explain",
+    tag = "scenario",
+    request_body = ApiEstateScenarioInput,
+    responses(
+        (status = 200, description = "Calculation trace with rule citations as plain text", content_type = "text/plain", body = String),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn explain_scenario_text(
+    Json(input): Json<ApiEstateScenarioInput>,
+) -> Result<impl IntoResponse, HttpError> {
+    let text = render_scenario_explain_text_contract(input).map_err(api_error_to_http)?;
+    Ok(([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], text))
+}
+
 #[utoipa::path(
     post,
     path = "/v1/scenario/optimize",
@@ -294,6 +694,134 @@ async fn optimize_scenarios(
         .map_err(api_error_to_http)
 }
 
+#[utoipa::path(
+    post,
+    path = "/v1/scenario/optimize/strategies",
+    tag = "scenario",
+    request_body = ApiStrategyOptimizationRequest,
+    responses(
+        (status = 200, description = "Generated planning strategies ranked best first, starting from the current plan", body = [ApiRankedStrategy]),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn optimize_strategies(
+    Json(request): Json<ApiStrategyOptimizationRequest>,
+) -> HttpResult<Vec<ApiRankedStrategy>> {
+    rank_planning_strategies_contract(request)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/scenario/couple/calculate",
+    tag = "scenario",
+    request_body = ApiCoupleScenarioInput,
+    responses(
+        (status = 200, description = "First- and second-death results with combined tax", body = ApiCoupleScenarioResult),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn calculate_couple_scenario(
+    Json(input): Json<ApiCoupleScenarioInput>,
+) -> HttpResult<ApiCoupleScenarioResult> {
+    calculate_couple_scenario_contract(input)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/scenario/cross-border/calculate",
+    tag = "scenario",
+    request_body = ApiCrossBorderScenarioInput,
+    responses(
+        (status = 200, description = "Home and situs-jurisdiction results with a consolidated liability and liquidity view", body = ApiCrossBorderScenarioResult),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn calculate_cross_border_scenario(
+    Json(input): Json<ApiCrossBorderScenarioInput>,
+) -> HttpResult<ApiCrossBorderScenarioResult> {
+    calculate_cross_border_estate_contract(input)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/scenario/liquidation-account",
+    tag = "scenario",
+    request_body = ApiEstateScenarioInput,
+    responses(
+        (status = 200, description = "Draft liquidation and distribution account", body = ApiLiquidationAndDistributionAccount),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn draft_liquidation_account(
+    Json(input): Json<ApiEstateScenarioInput>,
+) -> HttpResult<ApiLiquidationAndDistributionAccount> {
+    draft_liquidation_account_contract(input)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/scenario/liquidation-account/pdf",
+    tag = "scenario",
+    request_body = ApiEstateScenarioInput,
+    responses(
+        (status = 200, description = "Printable draft liquidation and distribution account", content_type = "application/pdf", body = Vec<u8>),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn render_liquidation_account_pdf(
+    Json(input): Json<ApiEstateScenarioInput>,
+) -> Result<impl IntoResponse, HttpError> {
+    let bytes = render_liquidation_account_pdf_contract(input).map_err(api_error_to_http)?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"liquidation-and-distribution-account.pdf\"",
+            ),
+        ],
+        bytes,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/donations/calculate",
+    tag = "donations",
+    request_body = ApiDonationsTaxInput,
+    responses(
+        (status = 200, description = "Per-gift and per-tax-year donations tax breakdown", body = ApiDonationsTaxResult),
+        (status = 400, description = "Input validation failed", body = ApiErrorResponse),
+        (status = 422, description = "Rules could not be selected", body = ApiErrorResponse),
+        (status = 500, description = "Computation failure", body = ApiErrorResponse)
+    )
+)]
+async fn calculate_donations(
+    Json(input): Json<ApiDonationsTaxInput>,
+) -> HttpResult<ApiDonationsTaxResult> {
+    calculate_donations_tax_contract(input)
+        .map(Json)
+        .map_err(api_error_to_http)
+}
+
 #[utoipa::path(
     post,
     path = "/v1/scenario/ingest",
@@ -394,6 +922,32 @@ fn api_error_to_http(error: ApiErrorResponse) -> HttpError {
     (status, Json(error))
 }
 
+fn engine_error_to_http(error: EngineError) -> HttpError {
+    api_error_to_http(to_api_error_response(error))
+}
+
+fn stored_rule_version_response(
+    result: Result<Option<StoredTaxRuleVersionRecord>, EngineError>,
+) -> HttpResult<ApiTaxRuleVersionRecord> {
+    match result.map_err(engine_error_to_http)? {
+        Some(record) => Ok(Json(record.into())),
+        None => Err(not_found_response("No stored rule version with this id")),
+    }
+}
+
+fn require_db_pool(state: AppState) -> Result<PgPool, HttpError> {
+    state.db_pool.ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiErrorResponse {
+                code: ApiErrorCode::Computation,
+                message: "Database pool is not configured".to_string(),
+                validation_issues: Vec::new(),
+            }),
+        )
+    })
+}
+
 fn not_found_response(message: &str) -> HttpError {
     (
         StatusCode::NOT_FOUND,
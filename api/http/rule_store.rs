@@ -0,0 +1,412 @@
+use crate::api::contracts::{ApiJurisdiction, ApiVersionedJurisdictionTaxRuleSet};
+use crate::core::errors::EngineError;
+use crate::core::rules::rule_catalog::{
+    install_tax_rule_catalogs, uncovered_tax_rule_versions, TaxRuleCatalogSource,
+};
+use crate::core::rules::rule_publishing::{
+    published_tax_rule_catalogs, TaxRuleVersionRecord, TaxRuleVersionReview, TaxRuleVersionStatus,
+};
+use crate::core::rules::tax_rules::{
+    supported_jurisdictions, tax_rule_catalog_for, Jurisdiction, VersionedJurisdictionTaxRuleSet,
+};
+use crate::core::validation::{InputValidationError, ValidationIssue};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+
+const CREATE_TAX_RULE_VERSIONS: &str = r#"
+CREATE TABLE IF NOT EXISTS tax_rule_versions (
+    id BIGSERIAL PRIMARY KEY,
+    jurisdiction TEXT NOT NULL,
+    version_id TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('draft', 'published', 'retired')),
+    rule_set JSONB NOT NULL,
+    author TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    reviewer TEXT,
+    review_approved BOOLEAN,
+    review_notes TEXT,
+    reviewed_at TIMESTAMPTZ,
+    published_by TEXT,
+    published_at TIMESTAMPTZ,
+    retired_by TEXT,
+    retired_at TIMESTAMPTZ,
+    UNIQUE (jurisdiction, version_id)
+)"#;
+
+const ADD_RETIRED_BY: &str =
+    "ALTER TABLE tax_rule_versions ADD COLUMN IF NOT EXISTS retired_by TEXT";
+
+// Timestamps are read back as UTC RFC 3339 text; the pool is built without chrono support.
+const RECORD_COLUMNS: &str = r#"
+    id, jurisdiction, status, rule_set::text AS rule_set, author,
+    to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS created_at,
+    reviewer, review_approved, review_notes,
+    to_char(reviewed_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS reviewed_at,
+    published_by,
+    to_char(published_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS published_at,
+    retired_by,
+    to_char(retired_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS retired_at"#;
+
+const RECORD_ORDER: &str = "jurisdiction, (rule_set->'version'->>'tax_year_from')::int, id";
+
+// Recorded as the author and publisher of the versions seeded into an empty table.
+const SEED_PRINCIPAL: &str = "seed";
+
+// A stored row with its rule set text kept owned. It is converted to the registry's rule set only
+// when published versions are installed, so drafts and retired rows are never interned.
+pub type StoredTaxRuleVersionRecord = TaxRuleVersionRecord<ApiVersionedJurisdictionTaxRuleSet>;
+
+fn store_error(err: sqlx::Error) -> EngineError {
+    EngineError::Computation(format!("Rule store query failed: {err}"))
+}
+
+fn jurisdiction_key(jurisdiction: Jurisdiction) -> String {
+    format!("{:?}", ApiJurisdiction::from(jurisdiction))
+}
+
+fn jurisdiction_from_key(key: &str) -> Option<Jurisdiction> {
+    supported_jurisdictions()
+        .into_iter()
+        .find(|jurisdiction| jurisdiction_key(*jurisdiction) == key)
+}
+
+fn rule_set_json(versioned: VersionedJurisdictionTaxRuleSet) -> Result<String, EngineError> {
+    serde_json::to_string(&ApiVersionedJurisdictionTaxRuleSet::from(versioned))
+        .map_err(|err| EngineError::Computation(format!("Rule set serialization failed: {err}")))
+}
+
+fn record_from_row(row: &PgRow) -> Result<StoredTaxRuleVersionRecord, EngineError> {
+    let id = row.try_get::<i64, _>("id").map_err(store_error)?;
+    let stored_jurisdiction = row
+        .try_get::<String, _>("jurisdiction")
+        .map_err(store_error)?;
+    let stored_status = row.try_get::<String, _>("status").map_err(store_error)?;
+    let rule_set = row.try_get::<String, _>("rule_set").map_err(store_error)?;
+
+    let jurisdiction = jurisdiction_from_key(&stored_jurisdiction).ok_or_else(|| {
+        EngineError::Computation(format!(
+            "Rule version {id} has unknown jurisdiction '{stored_jurisdiction}'"
+        ))
+    })?;
+    let status = TaxRuleVersionStatus::from_code(&stored_status).ok_or_else(|| {
+        EngineError::Computation(format!(
+            "Rule version {id} has unknown status '{stored_status}'"
+        ))
+    })?;
+    let versioned =
+        serde_json::from_str::<ApiVersionedJurisdictionTaxRuleSet>(&rule_set).map_err(|err| {
+            EngineError::Computation(format!(
+                "Rule version {id} does not match the rule set schema: {err}"
+            ))
+        })?;
+
+    let review = match row
+        .try_get::<Option<String>, _>("reviewer")
+        .map_err(store_error)?
+    {
+        Some(reviewer) => Some(TaxRuleVersionReview {
+            reviewer,
+            approved: row
+                .try_get::<Option<bool>, _>("review_approved")
+                .map_err(store_error)?
+                .unwrap_or(false),
+            notes: row.try_get("review_notes").map_err(store_error)?,
+            reviewed_at: row
+                .try_get::<Option<String>, _>("reviewed_at")
+                .map_err(store_error)?
+                .unwrap_or_default(),
+        }),
+        None => None,
+    };
+
+    Ok(TaxRuleVersionRecord {
+        id,
+        jurisdiction,
+        status,
+        author: row.try_get("author").map_err(store_error)?,
+        created_at: row.try_get("created_at").map_err(store_error)?,
+        review,
+        published_by: row.try_get("published_by").map_err(store_error)?,
+        published_at: row.try_get("published_at").map_err(store_error)?,
+        retired_by: row.try_get("retired_by").map_err(store_error)?,
+        retired_at: row.try_get("retired_at").map_err(store_error)?,
+        versioned,
+    })
+}
+
+// Creates the table and, when it is empty, seeds it with the versions being served as published
+// versions: the rule file's where one is loaded, otherwise the compiled-in catalog. The lock keeps
+// two instances starting together from seeding twice.
+pub async fn prepare_tax_rule_store(pool: &PgPool) -> Result<(), EngineError> {
+    for statement in [CREATE_TAX_RULE_VERSIONS, ADD_RETIRED_BY] {
+        sqlx::query(statement)
+            .execute(pool)
+            .await
+            .map_err(store_error)?;
+    }
+
+    let mut tx = pool.begin().await.map_err(store_error)?;
+    sqlx::query("LOCK TABLE tax_rule_versions IN EXCLUSIVE MODE")
+        .execute(&mut *tx)
+        .await
+        .map_err(store_error)?;
+    let stored = sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM tax_rule_versions)")
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(store_error)?;
+    if !stored {
+        for jurisdiction in supported_jurisdictions() {
+            for versioned in tax_rule_catalog_for(jurisdiction) {
+                let version_id = versioned.version.version_id;
+                sqlx::query(
+                    "INSERT INTO tax_rule_versions \
+                     (jurisdiction, version_id, status, rule_set, author, published_by, published_at) \
+                     VALUES ($1, $2, 'published', CAST($3 AS TEXT)::jsonb, $4, $4, now())",
+                )
+                .bind(jurisdiction_key(jurisdiction))
+                .bind(version_id)
+                .bind(rule_set_json(versioned)?)
+                .bind(SEED_PRINCIPAL)
+                .execute(&mut *tx)
+                .await
+                .map_err(store_error)?;
+            }
+        }
+    }
+    tx.commit().await.map_err(store_error)
+}
+
+fn installed(record: StoredTaxRuleVersionRecord) -> TaxRuleVersionRecord {
+    record.map_rule_set(VersionedJurisdictionTaxRuleSet::from)
+}
+
+// Swaps the published versions into the registry so `tax_rules_for` serves them.
+pub async fn refresh_published_tax_rules(pool: &PgPool) -> Result<usize, EngineError> {
+    let published =
+        list_tax_rule_versions(pool, None, Some(TaxRuleVersionStatus::Published)).await?;
+    let catalogs = published_tax_rule_catalogs(published.into_iter().map(installed).collect());
+    let count = catalogs.len();
+    install_tax_rule_catalogs(TaxRuleCatalogSource::Database, catalogs);
+    Ok(count)
+}
+
+pub async fn list_tax_rule_versions(
+    pool: &PgPool,
+    jurisdiction: Option<Jurisdiction>,
+    status: Option<TaxRuleVersionStatus>,
+) -> Result<Vec<StoredTaxRuleVersionRecord>, EngineError> {
+    let rows = sqlx::query(&format!(
+        "SELECT {RECORD_COLUMNS} FROM tax_rule_versions \
+         WHERE ($1::text IS NULL OR jurisdiction = $1) AND ($2::text IS NULL OR status = $2) \
+         ORDER BY {RECORD_ORDER}"
+    ))
+    .bind(jurisdiction.map(jurisdiction_key))
+    .bind(status.map(TaxRuleVersionStatus::code))
+    .fetch_all(pool)
+    .await
+    .map_err(store_error)?;
+
+    rows.iter().map(record_from_row).collect()
+}
+
+pub async fn fetch_tax_rule_version(
+    pool: &PgPool,
+    id: i64,
+) -> Result<Option<StoredTaxRuleVersionRecord>, EngineError> {
+    sqlx::query(&format!(
+        "SELECT {RECORD_COLUMNS} FROM tax_rule_versions WHERE id = $1"
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(store_error)?
+    .as_ref()
+    .map(record_from_row)
+    .transpose()
+}
+
+async fn lock_tax_rule_version(
+    tx: &mut Transaction<'_, Postgres>,
+    id: i64,
+) -> Result<Option<StoredTaxRuleVersionRecord>, EngineError> {
+    sqlx::query(&format!(
+        "SELECT {RECORD_COLUMNS} FROM tax_rule_versions WHERE id = $1 FOR UPDATE"
+    ))
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(store_error)?
+    .as_ref()
+    .map(record_from_row)
+    .transpose()
+}
+
+pub async fn create_tax_rule_draft(
+    pool: &PgPool,
+    jurisdiction: Jurisdiction,
+    author: &str,
+    versioned: VersionedJurisdictionTaxRuleSet,
+) -> Result<StoredTaxRuleVersionRecord, EngineError> {
+    versioned
+        .validate_draft(jurisdiction, author)
+        .map_err(EngineError::Validation)?;
+
+    let version_id = versioned.version.version_id.to_string();
+    let inserted = sqlx::query_scalar::<_, i64>(
+        "INSERT INTO tax_rule_versions (jurisdiction, version_id, status, rule_set, author) \
+         VALUES ($1, $2, 'draft', CAST($3 AS TEXT)::jsonb, $4) RETURNING id",
+    )
+    .bind(jurisdiction_key(jurisdiction))
+    .bind(&version_id)
+    .bind(rule_set_json(versioned)?)
+    .bind(author.trim())
+    .fetch_one(pool)
+    .await;
+
+    let id = match inserted {
+        Ok(id) => id,
+        Err(sqlx::Error::Database(err)) if err.is_unique_violation() => {
+            return Err(EngineError::Validation(InputValidationError::new(vec![
+                ValidationIssue::new(
+                    "rule_set.version.version_id".to_string(),
+                    format!("Version id '{version_id}' already exists for {jurisdiction:?}"),
+                ),
+            ])));
+        }
+        Err(err) => return Err(store_error(err)),
+    };
+
+    fetch_tax_rule_version(pool, id).await?.ok_or_else(|| {
+        EngineError::Computation(format!("Rule version {id} was not found after insert"))
+    })
+}
+
+pub async fn review_tax_rule_version(
+    pool: &PgPool,
+    id: i64,
+    reviewer: &str,
+    approved: bool,
+    notes: Option<&str>,
+) -> Result<Option<StoredTaxRuleVersionRecord>, EngineError> {
+    let mut tx = pool.begin().await.map_err(store_error)?;
+    let Some(record) = lock_tax_rule_version(&mut tx, id).await? else {
+        return Ok(None);
+    };
+    record
+        .validate_review(reviewer)
+        .map_err(EngineError::Validation)?;
+
+    sqlx::query(
+        "UPDATE tax_rule_versions \
+         SET reviewer = $2, review_approved = $3, review_notes = $4, reviewed_at = now() \
+         WHERE id = $1",
+    )
+    .bind(id)
+    .bind(reviewer.trim())
+    .bind(approved)
+    .bind(notes)
+    .execute(&mut *tx)
+    .await
+    .map_err(store_error)?;
+    tx.commit().await.map_err(store_error)?;
+
+    fetch_tax_rule_version(pool, id).await
+}
+
+// Publishes an approved draft in one transaction with the versions it supersedes: each has its
+// window closed the year before the draft starts, or is retired when the draft covers it entirely.
+// Then refreshes the registry.
+pub async fn publish_tax_rule_version(
+    pool: &PgPool,
+    id: i64,
+    published_by: &str,
+    supersedes: &[String],
+) -> Result<Option<StoredTaxRuleVersionRecord>, EngineError> {
+    let mut tx = pool.begin().await.map_err(store_error)?;
+    let Some(record) = lock_tax_rule_version(&mut tx, id).await? else {
+        return Ok(None);
+    };
+
+    // Publishing installs the draft alongside the published versions, so they are checked as the
+    // registry will serve them.
+    let record = installed(record);
+    let jurisdiction = jurisdiction_key(record.jurisdiction);
+    let published = sqlx::query(&format!(
+        "SELECT {RECORD_COLUMNS} FROM tax_rule_versions \
+         WHERE jurisdiction = $1 AND status = 'published' ORDER BY {RECORD_ORDER} FOR UPDATE"
+    ))
+    .bind(&jurisdiction)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(store_error)?
+    .iter()
+    .map(|row| record_from_row(row).map(installed))
+    .collect::<Result<Vec<_>, _>>()?;
+    record
+        .validate_publish(published_by, &published, supersedes)
+        .map_err(EngineError::Validation)?;
+
+    for superseded in published.into_iter().filter(|other| {
+        supersedes
+            .iter()
+            .any(|version_id| other.versioned.version.version_id == version_id.as_str())
+    }) {
+        match uncovered_tax_rule_versions(&superseded.versioned, &record.versioned.version).pop() {
+            Some(closed) => sqlx::query(
+                "UPDATE tax_rule_versions SET rule_set = CAST($2 AS TEXT)::jsonb WHERE id = $1",
+            )
+            .bind(superseded.id)
+            .bind(rule_set_json(closed)?),
+            None => sqlx::query(
+                "UPDATE tax_rule_versions \
+                 SET status = 'retired', retired_by = $2, retired_at = now() WHERE id = $1",
+            )
+            .bind(superseded.id)
+            .bind(published_by.trim().to_string()),
+        }
+        .execute(&mut *tx)
+        .await
+        .map_err(store_error)?;
+    }
+    sqlx::query(
+        "UPDATE tax_rule_versions \
+         SET status = 'published', published_by = $2, published_at = now() WHERE id = $1",
+    )
+    .bind(id)
+    .bind(published_by.trim())
+    .execute(&mut *tx)
+    .await
+    .map_err(store_error)?;
+    tx.commit().await.map_err(store_error)?;
+
+    refresh_published_tax_rules(pool).await?;
+    fetch_tax_rule_version(pool, id).await
+}
+
+pub async fn retire_tax_rule_version(
+    pool: &PgPool,
+    id: i64,
+    retired_by: &str,
+) -> Result<Option<StoredTaxRuleVersionRecord>, EngineError> {
+    let mut tx = pool.begin().await.map_err(store_error)?;
+    let Some(record) = lock_tax_rule_version(&mut tx, id).await? else {
+        return Ok(None);
+    };
+    record
+        .validate_retire(retired_by)
+        .map_err(EngineError::Validation)?;
+
+    sqlx::query(
+        "UPDATE tax_rule_versions \
+         SET status = 'retired', retired_by = $2, retired_at = now() WHERE id = $1",
+    )
+    .bind(id)
+    .bind(retired_by.trim())
+    .execute(&mut *tx)
+    .await
+    .map_err(store_error)?;
+    tx.commit().await.map_err(store_error)?;
+
+    refresh_published_tax_rules(pool).await?;
+    fetch_tax_rule_version(pool, id).await
+}
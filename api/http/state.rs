@@ -1,6 +1,68 @@
 use sqlx::PgPool;
+use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
 pub struct AppState {
     pub db_pool: Option<PgPool>,
+    // Directory of rule catalog files; `None` serves only the compiled-in catalogs.
+    pub rules_dir: Option<PathBuf>,
+    // Bearer tokens accepted on `/v1/admin`; with none configured the admin routes refuse every
+    // request.
+    pub admin_tokens: Vec<AdminToken>,
+}
+
+// A bearer token and the principal it authenticates, recorded as author, reviewer, publisher or
+// retirer of rule versions.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AdminToken {
+    pub principal: String,
+    pub token: String,
+}
+
+impl fmt::Debug for AdminToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdminToken")
+            .field("principal", &self.principal)
+            .finish_non_exhaustive()
+    }
+}
+
+// `principal:token` pairs separated by commas, as set in `ADMIN_API_TOKENS`.
+pub fn parse_admin_tokens(raw: &str) -> Result<Vec<AdminToken>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .enumerate()
+        .map(|(index, entry)| match entry.split_once(':') {
+            Some((principal, token))
+                if !principal.trim().is_empty() && !token.trim().is_empty() =>
+            {
+                Ok(AdminToken {
+                    principal: principal.trim().to_string(),
+                    token: token.trim().to_string(),
+                })
+            }
+            _ => Err(format!(
+                "ADMIN_API_TOKENS entry {} is not `principal:token`",
+                index + 1
+            )),
+        })
+        .collect()
+}
+
+// The principal whose token matches, compared in time independent of where the bytes differ.
+pub fn admin_principal_for<'a>(tokens: &'a [AdminToken], presented: &str) -> Option<&'a str> {
+    tokens
+        .iter()
+        .find(|admin| {
+            admin.token.len() == presented.len()
+                && admin
+                    .token
+                    .bytes()
+                    .zip(presented.bytes())
+                    .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                    == 0
+        })
+        .map(|admin| admin.principal.as_str())
 }
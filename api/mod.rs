@@ -1,7 +1,9 @@
 pub mod contracts;
 pub mod handler;
 pub mod http;
+pub mod pdf;
 
 pub use contracts::*;
 pub use handler::*;
 pub use http::*;
+pub use pdf::*;
@@ -0,0 +1,244 @@
+use crate::core::domain::models::{
+    AccountEntry, LiquidationAccountSection, LiquidationAndDistributionAccount,
+};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::Jurisdiction;
+use crate::jurisdictions::south_africa::south_africa_jurisdiction_baseline;
+use crate::jurisdictions::us::us_state_jurisdiction_baseline;
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+// A4 portrait in points, set in 9pt Courier so columns line up without font metrics.
+const PAGE_WIDTH: i64 = 595;
+const PAGE_HEIGHT: i64 = 842;
+const MARGIN: i64 = 50;
+const FONT_SIZE: i64 = 9;
+const LINE_HEIGHT: i64 = 12;
+const DESCRIPTION_WIDTH: usize = 62;
+const AMOUNT_WIDTH: usize = 20;
+
+fn jurisdiction_name(jurisdiction: Jurisdiction) -> &'static str {
+    match jurisdiction {
+        Jurisdiction::SouthAfrica => south_africa_jurisdiction_baseline().jurisdiction_name,
+        Jurisdiction::UsNewYork
+        | Jurisdiction::UsTexas
+        | Jurisdiction::UsCalifornia
+        | Jurisdiction::UsFlorida
+        | Jurisdiction::UsMinnesota => us_state_jurisdiction_baseline(jurisdiction)
+            .map(|law| law.jurisdiction_name)
+            .unwrap_or("United States"),
+    }
+}
+
+fn format_amount(amount: Decimal) -> String {
+    let rounded = amount
+        .abs()
+        .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+    let cents = (rounded * Decimal::ONE_HUNDRED).to_u128().unwrap_or(0);
+    let whole = (cents / 100).to_string();
+    let mut grouped = String::new();
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let formatted = format!("{grouped}.{:02}", cents % 100);
+    if amount.is_sign_negative() && cents > 0 {
+        format!("({formatted})")
+    } else {
+        formatted
+    }
+}
+
+fn amount_line(description: &str, amount: Decimal) -> String {
+    let description = description
+        .chars()
+        .map(|c| if c.is_ascii() { c } else { '?' })
+        .take(DESCRIPTION_WIDTH)
+        .collect::<String>();
+    format!(
+        "{description:<DESCRIPTION_WIDTH$}{:>AMOUNT_WIDTH$}",
+        format_amount(amount)
+    )
+}
+
+fn entry_lines(lines: &mut Vec<String>, entries: &[AccountEntry]) {
+    for entry in entries {
+        lines.push(amount_line(
+            &format!("  {}", entry.description),
+            entry.amount,
+        ));
+    }
+}
+
+fn section_lines(
+    lines: &mut Vec<String>,
+    heading: &str,
+    section: &LiquidationAccountSection,
+    balance_label: &str,
+) {
+    lines.push(heading.to_string());
+    lines.push("Receipts".to_string());
+    entry_lines(lines, &section.receipts);
+    lines.push(amount_line("Total receipts", section.total_receipts_amount));
+    lines.push("Payments".to_string());
+    entry_lines(lines, &section.payments);
+    lines.push(amount_line("Total payments", section.total_payments_amount));
+    lines.push(amount_line(balance_label, section.balance_amount));
+    lines.push(String::new());
+}
+
+pub fn liquidation_account_text_lines(account: &LiquidationAndDistributionAccount) -> Vec<String> {
+    let mut lines = vec![
+        "LIQUIDATION AND DISTRIBUTION ACCOUNT (DRAFT)".to_string(),
+        format!(
+            "Jurisdiction: {}    Tax year: {}",
+            jurisdiction_name(account.jurisdiction),
+            account.tax_year
+        ),
+        String::new(),
+    ];
+
+    section_lines(
+        &mut lines,
+        "CAPITAL ACCOUNT",
+        &account.capital_account,
+        "Balance carried to distribution account",
+    );
+    section_lines(
+        &mut lines,
+        "INCOME ACCOUNT",
+        &account.income_account,
+        "Balance carried to distribution account",
+    );
+
+    let distribution = &account.distribution_account;
+    lines.push("DISTRIBUTION ACCOUNT".to_string());
+    lines.push(amount_line(
+        "Balance for distribution",
+        distribution.balance_for_distribution_amount,
+    ));
+    lines.push("Awards".to_string());
+    entry_lines(&mut lines, &distribution.awards);
+    lines.push(amount_line(
+        "Total awarded",
+        distribution.total_awarded_amount,
+    ));
+    lines.push(String::new());
+
+    let reconciliation = &account.reconciliation;
+    lines.push("RECONCILIATION".to_string());
+    for (description, amount) in [
+        (
+            "Estate duty per estate duty breakdown",
+            reconciliation.estate_duty_per_breakdown_amount,
+        ),
+        (
+            "Estate duty per capital account",
+            reconciliation.estate_duty_per_account_amount,
+        ),
+        (
+            "Executor's remuneration per estate duty breakdown",
+            reconciliation.executor_fee_per_breakdown_amount,
+        ),
+        (
+            "Executor's remuneration per capital account",
+            reconciliation.executor_fee_per_account_amount,
+        ),
+        (
+            "Cash requirements per liquidity analysis",
+            reconciliation.cash_requirements_per_liquidity_amount,
+        ),
+        (
+            "Payments per capital account",
+            reconciliation.payments_per_account_amount,
+        ),
+    ] {
+        lines.push(amount_line(&format!("  {description}"), amount));
+    }
+    lines.push(String::new());
+    lines.push(
+        if account.balanced && reconciliation.reconciled {
+            "Status: account balances and reconciles to the calculation"
+        } else if account.balanced {
+            "Status: account balances but does not reconcile; review before lodging"
+        } else {
+            "Status: account does not balance (estate insolvent); review before lodging"
+        }
+        .to_string(),
+    );
+    lines
+}
+
+pub fn render_liquidation_account_pdf(
+    account: &LiquidationAndDistributionAccount,
+) -> Result<Vec<u8>, EngineError> {
+    let lines = liquidation_account_text_lines(account);
+    let lines_per_page = ((PAGE_HEIGHT - 2 * MARGIN) / LINE_HEIGHT) as usize;
+
+    let mut document = Document::with_version("1.5");
+    let pages_id = document.new_object_id();
+    let font_id = document.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Courier",
+        "Encoding" => "WinAnsiEncoding",
+    });
+    let resources_id = document.add_object(dictionary! {
+        "Font" => dictionary! { "F1" => font_id },
+    });
+
+    let mut page_ids = Vec::new();
+    for page_lines in lines.chunks(lines_per_page) {
+        let mut operations = vec![
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec!["F1".into(), FONT_SIZE.into()]),
+            Operation::new("TL", vec![LINE_HEIGHT.into()]),
+            Operation::new("Td", vec![MARGIN.into(), (PAGE_HEIGHT - MARGIN).into()]),
+        ];
+        for line in page_lines {
+            operations.push(Operation::new(
+                "Tj",
+                vec![Object::string_literal(line.as_str())],
+            ));
+            operations.push(Operation::new("T*", vec![]));
+        }
+        operations.push(Operation::new("ET", vec![]));
+
+        let content = Content { operations }
+            .encode()
+            .map_err(|err| EngineError::Computation(format!("Unable to render PDF: {err}")))?;
+        let content_id = document.add_object(Stream::new(dictionary! {}, content));
+        page_ids.push(document.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+        }));
+    }
+
+    document.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Count" => page_ids.len() as i64,
+            "Kids" => page_ids.into_iter().map(Object::from).collect::<Vec<_>>(),
+            "Resources" => resources_id,
+            "MediaBox" => vec![0.into(), 0.into(), PAGE_WIDTH.into(), PAGE_HEIGHT.into()],
+        }),
+    );
+    let catalog_id = document.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+    });
+    document.trailer.set("Root", catalog_id);
+    document.compress();
+
+    let mut bytes = Vec::new();
+    document
+        .save_to(&mut bytes)
+        .map_err(|err| EngineError::Computation(format!("Unable to render PDF: {err}")))?;
+    Ok(bytes)
+}
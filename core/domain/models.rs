@@ -1,4 +1,8 @@
-use crate::core::rules::tax_rules::{Jurisdiction, TaxPayerClass};
+use crate::core::rules::tax_rules::{
+    Currency, EstateTaxApportionment, Jurisdiction, TaxPayerClass,
+};
+use rust_decimal::Decimal;
+use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResidencyStatus {
@@ -6,23 +10,223 @@ pub enum ResidencyStatus {
     NonResident,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl CalendarDate {
+    pub fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.trim().splitn(3, '-');
+        let year = parts.next()?.parse::<u16>().ok()?;
+        let month = parts.next()?.parse::<u8>().ok()?;
+        let day = parts.next()?.parse::<u8>().ok()?;
+        if !(1..=12).contains(&month) || day == 0 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    pub fn previous_day(self) -> Self {
+        if self.day > 1 {
+            Self {
+                day: self.day - 1,
+                ..self
+            }
+        } else if self.month > 1 {
+            let month = self.month - 1;
+            Self {
+                month,
+                day: Self::days_in_month(self.year, month),
+                ..self
+            }
+        } else {
+            Self {
+                year: self.year - 1,
+                month: 12,
+                day: 31,
+            }
+        }
+    }
+
+    pub fn next_day(self) -> Self {
+        if self.day < Self::days_in_month(self.year, self.month) {
+            Self {
+                day: self.day + 1,
+                ..self
+            }
+        } else if self.month < 12 {
+            Self {
+                month: self.month + 1,
+                day: 1,
+                ..self
+            }
+        } else {
+            Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            4 | 6 | 9 | 11 => 30,
+            2 if (year.is_multiple_of(4) && !year.is_multiple_of(100))
+                || year.is_multiple_of(400) =>
+            {
+                29
+            }
+            2 => 28,
+            _ => 31,
+        }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EstateAsset {
     pub name: String,
-    pub market_value_amount: f64,
-    pub base_cost_amount: f64,
+    pub market_value_amount: Decimal,
+    pub base_cost_amount: Decimal,
     pub is_liquid: bool,
     pub situs_in_jurisdiction: bool,
+    // Where the asset is situated; when set, it overrides `situs_in_jurisdiction`.
+    pub situs_jurisdiction: Option<Jurisdiction>,
     pub included_in_estate_duty: bool,
     pub included_in_cgt_deemed_disposal: bool,
     pub bequeathed_to_surviving_spouse: bool,
     pub bequeathed_to_pbo: bool,
     pub qualifies_primary_residence_exclusion: bool,
+    // `None` means the amounts are already in the jurisdiction's reporting currency.
+    pub currency: Option<Currency>,
 }
 
 impl EstateAsset {
-    pub fn raw_capital_gain_amount(&self) -> f64 {
-        (self.market_value_amount - self.base_cost_amount).max(0.0)
+    pub fn is_situated_in(&self, jurisdiction: Jurisdiction) -> bool {
+        self.situs_jurisdiction
+            .map_or(self.situs_in_jurisdiction, |situs| situs == jurisdiction)
+    }
+
+    pub fn raw_capital_gain_amount(&self) -> Decimal {
+        (self.market_value_amount - self.base_cost_amount).max(Decimal::ZERO)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyParty {
+    Deceased,
+    Estate,
+    SurvivingSpouse,
+    NamedPerson,
+    Company,
+}
+
+#[derive(Debug, Clone)]
+pub struct LifeInsurancePolicy {
+    pub name: String,
+    pub owner: PolicyParty,
+    pub life_insured: PolicyParty,
+    pub beneficiary: PolicyParty,
+    pub sum_assured_amount: Decimal,
+    pub premiums_paid_by: PolicyParty,
+    pub held_under_buy_and_sell_agreement: bool,
+}
+
+impl LifeInsurancePolicy {
+    pub fn payable_to_estate_on_death(&self) -> bool {
+        self.life_insured == PolicyParty::Deceased && self.beneficiary == PolicyParty::Estate
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetirementFundType {
+    PensionFund,
+    ProvidentFund,
+    PreservationFund,
+    RetirementAnnuityFund,
+    LivingAnnuity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetirementBenefitRecipient {
+    SurvivingSpouse,
+    Dependants,
+    Estate,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetirementFundInterest {
+    pub name: String,
+    pub fund_type: RetirementFundType,
+    pub value_amount: Decimal,
+    pub disallowed_contributions_amount: Decimal,
+    pub nominated_recipient: RetirementBenefitRecipient,
+}
+
+#[derive(Debug, Clone)]
+pub struct Beneficiary {
+    pub name: String,
+    pub category: HeirCategory,
+    pub specific_bequest_asset_names: Vec<String>,
+    pub legacy_amount: Decimal,
+    pub residue_share: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaritalPropertyRegime {
+    #[default]
+    NotMarried,
+    OutOfCommunity,
+    InCommunity,
+    Accrual,
+}
+
+// Accrual values are net estate values; commencement values are already adjusted for inflation.
+#[derive(Debug, Clone, Default)]
+pub struct MaritalPropertyInput {
+    pub regime: MaritalPropertyRegime,
+    pub deceased_commencement_value_amount: Decimal,
+    pub deceased_excluded_assets_amount: Decimal,
+    pub spouse_net_estate_amount: Decimal,
+    pub spouse_commencement_value_amount: Decimal,
+    pub spouse_excluded_assets_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct CurrencyLiability {
+    pub description: String,
+    pub currency: Currency,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FxRate {
+    pub currency: Currency,
+    // Units of the reporting currency per one unit of `currency`.
+    pub rate: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct FxRateTable {
+    pub valuation_date: String,
+    pub rates: Vec<FxRate>,
+}
+
+impl FxRateTable {
+    pub fn rate_for(&self, currency: Currency) -> Option<Decimal> {
+        self.rates
+            .iter()
+            .find(|rate| rate.currency == currency)
+            .map(|rate| rate.rate)
     }
 }
 
@@ -30,26 +234,53 @@ impl EstateAsset {
 pub struct EstateScenarioInput {
     pub jurisdiction: Jurisdiction,
     pub tax_year: u16,
+    // `YYYY-MM-DD`; selects the rule version in effect on that date, and `tax_year` must be the
+    // jurisdiction's tax year containing it.
+    pub date_of_death: Option<String>,
+    // `YYYY-MM-DD` date the assets are valued at; `None` values them at the date of death.
+    pub valuation_date: Option<String>,
     pub taxpayer_class: TaxPayerClass,
     pub residency_status: ResidencyStatus,
-    pub marginal_income_tax_rate: f64,
+    // Taxable income from the start of the tax year to the date of death, before capital gains.
+    pub taxable_income_in_year_of_death_amount: Decimal,
+    // Age the deceased would have reached at the end of the tax year; selects age rebates.
+    pub age_at_tax_year_end: Option<u8>,
+    // Overrides the income tax tables with a flat rate on the taxable capital gain.
+    pub marginal_income_tax_rate: Option<Decimal>,
     pub assets: Vec<EstateAsset>,
-    pub debts_and_loans_amount: f64,
-    pub funeral_costs_amount: f64,
-    pub administration_costs_amount: f64,
-    pub masters_office_fees_amount: f64,
-    pub conveyancing_costs_amount: f64,
-    pub other_settlement_costs_amount: f64,
-    pub final_income_tax_due_amount: f64,
-    pub ongoing_estate_income_tax_provision_amount: f64,
-    pub additional_allowable_estate_transfer_tax_deductions_amount: f64,
-    pub ported_estate_tax_exemption_amount: f64,
-    pub primary_residence_cgt_exclusion_cap_amount: f64,
-    pub executor_fee_rate: f64,
-    pub vat_rate: f64,
-    pub explicit_executor_fee_amount: Option<f64>,
-    pub external_liquidity_proceeds_amount: f64,
-    pub cash_reserve_amount: f64,
+    pub debts_and_loans_amount: Decimal,
+    pub funeral_costs_amount: Decimal,
+    pub administration_costs_amount: Decimal,
+    pub masters_office_fees_amount: Decimal,
+    pub conveyancing_costs_amount: Decimal,
+    pub other_settlement_costs_amount: Decimal,
+    // Overrides the final income tax computed from the income tax tables.
+    pub final_income_tax_due_amount: Option<Decimal>,
+    pub ongoing_estate_income_tax_provision_amount: Decimal,
+    pub additional_allowable_estate_transfer_tax_deductions_amount: Decimal,
+    pub ported_estate_tax_exemption_amount: Decimal,
+    // `None` applies the jurisdiction's primary residence exclusion for the taxpayer class.
+    pub primary_residence_cgt_exclusion_cap_amount: Option<Decimal>,
+    // Overrides the jurisdiction's executor fee tariff with a flat rate on the gross assets.
+    pub executor_fee_rate: Option<Decimal>,
+    // Income the executor collects after death, such as interest, rent and dividends.
+    pub income_accrued_after_death_amount: Decimal,
+    // `None` applies the jurisdiction's standard VAT rate for the tax year.
+    pub vat_rate: Option<Decimal>,
+    pub explicit_executor_fee_amount: Option<Decimal>,
+    pub external_liquidity_proceeds_amount: Decimal,
+    pub cash_reserve_amount: Decimal,
+    pub marital_property: MaritalPropertyInput,
+    pub life_policies: Vec<LifeInsurancePolicy>,
+    pub retirement_funds: Vec<RetirementFundInterest>,
+    pub beneficiaries: Vec<Beneficiary>,
+    // Will clause directing how estate taxes are borne; `None` applies the statutory default.
+    pub tax_apportionment: Option<EstateTaxApportionment>,
+    // Foreign-currency debts, converted and added to `debts_and_loans_amount`.
+    pub liabilities: Vec<CurrencyLiability>,
+    pub fx_rates: Option<FxRateTable>,
+    // Returns an ordered trace of every calculation step with the rule it relied on.
+    pub explain: bool,
 }
 
 impl Default for EstateScenarioInput {
@@ -57,77 +288,535 @@ impl Default for EstateScenarioInput {
         Self {
             jurisdiction: Jurisdiction::SouthAfrica,
             tax_year: 2026,
+            date_of_death: None,
+            valuation_date: None,
             taxpayer_class: TaxPayerClass::NaturalPerson,
             residency_status: ResidencyStatus::Resident,
-            marginal_income_tax_rate: 0.45,
+            taxable_income_in_year_of_death_amount: Decimal::ZERO,
+            age_at_tax_year_end: None,
+            marginal_income_tax_rate: None,
             assets: Vec::new(),
-            debts_and_loans_amount: 0.0,
-            funeral_costs_amount: 0.0,
-            administration_costs_amount: 0.0,
-            masters_office_fees_amount: 0.0,
-            conveyancing_costs_amount: 0.0,
-            other_settlement_costs_amount: 0.0,
-            final_income_tax_due_amount: 0.0,
-            ongoing_estate_income_tax_provision_amount: 0.0,
-            additional_allowable_estate_transfer_tax_deductions_amount: 0.0,
-            ported_estate_tax_exemption_amount: 0.0,
-            primary_residence_cgt_exclusion_cap_amount: 2_000_000.0,
-            executor_fee_rate: 0.035,
-            vat_rate: 0.15,
+            debts_and_loans_amount: Decimal::ZERO,
+            funeral_costs_amount: Decimal::ZERO,
+            administration_costs_amount: Decimal::ZERO,
+            masters_office_fees_amount: Decimal::ZERO,
+            conveyancing_costs_amount: Decimal::ZERO,
+            other_settlement_costs_amount: Decimal::ZERO,
+            final_income_tax_due_amount: None,
+            ongoing_estate_income_tax_provision_amount: Decimal::ZERO,
+            additional_allowable_estate_transfer_tax_deductions_amount: Decimal::ZERO,
+            ported_estate_tax_exemption_amount: Decimal::ZERO,
+            primary_residence_cgt_exclusion_cap_amount: None,
+            executor_fee_rate: None,
+            income_accrued_after_death_amount: Decimal::ZERO,
+            vat_rate: None,
             explicit_executor_fee_amount: None,
-            external_liquidity_proceeds_amount: 0.0,
-            cash_reserve_amount: 0.0,
+            external_liquidity_proceeds_amount: Decimal::ZERO,
+            cash_reserve_amount: Decimal::ZERO,
+            marital_property: MaritalPropertyInput::default(),
+            life_policies: Vec::new(),
+            retirement_funds: Vec::new(),
+            beneficiaries: Vec::new(),
+            tax_apportionment: None,
+            liabilities: Vec::new(),
+            fx_rates: None,
+            explain: false,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeirCategory {
+    SurvivingSpouse,
+    PublicBenefitOrganisation,
+    OtherHeirs,
+}
+
+impl HeirCategory {
+    pub fn for_asset(asset: &EstateAsset) -> Self {
+        if asset.bequeathed_to_surviving_spouse {
+            HeirCategory::SurvivingSpouse
+        } else if asset.bequeathed_to_pbo {
+            HeirCategory::PublicBenefitOrganisation
+        } else {
+            HeirCategory::OtherHeirs
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SteppedUpBasisLine {
+    pub asset_name: String,
+    pub heir: HeirCategory,
+    pub original_base_cost_amount: Decimal,
+    pub stepped_up_basis_amount: Decimal,
+    pub basis_step_up_amount: Decimal,
+}
+
 #[derive(Debug, Clone)]
 pub struct CapitalGainsTaxBreakdown {
-    pub gross_capital_gain_amount: f64,
-    pub primary_residence_exclusion_used_amount: f64,
-    pub annual_exclusion_used_amount: f64,
-    pub inclusion_rate: f64,
-    pub taxable_capital_gain_in_income_amount: f64,
-    pub tax_payable_amount: f64,
+    pub deemed_disposal_applies: bool,
+    pub gross_capital_gain_amount: Decimal,
+    pub primary_residence_exclusion_used_amount: Decimal,
+    pub spousal_rollover_gain_amount: Decimal,
+    pub spouse_inherited_base_cost_amount: Decimal,
+    pub pbo_disregarded_gain_amount: Decimal,
+    pub retirement_fund_disregarded_amount: Decimal,
+    pub retirement_fund_gain_amount: Decimal,
+    pub retirement_fund_cgt_attributed_amount: Decimal,
+    pub annual_exclusion_used_amount: Decimal,
+    pub inclusion_rate: Decimal,
+    pub taxable_capital_gain_in_income_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+    pub stepped_up_basis_total_amount: Decimal,
+    pub stepped_up_basis: Vec<SteppedUpBasisLine>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RateBracketTaxLine {
+    pub from_amount: Decimal,
+    pub to_amount: Option<Decimal>,
+    pub rate: Decimal,
+    pub taxable_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct IncomeTaxBreakdown {
+    pub taxable_income_excluding_capital_gain_amount: Decimal,
+    pub taxable_capital_gain_amount: Decimal,
+    pub taxable_income_amount: Decimal,
+    pub bracket_taxes: Vec<RateBracketTaxLine>,
+    pub tax_before_rebates_amount: Decimal,
+    pub rebates_amount: Decimal,
+    pub normal_tax_amount: Decimal,
+    pub tax_on_income_excluding_capital_gain_amount: Decimal,
+    pub tax_on_capital_gain_amount: Decimal,
+    pub marginal_rate: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutorFeeBasis {
+    Tariff,
+    FlatRateOverride,
+    ExplicitAmount,
+    // The jurisdiction has no statutory tariff and no override was supplied.
+    NoTariff,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutorFeeBreakdown {
+    pub basis: ExecutorFeeBasis,
+    pub gross_assets_amount: Decimal,
+    pub income_accrued_after_death_amount: Decimal,
+    pub bracket_fees: Vec<RateBracketTaxLine>,
+    pub asset_fee_amount: Decimal,
+    pub income_fee_amount: Decimal,
+    pub minimum_fee_amount: Decimal,
+    pub fee_before_vat_amount: Decimal,
+    pub vat_amount: Decimal,
+    pub executor_fee_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct MaritalPropertyBreakdown {
+    pub regime: MaritalPropertyRegime,
+    pub spouse_share_of_joint_estate_amount: Decimal,
+    pub deceased_accrual_amount: Decimal,
+    pub spouse_accrual_amount: Decimal,
+    pub accrual_claim_against_estate_amount: Decimal,
+    pub accrual_claim_by_estate_amount: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifePolicyTreatment {
+    DeemedProperty,
+    ExemptBuyAndSell,
+    ExemptThirdPartyOwned,
+    NotOnDeceasedLife,
+}
+
+#[derive(Debug, Clone)]
+pub struct LifePolicyLine {
+    pub name: String,
+    pub treatment: LifePolicyTreatment,
+    pub sum_assured_amount: Decimal,
+    pub included_in_estate_amount: Decimal,
+    pub spousal_deduction_amount: Decimal,
+    pub liquidity_to_estate_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetirementFundLine {
+    pub name: String,
+    pub fund_type: RetirementFundType,
+    pub value_amount: Decimal,
+    pub included_in_estate_amount: Decimal,
+    pub spousal_deduction_amount: Decimal,
+    pub liquidity_to_estate_amount: Decimal,
 }
 
 #[derive(Debug, Clone)]
 pub struct EstateDutyBreakdown {
-    pub gross_estate_for_transfer_tax_amount: f64,
-    pub executor_fee_amount: f64,
-    pub spousal_deduction_amount: f64,
-    pub pbo_deduction_amount: f64,
-    pub total_allowable_deductions_amount: f64,
-    pub exemption_used_amount: f64,
-    pub dutiable_estate_after_exemption_amount: f64,
-    pub tax_payable_amount: f64,
+    pub gross_estate_for_transfer_tax_amount: Decimal,
+    pub accrual_claim_asset_amount: Decimal,
+    pub life_policy_deemed_property_amount: Decimal,
+    pub life_policies: Vec<LifePolicyLine>,
+    pub retirement_fund_included_amount: Decimal,
+    pub retirement_funds: Vec<RetirementFundLine>,
+    pub executor_fee: ExecutorFeeBreakdown,
+    pub executor_fee_amount: Decimal,
+    pub spousal_deduction_amount: Decimal,
+    pub pbo_deduction_amount: Decimal,
+    pub accrual_claim_deduction_amount: Decimal,
+    pub state_estate_tax_deduction_amount: Decimal,
+    pub total_allowable_deductions_amount: Decimal,
+    pub exemption_used_amount: Decimal,
+    pub dutiable_estate_after_exemption_amount: Decimal,
+    pub bracket_taxes: Vec<RateBracketTaxLine>,
+    pub unified_credit_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct StateEstateTaxBreakdown {
+    pub state_taxable_estate_amount: Decimal,
+    pub exemption_amount: Decimal,
+    pub tentative_tax_amount: Decimal,
+    pub credit_amount: Decimal,
+    pub tax_payable_amount: Decimal,
 }
 
 #[derive(Debug, Clone)]
 pub struct CombinedTaxLiability {
-    pub estate_transfer_tax_amount: f64,
-    pub cgt_on_death_amount: f64,
-    pub final_income_tax_amount: f64,
-    pub ongoing_estate_income_tax_provision_amount: f64,
-    pub total_tax_liability_amount: f64,
+    pub estate_transfer_tax_amount: Decimal,
+    pub state_estate_tax_amount: Decimal,
+    pub cgt_on_death_amount: Decimal,
+    pub final_income_tax_amount: Decimal,
+    pub ongoing_estate_income_tax_provision_amount: Decimal,
+    pub total_tax_liability_amount: Decimal,
 }
 
 #[derive(Debug, Clone)]
 pub struct LiquidityGapOutput {
-    pub liquid_assets_in_estate_amount: f64,
-    pub external_liquidity_proceeds_amount: f64,
-    pub cash_reserve_amount: f64,
-    pub total_available_liquidity_amount: f64,
-    pub executor_fee_amount: f64,
-    pub immediate_cash_requirements_amount: f64,
-    pub liquidity_gap_amount: f64,
-    pub liquidity_surplus_amount: f64,
+    pub liquid_assets_in_estate_amount: Decimal,
+    pub life_policy_proceeds_to_estate_amount: Decimal,
+    pub retirement_fund_proceeds_to_estate_amount: Decimal,
+    pub external_liquidity_proceeds_amount: Decimal,
+    pub cash_reserve_amount: Decimal,
+    pub total_available_liquidity_amount: Decimal,
+    pub executor_fee_amount: Decimal,
+    pub accrual_claim_payable_amount: Decimal,
+    pub immediate_cash_requirements_amount: Decimal,
+    pub liquidity_gap_amount: Decimal,
+    pub liquidity_surplus_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeirDistributionLine {
+    pub name: String,
+    pub category: HeirCategory,
+    pub specific_bequests_amount: Decimal,
+    pub legacy_amount: Decimal,
+    pub residue_amount: Decimal,
+    pub abatement_amount: Decimal,
+    pub gross_inheritance_amount: Decimal,
+    pub estate_tax_borne_amount: Decimal,
+    pub net_inheritance_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct DistributionBreakdown {
+    pub tax_apportionment: EstateTaxApportionment,
+    pub distributable_assets_amount: Decimal,
+    pub costs_and_liabilities_amount: Decimal,
+    pub estate_taxes_amount: Decimal,
+    pub specific_bequests_amount: Decimal,
+    pub legacies_amount: Decimal,
+    pub abatement_amount: Decimal,
+    pub residue_amount: Decimal,
+    pub undistributed_residue_amount: Decimal,
+    pub heirs: Vec<HeirDistributionLine>,
+    pub total_net_inheritance_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct AssetTaxAttributionLine {
+    pub asset_name: String,
+    pub market_value_amount: Decimal,
+    pub capital_gain_amount: Decimal,
+    pub primary_residence_exclusion_used_amount: Decimal,
+    pub gain_disregarded_amount: Decimal,
+    pub annual_exclusion_used_amount: Decimal,
+    pub cgt_attributed_amount: Decimal,
+    pub included_in_estate_amount: Decimal,
+    pub deductions_attributable_amount: Decimal,
+    pub estate_duty_attributed_amount: Decimal,
+    pub state_estate_tax_attributed_amount: Decimal,
+    pub total_tax_attributed_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct CurrencyConversionLine {
+    pub item_name: String,
+    pub field: String,
+    pub currency: Currency,
+    pub rate: Decimal,
+    pub original_amount: Decimal,
+    pub reporting_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct CurrencyConversionBreakdown {
+    pub reporting_currency: Currency,
+    pub valuation_date: Option<String>,
+    pub lines: Vec<CurrencyConversionLine>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainStage {
+    CurrencyConversion,
+    MaritalProperty,
+    CapitalGainsTax,
+    IncomeTax,
+    ExecutorFee,
+    EstateDuty,
+    StateEstateTax,
+    CombinedTax,
+    Liquidity,
+    Distribution,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplainValue {
+    pub name: String,
+    pub value: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleCitation {
+    // Path of the rule field in the jurisdiction rule set, such as `estate_duty.rate_schedule`.
+    pub rule_field: String,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplainStep {
+    pub step: usize,
+    pub stage: ExplainStage,
+    pub description: String,
+    pub formula: String,
+    pub inputs: Vec<ExplainValue>,
+    pub value: Decimal,
+    // `None` where the step applies scenario inputs or arithmetic rather than a rule.
+    pub rule: Option<RuleCitation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplainTrace {
+    pub jurisdiction: Jurisdiction,
+    pub tax_year: u16,
+    pub rule_version_id: &'static str,
+    pub reporting_currency: Currency,
+    pub steps: Vec<ExplainStep>,
+}
+
+// A scenario field left unset and filled from the selected rule version.
+#[derive(Debug, Clone)]
+pub struct DefaultedField {
+    pub field: String,
+    pub value: Decimal,
+    // `None` where the jurisdiction has no rule for the field, such as VAT in the US.
+    pub rule: Option<RuleCitation>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ScenarioResult {
+    pub marital_property: MaritalPropertyBreakdown,
+    pub income_tax: IncomeTaxBreakdown,
     pub cgt: CapitalGainsTaxBreakdown,
     pub estate_duty: EstateDutyBreakdown,
+    pub state_estate_tax: Option<StateEstateTaxBreakdown>,
     pub combined_tax: CombinedTaxLiability,
     pub liquidity: LiquidityGapOutput,
+    pub distribution: DistributionBreakdown,
+    pub assets: Vec<AssetTaxAttributionLine>,
+    pub currency: CurrencyConversionBreakdown,
+    pub explain: Option<ExplainTrace>,
+    pub defaulted_fields: Vec<DefaultedField>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountEntry {
+    pub description: String,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationAccountSection {
+    pub receipts: Vec<AccountEntry>,
+    pub payments: Vec<AccountEntry>,
+    pub total_receipts_amount: Decimal,
+    pub total_payments_amount: Decimal,
+    pub balance_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct DistributionAccount {
+    pub balance_for_distribution_amount: Decimal,
+    pub awards: Vec<AccountEntry>,
+    pub total_awarded_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountReconciliation {
+    pub estate_duty_per_breakdown_amount: Decimal,
+    pub estate_duty_per_account_amount: Decimal,
+    pub executor_fee_per_breakdown_amount: Decimal,
+    pub executor_fee_per_account_amount: Decimal,
+    pub cash_requirements_per_liquidity_amount: Decimal,
+    pub payments_per_account_amount: Decimal,
+    pub reconciled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationAndDistributionAccount {
+    pub jurisdiction: Jurisdiction,
+    pub tax_year: u16,
+    pub capital_account: LiquidationAccountSection,
+    pub income_account: LiquidationAccountSection,
+    pub distribution_account: DistributionAccount,
+    pub reconciliation: AccountReconciliation,
+    pub balanced: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeathOrder {
+    SpouseAFirst,
+    SpouseBFirst,
+}
+
+#[derive(Debug, Clone)]
+pub struct CoupleScenarioInput {
+    pub spouse_a: EstateScenarioInput,
+    pub spouse_b: EstateScenarioInput,
+    pub order_of_death: DeathOrder,
+}
+
+#[derive(Debug, Clone)]
+pub struct CoupleScenarioResult {
+    pub order_of_death: DeathOrder,
+    pub first_death: ScenarioResult,
+    pub rolled_over_to_survivor_amount: Decimal,
+    pub ported_exemption_amount: Decimal,
+    pub second_death: ScenarioResult,
+    pub combined_estate_transfer_tax_amount: Decimal,
+    pub combined_tax_liability_amount: Decimal,
+    pub combined_liquidity_gap_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignJurisdictionFiling {
+    pub jurisdiction: Jurisdiction,
+    pub tax_year: u16,
+    // Ancillary administration costs, in the foreign jurisdiction's reporting currency.
+    pub administration_costs_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrossBorderScenarioInput {
+    // Worldwide estate under the law of the domicile; assets name their situs jurisdiction.
+    pub estate: EstateScenarioInput,
+    pub foreign_filings: Vec<ForeignJurisdictionFiling>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignJurisdictionResult {
+    pub jurisdiction: Jurisdiction,
+    pub tax_year: u16,
+    pub reporting_currency: Currency,
+    pub rate_to_home_currency: Decimal,
+    pub result: ScenarioResult,
+    pub foreign_death_duty_amount: Decimal,
+    pub foreign_tax_liability_amount: Decimal,
+    pub administration_costs_amount: Decimal,
+    pub home_duty_on_situs_assets_amount: Decimal,
+    pub death_duty_credit_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsolidatedLiabilityAndLiquidity {
+    pub reporting_currency: Currency,
+    pub home_tax_liability_amount: Decimal,
+    pub foreign_death_duty_credit_amount: Decimal,
+    pub foreign_tax_liability_amount: Decimal,
+    pub foreign_administration_costs_amount: Decimal,
+    pub total_tax_liability_amount: Decimal,
+    pub total_available_liquidity_amount: Decimal,
+    pub immediate_cash_requirements_amount: Decimal,
+    pub liquidity_gap_amount: Decimal,
+    pub liquidity_surplus_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrossBorderScenarioResult {
+    pub home: ScenarioResult,
+    pub foreign: Vec<ForeignJurisdictionResult>,
+    pub consolidated: ConsolidatedLiabilityAndLiquidity,
+}
+
+#[derive(Debug, Clone)]
+pub struct LifetimeDonation {
+    pub donee: String,
+    pub donated_on: String,
+    pub amount: Decimal,
+    pub exempt_from_donations_tax: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DonationsTaxInput {
+    pub jurisdiction: Jurisdiction,
+    pub taxpayer_class: TaxPayerClass,
+    pub prior_cumulative_taxable_donations_amount: Decimal,
+    pub donations: Vec<LifetimeDonation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DonationTaxLine {
+    pub donation_index: usize,
+    pub donee: String,
+    pub donated_on: String,
+    pub tax_year: u16,
+    pub amount: Decimal,
+    pub exempt_amount: Decimal,
+    pub annual_exemption_used_amount: Decimal,
+    pub taxable_amount: Decimal,
+    pub primary_band_amount: Decimal,
+    pub secondary_band_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+    pub cumulative_taxable_donations_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct DonationsTaxYearSummary {
+    pub tax_year: u16,
+    pub rule_version_id: &'static str,
+    pub total_donations_amount: Decimal,
+    pub exempt_donations_amount: Decimal,
+    pub annual_exemption_available_amount: Decimal,
+    pub annual_exemption_used_amount: Decimal,
+    pub taxable_donations_amount: Decimal,
+    pub tax_payable_amount: Decimal,
+    pub cumulative_taxable_donations_amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct DonationsTaxResult {
+    pub donations: Vec<DonationTaxLine>,
+    pub tax_years: Vec<DonationsTaxYearSummary>,
+    pub total_donations_amount: Decimal,
+    pub total_taxable_donations_amount: Decimal,
+    pub total_tax_payable_amount: Decimal,
+    pub cumulative_taxable_donations_amount: Decimal,
 }
@@ -0,0 +1,113 @@
+use crate::core::domain::models::{
+    CalendarDate, CoupleScenarioInput, CoupleScenarioResult, DeathOrder, EstateAsset,
+    EstateScenarioInput, ScenarioResult,
+};
+use crate::core::engine::currency::convert_to_reporting_currency;
+use crate::core::engine::scenario::{
+    calculate_combined_tax_and_liquidity, scenario_tax_year, JurisdictionScenarioCalculator,
+};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{tax_rules_for_scenario, tax_year_start_date, EstateDutyRule};
+use rust_decimal::Decimal;
+
+// Without a date of death the first death is taken at the start of its tax year, so a tax year
+// that straddles the start date does not port.
+fn exemption_portable(rule: &EstateDutyRule, input: &EstateScenarioInput) -> bool {
+    let Some(portable_from) = rule.exemption_portable_from.and_then(CalendarDate::parse) else {
+        return false;
+    };
+    let date_of_death = input
+        .date_of_death
+        .as_deref()
+        .and_then(CalendarDate::parse)
+        .unwrap_or_else(|| tax_year_start_date(input.jurisdiction, scenario_tax_year(input)));
+    date_of_death >= portable_from
+}
+
+// Unused exemption of the first-dying spouse (SA section 4A(1A) abatement / US DSUE).
+// Only the deceased's own exemption ports; amounts it inherited do not carry forward again.
+fn unused_own_exemption_amount(input: &EstateScenarioInput, result: &ScenarioResult) -> Decimal {
+    let own_exemption_amount = (result.estate_duty.exemption_used_amount
+        - input.ported_estate_tax_exemption_amount.max(Decimal::ZERO))
+    .max(Decimal::ZERO);
+    let net_estate_amount = (result.estate_duty.gross_estate_for_transfer_tax_amount
+        - result.estate_duty.total_allowable_deductions_amount)
+        .max(Decimal::ZERO);
+    (own_exemption_amount - net_estate_amount).clamp(Decimal::ZERO, own_exemption_amount)
+}
+
+fn inherited_by_survivor(
+    input: &EstateScenarioInput,
+    heirs_inherit_base_cost: bool,
+) -> Vec<EstateAsset> {
+    input
+        .assets
+        .iter()
+        .filter(|asset| asset.bequeathed_to_surviving_spouse)
+        .map(|asset| EstateAsset {
+            name: format!("{} (inherited from spouse)", asset.name),
+            base_cost_amount: if heirs_inherit_base_cost {
+                asset.base_cost_amount
+            } else {
+                asset.market_value_amount
+            },
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            ..asset.clone()
+        })
+        .collect()
+}
+
+pub fn calculate_couple_second_death(
+    input: &CoupleScenarioInput,
+) -> Result<CoupleScenarioResult, EngineError> {
+    let (first_input, survivor_input) = match input.order_of_death {
+        DeathOrder::SpouseAFirst => (&input.spouse_a, &input.spouse_b),
+        DeathOrder::SpouseBFirst => (&input.spouse_b, &input.spouse_a),
+    };
+
+    let first_death = calculate_combined_tax_and_liquidity(first_input)?;
+    let first_rules = tax_rules_for_scenario(first_input)?.rules;
+
+    // A spousal roll-over hands the survivor the deceased's base cost; otherwise the survivor
+    // acquires at market value (taxed deemed disposal or basis step-up). Inherited assets pass
+    // in the first estate's reporting currency, and only the deceased's share of a joint estate
+    // is inherited.
+    let (first_reporting_input, _) = convert_to_reporting_currency(first_input)?;
+    let (first_deceased_input, _) =
+        JurisdictionScenarioCalculator::apply_marital_property_regime(&first_reporting_input);
+    let inherited_assets = inherited_by_survivor(
+        &first_deceased_input,
+        first_rules.cgt_on_death.spousal_rollover_on_death,
+    );
+    let rolled_over_to_survivor_amount = inherited_assets
+        .iter()
+        .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+        .sum::<Decimal>();
+    let ported_exemption_amount = if exemption_portable(&first_rules.estate_duty, first_input) {
+        unused_own_exemption_amount(first_input, &first_death)
+    } else {
+        Decimal::ZERO
+    };
+
+    let mut second_input = survivor_input.clone();
+    second_input.assets.extend(inherited_assets);
+    second_input.ported_estate_tax_exemption_amount = ported_exemption_amount;
+    let second_death = calculate_combined_tax_and_liquidity(&second_input)?;
+
+    Ok(CoupleScenarioResult {
+        order_of_death: input.order_of_death,
+        rolled_over_to_survivor_amount,
+        ported_exemption_amount,
+        combined_estate_transfer_tax_amount: first_death.combined_tax.estate_transfer_tax_amount
+            + first_death.combined_tax.state_estate_tax_amount
+            + second_death.combined_tax.estate_transfer_tax_amount
+            + second_death.combined_tax.state_estate_tax_amount,
+        combined_tax_liability_amount: first_death.combined_tax.total_tax_liability_amount
+            + second_death.combined_tax.total_tax_liability_amount,
+        combined_liquidity_gap_amount: first_death.liquidity.liquidity_gap_amount
+            + second_death.liquidity.liquidity_gap_amount,
+        first_death,
+        second_death,
+    })
+}
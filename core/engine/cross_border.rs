@@ -0,0 +1,207 @@
+use crate::core::domain::models::{
+    ConsolidatedLiabilityAndLiquidity, CrossBorderScenarioInput, CrossBorderScenarioResult,
+    CurrencyLiability, EstateAsset, EstateScenarioInput, ForeignJurisdictionFiling,
+    ForeignJurisdictionResult, FxRate, FxRateTable, MaritalPropertyInput, MaritalPropertyRegime,
+    ResidencyStatus, ScenarioResult,
+};
+use crate::core::engine::currency::{conversion_rate, convert_to_reporting_currency};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{reporting_currency_for, tax_rules_for_scenario};
+use rust_decimal::Decimal;
+
+// Builds the non-resident estate the situs jurisdiction taxes. `estate` is already in the home
+// reporting currency; the foreign calculation converts it into its own.
+fn foreign_situs_input(
+    estate: &EstateScenarioInput,
+    filing: &ForeignJurisdictionFiling,
+) -> Result<EstateScenarioInput, EngineError> {
+    let home_currency = reporting_currency_for(estate.jurisdiction);
+    let foreign_currency = reporting_currency_for(filing.jurisdiction);
+    let assets = estate
+        .assets
+        .iter()
+        .filter(|asset| asset.situs_jurisdiction == Some(filing.jurisdiction))
+        // Only death duties are filed abroad. A non-resident's CGT is narrower than the deemed
+        // disposal on a resident (SA taxes only immovable property, para 2(1)(b)), and assets do
+        // not carry that classification, so it is left out rather than overstated.
+        .map(|asset| EstateAsset {
+            situs_in_jurisdiction: true,
+            included_in_cgt_deemed_disposal: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: Some(home_currency),
+            ..asset.clone()
+        })
+        .collect::<Vec<_>>();
+
+    // Worldwide debts are deductible in the proportion the situs estate bears to the worldwide
+    // estate (US section 2106(a)(1)).
+    let worldwide_assets_amount = estate
+        .assets
+        .iter()
+        .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+        .sum::<Decimal>();
+    let situs_assets_amount = assets
+        .iter()
+        .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+        .sum::<Decimal>();
+    let debt_share = if worldwide_assets_amount > Decimal::ZERO {
+        situs_assets_amount / worldwide_assets_amount
+    } else {
+        Decimal::ZERO
+    };
+
+    // The foreign filing converts at the estate's own rate table and valuation date.
+    let fx_rates = if foreign_currency == home_currency {
+        None
+    } else {
+        let rate_to_home_currency = conversion_rate(estate, foreign_currency)?;
+        estate.fx_rates.as_ref().map(|table| FxRateTable {
+            valuation_date: table.valuation_date.clone(),
+            rates: vec![FxRate {
+                currency: home_currency,
+                rate: Decimal::ONE / rate_to_home_currency,
+            }],
+        })
+    };
+
+    Ok(EstateScenarioInput {
+        jurisdiction: filing.jurisdiction,
+        tax_year: filing.tax_year,
+        taxpayer_class: estate.taxpayer_class,
+        residency_status: ResidencyStatus::NonResident,
+        marginal_income_tax_rate: estate.marginal_income_tax_rate,
+        assets,
+        administration_costs_amount: filing.administration_costs_amount,
+        primary_residence_cgt_exclusion_cap_amount: Some(Decimal::ZERO),
+        executor_fee_rate: estate.executor_fee_rate,
+        vat_rate: estate.vat_rate,
+        // Ancillary administration is costed through `administration_costs_amount`.
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        marital_property: MaritalPropertyInput {
+            regime: if estate.marital_property.regime == MaritalPropertyRegime::InCommunity {
+                MaritalPropertyRegime::InCommunity
+            } else {
+                MaritalPropertyRegime::NotMarried
+            },
+            ..MaritalPropertyInput::default()
+        },
+        liabilities: vec![CurrencyLiability {
+            description: "Share of worldwide debts".to_string(),
+            currency: home_currency,
+            amount: home_currency
+                .round_amount(estate.debts_and_loans_amount.max(Decimal::ZERO) * debt_share),
+        }],
+        fx_rates,
+        ..EstateScenarioInput::default()
+    })
+}
+
+fn home_duty_on_situs_assets_amount(
+    estate: &EstateScenarioInput,
+    home: &ScenarioResult,
+    filing: &ForeignJurisdictionFiling,
+) -> Decimal {
+    estate
+        .assets
+        .iter()
+        .zip(&home.assets)
+        .filter(|(asset, _)| asset.situs_jurisdiction == Some(filing.jurisdiction))
+        .map(|(_, line)| line.estate_duty_attributed_amount)
+        .sum()
+}
+
+// Runs the domicile's rules on the worldwide estate and each situs jurisdiction's rules on the
+// assets situated there, then credits foreign death duty against the home estate duty
+// (SA section 16(b), US section 2014).
+pub fn calculate_cross_border_scenario(
+    input: &CrossBorderScenarioInput,
+) -> Result<CrossBorderScenarioResult, EngineError> {
+    let home = calculate_combined_tax_and_liquidity(&input.estate)?;
+    let home_rules = tax_rules_for_scenario(&input.estate)?.rules;
+    let (estate, _) = convert_to_reporting_currency(&input.estate)?;
+    let home_currency = reporting_currency_for(input.estate.jurisdiction);
+
+    let mut foreign = Vec::with_capacity(input.foreign_filings.len());
+    for filing in &input.foreign_filings {
+        let result = calculate_combined_tax_and_liquidity(&foreign_situs_input(&estate, filing)?)?;
+        let reporting_currency = reporting_currency_for(filing.jurisdiction);
+        let rate_to_home_currency = conversion_rate(&input.estate, reporting_currency)?;
+        let to_home_currency =
+            |amount: Decimal| home_currency.round_amount(amount * rate_to_home_currency);
+        let foreign_death_duty_amount = to_home_currency(
+            result.combined_tax.estate_transfer_tax_amount
+                + result.combined_tax.state_estate_tax_amount,
+        );
+        let home_duty_on_situs_assets_amount =
+            home_duty_on_situs_assets_amount(&estate, &home, filing);
+        let death_duty_credit_amount = if home_rules.estate_duty.foreign_death_duty_credit {
+            foreign_death_duty_amount.min(home_duty_on_situs_assets_amount)
+        } else {
+            Decimal::ZERO
+        };
+
+        foreign.push(ForeignJurisdictionResult {
+            jurisdiction: filing.jurisdiction,
+            tax_year: filing.tax_year,
+            reporting_currency,
+            rate_to_home_currency,
+            foreign_death_duty_amount,
+            foreign_tax_liability_amount: to_home_currency(
+                result.combined_tax.total_tax_liability_amount,
+            ),
+            administration_costs_amount: to_home_currency(
+                filing.administration_costs_amount.max(Decimal::ZERO),
+            ),
+            home_duty_on_situs_assets_amount,
+            death_duty_credit_amount,
+            result,
+        });
+    }
+
+    let foreign_death_duty_credit_amount = foreign
+        .iter()
+        .map(|line| line.death_duty_credit_amount)
+        .sum::<Decimal>()
+        .min(home.combined_tax.estate_transfer_tax_amount);
+    let foreign_tax_liability_amount = foreign
+        .iter()
+        .map(|line| line.foreign_tax_liability_amount)
+        .sum::<Decimal>();
+    let foreign_administration_costs_amount = foreign
+        .iter()
+        .map(|line| line.administration_costs_amount)
+        .sum::<Decimal>();
+    let home_tax_liability_amount = home.combined_tax.total_tax_liability_amount;
+
+    // Worldwide liquidity and home cash needs already include the foreign assets and debts; only
+    // the foreign taxes and ancillary costs are added.
+    let total_available_liquidity_amount = home.liquidity.total_available_liquidity_amount;
+    let immediate_cash_requirements_amount = home.liquidity.immediate_cash_requirements_amount
+        - foreign_death_duty_credit_amount
+        + foreign_tax_liability_amount
+        + foreign_administration_costs_amount;
+
+    Ok(CrossBorderScenarioResult {
+        consolidated: ConsolidatedLiabilityAndLiquidity {
+            reporting_currency: home_currency,
+            home_tax_liability_amount,
+            foreign_death_duty_credit_amount,
+            foreign_tax_liability_amount,
+            foreign_administration_costs_amount,
+            total_tax_liability_amount: home_tax_liability_amount
+                - foreign_death_duty_credit_amount
+                + foreign_tax_liability_amount,
+            total_available_liquidity_amount,
+            immediate_cash_requirements_amount,
+            liquidity_gap_amount: (immediate_cash_requirements_amount
+                - total_available_liquidity_amount)
+                .max(Decimal::ZERO),
+            liquidity_surplus_amount: (total_available_liquidity_amount
+                - immediate_cash_requirements_amount)
+                .max(Decimal::ZERO),
+        },
+        home,
+        foreign,
+    })
+}
@@ -0,0 +1,121 @@
+use crate::core::domain::models::{
+    CurrencyConversionBreakdown, CurrencyConversionLine, EstateScenarioInput,
+};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{reporting_currency_for, Currency};
+use crate::core::validation::{InputValidationError, ValidationIssue};
+use rust_decimal::Decimal;
+
+// Units of the reporting currency per unit of `currency`. A missing rate is a validation error
+// rather than a 1:1 conversion, for callers that skip `validate()`.
+pub fn conversion_rate(
+    input: &EstateScenarioInput,
+    currency: Currency,
+) -> Result<Decimal, EngineError> {
+    if currency == reporting_currency_for(input.jurisdiction) {
+        return Ok(Decimal::ONE);
+    }
+    input
+        .fx_rates
+        .as_ref()
+        .and_then(|table| table.rate_for(currency))
+        .filter(|rate| *rate > Decimal::ZERO)
+        .ok_or_else(|| {
+            EngineError::Validation(InputValidationError::new(vec![ValidationIssue::new(
+                "fx_rates".to_string(),
+                format!("No FX rate supplied for {} in `fx_rates`", currency.code()),
+            )]))
+        })
+}
+
+fn convert(
+    lines: &mut Vec<CurrencyConversionLine>,
+    reporting_currency: Currency,
+    item_name: &str,
+    field: &str,
+    currency: Currency,
+    rate: Decimal,
+    original_amount: Decimal,
+) -> Decimal {
+    let reporting_amount = reporting_currency.round_amount(original_amount * rate);
+    lines.push(CurrencyConversionLine {
+        item_name: item_name.to_string(),
+        field: field.to_string(),
+        currency,
+        rate,
+        original_amount,
+        reporting_amount,
+    });
+    reporting_amount
+}
+
+// Restates currency-tagged amounts in the jurisdiction's reporting currency at the spot rates of
+// the valuation date. Base cost is translated at the same rate as market value, as SA para 43(1)
+// requires for assets acquired and disposed of in the same foreign currency.
+pub fn convert_to_reporting_currency(
+    input: &EstateScenarioInput,
+) -> Result<(EstateScenarioInput, CurrencyConversionBreakdown), EngineError> {
+    let reporting_currency = reporting_currency_for(input.jurisdiction);
+    let mut converted = input.clone();
+    let mut lines = Vec::new();
+
+    for asset in &mut converted.assets {
+        let Some(currency) = asset
+            .currency
+            .filter(|currency| *currency != reporting_currency)
+        else {
+            asset.currency = None;
+            continue;
+        };
+        let rate = conversion_rate(input, currency)?;
+        asset.market_value_amount = convert(
+            &mut lines,
+            reporting_currency,
+            &asset.name,
+            "market_value_amount",
+            currency,
+            rate,
+            asset.market_value_amount,
+        );
+        asset.base_cost_amount = convert(
+            &mut lines,
+            reporting_currency,
+            &asset.name,
+            "base_cost_amount",
+            currency,
+            rate,
+            asset.base_cost_amount,
+        );
+        asset.currency = None;
+    }
+
+    for liability in &input.liabilities {
+        if liability.currency == reporting_currency {
+            converted.debts_and_loans_amount += liability.amount;
+            continue;
+        }
+        let rate = conversion_rate(input, liability.currency)?;
+        converted.debts_and_loans_amount += convert(
+            &mut lines,
+            reporting_currency,
+            &liability.description,
+            "amount",
+            liability.currency,
+            rate,
+            liability.amount,
+        );
+    }
+    converted.liabilities.clear();
+
+    Ok((
+        converted,
+        CurrencyConversionBreakdown {
+            reporting_currency,
+            valuation_date: input
+                .fx_rates
+                .as_ref()
+                .map(|table| table.valuation_date.clone()),
+            lines,
+        },
+    ))
+}
@@ -0,0 +1,95 @@
+use crate::core::domain::models::{
+    DefaultedField, EstateScenarioInput, ExecutorFeeBasis, ExecutorFeeBreakdown,
+    IncomeTaxBreakdown, RuleCitation,
+};
+use crate::core::rules::tax_rules::JurisdictionTaxRuleSet;
+use rust_decimal::Decimal;
+
+pub fn primary_residence_cgt_exclusion_cap_amount(
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+) -> Decimal {
+    input
+        .primary_residence_cgt_exclusion_cap_amount
+        .unwrap_or_else(|| {
+            rules
+                .cgt_on_death
+                .primary_residence_exclusion_for(input.taxpayer_class)
+        })
+        .max(Decimal::ZERO)
+}
+
+// A jurisdiction without VAT, or without a rate for the tax year, charges none.
+pub fn vat_rate(rules: &JurisdictionTaxRuleSet, input: &EstateScenarioInput) -> Decimal {
+    input
+        .vat_rate
+        .or_else(|| {
+            rules
+                .vat
+                .as_ref()
+                .and_then(|vat| vat.standard_rate_for(input.tax_year))
+        })
+        .unwrap_or(Decimal::ZERO)
+        .clamp(Decimal::ZERO, Decimal::ONE)
+}
+
+// Lists the unset scenario fields with the value the rule version supplied for them. The executor
+// fee and the marginal rate come from the calculated breakdowns, as the tariff and the income tax
+// tables produce them.
+pub fn defaulted_scenario_fields(
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    executor_fee: &ExecutorFeeBreakdown,
+    income_tax: &IncomeTaxBreakdown,
+) -> Vec<DefaultedField> {
+    let mut fields = Vec::new();
+    if input.primary_residence_cgt_exclusion_cap_amount.is_none() {
+        fields.push(DefaultedField {
+            field: "primary_residence_cgt_exclusion_cap_amount".to_string(),
+            value: primary_residence_cgt_exclusion_cap_amount(rules, input),
+            rule: Some(RuleCitation {
+                rule_field: "cgt_on_death.primary_residence_exclusion_amount".to_string(),
+                source: rules.cgt_on_death.source,
+                source_url: rules.cgt_on_death.source_url,
+            }),
+        });
+    }
+    if input.vat_rate.is_none() {
+        fields.push(DefaultedField {
+            field: "vat_rate".to_string(),
+            value: vat_rate(rules, input),
+            rule: rules.vat.as_ref().map(|vat| RuleCitation {
+                rule_field: "vat.rates".to_string(),
+                source: vat.source,
+                source_url: vat.source_url,
+            }),
+        });
+    }
+    if executor_fee.basis == ExecutorFeeBasis::Tariff {
+        if let Some(rule) = &rules.executor_fee {
+            fields.push(DefaultedField {
+                field: "explicit_executor_fee_amount".to_string(),
+                value: executor_fee.executor_fee_amount,
+                rule: Some(RuleCitation {
+                    rule_field: "executor_fee.asset_rate_schedule".to_string(),
+                    source: rule.source,
+                    source_url: rule.source_url,
+                }),
+            });
+        }
+    }
+    if input.marginal_income_tax_rate.is_none()
+        && rules.income_tax.table_for(input.tax_year).is_some()
+    {
+        fields.push(DefaultedField {
+            field: "marginal_income_tax_rate".to_string(),
+            value: income_tax.marginal_rate,
+            rule: Some(RuleCitation {
+                rule_field: "income_tax.tables".to_string(),
+                source: rules.income_tax.source,
+                source_url: rules.income_tax.source_url,
+            }),
+        });
+    }
+    fields
+}
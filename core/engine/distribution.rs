@@ -0,0 +1,246 @@
+use crate::core::domain::models::{
+    Beneficiary, CombinedTaxLiability, DistributionBreakdown, EstateDutyBreakdown,
+    EstateScenarioInput, HeirCategory, HeirDistributionLine, MaritalPropertyBreakdown,
+};
+use crate::core::rules::tax_rules::{reporting_currency_for, EstateTaxApportionment};
+use rust_decimal::Decimal;
+
+fn specific_bequests_amount(input: &EstateScenarioInput, beneficiary: &Beneficiary) -> Decimal {
+    input
+        .assets
+        .iter()
+        .filter(|asset| {
+            beneficiary
+                .specific_bequest_asset_names
+                .iter()
+                .any(|name| name == &asset.name)
+        })
+        .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+        .sum()
+}
+
+fn distribute(
+    input: &EstateScenarioInput,
+    tax_apportionment: EstateTaxApportionment,
+    distributable_assets_amount: Decimal,
+    costs_and_liabilities_amount: Decimal,
+    estate_taxes_amount: Decimal,
+) -> DistributionBreakdown {
+    let currency = reporting_currency_for(input.jurisdiction);
+    let bequests = input
+        .beneficiaries
+        .iter()
+        .map(|beneficiary| specific_bequests_amount(input, beneficiary))
+        .collect::<Vec<_>>();
+    let specific_bequests_amount = bequests.iter().sum::<Decimal>();
+    let legacies_amount = input
+        .beneficiaries
+        .iter()
+        .map(|beneficiary| beneficiary.legacy_amount.max(Decimal::ZERO))
+        .sum::<Decimal>();
+    let residue_taxes_amount = match tax_apportionment {
+        EstateTaxApportionment::BorneByResidue => estate_taxes_amount,
+        EstateTaxApportionment::ProRataAmongDutiableHeirs => Decimal::ZERO,
+    };
+
+    // Abatement order: the residue is exhausted first, then legacies, then specific bequests.
+    let residue_before_abatement_amount = distributable_assets_amount
+        - specific_bequests_amount
+        - legacies_amount
+        - costs_and_liabilities_amount
+        - residue_taxes_amount;
+    let shortfall_amount = (-residue_before_abatement_amount).max(Decimal::ZERO);
+    let legacy_abatement_amount = shortfall_amount.min(legacies_amount);
+    let specific_abatement_amount =
+        (shortfall_amount - legacy_abatement_amount).min(specific_bequests_amount);
+    let legacy_abatement_ratio = if legacies_amount > Decimal::ZERO {
+        legacy_abatement_amount / legacies_amount
+    } else {
+        Decimal::ZERO
+    };
+    let specific_abatement_ratio = if specific_bequests_amount > Decimal::ZERO {
+        specific_abatement_amount / specific_bequests_amount
+    } else {
+        Decimal::ZERO
+    };
+
+    let residue_amount = residue_before_abatement_amount.max(Decimal::ZERO);
+    let residue_tax_borne_amount = residue_taxes_amount
+        .min((residue_before_abatement_amount + residue_taxes_amount).max(Decimal::ZERO));
+    let residue_before_taxes_amount = residue_amount + residue_tax_borne_amount;
+    let unallocated_residue_share = (Decimal::ONE
+        - input
+            .beneficiaries
+            .iter()
+            .map(|beneficiary| beneficiary.residue_share.clamp(Decimal::ZERO, Decimal::ONE))
+            .sum::<Decimal>())
+    .max(Decimal::ZERO);
+
+    let mut heirs = input
+        .beneficiaries
+        .iter()
+        .zip(bequests)
+        .map(|(beneficiary, bequest_amount)| {
+            let residue_share = beneficiary.residue_share.clamp(Decimal::ZERO, Decimal::ONE);
+            let specific_bequests_amount =
+                currency.round_amount(bequest_amount * (Decimal::ONE - specific_abatement_ratio));
+            let legacy_amount = currency.round_amount(
+                beneficiary.legacy_amount.max(Decimal::ZERO)
+                    * (Decimal::ONE - legacy_abatement_ratio),
+            );
+            let residue_amount = currency.round_amount(residue_before_taxes_amount * residue_share);
+            let gross_inheritance_amount =
+                specific_bequests_amount + legacy_amount + residue_amount;
+            HeirDistributionLine {
+                name: beneficiary.name.clone(),
+                category: beneficiary.category,
+                specific_bequests_amount,
+                legacy_amount,
+                residue_amount,
+                abatement_amount: bequest_amount + beneficiary.legacy_amount.max(Decimal::ZERO)
+                    - specific_bequests_amount
+                    - legacy_amount,
+                gross_inheritance_amount,
+                estate_tax_borne_amount: currency
+                    .round_amount(residue_tax_borne_amount * residue_share),
+                net_inheritance_amount: Decimal::ZERO,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Each heir's share is rounded on its own, so the heir with the largest residue share takes
+    // the rounding remainder and the heirs together receive exactly their part of the residue.
+    let allocated_residue_share = Decimal::ONE - unallocated_residue_share;
+    let remainder_heir_index = input
+        .beneficiaries
+        .iter()
+        .enumerate()
+        .filter(|(_, beneficiary)| beneficiary.residue_share > Decimal::ZERO)
+        .max_by_key(|(_, beneficiary)| beneficiary.residue_share)
+        .map(|(index, _)| index);
+    if let Some(index) = remainder_heir_index {
+        let residue_remainder_amount = currency
+            .round_amount(residue_before_taxes_amount * allocated_residue_share)
+            - heirs
+                .iter()
+                .map(|heir| heir.residue_amount)
+                .sum::<Decimal>();
+        let tax_remainder_amount = currency
+            .round_amount(residue_tax_borne_amount * allocated_residue_share)
+            - heirs
+                .iter()
+                .map(|heir| heir.estate_tax_borne_amount)
+                .sum::<Decimal>();
+        let heir = &mut heirs[index];
+        heir.residue_amount += residue_remainder_amount;
+        heir.gross_inheritance_amount += residue_remainder_amount;
+        heir.estate_tax_borne_amount += tax_remainder_amount;
+    }
+
+    // The undistributed residue is what the heirs' rounded shares leave of the residue.
+    let mut undistributed_residue_amount = (currency.round_amount(residue_amount)
+        - heirs
+            .iter()
+            .map(|heir| heir.residue_amount - heir.estate_tax_borne_amount)
+            .sum::<Decimal>())
+    .max(Decimal::ZERO);
+    if tax_apportionment == EstateTaxApportionment::ProRataAmongDutiableHeirs {
+        let undistributed_before_taxes_amount =
+            residue_before_taxes_amount * unallocated_residue_share;
+        let dutiable_weight_amount = heirs
+            .iter()
+            .filter(|heir| heir.category == HeirCategory::OtherHeirs)
+            .map(|heir| heir.gross_inheritance_amount)
+            .sum::<Decimal>()
+            + undistributed_before_taxes_amount;
+        if dutiable_weight_amount <= Decimal::ZERO && estate_taxes_amount > Decimal::ZERO {
+            // With no dutiable heir to recover from, the tax stays with the residue.
+            return distribute(
+                input,
+                EstateTaxApportionment::BorneByResidue,
+                distributable_assets_amount,
+                costs_and_liabilities_amount,
+                estate_taxes_amount,
+            );
+        }
+        if dutiable_weight_amount > Decimal::ZERO {
+            let tax_rate_on_weight = estate_taxes_amount / dutiable_weight_amount;
+            for heir in heirs
+                .iter_mut()
+                .filter(|heir| heir.category == HeirCategory::OtherHeirs)
+            {
+                heir.estate_tax_borne_amount = currency
+                    .round_amount(heir.gross_inheritance_amount * tax_rate_on_weight)
+                    .min(heir.gross_inheritance_amount);
+            }
+            undistributed_residue_amount = currency
+                .round_amount(
+                    undistributed_before_taxes_amount * (Decimal::ONE - tax_rate_on_weight),
+                )
+                .max(Decimal::ZERO);
+        }
+    }
+
+    for heir in &mut heirs {
+        heir.net_inheritance_amount = heir.gross_inheritance_amount - heir.estate_tax_borne_amount;
+    }
+
+    DistributionBreakdown {
+        tax_apportionment,
+        distributable_assets_amount,
+        costs_and_liabilities_amount,
+        estate_taxes_amount,
+        specific_bequests_amount,
+        legacies_amount,
+        abatement_amount: legacy_abatement_amount + specific_abatement_amount,
+        residue_amount: residue_before_taxes_amount,
+        undistributed_residue_amount,
+        total_net_inheritance_amount: heirs.iter().map(|heir| heir.net_inheritance_amount).sum(),
+        heirs,
+    }
+}
+
+pub fn calculate_distribution(
+    input: &EstateScenarioInput,
+    default_tax_apportionment: EstateTaxApportionment,
+    marital_property: &MaritalPropertyBreakdown,
+    estate_duty: &EstateDutyBreakdown,
+    combined_tax: &CombinedTaxLiability,
+) -> DistributionBreakdown {
+    let distributable_assets_amount = input
+        .assets
+        .iter()
+        .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+        .sum::<Decimal>()
+        + marital_property.accrual_claim_by_estate_amount
+        + estate_duty
+            .life_policies
+            .iter()
+            .map(|line| line.liquidity_to_estate_amount)
+            .sum::<Decimal>()
+        + estate_duty
+            .retirement_funds
+            .iter()
+            .map(|line| line.liquidity_to_estate_amount)
+            .sum::<Decimal>();
+    let estate_taxes_amount =
+        combined_tax.estate_transfer_tax_amount + combined_tax.state_estate_tax_amount;
+    let costs_and_liabilities_amount = input.debts_and_loans_amount.max(Decimal::ZERO)
+        + input.funeral_costs_amount.max(Decimal::ZERO)
+        + input.administration_costs_amount.max(Decimal::ZERO)
+        + input.masters_office_fees_amount.max(Decimal::ZERO)
+        + input.conveyancing_costs_amount.max(Decimal::ZERO)
+        + input.other_settlement_costs_amount.max(Decimal::ZERO)
+        + estate_duty.executor_fee_amount.max(Decimal::ZERO)
+        + marital_property.accrual_claim_against_estate_amount
+        + (combined_tax.total_tax_liability_amount - estate_taxes_amount).max(Decimal::ZERO);
+
+    let tax_apportionment = input.tax_apportionment.unwrap_or(default_tax_apportionment);
+    distribute(
+        input,
+        tax_apportionment,
+        distributable_assets_amount,
+        costs_and_liabilities_amount,
+        estate_taxes_amount,
+    )
+}
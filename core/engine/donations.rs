@@ -0,0 +1,117 @@
+use crate::core::domain::models::{
+    CalendarDate, DonationTaxLine, DonationsTaxInput, DonationsTaxResult, DonationsTaxYearSummary,
+};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{
+    tax_rules_for, tax_year_for_date, DonationsTaxRule, TaxPayerClass,
+};
+use rust_decimal::Decimal;
+
+pub fn annual_exemption_for(rule: &DonationsTaxRule, taxpayer_class: TaxPayerClass) -> Decimal {
+    match taxpayer_class {
+        TaxPayerClass::NaturalPerson => rule.annual_exemption_natural_person_amount,
+        TaxPayerClass::Company | TaxPayerClass::Trust | TaxPayerClass::SpecialTrust => {
+            rule.annual_exemption_non_natural_casual_gifts_amount
+        }
+    }
+}
+
+pub fn calculate_donations_tax(
+    input: &DonationsTaxInput,
+) -> Result<DonationsTaxResult, EngineError> {
+    let mut dated = Vec::with_capacity(input.donations.len());
+    for (index, donation) in input.donations.iter().enumerate() {
+        let date = CalendarDate::parse(&donation.donated_on).ok_or_else(|| {
+            EngineError::Computation(format!(
+                "Donation {index} has an invalid date '{}'",
+                donation.donated_on
+            ))
+        })?;
+        dated.push((tax_year_for_date(input.jurisdiction, date), date, index));
+    }
+    dated.sort();
+
+    let mut cumulative_taxable_donations_amount = input
+        .prior_cumulative_taxable_donations_amount
+        .max(Decimal::ZERO);
+    let mut lines = Vec::with_capacity(dated.len());
+    let mut tax_years = Vec::new();
+
+    for year_donations in dated.chunk_by(|left, right| left.0 == right.0) {
+        let tax_year = year_donations[0].0;
+        let selected = tax_rules_for(input.jurisdiction, tax_year)?;
+        let rules = &selected.rules;
+        let rule = &rules.donations_tax;
+        let annual_exemption_available_amount = annual_exemption_for(rule, input.taxpayer_class);
+        let mut annual_exemption_remaining_amount = annual_exemption_available_amount;
+        let mut summary = DonationsTaxYearSummary {
+            tax_year,
+            rule_version_id: selected.version.version_id,
+            total_donations_amount: Decimal::ZERO,
+            exempt_donations_amount: Decimal::ZERO,
+            annual_exemption_available_amount,
+            annual_exemption_used_amount: Decimal::ZERO,
+            taxable_donations_amount: Decimal::ZERO,
+            tax_payable_amount: Decimal::ZERO,
+            cumulative_taxable_donations_amount,
+        };
+
+        for &(_, date, index) in year_donations {
+            let donation = &input.donations[index];
+            let amount = donation.amount.max(Decimal::ZERO);
+            let exempt_amount = if donation.exempt_from_donations_tax {
+                amount
+            } else {
+                Decimal::ZERO
+            };
+            let chargeable_amount = amount - exempt_amount;
+            let annual_exemption_used_amount =
+                chargeable_amount.min(annual_exemption_remaining_amount);
+            annual_exemption_remaining_amount -= annual_exemption_used_amount;
+            let taxable_amount = chargeable_amount - annual_exemption_used_amount;
+
+            let primary_band_room_amount = (rule.primary_rate_cap_cumulative_amount
+                - cumulative_taxable_donations_amount)
+                .max(Decimal::ZERO);
+            let primary_band_amount = taxable_amount.min(primary_band_room_amount);
+            let secondary_band_amount = taxable_amount - primary_band_amount;
+            let tax_payable_amount = rules.rounding.round_return_amount(
+                primary_band_amount * rule.primary_rate
+                    + secondary_band_amount * rule.secondary_rate,
+            );
+            cumulative_taxable_donations_amount += taxable_amount;
+
+            summary.total_donations_amount += amount;
+            summary.exempt_donations_amount += exempt_amount;
+            summary.annual_exemption_used_amount += annual_exemption_used_amount;
+            summary.taxable_donations_amount += taxable_amount;
+            summary.tax_payable_amount += tax_payable_amount;
+            summary.cumulative_taxable_donations_amount = cumulative_taxable_donations_amount;
+
+            lines.push(DonationTaxLine {
+                donation_index: index,
+                donee: donation.donee.clone(),
+                donated_on: date.to_string(),
+                tax_year,
+                amount,
+                exempt_amount,
+                annual_exemption_used_amount,
+                taxable_amount,
+                primary_band_amount,
+                secondary_band_amount,
+                tax_payable_amount,
+                cumulative_taxable_donations_amount,
+            });
+        }
+        tax_years.push(summary);
+    }
+
+    Ok(DonationsTaxResult {
+        total_donations_amount: lines.iter().map(|line| line.amount).sum(),
+        total_taxable_donations_amount: lines.iter().map(|line| line.taxable_amount).sum(),
+        total_tax_payable_amount: lines.iter().map(|line| line.tax_payable_amount).sum(),
+        cumulative_taxable_donations_amount,
+        donations: lines,
+        tax_years,
+    })
+}
@@ -0,0 +1,80 @@
+use crate::core::domain::models::{EstateScenarioInput, ExecutorFeeBasis, ExecutorFeeBreakdown};
+use crate::core::rules::tax_rules::{graduated_tax_lines, Currency, ExecutorFeeRule};
+use rust_decimal::Decimal;
+
+// Executor's remuneration under the jurisdiction's tariff unless the scenario supplies an explicit
+// fee or a flat rate. VAT is added only where the jurisdiction's remuneration attracts it.
+pub fn calculate_executor_fee(
+    rule: Option<&ExecutorFeeRule>,
+    input: &EstateScenarioInput,
+    currency: Currency,
+    vat_rate: Decimal,
+    gross_assets_amount: Decimal,
+) -> ExecutorFeeBreakdown {
+    let gross_assets_amount = gross_assets_amount.max(Decimal::ZERO);
+    let income_accrued_after_death_amount =
+        input.income_accrued_after_death_amount.max(Decimal::ZERO);
+    let mut breakdown = ExecutorFeeBreakdown {
+        basis: ExecutorFeeBasis::NoTariff,
+        gross_assets_amount,
+        income_accrued_after_death_amount,
+        bracket_fees: Vec::new(),
+        asset_fee_amount: Decimal::ZERO,
+        income_fee_amount: Decimal::ZERO,
+        minimum_fee_amount: Decimal::ZERO,
+        fee_before_vat_amount: Decimal::ZERO,
+        vat_amount: Decimal::ZERO,
+        executor_fee_amount: Decimal::ZERO,
+    };
+
+    // An agreed fee is taken as given, VAT included.
+    if let Some(explicit_executor_fee_amount) = input.explicit_executor_fee_amount {
+        breakdown.basis = ExecutorFeeBasis::ExplicitAmount;
+        breakdown.fee_before_vat_amount = explicit_executor_fee_amount.max(Decimal::ZERO);
+        breakdown.executor_fee_amount = explicit_executor_fee_amount.max(Decimal::ZERO);
+        return breakdown;
+    }
+
+    if let Some(executor_fee_rate) = input.executor_fee_rate {
+        breakdown.basis = ExecutorFeeBasis::FlatRateOverride;
+        breakdown.asset_fee_amount = currency.round_amount(
+            gross_assets_amount * executor_fee_rate.clamp(Decimal::ZERO, Decimal::ONE),
+        );
+        breakdown.fee_before_vat_amount = breakdown.asset_fee_amount;
+    } else if let Some(rule) = rule {
+        let asset_base_amount = match rule.income_rate {
+            Some(income_rate) => {
+                breakdown.income_fee_amount =
+                    currency.round_amount(income_accrued_after_death_amount * income_rate);
+                gross_assets_amount
+            }
+            None => gross_assets_amount + income_accrued_after_death_amount,
+        };
+        breakdown.basis = ExecutorFeeBasis::Tariff;
+        breakdown.bracket_fees = graduated_tax_lines(&rule.asset_rate_schedule, asset_base_amount);
+        breakdown.asset_fee_amount = currency.round_amount(
+            breakdown
+                .bracket_fees
+                .iter()
+                .map(|line| line.tax_amount)
+                .sum::<Decimal>(),
+        );
+        // The minimum only applies once there is something to administer.
+        if asset_base_amount + income_accrued_after_death_amount > Decimal::ZERO {
+            breakdown.minimum_fee_amount = rule.minimum_fee_amount;
+        }
+        breakdown.fee_before_vat_amount = (breakdown.asset_fee_amount
+            + breakdown.income_fee_amount)
+            .max(breakdown.minimum_fee_amount);
+    } else {
+        return breakdown;
+    }
+
+    if rule.is_some_and(|rule| rule.vat_applies) {
+        breakdown.vat_amount = currency.round_amount(
+            breakdown.fee_before_vat_amount * vat_rate.clamp(Decimal::ZERO, Decimal::ONE),
+        );
+    }
+    breakdown.executor_fee_amount = breakdown.fee_before_vat_amount + breakdown.vat_amount;
+    breakdown
+}
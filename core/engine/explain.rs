@@ -0,0 +1,1168 @@
+use crate::core::domain::models::{
+    EstateScenarioInput, ExecutorFeeBasis, ExplainStage, ExplainStep, ExplainTrace, ExplainValue,
+    LifePolicyTreatment, MaritalPropertyRegime, RateBracketTaxLine, RuleCitation, ScenarioResult,
+};
+use crate::core::engine::defaults::{primary_residence_cgt_exclusion_cap_amount, vat_rate};
+use crate::core::rules::tax_rules::{
+    reporting_currency_for, JurisdictionTaxRuleSet, ReturnRounding, TaxPayerClass, TaxRuleVersion,
+};
+use rust_decimal::Decimal;
+use std::fmt::Write;
+
+struct TraceBuilder {
+    steps: Vec<ExplainStep>,
+}
+
+impl TraceBuilder {
+    fn push(
+        &mut self,
+        stage: ExplainStage,
+        description: impl Into<String>,
+        formula: impl Into<String>,
+        inputs: &[(&str, Decimal)],
+        value: Decimal,
+        rule: Option<RuleCitation>,
+    ) {
+        self.steps.push(ExplainStep {
+            step: self.steps.len() + 1,
+            stage,
+            description: description.into(),
+            formula: formula.into(),
+            inputs: inputs
+                .iter()
+                .map(|(name, value)| ExplainValue {
+                    name: (*name).to_string(),
+                    value: *value,
+                })
+                .collect(),
+            value,
+            rule,
+        });
+    }
+
+    fn push_brackets(
+        &mut self,
+        stage: ExplainStage,
+        label: &str,
+        lines: &[RateBracketTaxLine],
+        rule: &Option<RuleCitation>,
+    ) {
+        for line in lines
+            .iter()
+            .filter(|line| line.taxable_amount > Decimal::ZERO)
+        {
+            let band = match line.to_amount {
+                Some(to_amount) => format!("{} to {}", line.from_amount, to_amount),
+                None => format!("above {}", line.from_amount),
+            };
+            self.push(
+                stage,
+                format!("{label} in band {band}"),
+                "taxable_amount * rate",
+                &[("taxable_amount", line.taxable_amount), ("rate", line.rate)],
+                line.tax_amount,
+                rule.clone(),
+            );
+        }
+    }
+}
+
+fn cite(
+    rule_field: impl Into<String>,
+    source: &'static str,
+    source_url: &'static str,
+) -> Option<RuleCitation> {
+    Some(RuleCitation {
+        rule_field: rule_field.into(),
+        source,
+        source_url,
+    })
+}
+
+fn return_rounding_text(rules: &JurisdictionTaxRuleSet) -> String {
+    let direction = match rules.rounding.return_rounding {
+        ReturnRounding::HalfUp => "half up",
+        ReturnRounding::Down => "down",
+    };
+    format!(
+        "rounded {direction} to {} decimal places",
+        rules.rounding.return_decimal_places
+    )
+}
+
+fn settlement_costs_amount(input: &EstateScenarioInput) -> Decimal {
+    input.debts_and_loans_amount.max(Decimal::ZERO)
+        + input.funeral_costs_amount.max(Decimal::ZERO)
+        + input.administration_costs_amount.max(Decimal::ZERO)
+        + input.masters_office_fees_amount.max(Decimal::ZERO)
+        + input.conveyancing_costs_amount.max(Decimal::ZERO)
+        + input.other_settlement_costs_amount.max(Decimal::ZERO)
+}
+
+fn explain_currency_and_marital_property(builder: &mut TraceBuilder, result: &ScenarioResult) {
+    for line in &result.currency.lines {
+        builder.push(
+            ExplainStage::CurrencyConversion,
+            format!(
+                "Convert {} {} from {}",
+                line.item_name,
+                line.field,
+                line.currency.code()
+            ),
+            "original_amount * rate, rounded to the minor unit",
+            &[
+                ("original_amount", line.original_amount),
+                ("rate", line.rate),
+            ],
+            line.reporting_amount,
+            None,
+        );
+    }
+
+    let marital_property = &result.marital_property;
+    match marital_property.regime {
+        MaritalPropertyRegime::NotMarried | MaritalPropertyRegime::OutOfCommunity => {}
+        MaritalPropertyRegime::InCommunity => builder.push(
+            ExplainStage::MaritalProperty,
+            "Surviving spouse's half of the joint estate",
+            "joint assets * 0.5",
+            &[],
+            marital_property.spouse_share_of_joint_estate_amount,
+            None,
+        ),
+        MaritalPropertyRegime::Accrual => {
+            builder.push(
+                ExplainStage::MaritalProperty,
+                "Deceased's accrual",
+                "net estate - excluded assets - commencement value",
+                &[],
+                marital_property.deceased_accrual_amount,
+                None,
+            );
+            builder.push(
+                ExplainStage::MaritalProperty,
+                "Surviving spouse's accrual",
+                "spouse_net_estate_amount - excluded assets - commencement value",
+                &[],
+                marital_property.spouse_accrual_amount,
+                None,
+            );
+            builder.push(
+                ExplainStage::MaritalProperty,
+                "Accrual claim against the estate",
+                "(deceased_accrual_amount - spouse_accrual_amount) * 0.5, if positive",
+                &[
+                    (
+                        "deceased_accrual_amount",
+                        marital_property.deceased_accrual_amount,
+                    ),
+                    (
+                        "spouse_accrual_amount",
+                        marital_property.spouse_accrual_amount,
+                    ),
+                ],
+                marital_property.accrual_claim_against_estate_amount,
+                None,
+            );
+            builder.push(
+                ExplainStage::MaritalProperty,
+                "Accrual claim by the estate",
+                "(spouse_accrual_amount - deceased_accrual_amount) * 0.5, if positive",
+                &[
+                    (
+                        "deceased_accrual_amount",
+                        marital_property.deceased_accrual_amount,
+                    ),
+                    (
+                        "spouse_accrual_amount",
+                        marital_property.spouse_accrual_amount,
+                    ),
+                ],
+                marital_property.accrual_claim_by_estate_amount,
+                None,
+            );
+        }
+    }
+}
+
+fn explain_capital_gains(
+    builder: &mut TraceBuilder,
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) {
+    let rule = &rules.cgt_on_death;
+    let cgt = &result.cgt;
+    if !cgt.deemed_disposal_applies {
+        for line in &cgt.stepped_up_basis {
+            builder.push(
+                ExplainStage::CapitalGainsTax,
+                format!("Stepped-up basis of {}", line.asset_name),
+                "market value at death",
+                &[("original_base_cost_amount", line.original_base_cost_amount)],
+                line.stepped_up_basis_amount,
+                cite(
+                    "cgt_on_death.base_cost_step_up_to_market_value_on_death",
+                    rule.source,
+                    rule.source_url,
+                ),
+            );
+        }
+        return;
+    }
+
+    for (asset, line) in input.assets.iter().zip(&result.assets) {
+        if !asset.included_in_cgt_deemed_disposal {
+            continue;
+        }
+        builder.push(
+            ExplainStage::CapitalGainsTax,
+            format!("Capital gain on deemed disposal of {}", asset.name),
+            "market_value_amount - base_cost_amount",
+            &[
+                ("market_value_amount", asset.market_value_amount),
+                ("base_cost_amount", asset.base_cost_amount),
+            ],
+            line.capital_gain_amount,
+            cite("cgt_on_death", rule.source, rule.source_url),
+        );
+        if line.primary_residence_exclusion_used_amount > Decimal::ZERO {
+            builder.push(
+                ExplainStage::CapitalGainsTax,
+                format!("Primary residence exclusion on {}", asset.name),
+                "min(capital_gain_amount, remaining primary_residence_cgt_exclusion_cap_amount)",
+                &[
+                    ("capital_gain_amount", line.capital_gain_amount),
+                    (
+                        "primary_residence_cgt_exclusion_cap_amount",
+                        primary_residence_cgt_exclusion_cap_amount(rules, input),
+                    ),
+                ],
+                line.primary_residence_exclusion_used_amount,
+                None,
+            );
+        }
+    }
+
+    if cgt.spousal_rollover_gain_amount > Decimal::ZERO {
+        builder.push(
+            ExplainStage::CapitalGainsTax,
+            "Gains rolled over to the surviving spouse",
+            "sum of gains on assets bequeathed to the surviving spouse",
+            &[],
+            cgt.spousal_rollover_gain_amount,
+            cite(
+                "cgt_on_death.spousal_rollover_on_death",
+                rule.source,
+                rule.source_url,
+            ),
+        );
+    }
+    if cgt.pbo_disregarded_gain_amount > Decimal::ZERO {
+        builder.push(
+            ExplainStage::CapitalGainsTax,
+            "Gains disregarded on bequests to a PBO",
+            "sum of gains on assets bequeathed to a PBO",
+            &[],
+            cgt.pbo_disregarded_gain_amount,
+            cite(
+                "cgt_on_death.pbo_bequest_gain_disregarded",
+                rule.source,
+                rule.source_url,
+            ),
+        );
+    }
+    if cgt.retirement_fund_disregarded_amount > Decimal::ZERO {
+        builder.push(
+            ExplainStage::CapitalGainsTax,
+            "Retirement fund interests disregarded",
+            "sum of retirement fund values",
+            &[],
+            cgt.retirement_fund_disregarded_amount,
+            cite(
+                "retirement_funds.disregarded_for_cgt_on_death",
+                rules.retirement_funds.source,
+                rules.retirement_funds.source_url,
+            ),
+        );
+    }
+
+    let asset_gains_amount = result
+        .assets
+        .iter()
+        .map(|line| line.capital_gain_amount)
+        .sum::<Decimal>();
+    builder.push(
+        ExplainStage::CapitalGainsTax,
+        "Gross capital gain",
+        "capital gains + retirement_fund_gain_amount - primary_residence_exclusion_used_amount - gains rolled over or disregarded",
+        &[
+            ("capital_gains_amount", asset_gains_amount),
+            ("retirement_fund_gain_amount", cgt.retirement_fund_gain_amount),
+            (
+                "primary_residence_exclusion_used_amount",
+                cgt.primary_residence_exclusion_used_amount,
+            ),
+            ("spousal_rollover_gain_amount", cgt.spousal_rollover_gain_amount),
+            ("pbo_disregarded_gain_amount", cgt.pbo_disregarded_gain_amount),
+        ],
+        cgt.gross_capital_gain_amount,
+        cite("cgt_on_death", rule.source, rule.source_url),
+    );
+    builder.push(
+        ExplainStage::CapitalGainsTax,
+        "Annual exclusion in the year of death",
+        "min(gross_capital_gain_amount, annual_exclusion_in_year_of_death_amount)",
+        &[
+            ("gross_capital_gain_amount", cgt.gross_capital_gain_amount),
+            (
+                "annual_exclusion_in_year_of_death_amount",
+                rule.annual_exclusion_in_year_of_death_amount,
+            ),
+        ],
+        cgt.annual_exclusion_used_amount,
+        cite(
+            "cgt_on_death.annual_exclusion_in_year_of_death_amount",
+            rule.source,
+            rule.source_url,
+        ),
+    );
+    let inclusion_rate_field = match input.taxpayer_class {
+        TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => {
+            "cgt_on_death.inclusion_rate_natural_person"
+        }
+        TaxPayerClass::Company => "cgt_on_death.inclusion_rate_company",
+        TaxPayerClass::Trust => "cgt_on_death.inclusion_rate_trust",
+    };
+    builder.push(
+        ExplainStage::CapitalGainsTax,
+        "Taxable capital gain included in income",
+        format!(
+            "(gross_capital_gain_amount - annual_exclusion_used_amount) * inclusion_rate, {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            ("gross_capital_gain_amount", cgt.gross_capital_gain_amount),
+            (
+                "annual_exclusion_used_amount",
+                cgt.annual_exclusion_used_amount,
+            ),
+            ("inclusion_rate", cgt.inclusion_rate),
+        ],
+        cgt.taxable_capital_gain_in_income_amount,
+        cite(inclusion_rate_field, rule.source, rule.source_url),
+    );
+}
+
+fn explain_income_tax(
+    builder: &mut TraceBuilder,
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) {
+    let rule = &rules.income_tax;
+    let income_tax = &result.income_tax;
+    let cgt = &result.cgt;
+    let schedule_field = match input.taxpayer_class {
+        TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => "rate_schedule",
+        TaxPayerClass::Company => "company_rate_schedule",
+        TaxPayerClass::Trust => "trust_rate_schedule",
+    };
+    let schedule_rule = cite(
+        format!("income_tax.tables.{schedule_field}"),
+        rule.source,
+        rule.source_url,
+    );
+
+    builder.push(
+        ExplainStage::IncomeTax,
+        "Taxable income for the final period of assessment",
+        format!(
+            "taxable_income_excluding_capital_gain_amount + taxable_capital_gain_amount, each {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            (
+                "taxable_income_excluding_capital_gain_amount",
+                income_tax.taxable_income_excluding_capital_gain_amount,
+            ),
+            (
+                "taxable_capital_gain_amount",
+                income_tax.taxable_capital_gain_amount,
+            ),
+        ],
+        income_tax.taxable_income_amount,
+        cite("income_tax.tables", rule.source, rule.source_url),
+    );
+    builder.push_brackets(
+        ExplainStage::IncomeTax,
+        "Income tax",
+        &income_tax.bracket_taxes,
+        &schedule_rule,
+    );
+    builder.push(
+        ExplainStage::IncomeTax,
+        "Tax before rebates",
+        "sum of bracket taxes",
+        &[],
+        income_tax.tax_before_rebates_amount,
+        schedule_rule.clone(),
+    );
+    let age_input = input
+        .age_at_tax_year_end
+        .map(|age| [("age_at_tax_year_end", Decimal::from(age))]);
+    builder.push(
+        ExplainStage::IncomeTax,
+        "Rebates",
+        "sum of rebates whose from_age is reached",
+        age_input.as_ref().map_or(&[][..], |age| &age[..]),
+        income_tax.rebates_amount,
+        cite("income_tax.tables.rebates", rule.source, rule.source_url),
+    );
+    builder.push(
+        ExplainStage::IncomeTax,
+        "Normal tax",
+        format!(
+            "tax_before_rebates_amount - rebates_amount, {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            (
+                "tax_before_rebates_amount",
+                income_tax.tax_before_rebates_amount,
+            ),
+            ("rebates_amount", income_tax.rebates_amount),
+        ],
+        income_tax.normal_tax_amount,
+        schedule_rule.clone(),
+    );
+    builder.push(
+        ExplainStage::IncomeTax,
+        "Tax on income excluding the capital gain",
+        format!(
+            "tax on taxable_income_excluding_capital_gain_amount - rebates_amount, {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            (
+                "taxable_income_excluding_capital_gain_amount",
+                income_tax.taxable_income_excluding_capital_gain_amount,
+            ),
+            ("rebates_amount", income_tax.rebates_amount),
+        ],
+        income_tax.tax_on_income_excluding_capital_gain_amount,
+        schedule_rule.clone(),
+    );
+    builder.push(
+        ExplainStage::IncomeTax,
+        "Tax on the capital gain",
+        "normal_tax_amount - tax_on_income_excluding_capital_gain_amount",
+        &[
+            ("normal_tax_amount", income_tax.normal_tax_amount),
+            (
+                "tax_on_income_excluding_capital_gain_amount",
+                income_tax.tax_on_income_excluding_capital_gain_amount,
+            ),
+        ],
+        income_tax.tax_on_capital_gain_amount,
+        schedule_rule,
+    );
+
+    if cgt.deemed_disposal_applies {
+        match input.marginal_income_tax_rate {
+            Some(rate) => builder.push(
+                ExplainStage::CapitalGainsTax,
+                "CGT on death at the override rate",
+                format!(
+                    "taxable_capital_gain_in_income_amount * marginal_income_tax_rate, {}",
+                    return_rounding_text(rules)
+                ),
+                &[
+                    (
+                        "taxable_capital_gain_in_income_amount",
+                        cgt.taxable_capital_gain_in_income_amount,
+                    ),
+                    ("marginal_income_tax_rate", rate),
+                ],
+                cgt.tax_payable_amount,
+                None,
+            ),
+            None => builder.push(
+                ExplainStage::CapitalGainsTax,
+                "CGT on death",
+                "tax_on_capital_gain_amount",
+                &[(
+                    "tax_on_capital_gain_amount",
+                    income_tax.tax_on_capital_gain_amount,
+                )],
+                cgt.tax_payable_amount,
+                cite("income_tax.tables", rule.source, rule.source_url),
+            ),
+        }
+    }
+
+    match input.final_income_tax_due_amount {
+        Some(amount) => builder.push(
+            ExplainStage::IncomeTax,
+            "Final income tax (override)",
+            "final_income_tax_due_amount",
+            &[("final_income_tax_due_amount", amount)],
+            result.combined_tax.final_income_tax_amount,
+            None,
+        ),
+        None => builder.push(
+            ExplainStage::IncomeTax,
+            "Final income tax",
+            "tax_on_income_excluding_capital_gain_amount",
+            &[(
+                "tax_on_income_excluding_capital_gain_amount",
+                income_tax.tax_on_income_excluding_capital_gain_amount,
+            )],
+            result.combined_tax.final_income_tax_amount,
+            cite("income_tax.tables", rule.source, rule.source_url),
+        ),
+    }
+}
+
+fn explain_executor_fee(
+    builder: &mut TraceBuilder,
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) {
+    let fee = &result.estate_duty.executor_fee;
+    let tariff = |field: &str| {
+        rules.executor_fee.as_ref().and_then(|rule| {
+            cite(
+                format!("executor_fee.{field}"),
+                rule.source,
+                rule.source_url,
+            )
+        })
+    };
+
+    builder.push(
+        ExplainStage::ExecutorFee,
+        "Gross assets for the executor fee",
+        "assets + life policy and retirement fund proceeds payable to the estate",
+        &[],
+        fee.gross_assets_amount,
+        None,
+    );
+    match fee.basis {
+        ExecutorFeeBasis::NoTariff => {
+            builder.push(
+                ExplainStage::ExecutorFee,
+                "Executor fee (no statutory tariff)",
+                "0",
+                &[],
+                fee.executor_fee_amount,
+                None,
+            );
+            return;
+        }
+        ExecutorFeeBasis::ExplicitAmount => {
+            builder.push(
+                ExplainStage::ExecutorFee,
+                "Executor fee (agreed amount, VAT included)",
+                "explicit_executor_fee_amount",
+                &[(
+                    "explicit_executor_fee_amount",
+                    input.explicit_executor_fee_amount.unwrap_or(Decimal::ZERO),
+                )],
+                fee.executor_fee_amount,
+                None,
+            );
+            return;
+        }
+        ExecutorFeeBasis::FlatRateOverride => builder.push(
+            ExplainStage::ExecutorFee,
+            "Executor fee at the override rate",
+            "gross_assets_amount * executor_fee_rate, rounded to the minor unit",
+            &[
+                ("gross_assets_amount", fee.gross_assets_amount),
+                (
+                    "executor_fee_rate",
+                    input.executor_fee_rate.unwrap_or(Decimal::ZERO),
+                ),
+            ],
+            fee.asset_fee_amount,
+            None,
+        ),
+        ExecutorFeeBasis::Tariff => {
+            builder.push_brackets(
+                ExplainStage::ExecutorFee,
+                "Executor fee",
+                &fee.bracket_fees,
+                &tariff("asset_rate_schedule"),
+            );
+            if let Some(income_rate) = rules
+                .executor_fee
+                .as_ref()
+                .and_then(|rule| rule.income_rate)
+            {
+                builder.push(
+                    ExplainStage::ExecutorFee,
+                    "Executor fee on income accrued after death",
+                    "income_accrued_after_death_amount * income_rate, rounded to the minor unit",
+                    &[
+                        (
+                            "income_accrued_after_death_amount",
+                            fee.income_accrued_after_death_amount,
+                        ),
+                        ("income_rate", income_rate),
+                    ],
+                    fee.income_fee_amount,
+                    tariff("income_rate"),
+                );
+            }
+            builder.push(
+                ExplainStage::ExecutorFee,
+                "Executor fee before VAT",
+                "max(asset_fee_amount + income_fee_amount, minimum_fee_amount)",
+                &[
+                    ("asset_fee_amount", fee.asset_fee_amount),
+                    ("income_fee_amount", fee.income_fee_amount),
+                    ("minimum_fee_amount", fee.minimum_fee_amount),
+                ],
+                fee.fee_before_vat_amount,
+                tariff("minimum_fee_amount"),
+            );
+        }
+    }
+    if fee.vat_amount > Decimal::ZERO {
+        builder.push(
+            ExplainStage::ExecutorFee,
+            "VAT on the executor fee",
+            "fee_before_vat_amount * vat_rate, rounded to the minor unit",
+            &[
+                ("fee_before_vat_amount", fee.fee_before_vat_amount),
+                ("vat_rate", vat_rate(rules, input)),
+            ],
+            fee.vat_amount,
+            tariff("vat_applies"),
+        );
+    }
+    builder.push(
+        ExplainStage::ExecutorFee,
+        "Executor fee",
+        "fee_before_vat_amount + vat_amount",
+        &[
+            ("fee_before_vat_amount", fee.fee_before_vat_amount),
+            ("vat_amount", fee.vat_amount),
+        ],
+        fee.executor_fee_amount,
+        None,
+    );
+}
+
+fn explain_estate_duty(
+    builder: &mut TraceBuilder,
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) {
+    let rule = &rules.estate_duty;
+    let estate_duty = &result.estate_duty;
+    let estate_duty_rule =
+        |field: &str| cite(format!("estate_duty.{field}"), rule.source, rule.source_url);
+
+    for line in &estate_duty.life_policies {
+        let formula = if line.treatment == LifePolicyTreatment::DeemedProperty {
+            "sum_assured_amount (deemed property)"
+        } else {
+            "0 (excluded)"
+        };
+        builder.push(
+            ExplainStage::EstateDuty,
+            format!("Life policy {} included in the estate", line.name),
+            formula,
+            &[("sum_assured_amount", line.sum_assured_amount)],
+            line.included_in_estate_amount,
+            estate_duty_rule("life_policy_inclusion"),
+        );
+    }
+    for line in &estate_duty.retirement_funds {
+        builder.push(
+            ExplainStage::EstateDuty,
+            format!("Retirement fund {} included in the estate", line.name),
+            "value_amount, disallowed contributions or 0 under the retirement fund rule",
+            &[("value_amount", line.value_amount)],
+            line.included_in_estate_amount,
+            cite(
+                "retirement_funds",
+                rules.retirement_funds.source,
+                rules.retirement_funds.source_url,
+            ),
+        );
+    }
+
+    let assets_included_amount = result
+        .assets
+        .iter()
+        .map(|line| line.included_in_estate_amount)
+        .sum::<Decimal>();
+    builder.push(
+        ExplainStage::EstateDuty,
+        "Gross estate",
+        "assets in the estate + accrual_claim_asset_amount + life_policy_deemed_property_amount + retirement_fund_included_amount",
+        &[
+            ("assets_in_estate_amount", assets_included_amount),
+            (
+                "accrual_claim_asset_amount",
+                estate_duty.accrual_claim_asset_amount,
+            ),
+            (
+                "life_policy_deemed_property_amount",
+                estate_duty.life_policy_deemed_property_amount,
+            ),
+            (
+                "retirement_fund_included_amount",
+                estate_duty.retirement_fund_included_amount,
+            ),
+        ],
+        estate_duty.gross_estate_for_transfer_tax_amount,
+        None,
+    );
+
+    if estate_duty.spousal_deduction_amount > Decimal::ZERO {
+        builder.push(
+            ExplainStage::EstateDuty,
+            "Deduction for property passing to the surviving spouse",
+            "sum of assets, policies and fund interests passing to the surviving spouse",
+            &[],
+            estate_duty.spousal_deduction_amount,
+            estate_duty_rule("spouse_deduction_unlimited"),
+        );
+    }
+    if estate_duty.pbo_deduction_amount > Decimal::ZERO {
+        builder.push(
+            ExplainStage::EstateDuty,
+            "Deduction for bequests to a PBO",
+            "sum of assets bequeathed to a PBO",
+            &[],
+            estate_duty.pbo_deduction_amount,
+            None,
+        );
+    }
+
+    builder.push(
+        ExplainStage::EstateDuty,
+        "Total allowable deductions",
+        "debts and settlement costs + executor_fee_amount + CGT and income taxes + spousal, PBO, accrual, state estate tax and additional deductions",
+        &[
+            (
+                "debts_and_settlement_costs_amount",
+                settlement_costs_amount(input),
+            ),
+            ("executor_fee_amount", estate_duty.executor_fee_amount),
+            ("cgt_tax_payable_amount", result.cgt.tax_payable_amount),
+            (
+                "final_income_tax_amount",
+                result.combined_tax.final_income_tax_amount,
+            ),
+            (
+                "ongoing_estate_income_tax_provision_amount",
+                result.combined_tax.ongoing_estate_income_tax_provision_amount,
+            ),
+            ("spousal_deduction_amount", estate_duty.spousal_deduction_amount),
+            ("pbo_deduction_amount", estate_duty.pbo_deduction_amount),
+            (
+                "accrual_claim_deduction_amount",
+                estate_duty.accrual_claim_deduction_amount,
+            ),
+            (
+                "state_estate_tax_deduction_amount",
+                estate_duty.state_estate_tax_deduction_amount,
+            ),
+            (
+                "additional_allowable_estate_transfer_tax_deductions_amount",
+                input
+                    .additional_allowable_estate_transfer_tax_deductions_amount
+                    .max(Decimal::ZERO),
+            ),
+        ],
+        estate_duty.total_allowable_deductions_amount,
+        None,
+    );
+
+    let net_estate_amount = rules.rounding.round_return_amount(
+        (estate_duty.gross_estate_for_transfer_tax_amount
+            - estate_duty.total_allowable_deductions_amount)
+            .max(Decimal::ZERO),
+    );
+    builder.push(
+        ExplainStage::EstateDuty,
+        "Net estate",
+        format!(
+            "gross_estate_for_transfer_tax_amount - total_allowable_deductions_amount, {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            (
+                "gross_estate_for_transfer_tax_amount",
+                estate_duty.gross_estate_for_transfer_tax_amount,
+            ),
+            (
+                "total_allowable_deductions_amount",
+                estate_duty.total_allowable_deductions_amount,
+            ),
+        ],
+        net_estate_amount,
+        cite("rounding", rules.rounding.source, rules.rounding.source_url),
+    );
+    let exemption_field = if input.residency_status
+        == crate::core::domain::models::ResidencyStatus::NonResident
+        && rule.non_resident_exemption_amount.is_some()
+    {
+        "non_resident_exemption_amount"
+    } else {
+        "exemption_amount"
+    };
+    builder.push(
+        ExplainStage::EstateDuty,
+        "Exemption",
+        format!("{exemption_field} + ported_estate_tax_exemption_amount"),
+        &[
+            (
+                exemption_field,
+                rule.exemption_amount_for(input.residency_status),
+            ),
+            (
+                "ported_estate_tax_exemption_amount",
+                input.ported_estate_tax_exemption_amount.max(Decimal::ZERO),
+            ),
+        ],
+        estate_duty.exemption_used_amount,
+        estate_duty_rule(exemption_field),
+    );
+    builder.push(
+        ExplainStage::EstateDuty,
+        "Dutiable estate",
+        "net estate - exemption_used_amount",
+        &[
+            ("net_estate_amount", net_estate_amount),
+            ("exemption_used_amount", estate_duty.exemption_used_amount),
+        ],
+        estate_duty.dutiable_estate_after_exemption_amount,
+        None,
+    );
+    builder.push_brackets(
+        ExplainStage::EstateDuty,
+        "Estate duty",
+        &estate_duty.bracket_taxes,
+        &estate_duty_rule("rate_schedule"),
+    );
+    if rule.exemption_as_unified_credit {
+        builder.push(
+            ExplainStage::EstateDuty,
+            "Unified credit",
+            "tax on min(exemption_used_amount, net estate)",
+            &[("exemption_used_amount", estate_duty.exemption_used_amount)],
+            estate_duty.unified_credit_amount,
+            estate_duty_rule("exemption_as_unified_credit"),
+        );
+    }
+    builder.push(
+        ExplainStage::EstateDuty,
+        "Estate duty payable",
+        format!(
+            "sum of bracket taxes - unified_credit_amount, {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            (
+                "bracket_taxes_amount",
+                estate_duty
+                    .bracket_taxes
+                    .iter()
+                    .map(|line| line.tax_amount)
+                    .sum::<Decimal>(),
+            ),
+            ("unified_credit_amount", estate_duty.unified_credit_amount),
+        ],
+        estate_duty.tax_payable_amount,
+        estate_duty_rule("rate_schedule"),
+    );
+}
+
+fn explain_state_estate_tax(
+    builder: &mut TraceBuilder,
+    rules: &JurisdictionTaxRuleSet,
+    result: &ScenarioResult,
+) {
+    let (Some(rule), Some(state)) = (
+        rules.state_estate_tax.as_ref(),
+        result.state_estate_tax.as_ref(),
+    ) else {
+        return;
+    };
+    let state_rule = |field: &str| {
+        cite(
+            format!("state_estate_tax.{field}"),
+            rule.source,
+            rule.source_url,
+        )
+    };
+
+    builder.push(
+        ExplainStage::StateEstateTax,
+        "State taxable estate",
+        format!(
+            "gross estate - deductions before the state estate tax, {}",
+            return_rounding_text(rules)
+        ),
+        &[],
+        state.state_taxable_estate_amount,
+        None,
+    );
+    builder.push(
+        ExplainStage::StateEstateTax,
+        "Tentative state estate tax",
+        "graduated tax on state_taxable_estate_amount",
+        &[(
+            "state_taxable_estate_amount",
+            state.state_taxable_estate_amount,
+        )],
+        state.tentative_tax_amount,
+        state_rule("rate_schedule"),
+    );
+    let credit_field = if rule.credit_phase_out_ceiling_ratio.is_some() {
+        "credit_phase_out_ceiling_ratio"
+    } else {
+        "exemption_amount"
+    };
+    builder.push(
+        ExplainStage::StateEstateTax,
+        "State exemption credit",
+        "tax on exemption_amount, phased out above the exemption where the state has a cliff",
+        &[
+            ("exemption_amount", state.exemption_amount),
+            (
+                "state_taxable_estate_amount",
+                state.state_taxable_estate_amount,
+            ),
+        ],
+        state.credit_amount,
+        state_rule(credit_field),
+    );
+    builder.push(
+        ExplainStage::StateEstateTax,
+        "State estate tax payable",
+        format!(
+            "tentative_tax_amount - credit_amount, {}",
+            return_rounding_text(rules)
+        ),
+        &[
+            ("tentative_tax_amount", state.tentative_tax_amount),
+            ("credit_amount", state.credit_amount),
+        ],
+        state.tax_payable_amount,
+        state_rule("rate_schedule"),
+    );
+}
+
+fn explain_totals(
+    builder: &mut TraceBuilder,
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) {
+    let combined_tax = &result.combined_tax;
+    builder.push(
+        ExplainStage::CombinedTax,
+        "Total tax liability",
+        "estate_transfer_tax_amount + state_estate_tax_amount + cgt_on_death_amount + final_income_tax_amount + ongoing_estate_income_tax_provision_amount",
+        &[
+            (
+                "estate_transfer_tax_amount",
+                combined_tax.estate_transfer_tax_amount,
+            ),
+            ("state_estate_tax_amount", combined_tax.state_estate_tax_amount),
+            ("cgt_on_death_amount", combined_tax.cgt_on_death_amount),
+            ("final_income_tax_amount", combined_tax.final_income_tax_amount),
+            (
+                "ongoing_estate_income_tax_provision_amount",
+                combined_tax.ongoing_estate_income_tax_provision_amount,
+            ),
+        ],
+        combined_tax.total_tax_liability_amount,
+        None,
+    );
+
+    let liquidity = &result.liquidity;
+    builder.push(
+        ExplainStage::Liquidity,
+        "Available liquidity",
+        "liquid_assets_in_estate_amount + life_policy_proceeds_to_estate_amount + retirement_fund_proceeds_to_estate_amount + external_liquidity_proceeds_amount + cash_reserve_amount",
+        &[
+            (
+                "liquid_assets_in_estate_amount",
+                liquidity.liquid_assets_in_estate_amount,
+            ),
+            (
+                "life_policy_proceeds_to_estate_amount",
+                liquidity.life_policy_proceeds_to_estate_amount,
+            ),
+            (
+                "retirement_fund_proceeds_to_estate_amount",
+                liquidity.retirement_fund_proceeds_to_estate_amount,
+            ),
+            (
+                "external_liquidity_proceeds_amount",
+                liquidity.external_liquidity_proceeds_amount,
+            ),
+            ("cash_reserve_amount", liquidity.cash_reserve_amount),
+        ],
+        liquidity.total_available_liquidity_amount,
+        cite(
+            "retirement_funds.fund_benefits_allocated_by_trustees",
+            rules.retirement_funds.source,
+            rules.retirement_funds.source_url,
+        ),
+    );
+    builder.push(
+        ExplainStage::Liquidity,
+        "Immediate cash requirements",
+        "total_tax_liability_amount + debts and settlement costs + executor_fee_amount + accrual_claim_payable_amount",
+        &[
+            (
+                "total_tax_liability_amount",
+                combined_tax.total_tax_liability_amount,
+            ),
+            (
+                "debts_and_settlement_costs_amount",
+                settlement_costs_amount(input),
+            ),
+            ("executor_fee_amount", liquidity.executor_fee_amount),
+            (
+                "accrual_claim_payable_amount",
+                liquidity.accrual_claim_payable_amount,
+            ),
+        ],
+        liquidity.immediate_cash_requirements_amount,
+        None,
+    );
+    builder.push(
+        ExplainStage::Liquidity,
+        "Liquidity gap",
+        "max(immediate_cash_requirements_amount - total_available_liquidity_amount, 0)",
+        &[
+            (
+                "immediate_cash_requirements_amount",
+                liquidity.immediate_cash_requirements_amount,
+            ),
+            (
+                "total_available_liquidity_amount",
+                liquidity.total_available_liquidity_amount,
+            ),
+        ],
+        liquidity.liquidity_gap_amount,
+        None,
+    );
+
+    let distribution = &result.distribution;
+    let apportionment_rule = if input.tax_apportionment.is_none() {
+        cite(
+            "estate_duty.default_tax_apportionment",
+            rules.estate_duty.source,
+            rules.estate_duty.source_url,
+        )
+    } else {
+        None
+    };
+    builder.push(
+        ExplainStage::Distribution,
+        "Residue before estate taxes",
+        "distributable_assets_amount - specific bequests - legacies - costs_and_liabilities_amount, after abatement",
+        &[
+            (
+                "distributable_assets_amount",
+                distribution.distributable_assets_amount,
+            ),
+            (
+                "specific_bequests_amount",
+                distribution.specific_bequests_amount,
+            ),
+            ("legacies_amount", distribution.legacies_amount),
+            (
+                "costs_and_liabilities_amount",
+                distribution.costs_and_liabilities_amount,
+            ),
+            ("abatement_amount", distribution.abatement_amount),
+        ],
+        distribution.residue_amount,
+        None,
+    );
+    for heir in &distribution.heirs {
+        builder.push(
+            ExplainStage::Distribution,
+            format!("Net inheritance of {}", heir.name),
+            "specific_bequests_amount + legacy_amount + residue_amount - estate_tax_borne_amount",
+            &[
+                ("specific_bequests_amount", heir.specific_bequests_amount),
+                ("legacy_amount", heir.legacy_amount),
+                ("residue_amount", heir.residue_amount),
+                ("estate_tax_borne_amount", heir.estate_tax_borne_amount),
+            ],
+            heir.net_inheritance_amount,
+            apportionment_rule.clone(),
+        );
+    }
+}
+
+// Replays a finished calculation as an ordered list of steps. `input` is the deceased's scenario
+// after currency conversion and the marital property regime, as the calculator saw it.
+pub fn explain_scenario(
+    version: &TaxRuleVersion,
+    rules: &JurisdictionTaxRuleSet,
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) -> ExplainTrace {
+    let mut builder = TraceBuilder { steps: Vec::new() };
+    explain_currency_and_marital_property(&mut builder, result);
+    explain_capital_gains(&mut builder, rules, input, result);
+    explain_income_tax(&mut builder, rules, input, result);
+    explain_executor_fee(&mut builder, rules, input, result);
+    explain_estate_duty(&mut builder, rules, input, result);
+    explain_state_estate_tax(&mut builder, rules, result);
+    explain_totals(&mut builder, rules, input, result);
+
+    ExplainTrace {
+        jurisdiction: input.jurisdiction,
+        tax_year: input.tax_year,
+        rule_version_id: version.version_id,
+        reporting_currency: reporting_currency_for(input.jurisdiction),
+        steps: builder.steps,
+    }
+}
+
+pub fn render_explain_text(trace: &ExplainTrace) -> String {
+    let mut text = format!(
+        "Calculation trace: {:?} {} (rules {}), amounts in {}\n",
+        trace.jurisdiction,
+        trace.tax_year,
+        trace.rule_version_id,
+        trace.reporting_currency.code()
+    );
+    for step in &trace.steps {
+        let _ = writeln!(
+            text,
+            "\n{}. [{:?}] {}",
+            step.step, step.stage, step.description
+        );
+        let _ = writeln!(text, "   = {}", step.formula);
+        for input in &step.inputs {
+            let _ = writeln!(text, "     {} = {}", input.name, input.value);
+        }
+        let _ = writeln!(text, "   -> {}", step.value);
+        if let Some(rule) = &step.rule {
+            let _ = writeln!(
+                text,
+                "   Rule: {} ({}; {})",
+                rule.rule_field, rule.source, rule.source_url
+            );
+        }
+    }
+    text
+}
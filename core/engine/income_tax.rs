@@ -0,0 +1,74 @@
+use crate::core::domain::models::{EstateScenarioInput, IncomeTaxBreakdown};
+use crate::core::rules::tax_rules::{
+    graduated_tax_amount, graduated_tax_lines, IncomeTaxRule, RoundingRule, TaxRuleSelectionError,
+};
+use rust_decimal::Decimal;
+
+// The taxable capital gain is part of the deceased's taxable income for the final period of
+// assessment (SA section 26A). The CGT on death is the extra tax the gain causes on top of the
+// tax on the other income, so the gain is taxed in whichever brackets it reaches. Taxable income
+// and tax are rounded as they would be on the return. A year without a table is only accepted
+// when the scenario supplies every figure the table would have produced.
+pub fn calculate_income_tax_on_death(
+    rule: &IncomeTaxRule,
+    rounding: &RoundingRule,
+    input: &EstateScenarioInput,
+    taxable_capital_gain_amount: Decimal,
+) -> Result<IncomeTaxBreakdown, TaxRuleSelectionError> {
+    let table = rule.table_for(input.tax_year);
+    let table_needed = (input.marginal_income_tax_rate.is_none()
+        && taxable_capital_gain_amount > Decimal::ZERO)
+        || (input.final_income_tax_due_amount.is_none()
+            && input.taxable_income_in_year_of_death_amount > Decimal::ZERO);
+    if table.is_none() && table_needed {
+        return Err(TaxRuleSelectionError::MissingIncomeTaxTable {
+            jurisdiction: input.jurisdiction,
+            tax_year: input.tax_year,
+        });
+    }
+    let rate_schedule = table
+        .map(|table| table.rate_schedule_for(input.taxpayer_class))
+        .unwrap_or_default();
+    let rebates_amount = table.map_or(Decimal::ZERO, |table| {
+        table.rebates_amount_for(input.taxpayer_class, input.age_at_tax_year_end)
+    });
+
+    let taxable_income_excluding_capital_gain_amount = rounding.round_return_amount(
+        input
+            .taxable_income_in_year_of_death_amount
+            .max(Decimal::ZERO),
+    );
+    let taxable_capital_gain_amount =
+        rounding.round_return_amount(taxable_capital_gain_amount.max(Decimal::ZERO));
+    let taxable_income_amount =
+        taxable_income_excluding_capital_gain_amount + taxable_capital_gain_amount;
+    let bracket_taxes = graduated_tax_lines(rate_schedule, taxable_income_amount);
+    let tax_before_rebates_amount = bracket_taxes
+        .iter()
+        .map(|line| line.tax_amount)
+        .sum::<Decimal>();
+    let normal_tax_amount = rounding
+        .round_return_amount((tax_before_rebates_amount - rebates_amount).max(Decimal::ZERO));
+    let tax_on_income_excluding_capital_gain_amount = rounding.round_return_amount(
+        (graduated_tax_amount(rate_schedule, taxable_income_excluding_capital_gain_amount)
+            - rebates_amount)
+            .max(Decimal::ZERO),
+    );
+    let marginal_rate = bracket_taxes
+        .iter()
+        .rfind(|line| line.from_amount <= taxable_income_amount)
+        .map_or(Decimal::ZERO, |line| line.rate);
+
+    Ok(IncomeTaxBreakdown {
+        taxable_income_excluding_capital_gain_amount,
+        taxable_capital_gain_amount,
+        taxable_income_amount,
+        bracket_taxes,
+        tax_before_rebates_amount,
+        rebates_amount,
+        normal_tax_amount,
+        tax_on_income_excluding_capital_gain_amount,
+        tax_on_capital_gain_amount: normal_tax_amount - tax_on_income_excluding_capital_gain_amount,
+        marginal_rate,
+    })
+}
@@ -0,0 +1,303 @@
+use crate::core::domain::models::{
+    AccountEntry, AccountReconciliation, DistributionAccount, EstateScenarioInput,
+    LiquidationAccountSection, LiquidationAndDistributionAccount, MaritalPropertyRegime,
+    ScenarioResult,
+};
+use crate::core::engine::currency::convert_to_reporting_currency;
+use crate::core::engine::scenario::{
+    calculate_combined_tax_and_liquidity, scenario_tax_year, JurisdictionScenarioCalculator,
+};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{EstateTaxApportionment, Jurisdiction};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+const BALANCE_TOLERANCE_AMOUNT: Decimal = dec!(0.01);
+
+fn entry(description: impl Into<String>, amount: Decimal) -> AccountEntry {
+    AccountEntry {
+        description: description.into(),
+        amount,
+    }
+}
+
+fn push_if_non_zero(entries: &mut Vec<AccountEntry>, description: &str, amount: Decimal) {
+    if amount.abs() >= BALANCE_TOLERANCE_AMOUNT {
+        entries.push(entry(description, amount));
+    }
+}
+
+fn section(receipts: Vec<AccountEntry>, payments: Vec<AccountEntry>) -> LiquidationAccountSection {
+    let total_receipts_amount = receipts.iter().map(|line| line.amount).sum::<Decimal>();
+    let total_payments_amount = payments.iter().map(|line| line.amount).sum::<Decimal>();
+    LiquidationAccountSection {
+        receipts,
+        payments,
+        total_receipts_amount,
+        total_payments_amount,
+        balance_amount: total_receipts_amount - total_payments_amount,
+    }
+}
+
+fn estate_transfer_tax_label(jurisdiction: Jurisdiction) -> &'static str {
+    match jurisdiction {
+        Jurisdiction::SouthAfrica => "Estate duty",
+        Jurisdiction::UsNewYork
+        | Jurisdiction::UsTexas
+        | Jurisdiction::UsCalifornia
+        | Jurisdiction::UsFlorida
+        | Jurisdiction::UsMinnesota => "Federal estate tax",
+    }
+}
+
+fn capital_account(
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) -> LiquidationAccountSection {
+    let in_community = result.marital_property.regime == MaritalPropertyRegime::InCommunity;
+    let mut receipts = input
+        .assets
+        .iter()
+        .map(|asset| {
+            let description = if in_community {
+                format!("{} (half share of joint estate)", asset.name)
+            } else {
+                asset.name.clone()
+            };
+            entry(description, asset.market_value_amount.max(Decimal::ZERO))
+        })
+        .collect::<Vec<_>>();
+    push_if_non_zero(
+        &mut receipts,
+        "Accrual claim against surviving spouse",
+        result.marital_property.accrual_claim_by_estate_amount,
+    );
+    for line in &result.estate_duty.life_policies {
+        push_if_non_zero(
+            &mut receipts,
+            &format!("Life policy proceeds: {}", line.name),
+            line.liquidity_to_estate_amount,
+        );
+    }
+    for line in &result.estate_duty.retirement_funds {
+        push_if_non_zero(
+            &mut receipts,
+            &format!("Retirement fund benefit: {}", line.name),
+            line.liquidity_to_estate_amount,
+        );
+    }
+
+    let combined_tax = &result.combined_tax;
+    let mut payments = Vec::new();
+    for (description, amount) in [
+        (
+            "Debts and loans",
+            input.debts_and_loans_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Funeral costs",
+            input.funeral_costs_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Master's office fees",
+            input.masters_office_fees_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Administration costs",
+            input.administration_costs_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Conveyancing costs",
+            input.conveyancing_costs_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Other settlement costs",
+            input.other_settlement_costs_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Executor's remuneration",
+            result.estate_duty.executor_fee_amount.max(Decimal::ZERO),
+        ),
+        (
+            "Accrual claim by surviving spouse",
+            result.marital_property.accrual_claim_against_estate_amount,
+        ),
+        (
+            "Income tax to date of death",
+            combined_tax.final_income_tax_amount,
+        ),
+        (
+            "Capital gains tax on death",
+            combined_tax.cgt_on_death_amount,
+        ),
+        (
+            estate_transfer_tax_label(input.jurisdiction),
+            combined_tax.estate_transfer_tax_amount,
+        ),
+        ("State estate tax", combined_tax.state_estate_tax_amount),
+        // Estate income is not modelled, so post-death income tax is funded from capital.
+        (
+            "Transfer to income account",
+            combined_tax.ongoing_estate_income_tax_provision_amount,
+        ),
+    ] {
+        push_if_non_zero(&mut payments, description, amount);
+    }
+
+    section(receipts, payments)
+}
+
+fn income_account(result: &ScenarioResult) -> LiquidationAccountSection {
+    let provision_amount = result
+        .combined_tax
+        .ongoing_estate_income_tax_provision_amount;
+    let mut receipts = Vec::new();
+    let mut payments = Vec::new();
+    push_if_non_zero(
+        &mut receipts,
+        "Transfer from capital account",
+        provision_amount,
+    );
+    push_if_non_zero(
+        &mut payments,
+        "Income tax on estate income (provision)",
+        provision_amount,
+    );
+    section(receipts, payments)
+}
+
+fn distribution_account(
+    balance_for_distribution_amount: Decimal,
+    result: &ScenarioResult,
+) -> DistributionAccount {
+    // Duty borne by the residue is already a capital payment; only a pro-rata apportionment is
+    // recovered from each heir's award.
+    let recovered_from_heirs =
+        result.distribution.tax_apportionment == EstateTaxApportionment::ProRataAmongDutiableHeirs;
+    let mut awards = Vec::new();
+    for heir in &result.distribution.heirs {
+        let (residue_amount, recovered_amount) = if recovered_from_heirs {
+            (heir.residue_amount, heir.estate_tax_borne_amount)
+        } else {
+            (
+                heir.residue_amount - heir.estate_tax_borne_amount,
+                Decimal::ZERO,
+            )
+        };
+        push_if_non_zero(
+            &mut awards,
+            &format!("{}: specific bequests", heir.name),
+            heir.specific_bequests_amount,
+        );
+        push_if_non_zero(
+            &mut awards,
+            &format!("{}: legacy", heir.name),
+            heir.legacy_amount,
+        );
+        push_if_non_zero(
+            &mut awards,
+            &format!("{}: share of residue", heir.name),
+            residue_amount,
+        );
+        push_if_non_zero(
+            &mut awards,
+            &format!("{}: estate tax recovered", heir.name),
+            -recovered_amount,
+        );
+    }
+    push_if_non_zero(
+        &mut awards,
+        "Undistributed residue (intestate succession)",
+        result.distribution.undistributed_residue_amount,
+    );
+
+    DistributionAccount {
+        balance_for_distribution_amount,
+        total_awarded_amount: awards.iter().map(|line| line.amount).sum(),
+        awards,
+    }
+}
+
+fn reconcile(
+    capital_account: &LiquidationAccountSection,
+    result: &ScenarioResult,
+    estate_transfer_tax_label: &str,
+) -> AccountReconciliation {
+    let payment_amount = |description: &str| {
+        capital_account
+            .payments
+            .iter()
+            .filter(|line| line.description == description)
+            .map(|line| line.amount)
+            .sum::<Decimal>()
+    };
+    let estate_duty_per_breakdown_amount = result.estate_duty.tax_payable_amount;
+    let estate_duty_per_account_amount = payment_amount(estate_transfer_tax_label);
+    let executor_fee_per_breakdown_amount =
+        result.estate_duty.executor_fee_amount.max(Decimal::ZERO);
+    let executor_fee_per_account_amount = payment_amount("Executor's remuneration");
+    let cash_requirements_per_liquidity_amount =
+        result.liquidity.immediate_cash_requirements_amount;
+    let payments_per_account_amount = capital_account.total_payments_amount;
+
+    AccountReconciliation {
+        reconciled: (estate_duty_per_breakdown_amount - estate_duty_per_account_amount).abs()
+            < BALANCE_TOLERANCE_AMOUNT
+            && (executor_fee_per_breakdown_amount - executor_fee_per_account_amount).abs()
+                < BALANCE_TOLERANCE_AMOUNT
+            && (result.liquidity.executor_fee_amount - executor_fee_per_account_amount).abs()
+                < BALANCE_TOLERANCE_AMOUNT
+            && (cash_requirements_per_liquidity_amount - payments_per_account_amount).abs()
+                < BALANCE_TOLERANCE_AMOUNT,
+        estate_duty_per_breakdown_amount,
+        estate_duty_per_account_amount,
+        executor_fee_per_breakdown_amount,
+        executor_fee_per_account_amount,
+        cash_requirements_per_liquidity_amount,
+        payments_per_account_amount,
+    }
+}
+
+// `input` is the scenario as submitted; currency conversion and the marital regime are applied
+// here exactly as the calculator does, so asset lines carry the deceased's share in the
+// reporting currency.
+pub fn build_liquidation_and_distribution_account(
+    input: &EstateScenarioInput,
+    result: &ScenarioResult,
+) -> Result<LiquidationAndDistributionAccount, EngineError> {
+    let (reporting_input, _) = convert_to_reporting_currency(input)?;
+    let (deceased_input, _) =
+        JurisdictionScenarioCalculator::apply_marital_property_regime(&reporting_input);
+    let capital_account = capital_account(&deceased_input, result);
+    let income_account = income_account(result);
+    let balance_for_distribution_amount =
+        capital_account.balance_amount + income_account.balance_amount;
+    let distribution_account = distribution_account(balance_for_distribution_amount, result);
+    let reconciliation = reconcile(
+        &capital_account,
+        result,
+        estate_transfer_tax_label(input.jurisdiction),
+    );
+    // An insolvent estate cannot be distributed, so its account never balances.
+    let balanced = (distribution_account.balance_for_distribution_amount
+        - distribution_account.total_awarded_amount)
+        .abs()
+        < BALANCE_TOLERANCE_AMOUNT;
+
+    Ok(LiquidationAndDistributionAccount {
+        jurisdiction: input.jurisdiction,
+        tax_year: scenario_tax_year(input),
+        capital_account,
+        income_account,
+        distribution_account,
+        reconciliation,
+        balanced,
+    })
+}
+
+pub fn draft_liquidation_and_distribution_account(
+    input: &EstateScenarioInput,
+) -> Result<LiquidationAndDistributionAccount, EngineError> {
+    let result = calculate_combined_tax_and_liquidity(input)?;
+    build_liquidation_and_distribution_account(input, &result)
+}
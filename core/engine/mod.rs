@@ -1,7 +1,29 @@
+pub mod couple;
+pub mod cross_border;
+pub mod currency;
+pub mod defaults;
+pub mod distribution;
+pub mod donations;
+pub mod executor_fee;
+pub mod explain;
+pub mod income_tax;
+pub mod liquidation_account;
 pub mod optimizer;
 pub mod scenario;
 pub mod scoring;
+pub mod strategies;
 
+pub use couple::*;
+pub use cross_border::*;
+pub use currency::*;
+pub use defaults::*;
+pub use distribution::*;
+pub use donations::*;
+pub use executor_fee::*;
+pub use explain::*;
+pub use income_tax::*;
+pub use liquidation_account::*;
 pub use optimizer::*;
 pub use scenario::*;
 pub use scoring::*;
+pub use strategies::*;
@@ -1,7 +1,15 @@
-use crate::core::domain::models::{EstateScenarioInput, ScenarioResult};
+use crate::core::domain::models::{
+    Beneficiary, CoupleScenarioInput, DeathOrder, EstateAsset, EstateScenarioInput, HeirCategory,
+    MaritalPropertyInput, MaritalPropertyRegime, ScenarioResult,
+};
+use crate::core::engine::couple::calculate_couple_second_death;
 use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
 use crate::core::engine::scoring::{score_scenario, ScenarioScore};
-use crate::core::rules::tax_rules::TaxRuleSelectionError;
+use crate::core::engine::strategies::{
+    generate_candidate_strategies, CandidateStrategy, StrategyGeneratorOptions,
+};
+use crate::core::errors::EngineError;
+use rust_decimal::Decimal;
 
 #[derive(Debug, Clone)]
 pub struct OptimizedScenario {
@@ -11,9 +19,91 @@ pub struct OptimizedScenario {
     pub score: ScenarioScore,
 }
 
+#[derive(Debug, Clone)]
+pub struct RankedStrategy {
+    pub rank: usize,
+    pub strategy: CandidateStrategy,
+    pub result: ScenarioResult,
+    pub score: ScenarioScore,
+    // Combined tax saved against the current plan; negative when the strategy costs more tax.
+    pub tax_saving_amount: Decimal,
+    // Estimated tax on the surviving spouse's later death; `None` when the deceased is unmarried.
+    pub second_death_tax_amount: Option<Decimal>,
+    // Tax saved across both deaths; the same as `tax_saving_amount` without a surviving spouse.
+    pub combined_tax_saving_amount: Decimal,
+    // The strategy saves tax on the first death only by passing at least as much to the second.
+    pub deferral_only: bool,
+}
+
+// The survivor's own estate is only known as the net estate given for the accrual, so it is
+// modelled as one liquid asset with no gain, left with everything inherited to the other heirs.
+fn surviving_spouse_estimate(input: &EstateScenarioInput) -> EstateScenarioInput {
+    let own_estate_amount = input
+        .marital_property
+        .spouse_net_estate_amount
+        .max(Decimal::ZERO);
+    let assets = if own_estate_amount > Decimal::ZERO {
+        vec![EstateAsset {
+            name: "Surviving spouse's own estate".to_string(),
+            market_value_amount: own_estate_amount,
+            base_cost_amount: own_estate_amount,
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: false,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }]
+    } else {
+        Vec::new()
+    };
+    EstateScenarioInput {
+        jurisdiction: input.jurisdiction,
+        tax_year: input.tax_year,
+        date_of_death: input.date_of_death.clone(),
+        valuation_date: input.valuation_date.clone(),
+        taxpayer_class: input.taxpayer_class,
+        residency_status: input.residency_status,
+        marginal_income_tax_rate: input.marginal_income_tax_rate,
+        vat_rate: input.vat_rate,
+        assets,
+        marital_property: MaritalPropertyInput::default(),
+        beneficiaries: vec![Beneficiary {
+            name: "Other heirs".to_string(),
+            category: HeirCategory::OtherHeirs,
+            specific_bequest_asset_names: Vec::new(),
+            legacy_amount: Decimal::ZERO,
+            residue_share: Decimal::ONE,
+        }],
+        ..EstateScenarioInput::default()
+    }
+}
+
+// A married scenario is run on to the survivor's death, so the result is the first death's and
+// the second amount is the survivor's estimated tax.
+fn calculate_through_second_death(
+    input: &EstateScenarioInput,
+) -> Result<(ScenarioResult, Option<Decimal>), EngineError> {
+    if input.marital_property.regime == MaritalPropertyRegime::NotMarried {
+        return Ok((calculate_combined_tax_and_liquidity(input)?, None));
+    }
+    let couple = calculate_couple_second_death(&CoupleScenarioInput {
+        spouse_a: input.clone(),
+        spouse_b: surviving_spouse_estimate(input),
+        order_of_death: DeathOrder::SpouseAFirst,
+    })?;
+    Ok((
+        couple.first_death,
+        Some(couple.second_death.combined_tax.total_tax_liability_amount),
+    ))
+}
+
 pub fn optimize_scenarios(
     candidates: Vec<EstateScenarioInput>,
-) -> Result<Option<OptimizedScenario>, TaxRuleSelectionError> {
+) -> Result<Option<OptimizedScenario>, EngineError> {
     let mut optimized = Vec::new();
 
     for (index, input) in candidates.into_iter().enumerate() {
@@ -31,3 +121,45 @@ pub fn optimize_scenarios(
         .into_iter()
         .min_by(|a, b| a.score.composite_score.total_cmp(&b.score.composite_score)))
 }
+
+// Best composite score first, with deferral-only strategies after the rest; ties keep the
+// generator's order, so the current plan wins a tie.
+pub fn rank_candidate_strategies(
+    base: &EstateScenarioInput,
+    options: StrategyGeneratorOptions,
+) -> Result<Vec<RankedStrategy>, EngineError> {
+    let mut ranked = Vec::new();
+    let mut current_tax_amounts = None;
+
+    for strategy in generate_candidate_strategies(base, options)? {
+        let (result, second_death_tax_amount) = calculate_through_second_death(&strategy.input)?;
+        let total_tax_amount = result.combined_tax.total_tax_liability_amount;
+        let both_deaths_tax_amount =
+            total_tax_amount + second_death_tax_amount.unwrap_or(Decimal::ZERO);
+        let (current_tax_amount, current_both_deaths_tax_amount) =
+            *current_tax_amounts.get_or_insert((total_tax_amount, both_deaths_tax_amount));
+        let tax_saving_amount = current_tax_amount - total_tax_amount;
+        let combined_tax_saving_amount = current_both_deaths_tax_amount - both_deaths_tax_amount;
+        ranked.push(RankedStrategy {
+            rank: 0,
+            score: score_scenario(&result),
+            tax_saving_amount,
+            second_death_tax_amount,
+            combined_tax_saving_amount,
+            deferral_only: tax_saving_amount > Decimal::ZERO
+                && combined_tax_saving_amount <= Decimal::ZERO,
+            strategy,
+            result,
+        });
+    }
+
+    ranked.sort_by(|a, b| {
+        a.deferral_only
+            .cmp(&b.deferral_only)
+            .then(a.score.composite_score.total_cmp(&b.score.composite_score))
+    });
+    for (index, strategy) in ranked.iter_mut().enumerate() {
+        strategy.rank = index + 1;
+    }
+    Ok(ranked)
+}
@@ -1,30 +1,70 @@
 use crate::core::domain::models::{
-    CapitalGainsTaxBreakdown, CombinedTaxLiability, EstateDutyBreakdown, EstateScenarioInput,
-    LiquidityGapOutput, ResidencyStatus, ScenarioResult,
+    AssetTaxAttributionLine, CalendarDate, CapitalGainsTaxBreakdown, CombinedTaxLiability,
+    EstateAsset, EstateDutyBreakdown, EstateScenarioInput, HeirCategory, IncomeTaxBreakdown,
+    LifeInsurancePolicy, LifePolicyLine, LifePolicyTreatment, LiquidityGapOutput,
+    MaritalPropertyBreakdown, MaritalPropertyRegime, PolicyParty, ResidencyStatus,
+    RetirementBenefitRecipient, RetirementFundInterest, RetirementFundLine, RetirementFundType,
+    ScenarioResult, StateEstateTaxBreakdown, SteppedUpBasisLine,
 };
+use crate::core::engine::currency::convert_to_reporting_currency;
+use crate::core::engine::defaults::{
+    defaulted_scenario_fields, primary_residence_cgt_exclusion_cap_amount, vat_rate,
+};
+use crate::core::engine::distribution::calculate_distribution;
+use crate::core::engine::executor_fee::calculate_executor_fee;
+use crate::core::engine::explain::explain_scenario;
+use crate::core::engine::income_tax::calculate_income_tax_on_death;
+use crate::core::errors::EngineError;
 use crate::core::rules::tax_rules::{
-    tax_rules_for, JurisdictionTaxRuleSet, TaxPayerClass, TaxRuleSelectionError,
+    graduated_tax_amount, graduated_tax_lines, reporting_currency_for, tax_rules_for_scenario,
+    tax_year_for_date, Currency, Jurisdiction, JurisdictionTaxRuleSet, LifePolicyInclusion,
+    StateEstateTaxRule, TaxPayerClass, TaxRuleSelectionError, TaxRuleVersion,
+    VersionedJurisdictionTaxRuleSet,
 };
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// A scenario may give only a date of death, leaving `tax_year` at 0; it is then assessed in the
+// tax year the date falls in.
+pub fn scenario_tax_year(input: &EstateScenarioInput) -> u16 {
+    match input.date_of_death.as_deref().and_then(CalendarDate::parse) {
+        Some(date_of_death) if input.tax_year == 0 => {
+            tax_year_for_date(input.jurisdiction, date_of_death)
+        }
+        _ => input.tax_year,
+    }
+}
 
 pub trait ScenarioCalculator {
-    fn calculate(&self, input: &EstateScenarioInput) -> ScenarioResult;
+    fn calculate(&self, input: &EstateScenarioInput) -> Result<ScenarioResult, EngineError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct JurisdictionScenarioCalculator {
+    version: TaxRuleVersion,
     rules: JurisdictionTaxRuleSet,
+    reporting_currency: Currency,
 }
 
 impl JurisdictionScenarioCalculator {
     pub fn new(input: &EstateScenarioInput) -> Result<Self, TaxRuleSelectionError> {
-        let selected = tax_rules_for(input.jurisdiction, input.tax_year)?;
-        Ok(Self {
+        let selected = tax_rules_for_scenario(input)?;
+        Ok(Self::from_rule_version(input.jurisdiction, selected))
+    }
+
+    pub fn from_rule_version(
+        jurisdiction: Jurisdiction,
+        selected: VersionedJurisdictionTaxRuleSet,
+    ) -> Self {
+        Self {
+            version: selected.version,
             rules: selected.rules,
-        })
+            reporting_currency: reporting_currency_for(jurisdiction),
+        }
     }
 
-    fn clamp_rate(rate: f64) -> f64 {
-        rate.clamp(0.0, 1.0)
+    fn clamp_rate(rate: Decimal) -> Decimal {
+        rate.clamp(Decimal::ZERO, Decimal::ONE)
     }
 
     fn asset_in_estate_duty_scope(
@@ -42,31 +82,251 @@ impl JurisdictionScenarioCalculator {
         }
     }
 
-    fn calculate_cgt(&self, input: &EstateScenarioInput) -> CapitalGainsTaxBreakdown {
-        let marginal_income_tax_rate = Self::clamp_rate(input.marginal_income_tax_rate);
-        let mut gross_capital_gain_amount = 0.0;
-        let mut primary_residence_exclusion_remaining_amount =
-            input.primary_residence_cgt_exclusion_cap_amount.max(0.0);
-        let mut primary_residence_exclusion_used_amount = 0.0;
+    // Reduces a community-of-property joint estate to the deceased's half and computes the
+    // accrual claim (Matrimonial Property Act 88 of 1984, sections 3 and 14).
+    pub fn apply_marital_property_regime(
+        input: &EstateScenarioInput,
+    ) -> (EstateScenarioInput, MaritalPropertyBreakdown) {
+        let marital_property = &input.marital_property;
+        let mut deceased_input = input.clone();
+        let mut breakdown = MaritalPropertyBreakdown {
+            regime: marital_property.regime,
+            spouse_share_of_joint_estate_amount: Decimal::ZERO,
+            deceased_accrual_amount: Decimal::ZERO,
+            spouse_accrual_amount: Decimal::ZERO,
+            accrual_claim_against_estate_amount: Decimal::ZERO,
+            accrual_claim_by_estate_amount: Decimal::ZERO,
+        };
 
-        for asset in &input.assets {
-            if !asset.included_in_cgt_deemed_disposal {
-                continue;
+        match marital_property.regime {
+            MaritalPropertyRegime::NotMarried | MaritalPropertyRegime::OutOfCommunity => {}
+            MaritalPropertyRegime::InCommunity => {
+                for asset in &mut deceased_input.assets {
+                    breakdown.spouse_share_of_joint_estate_amount +=
+                        asset.market_value_amount.max(Decimal::ZERO) * dec!(0.5);
+                    asset.market_value_amount *= dec!(0.5);
+                    asset.base_cost_amount *= dec!(0.5);
+                }
+                deceased_input.debts_and_loans_amount *= dec!(0.5);
             }
+            MaritalPropertyRegime::Accrual => {
+                let deceased_net_estate_amount = input
+                    .assets
+                    .iter()
+                    .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+                    .sum::<Decimal>()
+                    - input.debts_and_loans_amount.max(Decimal::ZERO);
+                breakdown.deceased_accrual_amount = (deceased_net_estate_amount
+                    - marital_property
+                        .deceased_excluded_assets_amount
+                        .max(Decimal::ZERO)
+                    - marital_property
+                        .deceased_commencement_value_amount
+                        .max(Decimal::ZERO))
+                .max(Decimal::ZERO);
+                breakdown.spouse_accrual_amount = (marital_property.spouse_net_estate_amount
+                    - marital_property
+                        .spouse_excluded_assets_amount
+                        .max(Decimal::ZERO)
+                    - marital_property
+                        .spouse_commencement_value_amount
+                        .max(Decimal::ZERO))
+                .max(Decimal::ZERO);
 
-            let mut gain = asset.raw_capital_gain_amount();
-            if asset.qualifies_primary_residence_exclusion
-                && primary_residence_exclusion_remaining_amount > 0.0
-            {
-                let exclusion = gain.min(primary_residence_exclusion_remaining_amount);
-                primary_residence_exclusion_used_amount += exclusion;
-                primary_residence_exclusion_remaining_amount -= exclusion;
-                gain -= exclusion;
+                let accrual_difference_amount =
+                    breakdown.deceased_accrual_amount - breakdown.spouse_accrual_amount;
+                if accrual_difference_amount > Decimal::ZERO {
+                    breakdown.accrual_claim_against_estate_amount =
+                        accrual_difference_amount * dec!(0.5);
+                } else {
+                    breakdown.accrual_claim_by_estate_amount =
+                        -accrual_difference_amount * dec!(0.5);
+                }
             }
+        }
+
+        (deceased_input, breakdown)
+    }
+
+    fn retirement_fund_value_amount(input: &EstateScenarioInput) -> Decimal {
+        input
+            .retirement_funds
+            .iter()
+            .map(|fund| fund.value_amount.max(Decimal::ZERO))
+            .sum()
+    }
+
+    fn retirement_fund_disregarded_amount(&self, input: &EstateScenarioInput) -> Decimal {
+        if self.rules.retirement_funds.disregarded_for_cgt_on_death {
+            Self::retirement_fund_value_amount(input)
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    fn asset_attribution_line(asset: &EstateAsset) -> AssetTaxAttributionLine {
+        AssetTaxAttributionLine {
+            asset_name: asset.name.clone(),
+            market_value_amount: asset.market_value_amount.max(Decimal::ZERO),
+            capital_gain_amount: Decimal::ZERO,
+            primary_residence_exclusion_used_amount: Decimal::ZERO,
+            gain_disregarded_amount: Decimal::ZERO,
+            annual_exclusion_used_amount: Decimal::ZERO,
+            cgt_attributed_amount: Decimal::ZERO,
+            included_in_estate_amount: Decimal::ZERO,
+            deductions_attributable_amount: Decimal::ZERO,
+            estate_duty_attributed_amount: Decimal::ZERO,
+            state_estate_tax_attributed_amount: Decimal::ZERO,
+            total_tax_attributed_amount: Decimal::ZERO,
+        }
+    }
+
+    fn calculate_basis_step_up(
+        &self,
+        input: &EstateScenarioInput,
+    ) -> Result<
+        (
+            CapitalGainsTaxBreakdown,
+            Vec<AssetTaxAttributionLine>,
+            IncomeTaxBreakdown,
+        ),
+        TaxRuleSelectionError,
+    > {
+        let asset_lines = input
+            .assets
+            .iter()
+            .map(|asset| {
+                let mut line = Self::asset_attribution_line(asset);
+                if asset.included_in_cgt_deemed_disposal {
+                    line.capital_gain_amount = asset.raw_capital_gain_amount();
+                    line.gain_disregarded_amount = line.capital_gain_amount;
+                }
+                line
+            })
+            .collect();
+        let stepped_up_basis = input
+            .assets
+            .iter()
+            .filter(|asset| asset.included_in_cgt_deemed_disposal)
+            .map(|asset| {
+                let original_base_cost_amount = asset.base_cost_amount.max(Decimal::ZERO);
+                let stepped_up_basis_amount = asset.market_value_amount.max(Decimal::ZERO);
+                SteppedUpBasisLine {
+                    asset_name: asset.name.clone(),
+                    heir: HeirCategory::for_asset(asset),
+                    original_base_cost_amount,
+                    stepped_up_basis_amount,
+                    basis_step_up_amount: stepped_up_basis_amount - original_base_cost_amount,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let breakdown = CapitalGainsTaxBreakdown {
+            deemed_disposal_applies: false,
+            gross_capital_gain_amount: Decimal::ZERO,
+            primary_residence_exclusion_used_amount: Decimal::ZERO,
+            spousal_rollover_gain_amount: Decimal::ZERO,
+            spouse_inherited_base_cost_amount: Decimal::ZERO,
+            pbo_disregarded_gain_amount: Decimal::ZERO,
+            retirement_fund_disregarded_amount: self.retirement_fund_disregarded_amount(input),
+            retirement_fund_gain_amount: Decimal::ZERO,
+            retirement_fund_cgt_attributed_amount: Decimal::ZERO,
+            annual_exclusion_used_amount: Decimal::ZERO,
+            inclusion_rate: self
+                .rules
+                .cgt_on_death
+                .inclusion_rate_for(input.taxpayer_class),
+            taxable_capital_gain_in_income_amount: Decimal::ZERO,
+            tax_payable_amount: Decimal::ZERO,
+            stepped_up_basis_total_amount: stepped_up_basis
+                .iter()
+                .map(|line| line.stepped_up_basis_amount)
+                .sum(),
+            stepped_up_basis,
+        };
+        let income_tax = calculate_income_tax_on_death(
+            &self.rules.income_tax,
+            &self.rules.rounding,
+            input,
+            Decimal::ZERO,
+        )?;
+        Ok((breakdown, asset_lines, income_tax))
+    }
+
+    fn calculate_cgt(
+        &self,
+        input: &EstateScenarioInput,
+    ) -> Result<
+        (
+            CapitalGainsTaxBreakdown,
+            Vec<AssetTaxAttributionLine>,
+            IncomeTaxBreakdown,
+        ),
+        TaxRuleSelectionError,
+    > {
+        if self
+            .rules
+            .cgt_on_death
+            .base_cost_step_up_to_market_value_on_death
+        {
+            return self.calculate_basis_step_up(input);
+        }
 
-            gross_capital_gain_amount += gain;
+        let mut gross_capital_gain_amount = Decimal::ZERO;
+        let mut primary_residence_exclusion_remaining_amount =
+            primary_residence_cgt_exclusion_cap_amount(&self.rules, input);
+        let mut primary_residence_exclusion_used_amount = Decimal::ZERO;
+        let mut spousal_rollover_gain_amount = Decimal::ZERO;
+        let mut spouse_inherited_base_cost_amount = Decimal::ZERO;
+        let mut pbo_disregarded_gain_amount = Decimal::ZERO;
+        let mut asset_lines = Vec::with_capacity(input.assets.len());
+        let mut asset_gains = Vec::with_capacity(input.assets.len());
+
+        for asset in &input.assets {
+            let mut line = Self::asset_attribution_line(asset);
+            let mut gain = Decimal::ZERO;
+            if asset.included_in_cgt_deemed_disposal {
+                line.capital_gain_amount = asset.raw_capital_gain_amount();
+                if asset.bequeathed_to_surviving_spouse
+                    && self.rules.cgt_on_death.spousal_rollover_on_death
+                {
+                    spousal_rollover_gain_amount += line.capital_gain_amount;
+                    spouse_inherited_base_cost_amount += asset.base_cost_amount.max(Decimal::ZERO);
+                    line.gain_disregarded_amount = line.capital_gain_amount;
+                } else if asset.bequeathed_to_pbo
+                    && self.rules.cgt_on_death.pbo_bequest_gain_disregarded
+                {
+                    pbo_disregarded_gain_amount += line.capital_gain_amount;
+                    line.gain_disregarded_amount = line.capital_gain_amount;
+                } else {
+                    gain = line.capital_gain_amount;
+                    if asset.qualifies_primary_residence_exclusion
+                        && primary_residence_exclusion_remaining_amount > Decimal::ZERO
+                    {
+                        let exclusion = gain.min(primary_residence_exclusion_remaining_amount);
+                        primary_residence_exclusion_used_amount += exclusion;
+                        primary_residence_exclusion_remaining_amount -= exclusion;
+                        line.primary_residence_exclusion_used_amount = exclusion;
+                        gain -= exclusion;
+                    }
+                    gross_capital_gain_amount += gain;
+                }
+            }
+            asset_lines.push(line);
+            asset_gains.push(gain);
         }
 
+        // Without an exemption a fund interest has no base cost to set against its value. Its gain
+        // gets its own attributed share so the asset lines and the fund line add up to the total.
+        let retirement_fund_disregarded_amount = self.retirement_fund_disregarded_amount(input);
+        let retirement_fund_gain_amount =
+            if self.rules.retirement_funds.disregarded_for_cgt_on_death {
+                Decimal::ZERO
+            } else {
+                Self::retirement_fund_value_amount(input)
+            };
+        gross_capital_gain_amount += retirement_fund_gain_amount;
+
         let annual_exclusion_used_amount = match input.taxpayer_class {
             TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => gross_capital_gain_amount
                 .min(
@@ -74,56 +334,278 @@ impl JurisdictionScenarioCalculator {
                         .cgt_on_death
                         .annual_exclusion_in_year_of_death_amount,
                 ),
-            TaxPayerClass::Company | TaxPayerClass::Trust => 0.0,
+            TaxPayerClass::Company | TaxPayerClass::Trust => Decimal::ZERO,
         };
 
         let inclusion_rate = self
             .rules
             .cgt_on_death
             .inclusion_rate_for(input.taxpayer_class);
-        let taxable_capital_gain_in_income_amount =
-            (gross_capital_gain_amount - annual_exclusion_used_amount).max(0.0) * inclusion_rate;
-        let tax_payable_amount = taxable_capital_gain_in_income_amount * marginal_income_tax_rate;
+        let rounding = &self.rules.rounding;
+        let taxable_capital_gain_in_income_amount = rounding.round_return_amount(
+            (gross_capital_gain_amount - annual_exclusion_used_amount).max(Decimal::ZERO)
+                * inclusion_rate,
+        );
+        let income_tax = calculate_income_tax_on_death(
+            &self.rules.income_tax,
+            rounding,
+            input,
+            taxable_capital_gain_in_income_amount,
+        )?;
+        let tax_payable_amount = match input.marginal_income_tax_rate {
+            Some(rate) => rounding.round_return_amount(
+                taxable_capital_gain_in_income_amount * Self::clamp_rate(rate),
+            ),
+            None => income_tax.tax_on_capital_gain_amount,
+        };
 
-        CapitalGainsTaxBreakdown {
+        // The annual exclusion and the tax are shared across assets in proportion to their gains.
+        let mut retirement_fund_cgt_attributed_amount = Decimal::ZERO;
+        if gross_capital_gain_amount > Decimal::ZERO {
+            for (line, gain) in asset_lines.iter_mut().zip(asset_gains) {
+                let share = gain / gross_capital_gain_amount;
+                line.annual_exclusion_used_amount = self
+                    .reporting_currency
+                    .round_amount(annual_exclusion_used_amount * share);
+                line.cgt_attributed_amount = self
+                    .reporting_currency
+                    .round_amount(tax_payable_amount * share);
+            }
+            retirement_fund_cgt_attributed_amount = self.reporting_currency.round_amount(
+                tax_payable_amount * retirement_fund_gain_amount / gross_capital_gain_amount,
+            );
+        }
+
+        let breakdown = CapitalGainsTaxBreakdown {
+            deemed_disposal_applies: true,
             gross_capital_gain_amount,
             primary_residence_exclusion_used_amount,
+            spousal_rollover_gain_amount,
+            spouse_inherited_base_cost_amount,
+            pbo_disregarded_gain_amount,
+            retirement_fund_disregarded_amount,
+            retirement_fund_gain_amount,
+            retirement_fund_cgt_attributed_amount,
             annual_exclusion_used_amount,
             inclusion_rate,
             taxable_capital_gain_in_income_amount,
             tax_payable_amount,
+            stepped_up_basis_total_amount: Decimal::ZERO,
+            stepped_up_basis: Vec::new(),
+        };
+        Ok((breakdown, asset_lines, income_tax))
+    }
+
+    fn classify_life_policy(&self, policy: &LifeInsurancePolicy) -> LifePolicyLine {
+        let sum_assured_amount = policy.sum_assured_amount.max(Decimal::ZERO);
+        let deceased_paid_premiums = policy.premiums_paid_by == PolicyParty::Deceased;
+        let treatment = if policy.life_insured != PolicyParty::Deceased {
+            LifePolicyTreatment::NotOnDeceasedLife
+        } else {
+            match self.rules.estate_duty.life_policy_inclusion {
+                LifePolicyInclusion::DeemedPropertyWithExemptions => {
+                    let third_party_owned = policy.owner != PolicyParty::Deceased
+                        && !deceased_paid_premiums
+                        && !matches!(
+                            policy.beneficiary,
+                            PolicyParty::Estate | PolicyParty::SurvivingSpouse
+                        )
+                        && policy.beneficiary == policy.owner;
+                    if policy.held_under_buy_and_sell_agreement && !deceased_paid_premiums {
+                        LifePolicyTreatment::ExemptBuyAndSell
+                    } else if third_party_owned {
+                        LifePolicyTreatment::ExemptThirdPartyOwned
+                    } else {
+                        LifePolicyTreatment::DeemedProperty
+                    }
+                }
+                LifePolicyInclusion::IncidentsOfOwnership => {
+                    if policy.beneficiary == PolicyParty::Estate
+                        || policy.owner == PolicyParty::Deceased
+                    {
+                        LifePolicyTreatment::DeemedProperty
+                    } else if policy.held_under_buy_and_sell_agreement {
+                        LifePolicyTreatment::ExemptBuyAndSell
+                    } else {
+                        LifePolicyTreatment::ExemptThirdPartyOwned
+                    }
+                }
+            }
+        };
+
+        let included_in_estate_amount = if treatment == LifePolicyTreatment::DeemedProperty {
+            sum_assured_amount
+        } else {
+            Decimal::ZERO
+        };
+        let spousal_deduction_amount = if self.rules.estate_duty.spouse_deduction_unlimited
+            && policy.beneficiary == PolicyParty::SurvivingSpouse
+        {
+            included_in_estate_amount
+        } else {
+            Decimal::ZERO
+        };
+
+        LifePolicyLine {
+            name: policy.name.clone(),
+            treatment,
+            sum_assured_amount,
+            included_in_estate_amount,
+            spousal_deduction_amount,
+            liquidity_to_estate_amount: if policy.payable_to_estate_on_death() {
+                sum_assured_amount
+            } else {
+                Decimal::ZERO
+            },
+        }
+    }
+
+    fn classify_retirement_fund(&self, fund: &RetirementFundInterest) -> RetirementFundLine {
+        let rule = &self.rules.retirement_funds;
+        let value_amount = fund.value_amount.max(Decimal::ZERO);
+        let is_living_annuity = fund.fund_type == RetirementFundType::LivingAnnuity;
+        let benefit_in_estate = if is_living_annuity {
+            rule.living_annuity_in_estate
+        } else {
+            rule.fund_benefits_in_estate
+        };
+
+        let included_in_estate_amount = if benefit_in_estate {
+            value_amount
+        } else if rule.disallowed_contributions_in_estate {
+            fund.disallowed_contributions_amount
+                .max(Decimal::ZERO)
+                .min(value_amount)
+        } else {
+            Decimal::ZERO
+        };
+        let spousal_deduction_amount = if self.rules.estate_duty.spouse_deduction_unlimited
+            && fund.nominated_recipient == RetirementBenefitRecipient::SurvivingSpouse
+        {
+            included_in_estate_amount
+        } else {
+            Decimal::ZERO
+        };
+        let paid_to_estate = fund.nominated_recipient == RetirementBenefitRecipient::Estate
+            && (is_living_annuity || !rule.fund_benefits_allocated_by_trustees);
+
+        RetirementFundLine {
+            name: fund.name.clone(),
+            fund_type: fund.fund_type,
+            value_amount,
+            included_in_estate_amount,
+            spousal_deduction_amount,
+            liquidity_to_estate_amount: if paid_to_estate {
+                value_amount
+            } else {
+                Decimal::ZERO
+            },
+        }
+    }
+
+    fn calculate_state_estate_tax(
+        &self,
+        rule: &StateEstateTaxRule,
+        state_taxable_estate_amount: Decimal,
+    ) -> StateEstateTaxBreakdown {
+        let rounding = &self.rules.rounding;
+        let state_taxable_estate_amount = rounding.round_return_amount(state_taxable_estate_amount);
+        let tentative_tax_amount =
+            graduated_tax_amount(&rule.rate_schedule, state_taxable_estate_amount);
+        let full_credit_amount = graduated_tax_amount(
+            &rule.rate_schedule,
+            rule.exemption_amount.min(state_taxable_estate_amount),
+        );
+        let credit_amount = match rule.credit_phase_out_ceiling_ratio {
+            Some(ceiling_ratio)
+                if ceiling_ratio > Decimal::ONE
+                    && rule.exemption_amount > Decimal::ZERO
+                    && state_taxable_estate_amount > rule.exemption_amount =>
+            {
+                let ceiling_amount = rule.exemption_amount * ceiling_ratio;
+                let remaining_share = ((ceiling_amount - state_taxable_estate_amount)
+                    / (ceiling_amount - rule.exemption_amount))
+                    .clamp(Decimal::ZERO, Decimal::ONE);
+                full_credit_amount * remaining_share
+            }
+            _ => full_credit_amount,
+        };
+
+        StateEstateTaxBreakdown {
+            state_taxable_estate_amount,
+            exemption_amount: rule.exemption_amount,
+            tentative_tax_amount,
+            credit_amount,
+            tax_payable_amount: rounding
+                .round_return_amount((tentative_tax_amount - credit_amount).max(Decimal::ZERO)),
         }
     }
 
     fn calculate_estate_duty(
         &self,
         input: &EstateScenarioInput,
-        cgt_tax_payable_amount: f64,
-    ) -> EstateDutyBreakdown {
-        let gross_estate_for_transfer_tax_amount = input
-            .assets
+        marital_property: &MaritalPropertyBreakdown,
+        cgt_tax_payable_amount: Decimal,
+        final_income_tax_amount: Decimal,
+    ) -> (EstateDutyBreakdown, Option<StateEstateTaxBreakdown>) {
+        let accrual_claim_asset_amount = marital_property.accrual_claim_by_estate_amount;
+        let accrual_claim_deduction_amount = marital_property.accrual_claim_against_estate_amount;
+        let life_policies = input
+            .life_policies
             .iter()
-            .filter(|asset| {
-                Self::asset_in_estate_duty_scope(
-                    input,
-                    asset.included_in_estate_duty,
-                    asset.situs_in_jurisdiction,
-                )
-            })
-            .map(|asset| asset.market_value_amount.max(0.0))
-            .sum::<f64>();
+            .map(|policy| self.classify_life_policy(policy))
+            .collect::<Vec<_>>();
+        let life_policy_deemed_property_amount = life_policies
+            .iter()
+            .map(|line| line.included_in_estate_amount)
+            .sum::<Decimal>();
+        let retirement_funds = input
+            .retirement_funds
+            .iter()
+            .map(|fund| self.classify_retirement_fund(fund))
+            .collect::<Vec<_>>();
+        let retirement_fund_included_amount = retirement_funds
+            .iter()
+            .map(|line| line.included_in_estate_amount)
+            .sum::<Decimal>();
+        let gross_estate_for_transfer_tax_amount = accrual_claim_asset_amount
+            + life_policy_deemed_property_amount
+            + retirement_fund_included_amount
+            + input
+                .assets
+                .iter()
+                .filter(|asset| {
+                    Self::asset_in_estate_duty_scope(
+                        input,
+                        asset.included_in_estate_duty,
+                        asset.is_situated_in(input.jurisdiction),
+                    )
+                })
+                .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+                .sum::<Decimal>();
 
         let gross_estate_for_executor_fee_amount = input
             .assets
             .iter()
-            .map(|asset| asset.market_value_amount.max(0.0))
-            .sum::<f64>();
+            .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+            .sum::<Decimal>()
+            + life_policies
+                .iter()
+                .map(|line| line.liquidity_to_estate_amount)
+                .sum::<Decimal>()
+            + retirement_funds
+                .iter()
+                .map(|line| line.liquidity_to_estate_amount)
+                .sum::<Decimal>();
 
-        let executor_fee_amount = input.explicit_executor_fee_amount.unwrap_or_else(|| {
-            let fee_rate = Self::clamp_rate(input.executor_fee_rate);
-            let vat_rate = Self::clamp_rate(input.vat_rate);
-            gross_estate_for_executor_fee_amount * fee_rate * (1.0 + vat_rate)
-        });
+        let executor_fee = calculate_executor_fee(
+            self.rules.executor_fee.as_ref(),
+            input,
+            self.reporting_currency,
+            vat_rate(&self.rules, input),
+            gross_estate_for_executor_fee_amount,
+        );
+        let executor_fee_amount = executor_fee.executor_fee_amount;
 
         let spousal_deduction_amount = if self.rules.estate_duty.spouse_deduction_unlimited {
             input
@@ -134,13 +616,21 @@ impl JurisdictionScenarioCalculator {
                         && Self::asset_in_estate_duty_scope(
                             input,
                             asset.included_in_estate_duty,
-                            asset.situs_in_jurisdiction,
+                            asset.is_situated_in(input.jurisdiction),
                         )
                 })
-                .map(|asset| asset.market_value_amount.max(0.0))
-                .sum::<f64>()
+                .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+                .sum::<Decimal>()
+                + life_policies
+                    .iter()
+                    .map(|line| line.spousal_deduction_amount)
+                    .sum::<Decimal>()
+                + retirement_funds
+                    .iter()
+                    .map(|line| line.spousal_deduction_amount)
+                    .sum::<Decimal>()
         } else {
-            0.0
+            Decimal::ZERO
         };
 
         let pbo_deduction_amount = input
@@ -151,70 +641,204 @@ impl JurisdictionScenarioCalculator {
                     && Self::asset_in_estate_duty_scope(
                         input,
                         asset.included_in_estate_duty,
-                        asset.situs_in_jurisdiction,
+                        asset.is_situated_in(input.jurisdiction),
                     )
             })
-            .map(|asset| asset.market_value_amount.max(0.0))
-            .sum::<f64>();
-
-        let total_allowable_deductions_amount = input.debts_and_loans_amount.max(0.0)
-            + input.funeral_costs_amount.max(0.0)
-            + input.administration_costs_amount.max(0.0)
-            + input.masters_office_fees_amount.max(0.0)
-            + input.conveyancing_costs_amount.max(0.0)
-            + input.other_settlement_costs_amount.max(0.0)
-            + input.final_income_tax_due_amount.max(0.0)
-            + input.ongoing_estate_income_tax_provision_amount.max(0.0)
-            + cgt_tax_payable_amount
-            + executor_fee_amount.max(0.0)
-            + spousal_deduction_amount
-            + pbo_deduction_amount
-            + input
-                .additional_allowable_estate_transfer_tax_deductions_amount
-                .max(0.0);
+            .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+            .sum::<Decimal>();
+
+        let deductions_before_state_estate_tax_amount =
+            input.debts_and_loans_amount.max(Decimal::ZERO)
+                + input.funeral_costs_amount.max(Decimal::ZERO)
+                + input.administration_costs_amount.max(Decimal::ZERO)
+                + input.masters_office_fees_amount.max(Decimal::ZERO)
+                + input.conveyancing_costs_amount.max(Decimal::ZERO)
+                + input.other_settlement_costs_amount.max(Decimal::ZERO)
+                + final_income_tax_amount
+                + input
+                    .ongoing_estate_income_tax_provision_amount
+                    .max(Decimal::ZERO)
+                + cgt_tax_payable_amount
+                + executor_fee_amount.max(Decimal::ZERO)
+                + spousal_deduction_amount
+                + pbo_deduction_amount
+                + accrual_claim_deduction_amount
+                + input
+                    .additional_allowable_estate_transfer_tax_deductions_amount
+                    .max(Decimal::ZERO);
 
-        let net_estate_before_exemption_amount =
-            (gross_estate_for_transfer_tax_amount - total_allowable_deductions_amount).max(0.0);
-        let exemption_used_amount = self.rules.estate_duty.exemption_amount
-            + input.ported_estate_tax_exemption_amount.max(0.0);
+        // State estate tax is levied on the estate before its own deduction; the federal
+        // computation then deducts it (26 U.S.C. section 2058).
+        let state_estate_tax = self.rules.state_estate_tax.as_ref().map(|rule| {
+            self.calculate_state_estate_tax(
+                rule,
+                (gross_estate_for_transfer_tax_amount - deductions_before_state_estate_tax_amount)
+                    .max(Decimal::ZERO),
+            )
+        });
+        let state_estate_tax_deduction_amount = match (
+            self.rules.state_estate_tax.as_ref(),
+            state_estate_tax.as_ref(),
+        ) {
+            (Some(rule), Some(breakdown)) if rule.deductible_for_federal_estate_tax => {
+                breakdown.tax_payable_amount
+            }
+            _ => Decimal::ZERO,
+        };
+        let total_allowable_deductions_amount =
+            deductions_before_state_estate_tax_amount + state_estate_tax_deduction_amount;
+
+        let net_estate_before_exemption_amount = self.rules.rounding.round_return_amount(
+            (gross_estate_for_transfer_tax_amount - total_allowable_deductions_amount)
+                .max(Decimal::ZERO),
+        );
+        let exemption_used_amount = self
+            .rules
+            .estate_duty
+            .exemption_amount_for(input.residency_status)
+            + input.ported_estate_tax_exemption_amount.max(Decimal::ZERO);
         let dutiable_estate_after_exemption_amount =
-            (net_estate_before_exemption_amount - exemption_used_amount).max(0.0);
-
-        let primary_band = dutiable_estate_after_exemption_amount
-            .min(self.rules.estate_duty.primary_rate_cap_amount);
-        let secondary_band = (dutiable_estate_after_exemption_amount
-            - self.rules.estate_duty.primary_rate_cap_amount)
-            .max(0.0);
-        let tax_payable_amount = primary_band * self.rules.estate_duty.primary_rate
-            + secondary_band * self.rules.estate_duty.secondary_rate;
-
-        EstateDutyBreakdown {
-            gross_estate_for_transfer_tax_amount,
-            executor_fee_amount,
-            spousal_deduction_amount,
-            pbo_deduction_amount,
-            total_allowable_deductions_amount,
-            exemption_used_amount,
-            dutiable_estate_after_exemption_amount,
-            tax_payable_amount,
+            (net_estate_before_exemption_amount - exemption_used_amount).max(Decimal::ZERO);
+
+        // Under a unified credit the schedule runs over the whole net estate and the tax on the
+        // exemption is credited back; otherwise the exemption reduces the base first.
+        let estate_duty_rule = &self.rules.estate_duty;
+        let (bracket_taxes, unified_credit_amount) = if estate_duty_rule.exemption_as_unified_credit
+        {
+            (
+                graduated_tax_lines(
+                    &estate_duty_rule.rate_schedule,
+                    net_estate_before_exemption_amount,
+                ),
+                estate_duty_rule
+                    .tax_on_amount(exemption_used_amount.min(net_estate_before_exemption_amount)),
+            )
+        } else {
+            (
+                graduated_tax_lines(
+                    &estate_duty_rule.rate_schedule,
+                    dutiable_estate_after_exemption_amount,
+                ),
+                Decimal::ZERO,
+            )
+        };
+        let tax_payable_amount = self.rules.rounding.round_return_amount(
+            (bracket_taxes
+                .iter()
+                .map(|line| line.tax_amount)
+                .sum::<Decimal>()
+                - unified_credit_amount)
+                .max(Decimal::ZERO),
+        );
+
+        (
+            EstateDutyBreakdown {
+                gross_estate_for_transfer_tax_amount,
+                accrual_claim_asset_amount,
+                life_policy_deemed_property_amount,
+                life_policies,
+                retirement_fund_included_amount,
+                retirement_funds,
+                executor_fee,
+                executor_fee_amount,
+                spousal_deduction_amount,
+                pbo_deduction_amount,
+                accrual_claim_deduction_amount,
+                state_estate_tax_deduction_amount,
+                total_allowable_deductions_amount,
+                exemption_used_amount,
+                dutiable_estate_after_exemption_amount,
+                bracket_taxes,
+                unified_credit_amount,
+                tax_payable_amount,
+            },
+            state_estate_tax,
+        )
+    }
+
+    // Transfer taxes are spread at the estate's average rate through the bands: each asset carries
+    // the share of the tax that its net contribution bears to the net estate.
+    fn attribute_estate_taxes(
+        &self,
+        input: &EstateScenarioInput,
+        asset_lines: &mut [AssetTaxAttributionLine],
+        estate_duty: &EstateDutyBreakdown,
+        state_estate_tax: Option<&StateEstateTaxBreakdown>,
+    ) {
+        for (line, asset) in asset_lines.iter_mut().zip(&input.assets) {
+            if Self::asset_in_estate_duty_scope(
+                input,
+                asset.included_in_estate_duty,
+                asset.is_situated_in(input.jurisdiction),
+            ) {
+                line.included_in_estate_amount = line.market_value_amount;
+                if (asset.bequeathed_to_surviving_spouse
+                    && self.rules.estate_duty.spouse_deduction_unlimited)
+                    || asset.bequeathed_to_pbo
+                {
+                    line.deductions_attributable_amount = line.market_value_amount;
+                }
+            }
+            // CGT on death is an estate liability, deducted against the asset that gave rise to it.
+            line.deductions_attributable_amount += line.cgt_attributed_amount;
+        }
+
+        let net_contribution = |line: &AssetTaxAttributionLine| {
+            (line.included_in_estate_amount - line.deductions_attributable_amount)
+                .max(Decimal::ZERO)
+        };
+        let net_estate_contributions_amount =
+            asset_lines.iter().map(net_contribution).sum::<Decimal>()
+                + estate_duty.accrual_claim_asset_amount
+                + estate_duty
+                    .life_policies
+                    .iter()
+                    .map(|line| line.included_in_estate_amount - line.spousal_deduction_amount)
+                    .sum::<Decimal>()
+                + estate_duty
+                    .retirement_funds
+                    .iter()
+                    .map(|line| line.included_in_estate_amount - line.spousal_deduction_amount)
+                    .sum::<Decimal>();
+        let state_estate_tax_amount =
+            state_estate_tax.map_or(Decimal::ZERO, |breakdown| breakdown.tax_payable_amount);
+
+        for line in asset_lines.iter_mut() {
+            if net_estate_contributions_amount > Decimal::ZERO {
+                let share = net_contribution(line) / net_estate_contributions_amount;
+                line.estate_duty_attributed_amount = self
+                    .reporting_currency
+                    .round_amount(estate_duty.tax_payable_amount * share);
+                line.state_estate_tax_attributed_amount = self
+                    .reporting_currency
+                    .round_amount(state_estate_tax_amount * share);
+            }
+            line.total_tax_attributed_amount = line.cgt_attributed_amount
+                + line.estate_duty_attributed_amount
+                + line.state_estate_tax_attributed_amount;
         }
     }
 
     fn calculate_combined_tax(
         &self,
         input: &EstateScenarioInput,
-        cgt_tax_payable_amount: f64,
-        estate_duty_tax_payable_amount: f64,
+        cgt_tax_payable_amount: Decimal,
+        final_income_tax_amount: Decimal,
+        estate_duty_tax_payable_amount: Decimal,
+        state_estate_tax_amount: Decimal,
     ) -> CombinedTaxLiability {
-        let final_income_tax_amount = input.final_income_tax_due_amount.max(0.0);
-        let ongoing_income_tax_amount = input.ongoing_estate_income_tax_provision_amount.max(0.0);
+        let ongoing_income_tax_amount = input
+            .ongoing_estate_income_tax_provision_amount
+            .max(Decimal::ZERO);
         let total_tax_liability_amount = estate_duty_tax_payable_amount
+            + state_estate_tax_amount
             + cgt_tax_payable_amount
             + final_income_tax_amount
             + ongoing_income_tax_amount;
 
         CombinedTaxLiability {
             estate_transfer_tax_amount: estate_duty_tax_payable_amount,
+            state_estate_tax_amount,
             cgt_on_death_amount: cgt_tax_payable_amount,
             final_income_tax_amount,
             ongoing_estate_income_tax_provision_amount: ongoing_income_tax_amount,
@@ -226,40 +850,64 @@ impl JurisdictionScenarioCalculator {
         &self,
         input: &EstateScenarioInput,
         combined_tax: &CombinedTaxLiability,
-        executor_fee_amount: f64,
+        executor_fee_amount: Decimal,
+        accrual_claim_payable_amount: Decimal,
     ) -> LiquidityGapOutput {
         let liquid_assets_in_estate_amount = input
             .assets
             .iter()
             .filter(|asset| asset.is_liquid)
-            .map(|asset| asset.market_value_amount.max(0.0))
-            .sum::<f64>();
+            .map(|asset| asset.market_value_amount.max(Decimal::ZERO))
+            .sum::<Decimal>();
 
         let immediate_cash_requirements_amount = combined_tax.total_tax_liability_amount
-            + input.debts_and_loans_amount.max(0.0)
-            + input.funeral_costs_amount.max(0.0)
-            + input.administration_costs_amount.max(0.0)
-            + executor_fee_amount.max(0.0)
-            + input.masters_office_fees_amount.max(0.0)
-            + input.conveyancing_costs_amount.max(0.0)
-            + input.other_settlement_costs_amount.max(0.0);
-
-        let external_liquidity_proceeds_amount = input.external_liquidity_proceeds_amount.max(0.0);
-        let cash_reserve_amount = input.cash_reserve_amount.max(0.0);
+            + input.debts_and_loans_amount.max(Decimal::ZERO)
+            + input.funeral_costs_amount.max(Decimal::ZERO)
+            + input.administration_costs_amount.max(Decimal::ZERO)
+            + executor_fee_amount.max(Decimal::ZERO)
+            + accrual_claim_payable_amount
+            + input.masters_office_fees_amount.max(Decimal::ZERO)
+            + input.conveyancing_costs_amount.max(Decimal::ZERO)
+            + input.other_settlement_costs_amount.max(Decimal::ZERO);
+
+        let life_policy_proceeds_to_estate_amount = input
+            .life_policies
+            .iter()
+            .filter(|policy| policy.payable_to_estate_on_death())
+            .map(|policy| policy.sum_assured_amount.max(Decimal::ZERO))
+            .sum::<Decimal>();
+        let retirement_fund_proceeds_to_estate_amount = input
+            .retirement_funds
+            .iter()
+            .map(|fund| {
+                self.classify_retirement_fund(fund)
+                    .liquidity_to_estate_amount
+            })
+            .sum::<Decimal>();
+        let external_liquidity_proceeds_amount =
+            input.external_liquidity_proceeds_amount.max(Decimal::ZERO);
+        let cash_reserve_amount = input.cash_reserve_amount.max(Decimal::ZERO);
         let total_available_liquidity_amount = liquid_assets_in_estate_amount
+            + life_policy_proceeds_to_estate_amount
+            + retirement_fund_proceeds_to_estate_amount
             + external_liquidity_proceeds_amount
             + cash_reserve_amount;
-        let liquidity_gap_amount =
-            (immediate_cash_requirements_amount - total_available_liquidity_amount).max(0.0);
-        let liquidity_surplus_amount =
-            (total_available_liquidity_amount - immediate_cash_requirements_amount).max(0.0);
+        let liquidity_gap_amount = (immediate_cash_requirements_amount
+            - total_available_liquidity_amount)
+            .max(Decimal::ZERO);
+        let liquidity_surplus_amount = (total_available_liquidity_amount
+            - immediate_cash_requirements_amount)
+            .max(Decimal::ZERO);
 
         LiquidityGapOutput {
             liquid_assets_in_estate_amount,
+            life_policy_proceeds_to_estate_amount,
+            retirement_fund_proceeds_to_estate_amount,
             external_liquidity_proceeds_amount,
             cash_reserve_amount,
             total_available_liquidity_amount,
-            executor_fee_amount: executor_fee_amount.max(0.0),
+            executor_fee_amount: executor_fee_amount.max(Decimal::ZERO),
+            accrual_claim_payable_amount,
             immediate_cash_requirements_amount,
             liquidity_gap_amount,
             liquidity_surplus_amount,
@@ -268,29 +916,73 @@ impl JurisdictionScenarioCalculator {
 }
 
 impl ScenarioCalculator for JurisdictionScenarioCalculator {
-    fn calculate(&self, input: &EstateScenarioInput) -> ScenarioResult {
-        let cgt = self.calculate_cgt(input);
-        let estate_duty = self.calculate_estate_duty(input, cgt.tax_payable_amount);
+    fn calculate(&self, input: &EstateScenarioInput) -> Result<ScenarioResult, EngineError> {
+        let (mut reporting_input, currency) = convert_to_reporting_currency(input)?;
+        reporting_input.tax_year = scenario_tax_year(input);
+        let (deceased_input, marital_property) =
+            Self::apply_marital_property_regime(&reporting_input);
+        let input = &deceased_input;
+        let (cgt, mut assets, income_tax) = self.calculate_cgt(input)?;
+        let final_income_tax_amount = input
+            .final_income_tax_due_amount
+            .unwrap_or(income_tax.tax_on_income_excluding_capital_gain_amount)
+            .max(Decimal::ZERO);
+        let (estate_duty, state_estate_tax) = self.calculate_estate_duty(
+            input,
+            &marital_property,
+            cgt.tax_payable_amount,
+            final_income_tax_amount,
+        );
         let combined_tax = self.calculate_combined_tax(
             input,
             cgt.tax_payable_amount,
+            final_income_tax_amount,
             estate_duty.tax_payable_amount,
+            state_estate_tax
+                .as_ref()
+                .map(|breakdown| breakdown.tax_payable_amount)
+                .unwrap_or(Decimal::ZERO),
+        );
+        self.attribute_estate_taxes(input, &mut assets, &estate_duty, state_estate_tax.as_ref());
+        let liquidity = self.calculate_liquidity(
+            input,
+            &combined_tax,
+            estate_duty.executor_fee_amount,
+            marital_property.accrual_claim_against_estate_amount,
+        );
+        let distribution = calculate_distribution(
+            input,
+            self.rules.estate_duty.default_tax_apportionment,
+            &marital_property,
+            &estate_duty,
+            &combined_tax,
         );
-        let liquidity =
-            self.calculate_liquidity(input, &combined_tax, estate_duty.executor_fee_amount);
 
-        ScenarioResult {
+        let defaulted_fields =
+            defaulted_scenario_fields(&self.rules, input, &estate_duty.executor_fee, &income_tax);
+        let mut result = ScenarioResult {
+            marital_property,
+            income_tax,
             cgt,
             estate_duty,
+            state_estate_tax,
             combined_tax,
             liquidity,
+            distribution,
+            assets,
+            currency,
+            explain: None,
+            defaulted_fields,
+        };
+        if input.explain {
+            result.explain = Some(explain_scenario(&self.version, &self.rules, input, &result));
         }
+        Ok(result)
     }
 }
 
 pub fn calculate_combined_tax_and_liquidity(
     input: &EstateScenarioInput,
-) -> Result<ScenarioResult, TaxRuleSelectionError> {
-    let calculator = JurisdictionScenarioCalculator::new(input)?;
-    Ok(calculator.calculate(input))
+) -> Result<ScenarioResult, EngineError> {
+    JurisdictionScenarioCalculator::new(input)?.calculate(input)
 }
@@ -1,4 +1,6 @@
 use crate::core::domain::models::ScenarioResult;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LiquidityRiskBand {
@@ -13,19 +15,23 @@ pub struct ScenarioScore {
     pub tax_burden_ratio: f64,
     pub liquidity_cover_ratio: f64,
     pub liquidity_risk_band: LiquidityRiskBand,
+    pub net_inheritance_ratio: f64,
+    pub bequest_shortfall_ratio: f64,
     pub composite_score: f64,
 }
 
+// Scores are dimensionless, so they are computed in floating point from the exact amounts.
+fn as_f64(amount: Decimal) -> f64 {
+    amount.to_f64().unwrap_or(0.0)
+}
+
 pub fn score_scenario(result: &ScenarioResult) -> ScenarioScore {
-    let gross_estate = result
-        .estate_duty
-        .gross_estate_for_transfer_tax_amount
-        .max(1.0);
-    let required_liquidity = result.liquidity.immediate_cash_requirements_amount.max(1.0);
+    let gross_estate = as_f64(result.estate_duty.gross_estate_for_transfer_tax_amount).max(1.0);
+    let required_liquidity = as_f64(result.liquidity.immediate_cash_requirements_amount).max(1.0);
 
-    let tax_burden_ratio = result.combined_tax.total_tax_liability_amount / gross_estate;
+    let tax_burden_ratio = as_f64(result.combined_tax.total_tax_liability_amount) / gross_estate;
     let liquidity_cover_ratio =
-        result.liquidity.total_available_liquidity_amount / required_liquidity;
+        as_f64(result.liquidity.total_available_liquidity_amount) / required_liquidity;
 
     let liquidity_risk_band = if liquidity_cover_ratio >= 1.20 {
         LiquidityRiskBand::Low
@@ -37,17 +43,41 @@ pub fn score_scenario(result: &ScenarioResult) -> ScenarioScore {
         LiquidityRiskBand::Critical
     };
 
+    let distribution = &result.distribution;
+    let net_inheritance_ratio = as_f64(distribution.total_net_inheritance_amount)
+        / as_f64(distribution.distributable_assets_amount).max(1.0);
+    let bequeathed_amount =
+        as_f64(distribution.specific_bequests_amount + distribution.legacies_amount);
+    let bequest_shortfall_ratio = if bequeathed_amount > 0.0 {
+        as_f64(distribution.abatement_amount) / bequeathed_amount
+    } else {
+        0.0
+    };
+
     let tax_penalty = tax_burden_ratio * 100.0;
     let liquidity_penalty = if liquidity_cover_ratio >= 1.0 {
         0.0
     } else {
         (1.0 - liquidity_cover_ratio) * 200.0
     };
+    // What does not reach the heirs is penalised at half the tax weight; without named heirs the
+    // ratio is always zero, so it is left out.
+    let inheritance_penalty = if distribution.heirs.is_empty() {
+        0.0
+    } else {
+        (1.0 - net_inheritance_ratio).max(0.0) * 50.0
+    };
 
     ScenarioScore {
         tax_burden_ratio,
         liquidity_cover_ratio,
         liquidity_risk_band,
-        composite_score: tax_penalty + liquidity_penalty,
+        net_inheritance_ratio,
+        bequest_shortfall_ratio,
+        // Bequests the estate cannot honour in full are penalised like a liquidity shortfall.
+        composite_score: tax_penalty
+            + liquidity_penalty
+            + inheritance_penalty
+            + bequest_shortfall_ratio * 100.0,
     }
 }
@@ -0,0 +1,570 @@
+use crate::core::domain::models::{
+    Beneficiary, CalendarDate, DonationsTaxInput, EstateAsset, EstateScenarioInput, HeirCategory,
+    LifeInsurancePolicy, LifetimeDonation, MaritalPropertyRegime, PolicyParty, ScenarioResult,
+};
+use crate::core::engine::currency::convert_to_reporting_currency;
+use crate::core::engine::donations::{annual_exemption_for, calculate_donations_tax};
+use crate::core::engine::income_tax::calculate_income_tax_on_death;
+use crate::core::engine::scenario::{calculate_combined_tax_and_liquidity, scenario_tax_year};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{
+    reporting_currency_for, tax_rules_for_scenario, tax_year_start_month, Currency, Jurisdiction,
+    JurisdictionTaxRuleSet, TaxPayerClass,
+};
+use rust_decimal::Decimal;
+
+// Each candidate pulls one planning lever on the base scenario. Amounts in descriptions are in
+// the jurisdiction's reporting currency.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyLever {
+    ResidueToSpouse,
+    PboBequest,
+    LifeCover,
+    TrustTransfer,
+    AnnualDonations,
+}
+
+#[derive(Debug, Clone)]
+pub struct StrategyChange {
+    pub lever: StrategyLever,
+    // Scenario input path the change touches, such as `assets[2].bequeathed_to_pbo`. Paths refer
+    // to the base scenario, so a removed asset keeps its original index.
+    pub field: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CandidateStrategy {
+    pub name: &'static str,
+    pub description: String,
+    pub changes: Vec<StrategyChange>,
+    pub input: EstateScenarioInput,
+}
+
+impl CandidateStrategy {
+    pub fn levers(&self) -> Vec<StrategyLever> {
+        let mut levers = Vec::new();
+        for change in &self.changes {
+            if !levers.contains(&change.lever) {
+                levers.push(change.lever);
+            }
+        }
+        levers
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StrategyGeneratorOptions {
+    // Years of annual donations made before death; 0 leaves that lever out.
+    pub annual_donation_years: u8,
+    // Date lifetime transfers are made; `None` uses the first day of the scenario's tax year.
+    pub planning_date: Option<CalendarDate>,
+}
+
+impl Default for StrategyGeneratorOptions {
+    fn default() -> Self {
+        Self {
+            annual_donation_years: 5,
+            planning_date: None,
+        }
+    }
+}
+
+fn amount_text(currency: Currency, amount: Decimal) -> String {
+    format!("{} {}", currency.code(), currency.round_amount(amount))
+}
+
+fn specifically_bequeathed(input: &EstateScenarioInput, asset: &EstateAsset) -> bool {
+    input.beneficiaries.iter().any(|beneficiary| {
+        beneficiary
+            .specific_bequest_asset_names
+            .iter()
+            .any(|name| name == &asset.name)
+    })
+}
+
+// Assets that pass under the residue and are not yet left to a spouse or PBO.
+fn in_residue(input: &EstateScenarioInput, asset: &EstateAsset) -> bool {
+    !asset.bequeathed_to_surviving_spouse
+        && !asset.bequeathed_to_pbo
+        && !specifically_bequeathed(input, asset)
+}
+
+// `reporting` is the base scenario converted to the reporting currency, with assets in the same
+// order as `base`.
+fn residue_to_spouse(
+    base: &EstateScenarioInput,
+    reporting: &EstateScenarioInput,
+    currency: Currency,
+) -> Option<CandidateStrategy> {
+    if base.marital_property.regime == MaritalPropertyRegime::NotMarried {
+        return None;
+    }
+
+    let lever = StrategyLever::ResidueToSpouse;
+    let mut input = base.clone();
+    let mut changes = Vec::new();
+    let mut moved_amount = Decimal::ZERO;
+    for (index, asset) in input.assets.iter_mut().enumerate() {
+        if !asset.included_in_estate_duty || !in_residue(base, asset) {
+            continue;
+        }
+        asset.bequeathed_to_surviving_spouse = true;
+        moved_amount += reporting.assets[index]
+            .market_value_amount
+            .max(Decimal::ZERO);
+        changes.push(StrategyChange {
+            lever,
+            field: format!("assets[{index}].bequeathed_to_surviving_spouse"),
+            description: format!("Leave {} to the surviving spouse", asset.name),
+        });
+    }
+
+    let spouse_index = input
+        .beneficiaries
+        .iter()
+        .position(|beneficiary| beneficiary.category == HeirCategory::SurvivingSpouse);
+    for (index, beneficiary) in input.beneficiaries.iter_mut().enumerate() {
+        let residue_share = if Some(index) == spouse_index {
+            Decimal::ONE
+        } else {
+            Decimal::ZERO
+        };
+        if beneficiary.residue_share != residue_share {
+            beneficiary.residue_share = residue_share;
+            changes.push(StrategyChange {
+                lever,
+                field: format!("beneficiaries[{index}].residue_share"),
+                description: format!(
+                    "Set {}'s share of the residue to {}%",
+                    beneficiary.name,
+                    residue_share * Decimal::ONE_HUNDRED
+                ),
+            });
+        }
+    }
+    if spouse_index.is_none() {
+        changes.push(StrategyChange {
+            lever,
+            field: format!("beneficiaries[{}]", input.beneficiaries.len()),
+            description: "Name the surviving spouse as sole heir of the residue".to_string(),
+        });
+        input.beneficiaries.push(Beneficiary {
+            name: "Surviving spouse".to_string(),
+            category: HeirCategory::SurvivingSpouse,
+            specific_bequest_asset_names: Vec::new(),
+            legacy_amount: Decimal::ZERO,
+            residue_share: Decimal::ONE,
+        });
+    }
+
+    if changes.is_empty() {
+        return None;
+    }
+    Some(CandidateStrategy {
+        name: "Residue to surviving spouse",
+        description: format!(
+            "Leave the residue of the estate to the surviving spouse. {} of assets move into the spousal deduction.",
+            amount_text(currency, moved_amount)
+        ),
+        changes,
+        input,
+    })
+}
+
+fn pbo_bequest(
+    base: &EstateScenarioInput,
+    reporting: &EstateScenarioInput,
+    rules: &JurisdictionTaxRuleSet,
+    currency: Currency,
+) -> Option<CandidateStrategy> {
+    // The residue asset with the largest gain, since a PBO bequest can also disregard the gain.
+    let (index, reporting_asset) = reporting
+        .assets
+        .iter()
+        .enumerate()
+        .filter(|(_, asset)| {
+            asset.included_in_estate_duty
+                && !asset.qualifies_primary_residence_exclusion
+                && asset.market_value_amount > Decimal::ZERO
+                && in_residue(base, asset)
+        })
+        .max_by_key(|(_, asset)| asset.raw_capital_gain_amount())?;
+
+    let lever = StrategyLever::PboBequest;
+    let mut input = base.clone();
+    let asset_name = input.assets[index].name.clone();
+    input.assets[index].bequeathed_to_pbo = true;
+    let mut changes = vec![StrategyChange {
+        lever,
+        field: format!("assets[{index}].bequeathed_to_pbo"),
+        description: format!("Bequeath {asset_name} to a public benefit organisation"),
+    }];
+
+    match input
+        .beneficiaries
+        .iter()
+        .position(|beneficiary| beneficiary.category == HeirCategory::PublicBenefitOrganisation)
+    {
+        Some(pbo_index) => {
+            let pbo = &mut input.beneficiaries[pbo_index];
+            changes.push(StrategyChange {
+                lever,
+                field: format!("beneficiaries[{pbo_index}].specific_bequest_asset_names"),
+                description: format!("Add {asset_name} to {}'s specific bequests", pbo.name),
+            });
+            pbo.specific_bequest_asset_names.push(asset_name.clone());
+        }
+        None => {
+            changes.push(StrategyChange {
+                lever,
+                field: format!("beneficiaries[{}]", input.beneficiaries.len()),
+                description: format!("Add a public benefit organisation as heir of {asset_name}"),
+            });
+            input.beneficiaries.push(Beneficiary {
+                name: "Public benefit organisation".to_string(),
+                category: HeirCategory::PublicBenefitOrganisation,
+                specific_bequest_asset_names: vec![asset_name.clone()],
+                legacy_amount: Decimal::ZERO,
+                residue_share: Decimal::ZERO,
+            });
+        }
+    }
+
+    let gain_text = if rules.cgt_on_death.pbo_bequest_gain_disregarded
+        && reporting_asset.included_in_cgt_deemed_disposal
+    {
+        format!(
+            ", and its capital gain of {} is disregarded",
+            amount_text(currency, reporting_asset.raw_capital_gain_amount())
+        )
+    } else {
+        String::new()
+    };
+    Some(CandidateStrategy {
+        name: "Bequest to a PBO",
+        description: format!(
+            "Bequeath {asset_name} ({}) to a public benefit organisation. Its value is deducted from the dutiable estate{gain_text}.",
+            amount_text(currency, reporting_asset.market_value_amount)
+        ),
+        changes,
+        input,
+    })
+}
+
+fn life_cover(
+    base: &EstateScenarioInput,
+    base_result: &ScenarioResult,
+    currency: Currency,
+) -> Result<Option<CandidateStrategy>, EngineError> {
+    let gap_amount = base_result.liquidity.liquidity_gap_amount;
+    if gap_amount <= Decimal::ZERO {
+        return Ok(None);
+    }
+
+    let policy_index = base.life_policies.len();
+    let with_cover = |sum_assured_amount: Decimal| {
+        let mut input = base.clone();
+        input.life_policies.push(LifeInsurancePolicy {
+            name: "Liquidity cover".to_string(),
+            owner: PolicyParty::Deceased,
+            life_insured: PolicyParty::Deceased,
+            beneficiary: PolicyParty::Estate,
+            sum_assured_amount,
+            premiums_paid_by: PolicyParty::Deceased,
+            held_under_buy_and_sell_agreement: false,
+        });
+        input
+    };
+
+    // The policy adds duty and executor fees of its own. A policy of the gap leaves exactly those
+    // costs unpaid, which gives the cost per unit of cover; the cover is then grossed up by it.
+    let cost_rate = calculate_combined_tax_and_liquidity(&with_cover(gap_amount))?
+        .liquidity
+        .liquidity_gap_amount
+        / gap_amount;
+    if cost_rate >= Decimal::ONE {
+        return Ok(None);
+    }
+    let sum_assured_amount = (gap_amount / (Decimal::ONE - cost_rate)).ceil();
+
+    Ok(Some(CandidateStrategy {
+        name: "Life cover for the liquidity gap",
+        description: format!(
+            "Take out life cover of {} on the deceased's life, payable to the estate, to close the liquidity gap of {}.",
+            amount_text(currency, sum_assured_amount),
+            amount_text(currency, gap_amount)
+        ),
+        changes: vec![StrategyChange {
+            lever: StrategyLever::LifeCover,
+            field: format!("life_policies[{policy_index}]"),
+            description: format!(
+                "Add a policy of {} payable to the estate",
+                amount_text(currency, sum_assured_amount)
+            ),
+        }],
+        input: with_cover(sum_assured_amount),
+    }))
+}
+
+fn donation_date(input: &EstateScenarioInput, options: StrategyGeneratorOptions) -> String {
+    if let Some(planning_date) = options.planning_date {
+        return planning_date.to_string();
+    }
+    let start_month = tax_year_start_month(input.jurisdiction);
+    let start_year = if start_month > 1 {
+        input.tax_year - 1
+    } else {
+        input.tax_year
+    };
+    format!("{start_year:04}-{start_month:02}-01")
+}
+
+// The donation is a disposal by a living person, separate from the deemed disposal on death. Its
+// gain gets the annual exclusion rather than the one for the year of death, and is taxed on top of
+// the scenario's other taxable income, or at its marginal rate where one is given.
+fn lifetime_transfer_cgt_amount(
+    reporting: &EstateScenarioInput,
+    rules: &JurisdictionTaxRuleSet,
+    annual_exclusion_amount: Decimal,
+    transferred: &[(usize, Decimal)],
+) -> Result<Decimal, EngineError> {
+    let gain_amount = transferred
+        .iter()
+        .map(|(index, _)| &reporting.assets[*index])
+        .filter(|asset| asset.included_in_cgt_deemed_disposal)
+        .map(|asset| asset.raw_capital_gain_amount())
+        .sum::<Decimal>();
+    let annual_exclusion_used_amount = match reporting.taxpayer_class {
+        TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => {
+            gain_amount.min(annual_exclusion_amount.max(Decimal::ZERO))
+        }
+        TaxPayerClass::Company | TaxPayerClass::Trust => Decimal::ZERO,
+    };
+    let rounding = &rules.rounding;
+    let taxable_capital_gain_amount = rounding.round_return_amount(
+        (gain_amount - annual_exclusion_used_amount)
+            * rules
+                .cgt_on_death
+                .inclusion_rate_for(reporting.taxpayer_class),
+    );
+    Ok(match reporting.marginal_income_tax_rate {
+        Some(rate) => rounding.round_return_amount(
+            taxable_capital_gain_amount * rate.clamp(Decimal::ZERO, Decimal::ONE),
+        ),
+        None => {
+            calculate_income_tax_on_death(
+                &rules.income_tax,
+                rounding,
+                reporting,
+                taxable_capital_gain_amount,
+            )?
+            .tax_on_capital_gain_amount
+        }
+    })
+}
+
+// Illiquid residue assets are donated to a trust, so they leave the estate. The donations tax
+// and the capital gains tax the donation triggers are paid before death, which is modelled as a
+// debt. Only South African donations tax is modelled, and the lever needs the rule version's
+// annual CGT exclusion, so it is left out otherwise.
+fn trust_transfer(
+    base: &EstateScenarioInput,
+    reporting: &EstateScenarioInput,
+    rules: &JurisdictionTaxRuleSet,
+    options: StrategyGeneratorOptions,
+    currency: Currency,
+) -> Result<Option<CandidateStrategy>, EngineError> {
+    if base.jurisdiction != Jurisdiction::SouthAfrica {
+        return Ok(None);
+    }
+    let Some(annual_exclusion_amount) = rules.cgt_on_death.annual_exclusion_amount else {
+        return Ok(None);
+    };
+    let transferred = reporting
+        .assets
+        .iter()
+        .enumerate()
+        .filter(|(_, asset)| {
+            !asset.is_liquid
+                && asset.included_in_estate_duty
+                && !asset.qualifies_primary_residence_exclusion
+                && asset.market_value_amount > Decimal::ZERO
+                && in_residue(base, asset)
+        })
+        .map(|(index, asset)| (index, asset.market_value_amount))
+        .collect::<Vec<_>>();
+    if transferred.is_empty() {
+        return Ok(None);
+    }
+
+    let lever = StrategyLever::TrustTransfer;
+    let mut input = base.clone();
+    input.assets = base
+        .assets
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| transferred.iter().all(|(moved, _)| moved != index))
+        .map(|(_, asset)| asset.clone())
+        .collect();
+    let transferred_amount = transferred
+        .iter()
+        .map(|(_, amount)| *amount)
+        .sum::<Decimal>();
+
+    let donations_tax_amount = calculate_donations_tax(&DonationsTaxInput {
+        jurisdiction: base.jurisdiction,
+        taxpayer_class: base.taxpayer_class,
+        prior_cumulative_taxable_donations_amount: Decimal::ZERO,
+        donations: vec![LifetimeDonation {
+            donee: "Family trust".to_string(),
+            donated_on: donation_date(base, options),
+            amount: transferred_amount,
+            exempt_from_donations_tax: false,
+        }],
+    })?
+    .total_tax_payable_amount;
+    let transfer_cgt_amount =
+        lifetime_transfer_cgt_amount(reporting, rules, annual_exclusion_amount, &transferred)?;
+    input.debts_and_loans_amount += donations_tax_amount + transfer_cgt_amount;
+
+    let mut changes = transferred
+        .iter()
+        .map(|(index, amount)| StrategyChange {
+            lever,
+            field: format!("assets[{index}]"),
+            description: format!(
+                "Donate {} ({}) to a family trust",
+                base.assets[*index].name,
+                amount_text(currency, *amount)
+            ),
+        })
+        .collect::<Vec<_>>();
+    changes.push(StrategyChange {
+        lever,
+        field: "debts_and_loans_amount".to_string(),
+        description: format!(
+            "Pay {} donations tax and {} capital gains tax on the transfer",
+            amount_text(currency, donations_tax_amount),
+            amount_text(currency, transfer_cgt_amount)
+        ),
+    });
+
+    Ok(Some(CandidateStrategy {
+        name: "Assets to a trust",
+        description: format!(
+            "Donate {} of illiquid assets to a family trust during lifetime, so they fall outside the estate. The transfer costs {} in donations tax and {} in capital gains tax.",
+            amount_text(currency, transferred_amount),
+            amount_text(currency, donations_tax_amount),
+            amount_text(currency, transfer_cgt_amount)
+        ),
+        changes,
+        input,
+    }))
+}
+
+// The annual exemption is donated each year out of the largest liquid residue assets held in the
+// reporting currency.
+fn annual_donations(
+    base: &EstateScenarioInput,
+    rules: &JurisdictionTaxRuleSet,
+    options: StrategyGeneratorOptions,
+    currency: Currency,
+) -> Option<CandidateStrategy> {
+    let annual_exemption_amount = annual_exemption_for(&rules.donations_tax, base.taxpayer_class);
+    let years = Decimal::from(options.annual_donation_years);
+    if annual_exemption_amount <= Decimal::ZERO || years <= Decimal::ZERO {
+        return None;
+    }
+
+    let mut sources = base
+        .assets
+        .iter()
+        .enumerate()
+        .filter(|(_, asset)| {
+            asset.is_liquid
+                && asset.market_value_amount > Decimal::ZERO
+                && asset.currency.is_none_or(|held_in| held_in == currency)
+                && in_residue(base, asset)
+        })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    sources.sort_by(|a, b| {
+        base.assets[*b]
+            .market_value_amount
+            .cmp(&base.assets[*a].market_value_amount)
+    });
+
+    let lever = StrategyLever::AnnualDonations;
+    let mut input = base.clone();
+    let mut changes = Vec::new();
+    let mut remaining_amount = annual_exemption_amount * years;
+    for index in sources {
+        if remaining_amount <= Decimal::ZERO {
+            break;
+        }
+        let asset = &mut input.assets[index];
+        let donated_amount = remaining_amount.min(asset.market_value_amount);
+        asset.base_cost_amount = currency.round_amount(
+            asset.base_cost_amount * (asset.market_value_amount - donated_amount)
+                / asset.market_value_amount,
+        );
+        asset.market_value_amount -= donated_amount;
+        remaining_amount -= donated_amount;
+        changes.push(StrategyChange {
+            lever,
+            field: format!("assets[{index}].market_value_amount"),
+            description: format!(
+                "Reduce {} by {} donated",
+                asset.name,
+                amount_text(currency, donated_amount)
+            ),
+        });
+    }
+    if changes.is_empty() {
+        return None;
+    }
+
+    let donated_amount = annual_exemption_amount * years - remaining_amount;
+    Some(CandidateStrategy {
+        name: "Annual donations",
+        description: format!(
+            "Donate the {} annual exemption each year for {} years before death, moving {} out of the estate free of donations tax.",
+            amount_text(currency, annual_exemption_amount),
+            options.annual_donation_years,
+            amount_text(currency, donated_amount)
+        ),
+        changes,
+        input,
+    })
+}
+
+// The current plan comes first, followed by each lever that applies to the scenario.
+pub fn generate_candidate_strategies(
+    base: &EstateScenarioInput,
+    options: StrategyGeneratorOptions,
+) -> Result<Vec<CandidateStrategy>, EngineError> {
+    // Candidates carry the resolved tax year, so each one can be recalculated on its own.
+    let base = &EstateScenarioInput {
+        tax_year: scenario_tax_year(base),
+        ..base.clone()
+    };
+    let rules = tax_rules_for_scenario(base)?.rules;
+    let base_result = calculate_combined_tax_and_liquidity(base)?;
+    let currency = reporting_currency_for(base.jurisdiction);
+    let (reporting, _) = convert_to_reporting_currency(base)?;
+
+    let mut strategies = vec![CandidateStrategy {
+        name: "Current plan",
+        description: "The scenario as given, with no planning changes.".to_string(),
+        changes: Vec::new(),
+        input: base.clone(),
+    }];
+    strategies.extend(residue_to_spouse(base, &reporting, currency));
+    strategies.extend(pbo_bequest(base, &reporting, &rules, currency));
+    strategies.extend(life_cover(base, &base_result, currency)?);
+    strategies.extend(trust_transfer(base, &reporting, &rules, options, currency)?);
+    strategies.extend(annual_donations(base, &rules, options, currency));
+    Ok(strategies)
+}
@@ -1,3 +1,9 @@
+pub mod rule_catalog;
+pub mod rule_diff;
+pub mod rule_publishing;
 pub mod tax_rules;
 
+pub use rule_catalog::*;
+pub use rule_diff::*;
+pub use rule_publishing::*;
 pub use tax_rules::*;
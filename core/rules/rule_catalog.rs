@@ -0,0 +1,217 @@
+use crate::core::domain::models::CalendarDate;
+use crate::core::rules::tax_rules::{
+    Jurisdiction, TaxRuleSelectionError, TaxRuleVersion, VersionedJurisdictionTaxRuleSet,
+};
+use std::collections::BTreeSet;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+// Rule versions loaded at runtime. A jurisdiction without a loaded catalog keeps the versions
+// compiled into its jurisdiction module.
+#[derive(Debug, Clone)]
+pub struct TaxRuleCatalog {
+    pub jurisdiction: Jurisdiction,
+    pub versions: Vec<VersionedJurisdictionTaxRuleSet>,
+}
+
+// Where a loaded catalog came from. Published database versions are layered over the rule file,
+// or over the compiled-in catalog when no file is loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxRuleCatalogSource {
+    Database,
+    Files,
+}
+
+// The registry is per process. Each server instance holds its own copy and only sees database
+// changes when it refreshes: after its own publish or retire, on SIGHUP, or at startup.
+static LOADED_TAX_RULE_CATALOGS: RwLock<Vec<(TaxRuleCatalogSource, TaxRuleCatalog)>> =
+    RwLock::new(Vec::new());
+static RULE_TEXT: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+// Rule sets carry `&'static str` sources and dates. Loaded text is leaked once per distinct
+// value, so reloading the same files does not grow memory.
+pub fn intern_rule_text(value: &str) -> &'static str {
+    let mut interned = RULE_TEXT.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_string().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+// Replaces every catalog loaded from `source` at once; an empty list drops that source.
+pub fn install_tax_rule_catalogs(source: TaxRuleCatalogSource, catalogs: Vec<TaxRuleCatalog>) {
+    let mut loaded = LOADED_TAX_RULE_CATALOGS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    loaded.retain(|(loaded_source, _)| *loaded_source != source);
+    loaded.extend(catalogs.into_iter().map(|mut catalog| {
+        catalog
+            .versions
+            .sort_by_key(|versioned| versioned.version.tax_year_from);
+        (source, catalog)
+    }));
+}
+
+pub fn loaded_tax_rule_jurisdictions(source: TaxRuleCatalogSource) -> Vec<Jurisdiction> {
+    LOADED_TAX_RULE_CATALOGS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .filter(|(loaded_source, _)| *loaded_source == source)
+        .map(|(_, catalog)| catalog.jurisdiction)
+        .collect()
+}
+
+// The loaded versions for the jurisdiction, or `None` when it serves only `built_in`. Database
+// versions replace the file (or `built_in`) versions for the tax years they cover.
+pub fn loaded_tax_rule_versions(
+    jurisdiction: Jurisdiction,
+    built_in: fn(Jurisdiction) -> Vec<VersionedJurisdictionTaxRuleSet>,
+) -> Option<Vec<VersionedJurisdictionTaxRuleSet>> {
+    let loaded = LOADED_TAX_RULE_CATALOGS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    let versions_from = |source| {
+        loaded
+            .iter()
+            .find(|(loaded_source, catalog)| {
+                *loaded_source == source && catalog.jurisdiction == jurisdiction
+            })
+            .map(|(_, catalog)| catalog.versions.clone())
+    };
+    let files = versions_from(TaxRuleCatalogSource::Files);
+    match versions_from(TaxRuleCatalogSource::Database) {
+        Some(database) => Some(merge_tax_rule_versions(
+            files.unwrap_or_else(|| built_in(jurisdiction)),
+            database,
+        )),
+        None => files,
+    }
+}
+
+pub fn tax_year_windows_overlap(version: &TaxRuleVersion, other: &TaxRuleVersion) -> bool {
+    version
+        .tax_year_to
+        .is_none_or(|tax_year_to| other.tax_year_from <= tax_year_to)
+        && other
+            .tax_year_to
+            .is_none_or(|other_to| version.tax_year_from <= other_to)
+}
+
+fn shifted_rule_date(
+    date: &'static str,
+    shift: fn(CalendarDate) -> CalendarDate,
+) -> Option<&'static str> {
+    CalendarDate::parse(date).map(|date| intern_rule_text(&shift(date).to_string()))
+}
+
+// What is left of `versioned` once `covering` takes over part of its tax-year window: nothing
+// when it is covered entirely, and two parts when `covering` sits inside it. A part ending where
+// `covering` starts stops taking effect the day before it does; a part starting after `covering`
+// takes effect the day after it ends.
+pub fn uncovered_tax_rule_versions(
+    versioned: &VersionedJurisdictionTaxRuleSet,
+    covering: &TaxRuleVersion,
+) -> Vec<VersionedJurisdictionTaxRuleSet> {
+    let version = &versioned.version;
+    if !tax_year_windows_overlap(version, covering) {
+        return vec![versioned.clone()];
+    }
+
+    let mut parts = Vec::new();
+    if version.tax_year_from < covering.tax_year_from {
+        let mut head = versioned.clone();
+        head.version.tax_year_to = Some(covering.tax_year_from - 1);
+        head.version.effective_to =
+            shifted_rule_date(covering.effective_from, CalendarDate::previous_day)
+                .or(version.effective_to);
+        parts.push(head);
+    }
+    if let Some(covering_to) = covering.tax_year_to {
+        if version
+            .tax_year_to
+            .is_none_or(|tax_year_to| tax_year_to > covering_to)
+        {
+            let mut tail = versioned.clone();
+            tail.version.tax_year_from = covering_to + 1;
+            if let Some(effective_from) = covering
+                .effective_to
+                .and_then(|effective_to| shifted_rule_date(effective_to, CalendarDate::next_day))
+            {
+                tail.version.effective_from = effective_from;
+            }
+            parts.push(tail);
+        }
+    }
+    parts
+}
+
+// Layers `overrides` over `base`: base versions keep only the tax years no override covers.
+pub fn merge_tax_rule_versions(
+    base: Vec<VersionedJurisdictionTaxRuleSet>,
+    overrides: Vec<VersionedJurisdictionTaxRuleSet>,
+) -> Vec<VersionedJurisdictionTaxRuleSet> {
+    let mut merged = overrides.iter().fold(base, |remaining, covering| {
+        remaining
+            .iter()
+            .flat_map(|versioned| uncovered_tax_rule_versions(versioned, &covering.version))
+            .collect()
+    });
+    merged.extend(overrides);
+    merged.sort_by_key(|versioned| versioned.version.tax_year_from);
+    merged
+}
+
+pub fn select_tax_rule_version(
+    jurisdiction: Jurisdiction,
+    versions: Vec<VersionedJurisdictionTaxRuleSet>,
+    tax_year: u16,
+) -> Result<VersionedJurisdictionTaxRuleSet, TaxRuleSelectionError> {
+    versions
+        .into_iter()
+        .find(|versioned| {
+            tax_year >= versioned.version.tax_year_from
+                && versioned
+                    .version
+                    .tax_year_to
+                    .is_none_or(|tax_year_to| tax_year <= tax_year_to)
+        })
+        .ok_or(TaxRuleSelectionError::UnsupportedTaxYear {
+            jurisdiction,
+            tax_year,
+        })
+}
+
+// Versions whose effective window contains the date; the one taking effect last wins.
+pub fn select_tax_rule_version_in_effect(
+    jurisdiction: Jurisdiction,
+    versions: Vec<VersionedJurisdictionTaxRuleSet>,
+    date: CalendarDate,
+) -> Result<VersionedJurisdictionTaxRuleSet, TaxRuleSelectionError> {
+    versions
+        .into_iter()
+        .filter_map(|versioned| {
+            let effective_from = CalendarDate::parse(versioned.version.effective_from)?;
+            let in_effect = effective_from <= date
+                && versioned.version.effective_to.is_none_or(|effective_to| {
+                    CalendarDate::parse(effective_to).is_some_and(|to| date <= to)
+                });
+            in_effect.then_some((effective_from, versioned))
+        })
+        .max_by_key(|(effective_from, _)| *effective_from)
+        .map(|(_, versioned)| versioned)
+        .ok_or(TaxRuleSelectionError::NoVersionInEffect { jurisdiction, date })
+}
+
+// The open-ended version, or else the one starting last.
+pub fn latest_tax_rule_version(
+    versions: Vec<VersionedJurisdictionTaxRuleSet>,
+) -> Option<VersionedJurisdictionTaxRuleSet> {
+    versions.into_iter().max_by_key(|versioned| {
+        (
+            versioned.version.tax_year_to.is_none(),
+            versioned.version.tax_year_from,
+        )
+    })
+}
@@ -0,0 +1,691 @@
+use crate::core::rules::tax_rules::{
+    CapitalGainsAtDeathRule, DonationsTaxRule, EstateDutyRule, ExecutorFeeRule, IncomeTaxRebate,
+    IncomeTaxRule, RateBracket, RetirementFundRule, RoundingRule, StateEstateTaxRule, VatRule,
+    VersionedJurisdictionTaxRuleSet,
+};
+use std::fmt::{Debug, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxRuleChangeKind {
+    Rate,
+    // Amounts: exemptions, exclusions, bracket starts, rebates and caps.
+    Threshold,
+    Flag,
+    // Non-boolean choices such as the apportionment method or return rounding.
+    Treatment,
+    Source,
+    // Effective dates and the tax-year windows of versions and tables.
+    EffectiveDate,
+}
+
+// One changed field. `from` or `to` is `None` when the field only exists on one side, such as
+// an added bracket or a rule that one version does not have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxRuleFieldChange {
+    pub field: String,
+    pub kind: TaxRuleChangeKind,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl TaxRuleFieldChange {
+    // One release-note line, such as `estate_duty.exemption_amount: 3500000 -> 4000000`.
+    pub fn description(&self) -> String {
+        let from = self.from.as_deref().unwrap_or("(none)");
+        let to = self.to.as_deref().unwrap_or("(none)");
+        format!("{}: {from} -> {to}", self.field)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxRuleVersionDiff {
+    pub from_version_id: &'static str,
+    pub to_version_id: &'static str,
+    pub changes: Vec<TaxRuleFieldChange>,
+}
+
+struct ChangeLog {
+    changes: Vec<TaxRuleFieldChange>,
+}
+
+impl ChangeLog {
+    fn push(
+        &mut self,
+        field: String,
+        kind: TaxRuleChangeKind,
+        from: Option<String>,
+        to: Option<String>,
+    ) {
+        self.changes.push(TaxRuleFieldChange {
+            field,
+            kind,
+            from,
+            to,
+        });
+    }
+
+    // Values compare by value, so `0.25` and `0.250` are not reported as a change.
+    fn value<T: PartialEq + Display>(
+        &mut self,
+        field: String,
+        kind: TaxRuleChangeKind,
+        from: Option<T>,
+        to: Option<T>,
+    ) {
+        if from != to {
+            self.push(
+                field,
+                kind,
+                from.map(|value| value.to_string()),
+                to.map(|value| value.to_string()),
+            );
+        }
+    }
+
+    fn variant<T: PartialEq + Debug>(&mut self, field: String, from: Option<T>, to: Option<T>) {
+        if from != to {
+            self.push(
+                field,
+                TaxRuleChangeKind::Treatment,
+                from.map(|value| format!("{value:?}")),
+                to.map(|value| format!("{value:?}")),
+            );
+        }
+    }
+
+    fn sources(
+        &mut self,
+        prefix: &str,
+        from: Option<(&'static str, &'static str)>,
+        to: Option<(&'static str, &'static str)>,
+    ) {
+        self.value(
+            format!("{prefix}.source"),
+            TaxRuleChangeKind::Source,
+            from.map(|(source, _)| source),
+            to.map(|(source, _)| source),
+        );
+        self.value(
+            format!("{prefix}.source_url"),
+            TaxRuleChangeKind::Source,
+            from.map(|(_, source_url)| source_url),
+            to.map(|(_, source_url)| source_url),
+        );
+    }
+
+    fn effective_from(
+        &mut self,
+        prefix: &str,
+        from: Option<&'static str>,
+        to: Option<&'static str>,
+    ) {
+        self.value(
+            format!("{prefix}.effective_from"),
+            TaxRuleChangeKind::EffectiveDate,
+            from,
+            to,
+        );
+    }
+
+    fn tax_year_window(
+        &mut self,
+        prefix: &str,
+        from: Option<(u16, Option<u16>)>,
+        to: Option<(u16, Option<u16>)>,
+    ) {
+        self.value(
+            format!("{prefix}.tax_year_from"),
+            TaxRuleChangeKind::EffectiveDate,
+            from.map(|(tax_year_from, _)| tax_year_from),
+            to.map(|(tax_year_from, _)| tax_year_from),
+        );
+        self.value(
+            format!("{prefix}.tax_year_to"),
+            TaxRuleChangeKind::EffectiveDate,
+            from.and_then(|(_, tax_year_to)| tax_year_to),
+            to.and_then(|(_, tax_year_to)| tax_year_to),
+        );
+    }
+
+    // Brackets are compared by position; an added or removed bracket shows on one side only.
+    fn rate_schedule(
+        &mut self,
+        field: String,
+        from: Option<&[RateBracket]>,
+        to: Option<&[RateBracket]>,
+    ) {
+        let from = from.unwrap_or_default();
+        let to = to.unwrap_or_default();
+        for index in 0..from.len().max(to.len()) {
+            self.value(
+                format!("{field}[{index}].from_amount"),
+                TaxRuleChangeKind::Threshold,
+                from.get(index).map(|bracket| bracket.from_amount),
+                to.get(index).map(|bracket| bracket.from_amount),
+            );
+            self.value(
+                format!("{field}[{index}].rate"),
+                TaxRuleChangeKind::Rate,
+                from.get(index).map(|bracket| bracket.rate),
+                to.get(index).map(|bracket| bracket.rate),
+            );
+        }
+    }
+
+    fn rebates(
+        &mut self,
+        field: String,
+        from: Option<&[IncomeTaxRebate]>,
+        to: Option<&[IncomeTaxRebate]>,
+    ) {
+        let from = from.unwrap_or_default();
+        let to = to.unwrap_or_default();
+        for index in 0..from.len().max(to.len()) {
+            self.value(
+                format!("{field}[{index}].from_age"),
+                TaxRuleChangeKind::Threshold,
+                from.get(index).map(|rebate| rebate.from_age),
+                to.get(index).map(|rebate| rebate.from_age),
+            );
+            self.value(
+                format!("{field}[{index}].amount"),
+                TaxRuleChangeKind::Threshold,
+                from.get(index).map(|rebate| rebate.amount),
+                to.get(index).map(|rebate| rebate.amount),
+            );
+        }
+    }
+
+    fn estate_duty(&mut self, from: Option<&EstateDutyRule>, to: Option<&EstateDutyRule>) {
+        let prefix = "estate_duty";
+        self.value(
+            format!("{prefix}.exemption_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.exemption_amount),
+            to.map(|rule| rule.exemption_amount),
+        );
+        self.rate_schedule(
+            format!("{prefix}.rate_schedule"),
+            from.map(|rule| rule.rate_schedule.as_slice()),
+            to.map(|rule| rule.rate_schedule.as_slice()),
+        );
+        self.value(
+            format!("{prefix}.exemption_as_unified_credit"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.exemption_as_unified_credit),
+            to.map(|rule| rule.exemption_as_unified_credit),
+        );
+        self.value(
+            format!("{prefix}.non_resident_exemption_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.and_then(|rule| rule.non_resident_exemption_amount),
+            to.and_then(|rule| rule.non_resident_exemption_amount),
+        );
+        self.value(
+            format!("{prefix}.spouse_deduction_unlimited"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.spouse_deduction_unlimited),
+            to.map(|rule| rule.spouse_deduction_unlimited),
+        );
+        self.variant(
+            format!("{prefix}.life_policy_inclusion"),
+            from.map(|rule| rule.life_policy_inclusion),
+            to.map(|rule| rule.life_policy_inclusion),
+        );
+        self.variant(
+            format!("{prefix}.default_tax_apportionment"),
+            from.map(|rule| rule.default_tax_apportionment),
+            to.map(|rule| rule.default_tax_apportionment),
+        );
+        self.value(
+            format!("{prefix}.foreign_death_duty_credit"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.foreign_death_duty_credit),
+            to.map(|rule| rule.foreign_death_duty_credit),
+        );
+        self.value(
+            format!("{prefix}.exemption_portable_from"),
+            TaxRuleChangeKind::EffectiveDate,
+            from.and_then(|rule| rule.exemption_portable_from),
+            to.and_then(|rule| rule.exemption_portable_from),
+        );
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    fn donations_tax(&mut self, from: Option<&DonationsTaxRule>, to: Option<&DonationsTaxRule>) {
+        let prefix = "donations_tax";
+        self.value(
+            format!("{prefix}.annual_exemption_natural_person_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.annual_exemption_natural_person_amount),
+            to.map(|rule| rule.annual_exemption_natural_person_amount),
+        );
+        self.value(
+            format!("{prefix}.annual_exemption_non_natural_casual_gifts_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.annual_exemption_non_natural_casual_gifts_amount),
+            to.map(|rule| rule.annual_exemption_non_natural_casual_gifts_amount),
+        );
+        self.value(
+            format!("{prefix}.primary_rate"),
+            TaxRuleChangeKind::Rate,
+            from.map(|rule| rule.primary_rate),
+            to.map(|rule| rule.primary_rate),
+        );
+        self.value(
+            format!("{prefix}.primary_rate_cap_cumulative_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.primary_rate_cap_cumulative_amount),
+            to.map(|rule| rule.primary_rate_cap_cumulative_amount),
+        );
+        self.value(
+            format!("{prefix}.secondary_rate"),
+            TaxRuleChangeKind::Rate,
+            from.map(|rule| rule.secondary_rate),
+            to.map(|rule| rule.secondary_rate),
+        );
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    fn cgt_on_death(
+        &mut self,
+        from: Option<&CapitalGainsAtDeathRule>,
+        to: Option<&CapitalGainsAtDeathRule>,
+    ) {
+        let prefix = "cgt_on_death";
+        self.value(
+            format!("{prefix}.annual_exclusion_in_year_of_death_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.annual_exclusion_in_year_of_death_amount),
+            to.map(|rule| rule.annual_exclusion_in_year_of_death_amount),
+        );
+        self.value(
+            format!("{prefix}.annual_exclusion_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.and_then(|rule| rule.annual_exclusion_amount),
+            to.and_then(|rule| rule.annual_exclusion_amount),
+        );
+        self.value(
+            format!("{prefix}.inclusion_rate_natural_person"),
+            TaxRuleChangeKind::Rate,
+            from.map(|rule| rule.inclusion_rate_natural_person),
+            to.map(|rule| rule.inclusion_rate_natural_person),
+        );
+        self.value(
+            format!("{prefix}.inclusion_rate_company"),
+            TaxRuleChangeKind::Rate,
+            from.map(|rule| rule.inclusion_rate_company),
+            to.map(|rule| rule.inclusion_rate_company),
+        );
+        self.value(
+            format!("{prefix}.inclusion_rate_trust"),
+            TaxRuleChangeKind::Rate,
+            from.map(|rule| rule.inclusion_rate_trust),
+            to.map(|rule| rule.inclusion_rate_trust),
+        );
+        self.value(
+            format!("{prefix}.primary_residence_exclusion_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.primary_residence_exclusion_amount),
+            to.map(|rule| rule.primary_residence_exclusion_amount),
+        );
+        self.value(
+            format!("{prefix}.base_cost_step_up_to_market_value_on_death"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.base_cost_step_up_to_market_value_on_death),
+            to.map(|rule| rule.base_cost_step_up_to_market_value_on_death),
+        );
+        self.value(
+            format!("{prefix}.spousal_rollover_on_death"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.spousal_rollover_on_death),
+            to.map(|rule| rule.spousal_rollover_on_death),
+        );
+        self.value(
+            format!("{prefix}.pbo_bequest_gain_disregarded"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.pbo_bequest_gain_disregarded),
+            to.map(|rule| rule.pbo_bequest_gain_disregarded),
+        );
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    fn retirement_funds(
+        &mut self,
+        from: Option<&RetirementFundRule>,
+        to: Option<&RetirementFundRule>,
+    ) {
+        let prefix = "retirement_funds";
+        self.value(
+            format!("{prefix}.fund_benefits_in_estate"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.fund_benefits_in_estate),
+            to.map(|rule| rule.fund_benefits_in_estate),
+        );
+        self.value(
+            format!("{prefix}.living_annuity_in_estate"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.living_annuity_in_estate),
+            to.map(|rule| rule.living_annuity_in_estate),
+        );
+        self.value(
+            format!("{prefix}.disallowed_contributions_in_estate"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.disallowed_contributions_in_estate),
+            to.map(|rule| rule.disallowed_contributions_in_estate),
+        );
+        self.value(
+            format!("{prefix}.disregarded_for_cgt_on_death"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.disregarded_for_cgt_on_death),
+            to.map(|rule| rule.disregarded_for_cgt_on_death),
+        );
+        self.value(
+            format!("{prefix}.fund_benefits_allocated_by_trustees"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.fund_benefits_allocated_by_trustees),
+            to.map(|rule| rule.fund_benefits_allocated_by_trustees),
+        );
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    // Tables are matched by `tax_year_from`, so inserting a year does not shift every table.
+    fn income_tax(&mut self, from: Option<&IncomeTaxRule>, to: Option<&IncomeTaxRule>) {
+        let prefix = "income_tax";
+        let from_tables = from.map(|rule| rule.tables.as_slice()).unwrap_or_default();
+        let to_tables = to.map(|rule| rule.tables.as_slice()).unwrap_or_default();
+        let mut tax_years = from_tables
+            .iter()
+            .chain(to_tables)
+            .map(|table| table.tax_year_from)
+            .collect::<Vec<_>>();
+        tax_years.sort_unstable();
+        tax_years.dedup();
+
+        for tax_year in tax_years {
+            let from_table = from_tables
+                .iter()
+                .find(|table| table.tax_year_from == tax_year);
+            let to_table = to_tables
+                .iter()
+                .find(|table| table.tax_year_from == tax_year);
+            let table_prefix = format!("{prefix}.tables[{tax_year}]");
+            self.value(
+                format!("{table_prefix}.tax_year_to"),
+                TaxRuleChangeKind::EffectiveDate,
+                from_table.and_then(|table| table.tax_year_to),
+                to_table.and_then(|table| table.tax_year_to),
+            );
+            self.rate_schedule(
+                format!("{table_prefix}.rate_schedule"),
+                from_table.map(|table| table.rate_schedule.as_slice()),
+                to_table.map(|table| table.rate_schedule.as_slice()),
+            );
+            self.rebates(
+                format!("{table_prefix}.rebates"),
+                from_table.map(|table| table.rebates.as_slice()),
+                to_table.map(|table| table.rebates.as_slice()),
+            );
+            self.rate_schedule(
+                format!("{table_prefix}.trust_rate_schedule"),
+                from_table.map(|table| table.trust_rate_schedule.as_slice()),
+                to_table.map(|table| table.trust_rate_schedule.as_slice()),
+            );
+            self.rate_schedule(
+                format!("{table_prefix}.company_rate_schedule"),
+                from_table.map(|table| table.company_rate_schedule.as_slice()),
+                to_table.map(|table| table.company_rate_schedule.as_slice()),
+            );
+        }
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    fn executor_fee(&mut self, from: Option<&ExecutorFeeRule>, to: Option<&ExecutorFeeRule>) {
+        let prefix = "executor_fee";
+        self.rate_schedule(
+            format!("{prefix}.asset_rate_schedule"),
+            from.map(|rule| rule.asset_rate_schedule.as_slice()),
+            to.map(|rule| rule.asset_rate_schedule.as_slice()),
+        );
+        self.value(
+            format!("{prefix}.income_rate"),
+            TaxRuleChangeKind::Rate,
+            from.and_then(|rule| rule.income_rate),
+            to.and_then(|rule| rule.income_rate),
+        );
+        self.value(
+            format!("{prefix}.minimum_fee_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.minimum_fee_amount),
+            to.map(|rule| rule.minimum_fee_amount),
+        );
+        self.value(
+            format!("{prefix}.vat_applies"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.vat_applies),
+            to.map(|rule| rule.vat_applies),
+        );
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    // Rates are matched by `tax_year_from`, like income tax tables.
+    fn vat(&mut self, from: Option<&VatRule>, to: Option<&VatRule>) {
+        let prefix = "vat";
+        let from_rates = from.map(|rule| rule.rates.as_slice()).unwrap_or_default();
+        let to_rates = to.map(|rule| rule.rates.as_slice()).unwrap_or_default();
+        let mut tax_years = from_rates
+            .iter()
+            .chain(to_rates)
+            .map(|rate| rate.tax_year_from)
+            .collect::<Vec<_>>();
+        tax_years.sort_unstable();
+        tax_years.dedup();
+
+        for tax_year in tax_years {
+            let from_rate = from_rates
+                .iter()
+                .find(|rate| rate.tax_year_from == tax_year);
+            let to_rate = to_rates.iter().find(|rate| rate.tax_year_from == tax_year);
+            self.value(
+                format!("{prefix}.rates[{tax_year}].tax_year_to"),
+                TaxRuleChangeKind::EffectiveDate,
+                from_rate.and_then(|rate| rate.tax_year_to),
+                to_rate.and_then(|rate| rate.tax_year_to),
+            );
+            self.value(
+                format!("{prefix}.rates[{tax_year}].standard_rate"),
+                TaxRuleChangeKind::Rate,
+                from_rate.map(|rate| rate.standard_rate),
+                to_rate.map(|rate| rate.standard_rate),
+            );
+        }
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    fn rounding(&mut self, from: Option<&RoundingRule>, to: Option<&RoundingRule>) {
+        let prefix = "rounding";
+        self.value(
+            format!("{prefix}.return_decimal_places"),
+            TaxRuleChangeKind::Treatment,
+            from.map(|rule| rule.return_decimal_places),
+            to.map(|rule| rule.return_decimal_places),
+        );
+        self.variant(
+            format!("{prefix}.return_rounding"),
+            from.map(|rule| rule.return_rounding),
+            to.map(|rule| rule.return_rounding),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+
+    fn state_estate_tax(
+        &mut self,
+        from: Option<&StateEstateTaxRule>,
+        to: Option<&StateEstateTaxRule>,
+    ) {
+        let prefix = "state_estate_tax";
+        self.value(
+            format!("{prefix}.exemption_amount"),
+            TaxRuleChangeKind::Threshold,
+            from.map(|rule| rule.exemption_amount),
+            to.map(|rule| rule.exemption_amount),
+        );
+        self.rate_schedule(
+            format!("{prefix}.rate_schedule"),
+            from.map(|rule| rule.rate_schedule.as_slice()),
+            to.map(|rule| rule.rate_schedule.as_slice()),
+        );
+        self.value(
+            format!("{prefix}.credit_phase_out_ceiling_ratio"),
+            TaxRuleChangeKind::Threshold,
+            from.and_then(|rule| rule.credit_phase_out_ceiling_ratio),
+            to.and_then(|rule| rule.credit_phase_out_ceiling_ratio),
+        );
+        self.value(
+            format!("{prefix}.deductible_for_federal_estate_tax"),
+            TaxRuleChangeKind::Flag,
+            from.map(|rule| rule.deductible_for_federal_estate_tax),
+            to.map(|rule| rule.deductible_for_federal_estate_tax),
+        );
+        self.effective_from(
+            prefix,
+            from.map(|rule| rule.effective_from),
+            to.map(|rule| rule.effective_from),
+        );
+        self.sources(
+            prefix,
+            from.map(|rule| (rule.source, rule.source_url)),
+            to.map(|rule| (rule.source, rule.source_url)),
+        );
+    }
+}
+
+// Field-by-field changes from one version to another, in rule-set order.
+pub fn diff_tax_rule_versions(
+    from: &VersionedJurisdictionTaxRuleSet,
+    to: &VersionedJurisdictionTaxRuleSet,
+) -> TaxRuleVersionDiff {
+    let mut log = ChangeLog {
+        changes: Vec::new(),
+    };
+
+    let (from_version, to_version) = (&from.version, &to.version);
+    log.tax_year_window(
+        "version",
+        Some((from_version.tax_year_from, from_version.tax_year_to)),
+        Some((to_version.tax_year_from, to_version.tax_year_to)),
+    );
+    log.effective_from(
+        "version",
+        Some(from_version.effective_from),
+        Some(to_version.effective_from),
+    );
+    log.value(
+        "version.effective_to".to_string(),
+        TaxRuleChangeKind::EffectiveDate,
+        from_version.effective_to,
+        to_version.effective_to,
+    );
+    log.value(
+        "version.source_last_verified_on".to_string(),
+        TaxRuleChangeKind::Source,
+        Some(from_version.source_last_verified_on),
+        Some(to_version.source_last_verified_on),
+    );
+
+    let (from_rules, to_rules) = (&from.rules, &to.rules);
+    log.estate_duty(Some(&from_rules.estate_duty), Some(&to_rules.estate_duty));
+    log.donations_tax(
+        Some(&from_rules.donations_tax),
+        Some(&to_rules.donations_tax),
+    );
+    log.cgt_on_death(Some(&from_rules.cgt_on_death), Some(&to_rules.cgt_on_death));
+    log.retirement_funds(
+        Some(&from_rules.retirement_funds),
+        Some(&to_rules.retirement_funds),
+    );
+    log.income_tax(Some(&from_rules.income_tax), Some(&to_rules.income_tax));
+    log.executor_fee(
+        from_rules.executor_fee.as_ref(),
+        to_rules.executor_fee.as_ref(),
+    );
+    log.vat(from_rules.vat.as_ref(), to_rules.vat.as_ref());
+    log.rounding(Some(&from_rules.rounding), Some(&to_rules.rounding));
+    log.state_estate_tax(
+        from_rules.state_estate_tax.as_ref(),
+        to_rules.state_estate_tax.as_ref(),
+    );
+
+    TaxRuleVersionDiff {
+        from_version_id: from_version.version_id,
+        to_version_id: to_version.version_id,
+        changes: log.changes,
+    }
+}
@@ -0,0 +1,94 @@
+use crate::core::rules::rule_catalog::TaxRuleCatalog;
+use crate::core::rules::tax_rules::{Jurisdiction, VersionedJurisdictionTaxRuleSet};
+
+// Lifecycle of a stored rule version. Only published versions are served to calculations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxRuleVersionStatus {
+    Draft,
+    Published,
+    Retired,
+}
+
+impl TaxRuleVersionStatus {
+    pub fn code(self) -> &'static str {
+        match self {
+            TaxRuleVersionStatus::Draft => "draft",
+            TaxRuleVersionStatus::Published => "published",
+            TaxRuleVersionStatus::Retired => "retired",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "draft" => Some(TaxRuleVersionStatus::Draft),
+            "published" => Some(TaxRuleVersionStatus::Published),
+            "retired" => Some(TaxRuleVersionStatus::Retired),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxRuleVersionReview {
+    pub reviewer: String,
+    pub approved: bool,
+    pub notes: Option<String>,
+    pub reviewed_at: String,
+}
+
+// `R` is the rule set as served by the registry. The store keeps the text of rows it reads owned,
+// and only versions installed for rule selection are converted.
+#[derive(Debug, Clone)]
+pub struct TaxRuleVersionRecord<R = VersionedJurisdictionTaxRuleSet> {
+    pub id: i64,
+    pub jurisdiction: Jurisdiction,
+    pub status: TaxRuleVersionStatus,
+    pub author: String,
+    pub created_at: String,
+    pub review: Option<TaxRuleVersionReview>,
+    pub published_by: Option<String>,
+    pub published_at: Option<String>,
+    pub retired_by: Option<String>,
+    pub retired_at: Option<String>,
+    pub versioned: R,
+}
+
+impl<R> TaxRuleVersionRecord<R> {
+    pub fn map_rule_set<T>(self, map: impl FnOnce(R) -> T) -> TaxRuleVersionRecord<T> {
+        TaxRuleVersionRecord {
+            id: self.id,
+            jurisdiction: self.jurisdiction,
+            status: self.status,
+            author: self.author,
+            created_at: self.created_at,
+            review: self.review,
+            published_by: self.published_by,
+            published_at: self.published_at,
+            retired_by: self.retired_by,
+            retired_at: self.retired_at,
+            versioned: map(self.versioned),
+        }
+    }
+}
+
+// Published records grouped into one catalog per jurisdiction. Jurisdictions without a
+// published record are left out so they keep their file or compiled-in catalog.
+pub fn published_tax_rule_catalogs(records: Vec<TaxRuleVersionRecord>) -> Vec<TaxRuleCatalog> {
+    let mut catalogs: Vec<TaxRuleCatalog> = Vec::new();
+    for record in records
+        .into_iter()
+        .filter(|record| record.status == TaxRuleVersionStatus::Published)
+    {
+        match catalogs
+            .iter_mut()
+            .find(|catalog| catalog.jurisdiction == record.jurisdiction)
+        {
+            Some(catalog) => catalog.versions.push(record.versioned),
+            None => catalogs.push(TaxRuleCatalog {
+                jurisdiction: record.jurisdiction,
+                versions: vec![record.versioned],
+            }),
+        }
+    }
+    catalogs
+}
@@ -1,13 +1,24 @@
+use crate::core::domain::models::{
+    CalendarDate, EstateScenarioInput, RateBracketTaxLine, ResidencyStatus,
+};
+use crate::core::rules::rule_catalog::{
+    latest_tax_rule_version, loaded_tax_rule_versions, select_tax_rule_version,
+    select_tax_rule_version_in_effect,
+};
 use crate::jurisdictions::south_africa::{
-    south_africa_latest_tax_rules, south_africa_tax_rules_catalog, south_africa_tax_rules_for_year,
+    south_africa_latest_tax_rules, south_africa_reporting_currency, south_africa_tax_rules_catalog,
+    south_africa_tax_rules_for_year, south_africa_tax_year_start_month,
 };
 use crate::jurisdictions::us::{
-    us_state_latest_tax_rules, us_state_tax_rules_catalog, us_state_tax_rules_for_year,
+    us_reporting_currency, us_state_latest_tax_rules, us_state_tax_rules_catalog,
+    us_state_tax_rules_for_year, us_tax_year_start_month,
 };
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::fmt;
 
 // Country rates/thresholds are owned by jurisdiction modules.
 // Keep these core types country-agnostic and route via `baseline_tax_rules_for`.
+// Catalogs loaded from data files take precedence over the compiled-in jurisdiction catalogs.
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TaxPayerClass {
@@ -27,6 +38,39 @@ pub enum Jurisdiction {
     UsMinnesota,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Zar,
+    Usd,
+    Gbp,
+    Eur,
+}
+
+impl Currency {
+    pub fn code(self) -> &'static str {
+        match self {
+            Currency::Zar => "ZAR",
+            Currency::Usd => "USD",
+            Currency::Gbp => "GBP",
+            Currency::Eur => "EUR",
+        }
+    }
+
+    pub fn minor_unit_decimal_places(self) -> u32 {
+        match self {
+            Currency::Zar | Currency::Usd | Currency::Gbp | Currency::Eur => 2,
+        }
+    }
+
+    // Computed amounts such as fees, conversions and pro-rata shares are carried to the minor unit.
+    pub fn round_amount(self, amount: Decimal) -> Decimal {
+        amount.round_dp_with_strategy(
+            self.minor_unit_decimal_places(),
+            RoundingStrategy::MidpointAwayFromZero,
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TaxRuleVersion {
     pub version_id: &'static str,
@@ -37,13 +81,108 @@ pub struct TaxRuleVersion {
     pub source_last_verified_on: &'static str,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateBracket {
+    pub from_amount: Decimal,
+    pub rate: Decimal,
+}
+
+// Brackets are ordered by ascending `from_amount`; each runs up to the next bracket's start.
+pub fn graduated_tax_lines(brackets: &[RateBracket], amount: Decimal) -> Vec<RateBracketTaxLine> {
+    let amount = amount.max(Decimal::ZERO);
+    brackets
+        .iter()
+        .enumerate()
+        .map(|(index, bracket)| {
+            let to_amount = brackets.get(index + 1).map(|next| next.from_amount);
+            let taxable_amount = (to_amount.map_or(amount, |to_amount| amount.min(to_amount))
+                - bracket.from_amount)
+                .max(Decimal::ZERO);
+            RateBracketTaxLine {
+                from_amount: bracket.from_amount,
+                to_amount,
+                rate: bracket.rate,
+                taxable_amount,
+                tax_amount: taxable_amount * bracket.rate,
+            }
+        })
+        .collect()
+}
+
+pub fn graduated_tax_amount(brackets: &[RateBracket], amount: Decimal) -> Decimal {
+    graduated_tax_lines(brackets, amount)
+        .iter()
+        .map(|line| line.tax_amount)
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifePolicyInclusion {
+    // Policies on the deceased's life are deemed property unless a statutory exemption applies.
+    DeemedPropertyWithExemptions,
+    // Included when payable to the estate or the deceased held incidents of ownership.
+    IncidentsOfOwnership,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EstateTaxApportionment {
+    // Duty is an estate liability settled before distribution, so the residue carries it.
+    BorneByResidue,
+    // Heirs bear duty in proportion to the value they receive; spouse and PBO shares bear none.
+    ProRataAmongDutiableHeirs,
+}
+
 #[derive(Debug, Clone)]
 pub struct EstateDutyRule {
-    pub exemption_amount: f64,
-    pub primary_rate: f64,
-    pub primary_rate_cap_amount: f64,
-    pub secondary_rate: f64,
+    pub exemption_amount: Decimal,
+    pub rate_schedule: Vec<RateBracket>,
+    // Exemption reduces tax by the tax on the exemption amount rather than reducing the base.
+    pub exemption_as_unified_credit: bool,
+    // Exemption for a non-resident's situs estate; `None` applies `exemption_amount`.
+    pub non_resident_exemption_amount: Option<Decimal>,
     pub spouse_deduction_unlimited: bool,
+    pub life_policy_inclusion: LifePolicyInclusion,
+    pub default_tax_apportionment: EstateTaxApportionment,
+    // Rebate for death duty paid abroad on foreign-situs property, capped at the domestic duty
+    // attributable to that property.
+    pub foreign_death_duty_credit: bool,
+    // First date of death from which the unused exemption ports to the surviving spouse; `None`
+    // when it does not port.
+    pub exemption_portable_from: Option<&'static str>,
+    pub effective_from: &'static str,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+impl EstateDutyRule {
+    pub fn tax_on_amount(&self, amount: Decimal) -> Decimal {
+        graduated_tax_amount(&self.rate_schedule, amount)
+    }
+
+    pub fn exemption_amount_for(&self, residency_status: ResidencyStatus) -> Decimal {
+        match residency_status {
+            ResidencyStatus::Resident => self.exemption_amount,
+            ResidencyStatus::NonResident => self
+                .non_resident_exemption_amount
+                .unwrap_or(self.exemption_amount),
+        }
+    }
+
+    pub fn top_rate(&self) -> Decimal {
+        self.rate_schedule
+            .last()
+            .map(|bracket| bracket.rate)
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StateEstateTaxRule {
+    pub exemption_amount: Decimal,
+    pub rate_schedule: Vec<RateBracket>,
+    // Credit phases out linearly between the exemption and this multiple of it (New York cliff).
+    pub credit_phase_out_ceiling_ratio: Option<Decimal>,
+    pub deductible_for_federal_estate_tax: bool,
     pub effective_from: &'static str,
     pub source: &'static str,
     pub source_url: &'static str,
@@ -51,11 +190,11 @@ pub struct EstateDutyRule {
 
 #[derive(Debug, Clone)]
 pub struct DonationsTaxRule {
-    pub annual_exemption_natural_person_amount: f64,
-    pub annual_exemption_non_natural_casual_gifts_amount: f64,
-    pub primary_rate: f64,
-    pub primary_rate_cap_cumulative_amount: f64,
-    pub secondary_rate: f64,
+    pub annual_exemption_natural_person_amount: Decimal,
+    pub annual_exemption_non_natural_casual_gifts_amount: Decimal,
+    pub primary_rate: Decimal,
+    pub primary_rate_cap_cumulative_amount: Decimal,
+    pub secondary_rate: Decimal,
     pub effective_from: &'static str,
     pub source: &'static str,
     pub source_url: &'static str,
@@ -63,18 +202,26 @@ pub struct DonationsTaxRule {
 
 #[derive(Debug, Clone)]
 pub struct CapitalGainsAtDeathRule {
-    pub annual_exclusion_in_year_of_death_amount: f64,
-    pub inclusion_rate_natural_person: f64,
-    pub inclusion_rate_company: f64,
-    pub inclusion_rate_trust: f64,
+    pub annual_exclusion_in_year_of_death_amount: Decimal,
+    // The exclusion for a living person's disposals, used to price lifetime transfers. `None`
+    // where the version does not record it.
+    pub annual_exclusion_amount: Option<Decimal>,
+    pub inclusion_rate_natural_person: Decimal,
+    pub inclusion_rate_company: Decimal,
+    pub inclusion_rate_trust: Decimal,
+    // Gain on a primary residence disregarded up to this amount, for natural persons and special
+    // trusts only.
+    pub primary_residence_exclusion_amount: Decimal,
     pub base_cost_step_up_to_market_value_on_death: bool,
+    pub spousal_rollover_on_death: bool,
+    pub pbo_bequest_gain_disregarded: bool,
     pub effective_from: &'static str,
     pub source: &'static str,
     pub source_url: &'static str,
 }
 
 impl CapitalGainsAtDeathRule {
-    pub fn inclusion_rate_for(&self, taxpayer: TaxPayerClass) -> f64 {
+    pub fn inclusion_rate_for(&self, taxpayer: TaxPayerClass) -> Decimal {
         match taxpayer {
             TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => {
                 self.inclusion_rate_natural_person
@@ -83,6 +230,162 @@ impl CapitalGainsAtDeathRule {
             TaxPayerClass::Trust => self.inclusion_rate_trust,
         }
     }
+
+    pub fn primary_residence_exclusion_for(&self, taxpayer: TaxPayerClass) -> Decimal {
+        match taxpayer {
+            TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => {
+                self.primary_residence_exclusion_amount
+            }
+            TaxPayerClass::Company | TaxPayerClass::Trust => Decimal::ZERO,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetirementFundRule {
+    pub fund_benefits_in_estate: bool,
+    pub living_annuity_in_estate: bool,
+    // Contributions that were not deductible are pulled back in, capped at the benefit.
+    pub disallowed_contributions_in_estate: bool,
+    pub disregarded_for_cgt_on_death: bool,
+    // Fund trustees allocate death benefits, so nominations cannot be relied on for estate liquidity.
+    pub fund_benefits_allocated_by_trustees: bool,
+    pub effective_from: &'static str,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IncomeTaxRebate {
+    pub from_age: u8,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct IncomeTaxTable {
+    pub tax_year_from: u16,
+    pub tax_year_to: Option<u16>,
+    // Natural persons and special trusts.
+    pub rate_schedule: Vec<RateBracket>,
+    // Natural persons only; every rebate whose age threshold is reached is granted.
+    pub rebates: Vec<IncomeTaxRebate>,
+    pub trust_rate_schedule: Vec<RateBracket>,
+    pub company_rate_schedule: Vec<RateBracket>,
+}
+
+impl IncomeTaxTable {
+    pub fn rate_schedule_for(&self, taxpayer: TaxPayerClass) -> &[RateBracket] {
+        match taxpayer {
+            TaxPayerClass::NaturalPerson | TaxPayerClass::SpecialTrust => &self.rate_schedule,
+            TaxPayerClass::Company => &self.company_rate_schedule,
+            TaxPayerClass::Trust => &self.trust_rate_schedule,
+        }
+    }
+
+    pub fn rebates_amount_for(&self, taxpayer: TaxPayerClass, age: Option<u8>) -> Decimal {
+        if taxpayer != TaxPayerClass::NaturalPerson {
+            return Decimal::ZERO;
+        }
+        // Without an age only the rebates available at every age apply.
+        let age = age.unwrap_or(0);
+        self.rebates
+            .iter()
+            .filter(|rebate| age >= rebate.from_age)
+            .map(|rebate| rebate.amount)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IncomeTaxRule {
+    // Bracket tables change every year; each covers a range of tax years.
+    pub tables: Vec<IncomeTaxTable>,
+    pub effective_from: &'static str,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+impl IncomeTaxRule {
+    pub fn table_for(&self, tax_year: u16) -> Option<&IncomeTaxTable> {
+        self.tables.iter().find(|table| {
+            tax_year >= table.tax_year_from
+                && table
+                    .tax_year_to
+                    .is_none_or(|tax_year_to| tax_year <= tax_year_to)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutorFeeRule {
+    // Sliding scale on the gross value of the assets administered.
+    pub asset_rate_schedule: Vec<RateBracket>,
+    // Separate rate on income accrued after death; `None` adds that income to the asset base.
+    pub income_rate: Option<Decimal>,
+    pub minimum_fee_amount: Decimal,
+    // Remuneration of a VAT-registered executor attracts VAT at the scenario's `vat_rate`, or the
+    // jurisdiction's standard rate when the scenario leaves it unset.
+    pub vat_applies: bool,
+    pub effective_from: &'static str,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VatRate {
+    pub tax_year_from: u16,
+    pub tax_year_to: Option<u16>,
+    pub standard_rate: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct VatRule {
+    // Standard rates by the tax years they cover.
+    pub rates: Vec<VatRate>,
+    pub effective_from: &'static str,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+impl VatRule {
+    pub fn standard_rate_for(&self, tax_year: u16) -> Option<Decimal> {
+        self.rates
+            .iter()
+            .find(|rate| {
+                tax_year >= rate.tax_year_from
+                    && rate
+                        .tax_year_to
+                        .is_none_or(|tax_year_to| tax_year <= tax_year_to)
+            })
+            .map(|rate| rate.standard_rate)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnRounding {
+    // Half a unit or more rounds up.
+    HalfUp,
+    // Fractions of a unit are dropped.
+    Down,
+}
+
+#[derive(Debug, Clone)]
+pub struct RoundingRule {
+    // Taxable amounts and tax payable are entered on returns to this precision.
+    pub return_decimal_places: u32,
+    pub return_rounding: ReturnRounding,
+    pub source: &'static str,
+    pub source_url: &'static str,
+}
+
+impl RoundingRule {
+    pub fn round_return_amount(&self, amount: Decimal) -> Decimal {
+        let strategy = match self.return_rounding {
+            ReturnRounding::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            ReturnRounding::Down => RoundingStrategy::ToZero,
+        };
+        amount.round_dp_with_strategy(self.return_decimal_places, strategy)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -90,6 +393,14 @@ pub struct JurisdictionTaxRuleSet {
     pub estate_duty: EstateDutyRule,
     pub donations_tax: DonationsTaxRule,
     pub cgt_on_death: CapitalGainsAtDeathRule,
+    pub retirement_funds: RetirementFundRule,
+    pub income_tax: IncomeTaxRule,
+    // `None` where remuneration is left to the court or the will.
+    pub executor_fee: Option<ExecutorFeeRule>,
+    // `None` where the jurisdiction levies no value-added tax.
+    pub vat: Option<VatRule>,
+    pub rounding: RoundingRule,
+    pub state_estate_tax: Option<StateEstateTaxRule>,
 }
 
 #[derive(Debug, Clone)]
@@ -110,6 +421,16 @@ pub enum TaxRuleSelectionError {
         jurisdiction: Jurisdiction,
         tax_year: u16,
     },
+    NoVersionInEffect {
+        jurisdiction: Jurisdiction,
+        date: CalendarDate,
+    },
+    // The version applies but carries no income tax table for the year, and the scenario does
+    // not supply the figures the table would give.
+    MissingIncomeTaxTable {
+        jurisdiction: Jurisdiction,
+        tax_year: u16,
+    },
 }
 
 impl fmt::Display for TaxRuleSelectionError {
@@ -123,6 +444,20 @@ impl fmt::Display for TaxRuleSelectionError {
                 "No tax rule version found for jurisdiction {:?} and tax year {}",
                 jurisdiction, tax_year
             ),
+            TaxRuleSelectionError::NoVersionInEffect { jurisdiction, date } => write!(
+                f,
+                "No tax rule version in effect for jurisdiction {:?} on {}",
+                jurisdiction, date
+            ),
+            TaxRuleSelectionError::MissingIncomeTaxTable {
+                jurisdiction,
+                tax_year,
+            } => write!(
+                f,
+                "No income tax table for jurisdiction {:?} and tax year {}; supply \
+                 `marginal_income_tax_rate` and `final_income_tax_due_amount`",
+                jurisdiction, tax_year
+            ),
         }
     }
 }
@@ -140,23 +475,31 @@ pub fn supported_jurisdictions() -> Vec<Jurisdiction> {
     ]
 }
 
-pub fn tax_rule_registry_for(jurisdiction: Jurisdiction) -> Vec<TaxRuleVersion> {
+fn built_in_tax_rule_catalog(jurisdiction: Jurisdiction) -> Vec<VersionedJurisdictionTaxRuleSet> {
     match jurisdiction {
-        Jurisdiction::SouthAfrica => south_africa_tax_rules_catalog()
-            .into_iter()
-            .map(|versioned| versioned.version)
-            .collect(),
+        Jurisdiction::SouthAfrica => south_africa_tax_rules_catalog(),
         Jurisdiction::UsNewYork
         | Jurisdiction::UsTexas
         | Jurisdiction::UsCalifornia
         | Jurisdiction::UsFlorida
-        | Jurisdiction::UsMinnesota => us_state_tax_rules_catalog(jurisdiction)
-            .into_iter()
-            .map(|versioned| versioned.version)
-            .collect(),
+        | Jurisdiction::UsMinnesota => us_state_tax_rules_catalog(jurisdiction),
     }
 }
 
+// Every version currently served for the jurisdiction: its rule file or compiled-in catalog, with
+// published database versions layered over it.
+pub fn tax_rule_catalog_for(jurisdiction: Jurisdiction) -> Vec<VersionedJurisdictionTaxRuleSet> {
+    loaded_tax_rule_versions(jurisdiction, built_in_tax_rule_catalog)
+        .unwrap_or_else(|| built_in_tax_rule_catalog(jurisdiction))
+}
+
+pub fn tax_rule_registry_for(jurisdiction: Jurisdiction) -> Vec<TaxRuleVersion> {
+    tax_rule_catalog_for(jurisdiction)
+        .into_iter()
+        .map(|versioned| versioned.version)
+        .collect()
+}
+
 pub fn tax_rule_registry() -> Vec<TaxRuleRegistryEntry> {
     supported_jurisdictions()
         .into_iter()
@@ -195,6 +538,10 @@ pub fn tax_rules_for(
     jurisdiction: Jurisdiction,
     tax_year: u16,
 ) -> Result<VersionedJurisdictionTaxRuleSet, TaxRuleSelectionError> {
+    if let Some(versions) = loaded_tax_rule_versions(jurisdiction, built_in_tax_rule_catalog) {
+        return select_tax_rule_version(jurisdiction, versions, tax_year);
+    }
+
     match jurisdiction {
         Jurisdiction::SouthAfrica => south_africa_tax_rules_for_year(tax_year),
         Jurisdiction::UsNewYork
@@ -205,7 +552,31 @@ pub fn tax_rules_for(
     }
 }
 
+pub fn tax_rules_in_effect_on(
+    jurisdiction: Jurisdiction,
+    date: CalendarDate,
+) -> Result<VersionedJurisdictionTaxRuleSet, TaxRuleSelectionError> {
+    select_tax_rule_version_in_effect(jurisdiction, tax_rule_catalog_for(jurisdiction), date)
+}
+
+// A scenario with a date of death uses the version in effect on that date, otherwise the version
+// covering its tax year.
+pub fn tax_rules_for_scenario(
+    input: &EstateScenarioInput,
+) -> Result<VersionedJurisdictionTaxRuleSet, TaxRuleSelectionError> {
+    match input.date_of_death.as_deref().and_then(CalendarDate::parse) {
+        Some(date_of_death) => tax_rules_in_effect_on(input.jurisdiction, date_of_death),
+        None => tax_rules_for(input.jurisdiction, input.tax_year),
+    }
+}
+
 pub fn latest_tax_rules_for(jurisdiction: Jurisdiction) -> VersionedJurisdictionTaxRuleSet {
+    if let Some(latest) = loaded_tax_rule_versions(jurisdiction, built_in_tax_rule_catalog)
+        .and_then(latest_tax_rule_version)
+    {
+        return latest;
+    }
+
     match jurisdiction {
         Jurisdiction::SouthAfrica => south_africa_latest_tax_rules(),
         Jurisdiction::UsNewYork
@@ -220,6 +591,46 @@ pub fn baseline_tax_rules_for(jurisdiction: Jurisdiction) -> JurisdictionTaxRule
     latest_tax_rules_for(jurisdiction).rules
 }
 
+pub fn tax_year_start_month(jurisdiction: Jurisdiction) -> u8 {
+    match jurisdiction {
+        Jurisdiction::SouthAfrica => south_africa_tax_year_start_month(),
+        Jurisdiction::UsNewYork
+        | Jurisdiction::UsTexas
+        | Jurisdiction::UsCalifornia
+        | Jurisdiction::UsFlorida
+        | Jurisdiction::UsMinnesota => us_tax_year_start_month(),
+    }
+}
+
+pub fn tax_year_start_date(jurisdiction: Jurisdiction, tax_year: u16) -> CalendarDate {
+    let month = tax_year_start_month(jurisdiction);
+    CalendarDate {
+        year: if month > 1 { tax_year - 1 } else { tax_year },
+        month,
+        day: 1,
+    }
+}
+
+pub fn reporting_currency_for(jurisdiction: Jurisdiction) -> Currency {
+    match jurisdiction {
+        Jurisdiction::SouthAfrica => south_africa_reporting_currency(),
+        Jurisdiction::UsNewYork
+        | Jurisdiction::UsTexas
+        | Jurisdiction::UsCalifornia
+        | Jurisdiction::UsFlorida
+        | Jurisdiction::UsMinnesota => us_reporting_currency(),
+    }
+}
+
+pub fn tax_year_for_date(jurisdiction: Jurisdiction, date: CalendarDate) -> u16 {
+    let start_month = tax_year_start_month(jurisdiction);
+    if start_month > 1 && date.month >= start_month {
+        date.year + 1
+    } else {
+        date.year
+    }
+}
+
 pub fn is_supported_tax_year(jurisdiction: Jurisdiction, tax_year: u16) -> bool {
     tax_rules_for(jurisdiction, tax_year).is_ok()
 }
@@ -1,5 +1,18 @@
-use crate::core::domain::models::{EstateAsset, EstateScenarioInput, ResidencyStatus};
-use crate::core::rules::tax_rules::{is_supported_tax_year, TaxPayerClass};
+use crate::core::domain::models::{
+    Beneficiary, CalendarDate, CoupleScenarioInput, CrossBorderScenarioInput, CurrencyLiability,
+    DonationsTaxInput, EstateAsset, EstateScenarioInput, ForeignJurisdictionFiling, FxRateTable,
+    LifeInsurancePolicy, LifetimeDonation, PolicyParty, ResidencyStatus, RetirementFundInterest,
+};
+use crate::core::rules::rule_catalog::{
+    tax_year_windows_overlap, uncovered_tax_rule_versions, TaxRuleCatalog,
+};
+use crate::core::rules::rule_publishing::{TaxRuleVersionRecord, TaxRuleVersionStatus};
+use crate::core::rules::tax_rules::{
+    is_supported_tax_year, reporting_currency_for, tax_rules_in_effect_on, tax_year_for_date,
+    Currency, Jurisdiction, JurisdictionTaxRuleSet, RateBracket, TaxPayerClass,
+    VersionedJurisdictionTaxRuleSet,
+};
+use rust_decimal::Decimal;
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -48,22 +61,14 @@ impl fmt::Display for InputValidationError {
 
 impl std::error::Error for InputValidationError {}
 
-fn check_non_negative_finite(issues: &mut Vec<ValidationIssue>, field: String, value: f64) {
-    if !value.is_finite() {
-        issues.push(ValidationIssue::new(field, "Value must be finite"));
-        return;
-    }
-    if value < 0.0 {
+fn check_non_negative(issues: &mut Vec<ValidationIssue>, field: String, value: Decimal) {
+    if value < Decimal::ZERO {
         issues.push(ValidationIssue::new(field, "Value cannot be negative"));
     }
 }
 
-fn check_rate_inclusive(issues: &mut Vec<ValidationIssue>, field: String, value: f64) {
-    if !value.is_finite() {
-        issues.push(ValidationIssue::new(field, "Rate must be finite"));
-        return;
-    }
-    if !(0.0..=1.0).contains(&value) {
+fn check_rate_inclusive(issues: &mut Vec<ValidationIssue>, field: String, value: Decimal) {
+    if !(Decimal::ZERO..=Decimal::ONE).contains(&value) {
         issues.push(ValidationIssue::new(
             field,
             "Rate must be between 0.0 and 1.0 (inclusive)",
@@ -75,6 +80,7 @@ impl EstateAsset {
     fn validate_contract(
         &self,
         index: usize,
+        jurisdiction: Jurisdiction,
         taxpayer_class: TaxPayerClass,
         residency_status: ResidencyStatus,
         issues: &mut Vec<ValidationIssue>,
@@ -88,12 +94,12 @@ impl EstateAsset {
             ));
         }
 
-        check_non_negative_finite(
+        check_non_negative(
             issues,
             format!("{prefix}.market_value_amount"),
             self.market_value_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             issues,
             format!("{prefix}.base_cost_amount"),
             self.base_cost_amount,
@@ -136,7 +142,7 @@ impl EstateAsset {
 
         if matches!(residency_status, ResidencyStatus::NonResident)
             && self.included_in_estate_duty
-            && !self.situs_in_jurisdiction
+            && !self.is_situated_in(jurisdiction)
         {
             issues.push(ValidationIssue::new(
                 format!("{prefix}.situs_in_jurisdiction"),
@@ -146,20 +152,276 @@ impl EstateAsset {
     }
 }
 
-impl EstateScenarioInput {
-    pub fn validate(&self) -> Result<(), InputValidationError> {
-        let mut issues = Vec::new();
+impl LifeInsurancePolicy {
+    fn validate_contract(&self, index: usize, issues: &mut Vec<ValidationIssue>) {
+        let prefix = format!("life_policies[{index}]");
 
-        if !is_supported_tax_year(self.jurisdiction, self.tax_year) {
+        if self.name.trim().is_empty() {
             issues.push(ValidationIssue::new(
-                "tax_year".to_string(),
-                format!(
-                    "Tax year {} is not supported for {:?}",
-                    self.tax_year, self.jurisdiction
-                ),
+                format!("{prefix}.name"),
+                "Policy name cannot be empty",
+            ));
+        }
+
+        check_non_negative(
+            issues,
+            format!("{prefix}.sum_assured_amount"),
+            self.sum_assured_amount,
+        );
+
+        if self.owner == PolicyParty::Estate {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.owner"),
+                "The estate cannot own a policy before death",
+            ));
+        }
+
+        if matches!(
+            self.life_insured,
+            PolicyParty::Estate | PolicyParty::Company
+        ) {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.life_insured"),
+                "Life insured must be a natural person",
+            ));
+        }
+
+        if self.premiums_paid_by == PolicyParty::Estate {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.premiums_paid_by"),
+                "The estate cannot have paid premiums before death",
+            ));
+        }
+    }
+}
+
+impl RetirementFundInterest {
+    fn validate_contract(&self, index: usize, issues: &mut Vec<ValidationIssue>) {
+        let prefix = format!("retirement_funds[{index}]");
+
+        if self.name.trim().is_empty() {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.name"),
+                "Fund name cannot be empty",
+            ));
+        }
+
+        check_non_negative(issues, format!("{prefix}.value_amount"), self.value_amount);
+        check_non_negative(
+            issues,
+            format!("{prefix}.disallowed_contributions_amount"),
+            self.disallowed_contributions_amount,
+        );
+    }
+}
+
+impl Beneficiary {
+    fn validate_contract(
+        &self,
+        index: usize,
+        assets: &[EstateAsset],
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let prefix = format!("beneficiaries[{index}]");
+
+        if self.name.trim().is_empty() {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.name"),
+                "Beneficiary name cannot be empty",
+            ));
+        }
+
+        check_non_negative(
+            issues,
+            format!("{prefix}.legacy_amount"),
+            self.legacy_amount,
+        );
+        check_rate_inclusive(
+            issues,
+            format!("{prefix}.residue_share"),
+            self.residue_share,
+        );
+
+        for (bequest_index, asset_name) in self.specific_bequest_asset_names.iter().enumerate() {
+            if !assets.iter().any(|asset| &asset.name == asset_name) {
+                issues.push(ValidationIssue::new(
+                    format!("{prefix}.specific_bequest_asset_names[{bequest_index}]"),
+                    format!("No asset named '{asset_name}'"),
+                ));
+            }
+        }
+    }
+}
+
+impl CurrencyLiability {
+    fn validate_contract(&self, index: usize, issues: &mut Vec<ValidationIssue>) {
+        let prefix = format!("liabilities[{index}]");
+
+        if self.description.trim().is_empty() {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.description"),
+                "Liability description cannot be empty",
+            ));
+        }
+
+        check_non_negative(issues, format!("{prefix}.amount"), self.amount);
+    }
+}
+
+impl FxRateTable {
+    fn validate_contract(&self, issues: &mut Vec<ValidationIssue>) {
+        if CalendarDate::parse(&self.valuation_date).is_none() {
+            issues.push(ValidationIssue::new(
+                "fx_rates.valuation_date".to_string(),
+                "Valuation date must be a valid `YYYY-MM-DD` date",
             ));
         }
 
+        for (index, rate) in self.rates.iter().enumerate() {
+            let field = format!("fx_rates.rates[{index}].rate");
+            if rate.rate <= Decimal::ZERO {
+                issues.push(ValidationIssue::new(field, "Rate must be greater than 0"));
+            }
+
+            if self.rates[..index]
+                .iter()
+                .any(|earlier| earlier.currency == rate.currency)
+            {
+                issues.push(ValidationIssue::new(
+                    format!("fx_rates.rates[{index}].currency"),
+                    format!("Duplicate rate for {}", rate.currency.code()),
+                ));
+            }
+        }
+    }
+}
+
+fn check_fx_rate_available(
+    issues: &mut Vec<ValidationIssue>,
+    field: String,
+    input: &EstateScenarioInput,
+    currency: Currency,
+) {
+    if currency == reporting_currency_for(input.jurisdiction) {
+        return;
+    }
+    let has_rate = input
+        .fx_rates
+        .as_ref()
+        .is_some_and(|table| table.rate_for(currency).is_some());
+    if !has_rate {
+        issues.push(ValidationIssue::new(
+            field,
+            format!("No FX rate supplied for {} in `fx_rates`", currency.code()),
+        ));
+    }
+}
+
+impl EstateScenarioInput {
+    // With a date of death the rule version is chosen by effective date, so the tax year may be
+    // left at 0 for the engine to derive and otherwise only has to agree with it.
+    fn validate_tax_year_and_dates(&self, issues: &mut Vec<ValidationIssue>) {
+        let date_of_death = match self.date_of_death.as_deref() {
+            Some(raw) => match CalendarDate::parse(raw) {
+                Some(date_of_death) => Some(date_of_death),
+                None => {
+                    issues.push(ValidationIssue::new(
+                        "date_of_death".to_string(),
+                        "Date of death must be a valid `YYYY-MM-DD` date",
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        match date_of_death {
+            Some(date_of_death) => {
+                let tax_year = tax_year_for_date(self.jurisdiction, date_of_death);
+                if self.tax_year != 0 && self.tax_year != tax_year {
+                    issues.push(ValidationIssue::new(
+                        "tax_year".to_string(),
+                        format!(
+                            "Tax year {} does not match the date of death {date_of_death}, which falls in tax year {tax_year} for {:?}",
+                            self.tax_year, self.jurisdiction
+                        ),
+                    ));
+                }
+                if tax_rules_in_effect_on(self.jurisdiction, date_of_death).is_err() {
+                    issues.push(ValidationIssue::new(
+                        "date_of_death".to_string(),
+                        format!(
+                            "No tax rule version is in effect for {:?} on {date_of_death}",
+                            self.jurisdiction
+                        ),
+                    ));
+                }
+            }
+            // An unparseable date of death is reported above.
+            None if self.date_of_death.is_some() => {}
+            None if self.tax_year == 0 => issues.push(ValidationIssue::new(
+                "tax_year".to_string(),
+                "Either `tax_year` or `date_of_death` is required",
+            )),
+            None if !is_supported_tax_year(self.jurisdiction, self.tax_year) => {
+                issues.push(ValidationIssue::new(
+                    "tax_year".to_string(),
+                    format!(
+                        "Tax year {} is not supported for {:?}",
+                        self.tax_year, self.jurisdiction
+                    ),
+                ))
+            }
+            None => {}
+        }
+
+        let valuation_date = match self.valuation_date.as_deref() {
+            Some(raw) => {
+                let Some(valuation_date) = CalendarDate::parse(raw) else {
+                    issues.push(ValidationIssue::new(
+                        "valuation_date".to_string(),
+                        "Valuation date must be a valid `YYYY-MM-DD` date",
+                    ));
+                    return;
+                };
+                if self.date_of_death.is_none() {
+                    issues.push(ValidationIssue::new(
+                        "valuation_date".to_string(),
+                        "`valuation_date` requires `date_of_death`",
+                    ));
+                } else if date_of_death.is_some_and(|date_of_death| valuation_date < date_of_death)
+                {
+                    issues.push(ValidationIssue::new(
+                        "valuation_date".to_string(),
+                        "Valuation date cannot be before the date of death",
+                    ));
+                }
+                Some(valuation_date)
+            }
+            None => date_of_death,
+        };
+
+        // Foreign amounts are converted at the rates on the date the assets are valued.
+        let fx_valuation_date = self
+            .fx_rates
+            .as_ref()
+            .and_then(|table| CalendarDate::parse(&table.valuation_date));
+        if let (Some(valuation_date), Some(fx_valuation_date)) = (valuation_date, fx_valuation_date)
+        {
+            if fx_valuation_date != valuation_date {
+                issues.push(ValidationIssue::new(
+                    "fx_rates.valuation_date".to_string(),
+                    format!("FX rates must be quoted at the valuation date {valuation_date}"),
+                ));
+            }
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+
+        self.validate_tax_year_and_dates(&mut issues);
+
         if self.assets.is_empty() {
             issues.push(ValidationIssue::new(
                 "assets".to_string(),
@@ -170,7 +432,7 @@ impl EstateScenarioInput {
         let assets_with_positive_value = self
             .assets
             .iter()
-            .any(|asset| asset.market_value_amount.is_finite() && asset.market_value_amount > 0.0);
+            .any(|asset| asset.market_value_amount > Decimal::ZERO);
         if !self.assets.is_empty() && !assets_with_positive_value {
             issues.push(ValidationIssue::new(
                 "assets".to_string(),
@@ -178,86 +440,173 @@ impl EstateScenarioInput {
             ));
         }
 
-        check_rate_inclusive(
-            &mut issues,
-            "marginal_income_tax_rate".to_string(),
-            self.marginal_income_tax_rate,
-        );
-        check_rate_inclusive(
-            &mut issues,
-            "executor_fee_rate".to_string(),
-            self.executor_fee_rate,
-        );
-        check_rate_inclusive(&mut issues, "vat_rate".to_string(), self.vat_rate);
+        if let Some(marginal_income_tax_rate) = self.marginal_income_tax_rate {
+            check_rate_inclusive(
+                &mut issues,
+                "marginal_income_tax_rate".to_string(),
+                marginal_income_tax_rate,
+            );
+        }
+        if let Some(executor_fee_rate) = self.executor_fee_rate {
+            check_rate_inclusive(
+                &mut issues,
+                "executor_fee_rate".to_string(),
+                executor_fee_rate,
+            );
+        }
+        if let Some(vat_rate) = self.vat_rate {
+            check_rate_inclusive(&mut issues, "vat_rate".to_string(), vat_rate);
+        }
 
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "debts_and_loans_amount".to_string(),
             self.debts_and_loans_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "funeral_costs_amount".to_string(),
             self.funeral_costs_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "administration_costs_amount".to_string(),
             self.administration_costs_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "masters_office_fees_amount".to_string(),
             self.masters_office_fees_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "conveyancing_costs_amount".to_string(),
             self.conveyancing_costs_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "other_settlement_costs_amount".to_string(),
             self.other_settlement_costs_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
-            "final_income_tax_due_amount".to_string(),
-            self.final_income_tax_due_amount,
+            "taxable_income_in_year_of_death_amount".to_string(),
+            self.taxable_income_in_year_of_death_amount,
         );
-        check_non_negative_finite(
+        if let Some(final_income_tax_due_amount) = self.final_income_tax_due_amount {
+            check_non_negative(
+                &mut issues,
+                "final_income_tax_due_amount".to_string(),
+                final_income_tax_due_amount,
+            );
+        }
+        check_non_negative(
             &mut issues,
             "ongoing_estate_income_tax_provision_amount".to_string(),
             self.ongoing_estate_income_tax_provision_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "additional_allowable_estate_transfer_tax_deductions_amount".to_string(),
             self.additional_allowable_estate_transfer_tax_deductions_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "ported_estate_tax_exemption_amount".to_string(),
             self.ported_estate_tax_exemption_amount,
         );
-        check_non_negative_finite(
-            &mut issues,
-            "primary_residence_cgt_exclusion_cap_amount".to_string(),
-            self.primary_residence_cgt_exclusion_cap_amount,
-        );
-        check_non_negative_finite(
+        if let Some(primary_residence_cgt_exclusion_cap_amount) =
+            self.primary_residence_cgt_exclusion_cap_amount
+        {
+            check_non_negative(
+                &mut issues,
+                "primary_residence_cgt_exclusion_cap_amount".to_string(),
+                primary_residence_cgt_exclusion_cap_amount,
+            );
+        }
+        check_non_negative(
             &mut issues,
             "external_liquidity_proceeds_amount".to_string(),
             self.external_liquidity_proceeds_amount,
         );
-        check_non_negative_finite(
+        check_non_negative(
             &mut issues,
             "cash_reserve_amount".to_string(),
             self.cash_reserve_amount,
         );
 
+        let marital_property = &self.marital_property;
+        for (field, value) in [
+            (
+                "deceased_commencement_value_amount",
+                marital_property.deceased_commencement_value_amount,
+            ),
+            (
+                "deceased_excluded_assets_amount",
+                marital_property.deceased_excluded_assets_amount,
+            ),
+            (
+                "spouse_net_estate_amount",
+                marital_property.spouse_net_estate_amount,
+            ),
+            (
+                "spouse_commencement_value_amount",
+                marital_property.spouse_commencement_value_amount,
+            ),
+            (
+                "spouse_excluded_assets_amount",
+                marital_property.spouse_excluded_assets_amount,
+            ),
+        ] {
+            check_non_negative(&mut issues, format!("marital_property.{field}"), value);
+        }
+
+        for (index, policy) in self.life_policies.iter().enumerate() {
+            policy.validate_contract(index, &mut issues);
+        }
+
+        for (index, fund) in self.retirement_funds.iter().enumerate() {
+            fund.validate_contract(index, &mut issues);
+        }
+
+        for (index, beneficiary) in self.beneficiaries.iter().enumerate() {
+            beneficiary.validate_contract(index, &self.assets, &mut issues);
+        }
+
+        let total_residue_share = self
+            .beneficiaries
+            .iter()
+            .map(|beneficiary| beneficiary.residue_share)
+            .sum::<Decimal>();
+        if total_residue_share > Decimal::ONE {
+            issues.push(ValidationIssue::new(
+                "beneficiaries".to_string(),
+                "Residue shares cannot exceed 100% in total",
+            ));
+        }
+
+        for asset in &self.assets {
+            let bequest_count = self
+                .beneficiaries
+                .iter()
+                .flat_map(|beneficiary| &beneficiary.specific_bequest_asset_names)
+                .filter(|name| *name == &asset.name)
+                .count();
+            if bequest_count > 1 {
+                issues.push(ValidationIssue::new(
+                    "beneficiaries".to_string(),
+                    format!("Asset '{}' is bequeathed more than once", asset.name),
+                ));
+            }
+        }
+
+        check_non_negative(
+            &mut issues,
+            "income_accrued_after_death_amount".to_string(),
+            self.income_accrued_after_death_amount,
+        );
         if let Some(explicit_executor_fee_amount) = self.explicit_executor_fee_amount {
-            check_non_negative_finite(
+            check_non_negative(
                 &mut issues,
                 "explicit_executor_fee_amount".to_string(),
                 explicit_executor_fee_amount,
@@ -267,7 +616,9 @@ impl EstateScenarioInput {
         if matches!(
             self.taxpayer_class,
             TaxPayerClass::Company | TaxPayerClass::Trust
-        ) && self.primary_residence_cgt_exclusion_cap_amount > 0.0
+        ) && self
+            .primary_residence_cgt_exclusion_cap_amount
+            .is_some_and(|cap_amount| cap_amount > Decimal::ZERO)
         {
             issues.push(ValidationIssue::new(
                 "primary_residence_cgt_exclusion_cap_amount".to_string(),
@@ -278,10 +629,848 @@ impl EstateScenarioInput {
         for (index, asset) in self.assets.iter().enumerate() {
             asset.validate_contract(
                 index,
+                self.jurisdiction,
                 self.taxpayer_class,
                 self.residency_status,
                 &mut issues,
             );
+            if let Some(currency) = asset.currency {
+                check_fx_rate_available(
+                    &mut issues,
+                    format!("assets[{index}].currency"),
+                    self,
+                    currency,
+                );
+            }
+        }
+
+        for (index, liability) in self.liabilities.iter().enumerate() {
+            liability.validate_contract(index, &mut issues);
+            check_fx_rate_available(
+                &mut issues,
+                format!("liabilities[{index}].currency"),
+                self,
+                liability.currency,
+            );
+        }
+
+        if let Some(fx_rates) = &self.fx_rates {
+            fx_rates.validate_contract(&mut issues);
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+impl CoupleScenarioInput {
+    pub fn validate(&self) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+
+        for (prefix, spouse) in [("spouse_a", &self.spouse_a), ("spouse_b", &self.spouse_b)] {
+            if let Err(err) = spouse.validate() {
+                for mut issue in err.issues {
+                    issue.field = format!("{prefix}.{}", issue.field);
+                    issues.push(issue);
+                }
+            }
+        }
+
+        if self.spouse_a.jurisdiction != self.spouse_b.jurisdiction {
+            issues.push(ValidationIssue::new(
+                "spouse_b.jurisdiction".to_string(),
+                "Both spouses must use the same jurisdiction to port the unused exemption",
+            ));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+fn is_us_state(jurisdiction: Jurisdiction) -> bool {
+    match jurisdiction {
+        Jurisdiction::SouthAfrica => false,
+        Jurisdiction::UsNewYork
+        | Jurisdiction::UsTexas
+        | Jurisdiction::UsCalifornia
+        | Jurisdiction::UsFlorida
+        | Jurisdiction::UsMinnesota => true,
+    }
+}
+
+impl ForeignJurisdictionFiling {
+    fn validate_contract(
+        &self,
+        index: usize,
+        estate: &EstateScenarioInput,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let prefix = format!("foreign_filings[{index}]");
+
+        // US states share the federal estate tax, so they are never foreign to each other.
+        if self.jurisdiction == estate.jurisdiction
+            || (is_us_state(self.jurisdiction) && is_us_state(estate.jurisdiction))
+        {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.jurisdiction"),
+                "Foreign filing must be in a different country from the estate's jurisdiction",
+            ));
+        }
+
+        if !is_supported_tax_year(self.jurisdiction, self.tax_year) {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.tax_year"),
+                format!(
+                    "Tax year {} is not supported for {:?}",
+                    self.tax_year, self.jurisdiction
+                ),
+            ));
+        }
+
+        check_non_negative(
+            issues,
+            format!("{prefix}.administration_costs_amount"),
+            self.administration_costs_amount,
+        );
+        check_fx_rate_available(
+            issues,
+            format!("{prefix}.jurisdiction"),
+            estate,
+            reporting_currency_for(self.jurisdiction),
+        );
+    }
+}
+
+impl CrossBorderScenarioInput {
+    pub fn validate(&self) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+
+        if let Err(err) = self.estate.validate() {
+            for mut issue in err.issues {
+                issue.field = format!("estate.{}", issue.field);
+                issues.push(issue);
+            }
+        }
+
+        if self.estate.residency_status != ResidencyStatus::Resident {
+            issues.push(ValidationIssue::new(
+                "estate.residency_status".to_string(),
+                "Cross-border estates are calculated from the domicile; use `Resident`",
+            ));
+        }
+
+        for (index, filing) in self.foreign_filings.iter().enumerate() {
+            filing.validate_contract(index, &self.estate, &mut issues);
+
+            if self.foreign_filings[..index]
+                .iter()
+                .any(|earlier| earlier.jurisdiction == filing.jurisdiction)
+            {
+                issues.push(ValidationIssue::new(
+                    format!("foreign_filings[{index}].jurisdiction"),
+                    format!("Duplicate foreign filing for {:?}", filing.jurisdiction),
+                ));
+            } else if is_us_state(filing.jurisdiction)
+                && self.foreign_filings[..index]
+                    .iter()
+                    .any(|earlier| is_us_state(earlier.jurisdiction))
+            {
+                issues.push(ValidationIssue::new(
+                    format!("foreign_filings[{index}].jurisdiction"),
+                    "US-situs assets must be filed under a single state",
+                ));
+            }
+        }
+
+        for (index, asset) in self.estate.assets.iter().enumerate() {
+            let Some(situs) = asset.situs_jurisdiction else {
+                continue;
+            };
+            if situs != self.estate.jurisdiction
+                && !self
+                    .foreign_filings
+                    .iter()
+                    .any(|filing| filing.jurisdiction == situs)
+            {
+                issues.push(ValidationIssue::new(
+                    format!("estate.assets[{index}].situs_jurisdiction"),
+                    format!("No foreign filing for {situs:?}"),
+                ));
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+impl LifetimeDonation {
+    fn validate_contract(
+        &self,
+        index: usize,
+        jurisdiction: Jurisdiction,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let prefix = format!("donations[{index}]");
+
+        if self.donee.trim().is_empty() {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.donee"),
+                "Donee cannot be empty",
+            ));
+        }
+
+        check_non_negative(issues, format!("{prefix}.amount"), self.amount);
+
+        match CalendarDate::parse(&self.donated_on) {
+            Some(date) => {
+                let tax_year = tax_year_for_date(jurisdiction, date);
+                if !is_supported_tax_year(jurisdiction, tax_year) {
+                    issues.push(ValidationIssue::new(
+                        format!("{prefix}.donated_on"),
+                        format!(
+                            "Donation date falls in tax year {tax_year}, which is not supported for {jurisdiction:?}"
+                        ),
+                    ));
+                }
+            }
+            None => issues.push(ValidationIssue::new(
+                format!("{prefix}.donated_on"),
+                "Donation date must be a valid `YYYY-MM-DD` date",
+            )),
+        }
+    }
+}
+
+impl DonationsTaxInput {
+    pub fn validate(&self) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+
+        // US gift tax needs per-donee exclusions and the lifetime exemption, which are not
+        // modelled, so only South African donations tax is calculated.
+        if self.jurisdiction != Jurisdiction::SouthAfrica {
+            issues.push(ValidationIssue::new(
+                "jurisdiction".to_string(),
+                format!(
+                    "Donations tax is only calculated for SouthAfrica; {:?} gift tax is not supported",
+                    self.jurisdiction
+                ),
+            ));
+        }
+
+        if self.donations.is_empty() {
+            issues.push(ValidationIssue::new(
+                "donations".to_string(),
+                "At least one donation is required",
+            ));
+        }
+
+        check_non_negative(
+            &mut issues,
+            "prior_cumulative_taxable_donations_amount".to_string(),
+            self.prior_cumulative_taxable_donations_amount,
+        );
+
+        for (index, donation) in self.donations.iter().enumerate() {
+            donation.validate_contract(index, self.jurisdiction, &mut issues);
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+fn check_rule_date(issues: &mut Vec<ValidationIssue>, field: String, value: &str) {
+    if CalendarDate::parse(value).is_none() {
+        issues.push(ValidationIssue::new(
+            field,
+            "Date must be a valid `YYYY-MM-DD` date",
+        ));
+    }
+}
+
+fn check_rule_text(issues: &mut Vec<ValidationIssue>, field: String, value: &str) {
+    if value.trim().is_empty() {
+        issues.push(ValidationIssue::new(field, "Value cannot be empty"));
+    }
+}
+
+fn check_rate_schedule(issues: &mut Vec<ValidationIssue>, field: String, schedule: &[RateBracket]) {
+    if schedule.is_empty() {
+        issues.push(ValidationIssue::new(
+            field,
+            "Rate schedule needs at least one bracket",
+        ));
+        return;
+    }
+
+    for (index, bracket) in schedule.iter().enumerate() {
+        check_non_negative(
+            issues,
+            format!("{field}[{index}].from_amount"),
+            bracket.from_amount,
+        );
+        check_rate_inclusive(issues, format!("{field}[{index}].rate"), bracket.rate);
+        if index > 0 && bracket.from_amount <= schedule[index - 1].from_amount {
+            issues.push(ValidationIssue::new(
+                format!("{field}[{index}].from_amount"),
+                "Brackets must be in ascending order of `from_amount`",
+            ));
+        }
+    }
+}
+
+// Each window runs from `tax_year_from` to `tax_year_to`, or is open-ended when that is `None`.
+fn check_tax_year_windows(
+    issues: &mut Vec<ValidationIssue>,
+    field: &str,
+    windows: &[(u16, Option<u16>)],
+) {
+    for (index, &(tax_year_from, tax_year_to)) in windows.iter().enumerate() {
+        if tax_year_to.is_some_and(|tax_year_to| tax_year_to < tax_year_from) {
+            issues.push(ValidationIssue::new(
+                format!("{field}[{index}].tax_year_to"),
+                "`tax_year_to` cannot be before `tax_year_from`",
+            ));
+        }
+
+        for (other_index, &(other_from, other_to)) in windows[..index].iter().enumerate() {
+            let overlaps = tax_year_to.is_none_or(|tax_year_to| other_from <= tax_year_to)
+                && other_to.is_none_or(|other_to| tax_year_from <= other_to);
+            if overlaps {
+                issues.push(ValidationIssue::new(
+                    format!("{field}[{index}].tax_year_from"),
+                    format!("Tax-year window overlaps {field}[{other_index}]"),
+                ));
+            }
+        }
+    }
+}
+
+// Effective-date windows must neither overlap nor leave days between one version and the next, so
+// every date of death from the first version on selects exactly one version.
+fn check_effective_windows(
+    issues: &mut Vec<ValidationIssue>,
+    versions: &[VersionedJurisdictionTaxRuleSet],
+) {
+    let mut windows = versions
+        .iter()
+        .enumerate()
+        .filter_map(|(index, versioned)| {
+            let effective_from = CalendarDate::parse(versioned.version.effective_from)?;
+            let effective_to = match versioned.version.effective_to {
+                Some(effective_to) => Some(CalendarDate::parse(effective_to)?),
+                None => None,
+            };
+            Some((index, effective_from, effective_to))
+        })
+        .collect::<Vec<_>>();
+    windows.sort_by_key(|&(_, effective_from, _)| effective_from);
+
+    for (position, &(index, effective_from, effective_to)) in windows.iter().enumerate() {
+        for &(other_index, other_from, other_to) in &windows[..position] {
+            let overlaps = effective_to.is_none_or(|effective_to| other_from <= effective_to)
+                && other_to.is_none_or(|other_to| effective_from <= other_to);
+            if overlaps {
+                issues.push(ValidationIssue::new(
+                    format!("versions[{index}].version.effective_from"),
+                    format!("Effective window overlaps versions[{other_index}]"),
+                ));
+            }
+        }
+
+        if let Some(&(previous_index, _, Some(previous_to))) = position
+            .checked_sub(1)
+            .and_then(|previous| windows.get(previous))
+        {
+            if previous_to.next_day() < effective_from {
+                issues.push(ValidationIssue::new(
+                    format!("versions[{index}].version.effective_from"),
+                    format!(
+                        "Effective window leaves a gap after versions[{previous_index}], which ends on {previous_to}"
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+impl JurisdictionTaxRuleSet {
+    fn validate_contract(&self, prefix: &str, issues: &mut Vec<ValidationIssue>) {
+        let estate_duty = &self.estate_duty;
+        check_non_negative(
+            issues,
+            format!("{prefix}.estate_duty.exemption_amount"),
+            estate_duty.exemption_amount,
+        );
+        if let Some(amount) = estate_duty.non_resident_exemption_amount {
+            check_non_negative(
+                issues,
+                format!("{prefix}.estate_duty.non_resident_exemption_amount"),
+                amount,
+            );
+        }
+        check_rate_schedule(
+            issues,
+            format!("{prefix}.estate_duty.rate_schedule"),
+            &estate_duty.rate_schedule,
+        );
+
+        let donations = &self.donations_tax;
+        check_non_negative(
+            issues,
+            format!("{prefix}.donations_tax.annual_exemption_natural_person_amount"),
+            donations.annual_exemption_natural_person_amount,
+        );
+        check_non_negative(
+            issues,
+            format!("{prefix}.donations_tax.annual_exemption_non_natural_casual_gifts_amount"),
+            donations.annual_exemption_non_natural_casual_gifts_amount,
+        );
+        check_non_negative(
+            issues,
+            format!("{prefix}.donations_tax.primary_rate_cap_cumulative_amount"),
+            donations.primary_rate_cap_cumulative_amount,
+        );
+        check_rate_inclusive(
+            issues,
+            format!("{prefix}.donations_tax.primary_rate"),
+            donations.primary_rate,
+        );
+        check_rate_inclusive(
+            issues,
+            format!("{prefix}.donations_tax.secondary_rate"),
+            donations.secondary_rate,
+        );
+
+        let cgt = &self.cgt_on_death;
+        check_non_negative(
+            issues,
+            format!("{prefix}.cgt_on_death.annual_exclusion_in_year_of_death_amount"),
+            cgt.annual_exclusion_in_year_of_death_amount,
+        );
+        if let Some(annual_exclusion_amount) = cgt.annual_exclusion_amount {
+            check_non_negative(
+                issues,
+                format!("{prefix}.cgt_on_death.annual_exclusion_amount"),
+                annual_exclusion_amount,
+            );
+        }
+        check_non_negative(
+            issues,
+            format!("{prefix}.cgt_on_death.primary_residence_exclusion_amount"),
+            cgt.primary_residence_exclusion_amount,
+        );
+        for (name, rate) in [
+            (
+                "inclusion_rate_natural_person",
+                cgt.inclusion_rate_natural_person,
+            ),
+            ("inclusion_rate_company", cgt.inclusion_rate_company),
+            ("inclusion_rate_trust", cgt.inclusion_rate_trust),
+        ] {
+            check_rate_inclusive(issues, format!("{prefix}.cgt_on_death.{name}"), rate);
+        }
+
+        let income_tax = &self.income_tax;
+        if income_tax.tables.is_empty() {
+            issues.push(ValidationIssue::new(
+                format!("{prefix}.income_tax.tables"),
+                "At least one income tax table is required",
+            ));
+        }
+        for (index, table) in income_tax.tables.iter().enumerate() {
+            let table_prefix = format!("{prefix}.income_tax.tables[{index}]");
+            check_rate_schedule(
+                issues,
+                format!("{table_prefix}.rate_schedule"),
+                &table.rate_schedule,
+            );
+            check_rate_schedule(
+                issues,
+                format!("{table_prefix}.trust_rate_schedule"),
+                &table.trust_rate_schedule,
+            );
+            check_rate_schedule(
+                issues,
+                format!("{table_prefix}.company_rate_schedule"),
+                &table.company_rate_schedule,
+            );
+            for (rebate_index, rebate) in table.rebates.iter().enumerate() {
+                check_non_negative(
+                    issues,
+                    format!("{table_prefix}.rebates[{rebate_index}].amount"),
+                    rebate.amount,
+                );
+            }
+        }
+        let table_windows = income_tax
+            .tables
+            .iter()
+            .map(|table| (table.tax_year_from, table.tax_year_to))
+            .collect::<Vec<_>>();
+        check_tax_year_windows(
+            issues,
+            &format!("{prefix}.income_tax.tables"),
+            &table_windows,
+        );
+
+        if let Some(executor_fee) = &self.executor_fee {
+            check_rate_schedule(
+                issues,
+                format!("{prefix}.executor_fee.asset_rate_schedule"),
+                &executor_fee.asset_rate_schedule,
+            );
+            if let Some(rate) = executor_fee.income_rate {
+                check_rate_inclusive(issues, format!("{prefix}.executor_fee.income_rate"), rate);
+            }
+            check_non_negative(
+                issues,
+                format!("{prefix}.executor_fee.minimum_fee_amount"),
+                executor_fee.minimum_fee_amount,
+            );
+        }
+
+        if let Some(vat) = &self.vat {
+            for (index, rate) in vat.rates.iter().enumerate() {
+                check_rate_inclusive(
+                    issues,
+                    format!("{prefix}.vat.rates[{index}].standard_rate"),
+                    rate.standard_rate,
+                );
+            }
+            let rate_windows = vat
+                .rates
+                .iter()
+                .map(|rate| (rate.tax_year_from, rate.tax_year_to))
+                .collect::<Vec<_>>();
+            check_tax_year_windows(issues, &format!("{prefix}.vat.rates"), &rate_windows);
+        }
+
+        if let Some(state_estate_tax) = &self.state_estate_tax {
+            check_non_negative(
+                issues,
+                format!("{prefix}.state_estate_tax.exemption_amount"),
+                state_estate_tax.exemption_amount,
+            );
+            check_rate_schedule(
+                issues,
+                format!("{prefix}.state_estate_tax.rate_schedule"),
+                &state_estate_tax.rate_schedule,
+            );
+            if state_estate_tax
+                .credit_phase_out_ceiling_ratio
+                .is_some_and(|ratio| ratio < Decimal::ONE)
+            {
+                issues.push(ValidationIssue::new(
+                    format!("{prefix}.state_estate_tax.credit_phase_out_ceiling_ratio"),
+                    "Phase-out ceiling must be at least 1.0 times the exemption",
+                ));
+            }
+        }
+
+        for (name, effective_from, source, source_url) in [
+            (
+                "estate_duty",
+                estate_duty.effective_from,
+                estate_duty.source,
+                estate_duty.source_url,
+            ),
+            (
+                "donations_tax",
+                donations.effective_from,
+                donations.source,
+                donations.source_url,
+            ),
+            (
+                "cgt_on_death",
+                cgt.effective_from,
+                cgt.source,
+                cgt.source_url,
+            ),
+            (
+                "retirement_funds",
+                self.retirement_funds.effective_from,
+                self.retirement_funds.source,
+                self.retirement_funds.source_url,
+            ),
+            (
+                "income_tax",
+                income_tax.effective_from,
+                income_tax.source,
+                income_tax.source_url,
+            ),
+        ] {
+            check_rule_date(
+                issues,
+                format!("{prefix}.{name}.effective_from"),
+                effective_from,
+            );
+            check_rule_text(issues, format!("{prefix}.{name}.source"), source);
+            check_rule_text(issues, format!("{prefix}.{name}.source_url"), source_url);
+        }
+        if let Some(exemption_portable_from) = estate_duty.exemption_portable_from {
+            check_rule_date(
+                issues,
+                format!("{prefix}.estate_duty.exemption_portable_from"),
+                exemption_portable_from,
+            );
+        }
+    }
+}
+
+impl TaxRuleCatalog {
+    pub fn validate(&self) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+
+        if self.versions.is_empty() {
+            issues.push(ValidationIssue::new(
+                "versions".to_string(),
+                "At least one rule version is required",
+            ));
+        }
+
+        for (index, versioned) in self.versions.iter().enumerate() {
+            let prefix = format!("versions[{index}]");
+            let version = &versioned.version;
+
+            check_rule_text(
+                &mut issues,
+                format!("{prefix}.version.version_id"),
+                version.version_id,
+            );
+            if self.versions[..index]
+                .iter()
+                .any(|earlier| earlier.version.version_id == version.version_id)
+            {
+                issues.push(ValidationIssue::new(
+                    format!("{prefix}.version.version_id"),
+                    format!("Duplicate version id '{}'", version.version_id),
+                ));
+            }
+
+            check_rule_date(
+                &mut issues,
+                format!("{prefix}.version.effective_from"),
+                version.effective_from,
+            );
+            check_rule_date(
+                &mut issues,
+                format!("{prefix}.version.source_last_verified_on"),
+                version.source_last_verified_on,
+            );
+            if let Some(effective_to) = version.effective_to {
+                check_rule_date(
+                    &mut issues,
+                    format!("{prefix}.version.effective_to"),
+                    effective_to,
+                );
+                if let (Some(from), Some(to)) = (
+                    CalendarDate::parse(version.effective_from),
+                    CalendarDate::parse(effective_to),
+                ) {
+                    if to < from {
+                        issues.push(ValidationIssue::new(
+                            format!("{prefix}.version.effective_to"),
+                            "`effective_to` cannot be before `effective_from`",
+                        ));
+                    }
+                }
+            }
+
+            versioned
+                .rules
+                .validate_contract(&format!("{prefix}.rules"), &mut issues);
+        }
+
+        let version_windows = self
+            .versions
+            .iter()
+            .map(|versioned| {
+                (
+                    versioned.version.tax_year_from,
+                    versioned.version.tax_year_to,
+                )
+            })
+            .collect::<Vec<_>>();
+        check_tax_year_windows(&mut issues, "versions", &version_windows);
+        check_effective_windows(&mut issues, &self.versions);
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+impl VersionedJurisdictionTaxRuleSet {
+    // A draft is checked on its own; overlaps with published versions are checked on publish.
+    pub fn validate_draft(
+        &self,
+        jurisdiction: Jurisdiction,
+        author: &str,
+    ) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+        check_rule_text(&mut issues, "author".to_string(), author);
+
+        let catalog = TaxRuleCatalog {
+            jurisdiction,
+            versions: vec![self.clone()],
+        };
+        if let Err(catalog_error) = catalog.validate() {
+            issues.extend(catalog_error.issues.into_iter().map(|issue| {
+                ValidationIssue::new(
+                    issue.field.replacen("versions[0]", "rule_set", 1),
+                    issue.message,
+                )
+            }));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+fn check_record_status<R>(
+    issues: &mut Vec<ValidationIssue>,
+    record: &TaxRuleVersionRecord<R>,
+    expected: TaxRuleVersionStatus,
+    action: &str,
+) {
+    if record.status != expected {
+        issues.push(ValidationIssue::new(
+            "status".to_string(),
+            format!(
+                "Only {} versions can be {action}; version {} is {}",
+                expected.code(),
+                record.id,
+                record.status.code()
+            ),
+        ));
+    }
+}
+
+impl<R> TaxRuleVersionRecord<R> {
+    pub fn validate_review(&self, reviewer: &str) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+        check_record_status(&mut issues, self, TaxRuleVersionStatus::Draft, "reviewed");
+        check_rule_text(&mut issues, "reviewer".to_string(), reviewer);
+        if reviewer.trim() == self.author.trim() {
+            issues.push(ValidationIssue::new(
+                "reviewer".to_string(),
+                "Reviewer must be someone other than the author",
+            ));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+
+    // Published versions are retired when withdrawn; drafts when rejected or abandoned.
+    pub fn validate_retire(&self, retired_by: &str) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+        check_rule_text(&mut issues, "retired_by".to_string(), retired_by);
+        if self.status == TaxRuleVersionStatus::Retired {
+            issues.push(ValidationIssue::new(
+                "status".to_string(),
+                format!("Version {} is already retired", self.id),
+            ));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(InputValidationError::new(issues))
+        }
+    }
+}
+
+impl TaxRuleVersionRecord {
+    // `published` holds the jurisdiction's currently published records; `supersedes` names the
+    // ones whose window this version takes over. Those starting earlier have their window closed
+    // the year before this one starts, and those it covers entirely are retired.
+    pub fn validate_publish(
+        &self,
+        published_by: &str,
+        published: &[TaxRuleVersionRecord],
+        supersedes: &[String],
+    ) -> Result<(), InputValidationError> {
+        let mut issues = Vec::new();
+        check_record_status(&mut issues, self, TaxRuleVersionStatus::Draft, "published");
+        check_rule_text(&mut issues, "published_by".to_string(), published_by);
+        if !self.review.as_ref().is_some_and(|review| review.approved) {
+            issues.push(ValidationIssue::new(
+                "review".to_string(),
+                "Version needs an approving review before it can be published",
+            ));
+        }
+
+        let version = &self.versioned.version;
+        for (index, version_id) in supersedes.iter().enumerate() {
+            let Some(record) = published
+                .iter()
+                .find(|record| record.versioned.version.version_id == version_id.as_str())
+            else {
+                issues.push(ValidationIssue::new(
+                    format!("supersedes[{index}]"),
+                    format!(
+                        "'{version_id}' is not a published {:?} version",
+                        self.jurisdiction
+                    ),
+                ));
+                continue;
+            };
+            if !tax_year_windows_overlap(version, &record.versioned.version) {
+                issues.push(ValidationIssue::new(
+                    format!("supersedes[{index}]"),
+                    format!("'{version_id}' does not overlap this version; retire it instead"),
+                ));
+            } else if uncovered_tax_rule_versions(&record.versioned, version).len() > 1 {
+                issues.push(ValidationIssue::new(
+                    format!("supersedes[{index}]"),
+                    format!(
+                        "This version falls inside the window of '{version_id}', which would have \
+                         to be split in two"
+                    ),
+                ));
+            }
+        }
+
+        for record in published.iter().filter(|record| {
+            !supersedes
+                .iter()
+                .any(|version_id| record.versioned.version.version_id == version_id.as_str())
+        }) {
+            let other = &record.versioned.version;
+            if tax_year_windows_overlap(version, other) {
+                issues.push(ValidationIssue::new(
+                    "rule_set.version.tax_year_from".to_string(),
+                    format!(
+                        "Tax-year window overlaps published version '{}'; list it in `supersedes` to close or retire it",
+                        other.version_id
+                    ),
+                ));
+            }
         }
 
         if issues.is_empty() {
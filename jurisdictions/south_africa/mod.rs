@@ -1,7 +1,12 @@
 use crate::core::rules::tax_rules::{
-    CapitalGainsAtDeathRule, DonationsTaxRule, EstateDutyRule, Jurisdiction,
-    JurisdictionTaxRuleSet, TaxRuleSelectionError, TaxRuleVersion, VersionedJurisdictionTaxRuleSet,
+    CapitalGainsAtDeathRule, Currency, DonationsTaxRule, EstateDutyRule, EstateTaxApportionment,
+    ExecutorFeeRule, IncomeTaxRebate, IncomeTaxRule, IncomeTaxTable, Jurisdiction,
+    JurisdictionTaxRuleSet, LifePolicyInclusion, RateBracket, RetirementFundRule, ReturnRounding,
+    RoundingRule, TaxRuleSelectionError, TaxRuleVersion, VatRate, VatRule,
+    VersionedJurisdictionTaxRuleSet,
 };
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
 #[derive(Debug, Clone)]
 pub struct LegalInstrument {
@@ -25,20 +30,25 @@ pub struct SouthAfricaJurisdictionLaw {
 pub struct SouthAfricaTaxSummary {
     pub tax_year_context: &'static str,
     pub rates_last_verified_on: &'static str,
-    pub estate_duty_rate_main: f64,
-    pub estate_duty_rate_above_30m: f64,
-    pub estate_duty_exemption_amount: f64,
-    pub donations_tax_rate_main: f64,
-    pub donations_tax_rate_above_30m: f64,
-    pub cgt_annual_exclusion_year_of_death_amount: f64,
+    pub estate_duty_rate_main: Decimal,
+    pub estate_duty_rate_above_30m: Decimal,
+    pub estate_duty_exemption_amount: Decimal,
+    pub donations_tax_rate_main: Decimal,
+    pub donations_tax_rate_above_30m: Decimal,
+    pub cgt_annual_exclusion_year_of_death_amount: Decimal,
 }
 
 fn summarize_tax_rules(rules: &JurisdictionTaxRuleSet) -> SouthAfricaTaxSummary {
     SouthAfricaTaxSummary {
-        tax_year_context: "Tax years from 2018 onwards",
+        tax_year_context: "Tax years from 2019 onwards; historical versions back to 2002",
         rates_last_verified_on: "2026-02-21",
-        estate_duty_rate_main: rules.estate_duty.primary_rate,
-        estate_duty_rate_above_30m: rules.estate_duty.secondary_rate,
+        estate_duty_rate_main: rules
+            .estate_duty
+            .rate_schedule
+            .first()
+            .map(|bracket| bracket.rate)
+            .unwrap_or(Decimal::ZERO),
+        estate_duty_rate_above_30m: rules.estate_duty.top_rate(),
         estate_duty_exemption_amount: rules.estate_duty.exemption_amount,
         donations_tax_rate_main: rules.donations_tax.primary_rate,
         donations_tax_rate_above_30m: rules.donations_tax.secondary_rate,
@@ -48,11 +58,171 @@ fn summarize_tax_rules(rules: &JurisdictionTaxRuleSet) -> SouthAfricaTaxSummary
     }
 }
 
+// Section 5(2) tables: the 18%, 26%, 31%, 36%, 39% and 41% bands up to each threshold, then 45%.
+// Rebates are primary, secondary (65 and older) and tertiary (75 and older), under section 6.
+fn income_tax_table(
+    tax_year_from: u16,
+    tax_year_to: Option<u16>,
+    thresholds: [Decimal; 6],
+    rebates: [Decimal; 3],
+    company_rate: Decimal,
+) -> IncomeTaxTable {
+    let rates = [
+        dec!(0.18),
+        dec!(0.26),
+        dec!(0.31),
+        dec!(0.36),
+        dec!(0.39),
+        dec!(0.41),
+        dec!(0.45),
+    ];
+    let mut rate_schedule = vec![RateBracket {
+        from_amount: Decimal::ZERO,
+        rate: rates[0],
+    }];
+    rate_schedule.extend(
+        thresholds
+            .iter()
+            .zip(&rates[1..])
+            .map(|(&from_amount, &rate)| RateBracket { from_amount, rate }),
+    );
+
+    IncomeTaxTable {
+        tax_year_from,
+        tax_year_to,
+        rate_schedule,
+        rebates: [0, 65, 75]
+            .into_iter()
+            .zip(rebates)
+            .map(|(from_age, amount)| IncomeTaxRebate { from_age, amount })
+            .collect(),
+        // Trusts other than special trusts pay a flat 45%.
+        trust_rate_schedule: vec![RateBracket {
+            from_amount: Decimal::ZERO,
+            rate: dec!(0.45),
+        }],
+        company_rate_schedule: vec![RateBracket {
+            from_amount: Decimal::ZERO,
+            rate: company_rate,
+        }],
+    }
+}
+
+fn south_africa_income_tax_rule() -> IncomeTaxRule {
+    IncomeTaxRule {
+        tables: vec![
+            income_tax_table(
+                2018,
+                Some(2018),
+                [
+                    dec!(189_880),
+                    dec!(296_540),
+                    dec!(410_460),
+                    dec!(555_600),
+                    dec!(708_310),
+                    dec!(1_500_000),
+                ],
+                [dec!(13_635), dec!(7_479), dec!(2_493)],
+                dec!(0.28),
+            ),
+            income_tax_table(
+                2019,
+                Some(2019),
+                [
+                    dec!(195_850),
+                    dec!(305_850),
+                    dec!(423_300),
+                    dec!(555_600),
+                    dec!(708_310),
+                    dec!(1_500_000),
+                ],
+                [dec!(14_067), dec!(7_713), dec!(2_574)],
+                dec!(0.28),
+            ),
+            income_tax_table(
+                2020,
+                Some(2020),
+                [
+                    dec!(195_850),
+                    dec!(305_850),
+                    dec!(423_300),
+                    dec!(555_600),
+                    dec!(708_310),
+                    dec!(1_500_000),
+                ],
+                [dec!(14_220), dec!(7_794), dec!(2_601)],
+                dec!(0.28),
+            ),
+            income_tax_table(
+                2021,
+                Some(2021),
+                [
+                    dec!(205_900),
+                    dec!(321_600),
+                    dec!(445_100),
+                    dec!(584_200),
+                    dec!(744_800),
+                    dec!(1_577_300),
+                ],
+                [dec!(14_958), dec!(8_199), dec!(2_736)],
+                dec!(0.28),
+            ),
+            income_tax_table(
+                2022,
+                Some(2022),
+                [
+                    dec!(216_200),
+                    dec!(337_800),
+                    dec!(467_500),
+                    dec!(613_600),
+                    dec!(782_200),
+                    dec!(1_656_600),
+                ],
+                [dec!(15_714), dec!(8_613), dec!(2_871)],
+                dec!(0.28),
+            ),
+            // Companies with years of assessment ending on or after 31 March 2023 pay 27%.
+            income_tax_table(
+                2023,
+                Some(2023),
+                [
+                    dec!(226_000),
+                    dec!(353_100),
+                    dec!(488_700),
+                    dec!(641_400),
+                    dec!(817_600),
+                    dec!(1_731_600),
+                ],
+                [dec!(16_425), dec!(9_000), dec!(2_997)],
+                dec!(0.27),
+            ),
+            // Brackets and rebates were not adjusted for inflation in 2025 or 2026.
+            income_tax_table(
+                2024,
+                None,
+                [
+                    dec!(237_100),
+                    dec!(370_500),
+                    dec!(512_800),
+                    dec!(673_000),
+                    dec!(857_900),
+                    dec!(1_817_000),
+                ],
+                [dec!(17_235), dec!(9_444), dec!(3_145)],
+                dec!(0.27),
+            ),
+        ],
+        effective_from: "2017-03-01",
+        source: "Income Tax Act 58 of 1962, sections 5(2) and 6; SARS rates of tax for individuals",
+        source_url: "https://www.sars.gov.za/tax-rates/income-tax/rates-of-tax-for-individuals/",
+    }
+}
+
 fn south_africa_tax_rules_2018_onwards() -> VersionedJurisdictionTaxRuleSet {
     VersionedJurisdictionTaxRuleSet {
         version: TaxRuleVersion {
             version_id: "ZA-ESTATE-BASELINE-2018+",
-            tax_year_from: 2018,
+            tax_year_from: 2019,
             tax_year_to: None,
             effective_from: "2018-03-01",
             effective_to: None,
@@ -60,41 +230,343 @@ fn south_africa_tax_rules_2018_onwards() -> VersionedJurisdictionTaxRuleSet {
         },
         rules: JurisdictionTaxRuleSet {
             estate_duty: EstateDutyRule {
-                exemption_amount: 3_500_000.0,
-                primary_rate: 0.20,
-                primary_rate_cap_amount: 30_000_000.0,
-                secondary_rate: 0.25,
+                exemption_amount: dec!(3_500_000),
+                rate_schedule: vec![
+                    RateBracket {
+                        from_amount: Decimal::ZERO,
+                        rate: dec!(0.20),
+                    },
+                    RateBracket {
+                        from_amount: dec!(30_000_000),
+                        rate: dec!(0.25),
+                    },
+                ],
+                exemption_as_unified_credit: false,
+                non_resident_exemption_amount: None,
                 spouse_deduction_unlimited: true, // Estate Duty Act, section 4(q)
+                // Estate Duty Act, section 3(3)(a), including the buy-and-sell and
+                // third-party-owned exemptions.
+                life_policy_inclusion: LifePolicyInclusion::DeemedPropertyWithExemptions,
+                default_tax_apportionment: EstateTaxApportionment::BorneByResidue,
+                foreign_death_duty_credit: true, // Estate Duty Act, section 16(b)
+                // Estate Duty Act, section 4A(1A), for deaths from 1 January 2010.
+                exemption_portable_from: Some("2010-01-01"),
                 effective_from: "2018-03-01",
                 source: "SARS Estate Duty (accessed 2026-02-21)",
                 source_url: "https://www.sars.gov.za/types-of-tax/estate-duty/",
             },
             donations_tax: DonationsTaxRule {
-                annual_exemption_natural_person_amount: 100_000.0,
-                annual_exemption_non_natural_casual_gifts_amount: 10_000.0,
-                primary_rate: 0.20,
-                primary_rate_cap_cumulative_amount: 30_000_000.0,
-                secondary_rate: 0.25,
+                annual_exemption_natural_person_amount: dec!(100_000),
+                annual_exemption_non_natural_casual_gifts_amount: dec!(10_000),
+                primary_rate: dec!(0.20),
+                primary_rate_cap_cumulative_amount: dec!(30_000_000),
+                secondary_rate: dec!(0.25),
                 effective_from: "2018-03-01",
                 source: "SARS Donations Tax (accessed 2026-02-21)",
                 source_url: "https://www.sars.gov.za/types-of-tax/donations-tax/",
             },
             cgt_on_death: CapitalGainsAtDeathRule {
-                annual_exclusion_in_year_of_death_amount: 300_000.0,
-                inclusion_rate_natural_person: 0.40,
-                inclusion_rate_company: 0.80,
-                inclusion_rate_trust: 0.80,
-                base_cost_step_up_to_market_value_on_death: true,
+                annual_exclusion_in_year_of_death_amount: dec!(300_000),
+                annual_exclusion_amount: Some(dec!(40_000)),
+                inclusion_rate_natural_person: dec!(0.40),
+                inclusion_rate_company: dec!(0.80),
+                inclusion_rate_trust: dec!(0.80),
+                primary_residence_exclusion_amount: dec!(2_000_000), // Eighth Schedule, paragraph 45
+                // Heirs acquire at market value via the taxed deemed disposal (paragraph 40),
+                // not via a tax-free step-up.
+                base_cost_step_up_to_market_value_on_death: false,
+                spousal_rollover_on_death: true, // Eighth Schedule, paragraph 67
+                pbo_bequest_gain_disregarded: true, // Eighth Schedule, paragraph 62
                 effective_from: "2016-03-01",
                 source: "SARS CGT (page updated 2025-05-21; accessed 2026-02-21)",
                 source_url: "https://www.sars.gov.za/tax-rates/income-tax/capital-gains-tax-cgt/",
             },
+            retirement_funds: RetirementFundRule {
+                fund_benefits_in_estate: false, // Estate Duty Act, section 3(2)(i)
+                living_annuity_in_estate: false,
+                disallowed_contributions_in_estate: true, // Estate Duty Act, section 3(3)(bA)
+                disregarded_for_cgt_on_death: true,       // Eighth Schedule, paragraph 55(1)(c)
+                fund_benefits_allocated_by_trustees: true, // Pension Funds Act, section 37C
+                effective_from: "2015-03-01",
+                source: "Estate Duty Act 45 of 1955; Pension Funds Act 24 of 1956",
+                source_url: "https://www.sars.gov.za/types-of-tax/estate-duty/",
+            },
+            income_tax: south_africa_income_tax_rule(),
+            // Regulation 8(1) tariff: 3.5% on the gross value of the assets and 6% on income
+            // accrued and collected after death, subject to a R350 minimum.
+            executor_fee: Some(ExecutorFeeRule {
+                asset_rate_schedule: vec![RateBracket {
+                    from_amount: Decimal::ZERO,
+                    rate: dec!(0.035),
+                }],
+                income_rate: Some(dec!(0.06)),
+                minimum_fee_amount: dec!(350),
+                vat_applies: true,
+                effective_from: "2001-01-01",
+                source: "Administration of Estates Act 66 of 1965, section 51 and regulation 8",
+                source_url: "https://www.justice.gov.za/master/deceased.html",
+            }),
+            // VAT Act, section 7(1). The 15% rate applies from 1 April 2018, a month into the
+            // 2019 tax year.
+            vat: Some(VatRule {
+                rates: vec![VatRate {
+                    tax_year_from: 2019,
+                    tax_year_to: None,
+                    standard_rate: dec!(0.15),
+                }],
+                effective_from: "2018-04-01",
+                source: "Value-Added Tax Act 89 of 1991, section 7(1)",
+                source_url: "https://www.sars.gov.za/types-of-tax/value-added-tax/",
+            }),
+            // Returns are completed in whole rand with the cents dropped.
+            rounding: RoundingRule {
+                return_decimal_places: 0,
+                return_rounding: ReturnRounding::Down,
+                source: "SARS return completion guides: amounts in rand, cents omitted",
+                source_url: "https://www.sars.gov.za/types-of-tax/personal-income-tax/",
+            },
+            state_estate_tax: None,
+        },
+    }
+}
+
+// Figures that changed between the introduction of CGT (1 October 2001) and the 2019 tax year.
+// Before 1 March 2018 estate duty and donations tax were a flat 20%.
+struct SouthAfricaHistoricalRates {
+    version_id: &'static str,
+    tax_year_from: u16,
+    tax_year_to: u16,
+    effective_from: &'static str,
+    effective_to: &'static str,
+    // Estate Duty Act, section 4A(1).
+    estate_duty_abatement_amount: Decimal,
+    // Income Tax Act, section 56(2)(b).
+    donations_annual_exemption_amount: Decimal,
+    // Eighth Schedule, paragraph 5(2).
+    cgt_annual_exclusion_in_year_of_death_amount: Decimal,
+    // Eighth Schedule, paragraph 5(1). Only recorded from the 2012 tax year.
+    cgt_annual_exclusion_amount: Option<Decimal>,
+    // Eighth Schedule, paragraph 10.
+    cgt_inclusion_rate_natural_person: Decimal,
+    cgt_inclusion_rate_company_and_trust: Decimal,
+    // Eighth Schedule, paragraph 45.
+    primary_residence_exclusion_amount: Decimal,
+    // Estate Duty Act, section 3(3)(bA), for deaths from 1 March 2015.
+    disallowed_contributions_in_estate: bool,
+    // Estate Duty Act, section 4A(1A), for deaths from 1 January 2010.
+    abatement_portable: bool,
+}
+
+fn south_africa_historical_rates() -> Vec<SouthAfricaHistoricalRates> {
+    vec![
+        SouthAfricaHistoricalRates {
+            // The R1.5 million abatement applies to deaths from 1 January 2002.
+            version_id: "ZA-ESTATE-2002-2006",
+            tax_year_from: 2002,
+            tax_year_to: 2006,
+            effective_from: "2002-01-01",
+            effective_to: "2006-02-28",
+            estate_duty_abatement_amount: dec!(1_500_000),
+            donations_annual_exemption_amount: dec!(30_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(50_000),
+            cgt_annual_exclusion_amount: None,
+            cgt_inclusion_rate_natural_person: dec!(0.25),
+            cgt_inclusion_rate_company_and_trust: dec!(0.50),
+            primary_residence_exclusion_amount: dec!(1_000_000),
+            disallowed_contributions_in_estate: false,
+            abatement_portable: false,
+        },
+        SouthAfricaHistoricalRates {
+            version_id: "ZA-ESTATE-2007",
+            tax_year_from: 2007,
+            tax_year_to: 2007,
+            effective_from: "2006-03-01",
+            effective_to: "2007-02-28",
+            estate_duty_abatement_amount: dec!(2_500_000),
+            donations_annual_exemption_amount: dec!(30_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(50_000),
+            cgt_annual_exclusion_amount: None,
+            cgt_inclusion_rate_natural_person: dec!(0.25),
+            cgt_inclusion_rate_company_and_trust: dec!(0.50),
+            primary_residence_exclusion_amount: dec!(1_500_000),
+            disallowed_contributions_in_estate: false,
+            abatement_portable: false,
+        },
+        SouthAfricaHistoricalRates {
+            version_id: "ZA-ESTATE-2008",
+            tax_year_from: 2008,
+            tax_year_to: 2008,
+            effective_from: "2007-03-01",
+            effective_to: "2008-02-29",
+            estate_duty_abatement_amount: dec!(3_500_000),
+            donations_annual_exemption_amount: dec!(100_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(60_000),
+            cgt_annual_exclusion_amount: None,
+            cgt_inclusion_rate_natural_person: dec!(0.25),
+            cgt_inclusion_rate_company_and_trust: dec!(0.50),
+            primary_residence_exclusion_amount: dec!(1_500_000),
+            disallowed_contributions_in_estate: false,
+            abatement_portable: false,
+        },
+        SouthAfricaHistoricalRates {
+            version_id: "ZA-ESTATE-2009-2011",
+            tax_year_from: 2009,
+            tax_year_to: 2011,
+            effective_from: "2008-03-01",
+            effective_to: "2011-02-28",
+            estate_duty_abatement_amount: dec!(3_500_000),
+            donations_annual_exemption_amount: dec!(100_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(120_000),
+            cgt_annual_exclusion_amount: None,
+            cgt_inclusion_rate_natural_person: dec!(0.25),
+            cgt_inclusion_rate_company_and_trust: dec!(0.50),
+            primary_residence_exclusion_amount: dec!(1_500_000),
+            disallowed_contributions_in_estate: false,
+            abatement_portable: true,
         },
+        SouthAfricaHistoricalRates {
+            version_id: "ZA-ESTATE-2012",
+            tax_year_from: 2012,
+            tax_year_to: 2012,
+            effective_from: "2011-03-01",
+            effective_to: "2012-02-29",
+            estate_duty_abatement_amount: dec!(3_500_000),
+            donations_annual_exemption_amount: dec!(100_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(200_000),
+            cgt_annual_exclusion_amount: Some(dec!(20_000)),
+            cgt_inclusion_rate_natural_person: dec!(0.25),
+            cgt_inclusion_rate_company_and_trust: dec!(0.50),
+            primary_residence_exclusion_amount: dec!(1_500_000),
+            disallowed_contributions_in_estate: false,
+            abatement_portable: true,
+        },
+        SouthAfricaHistoricalRates {
+            version_id: "ZA-ESTATE-2013-2015",
+            tax_year_from: 2013,
+            tax_year_to: 2015,
+            effective_from: "2012-03-01",
+            effective_to: "2015-02-28",
+            estate_duty_abatement_amount: dec!(3_500_000),
+            donations_annual_exemption_amount: dec!(100_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(300_000),
+            cgt_annual_exclusion_amount: Some(dec!(30_000)),
+            cgt_inclusion_rate_natural_person: dec!(0.333),
+            cgt_inclusion_rate_company_and_trust: dec!(0.666),
+            primary_residence_exclusion_amount: dec!(2_000_000),
+            disallowed_contributions_in_estate: false,
+            abatement_portable: true,
+        },
+        SouthAfricaHistoricalRates {
+            version_id: "ZA-ESTATE-2016",
+            tax_year_from: 2016,
+            tax_year_to: 2016,
+            effective_from: "2015-03-01",
+            effective_to: "2016-02-29",
+            estate_duty_abatement_amount: dec!(3_500_000),
+            donations_annual_exemption_amount: dec!(100_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(300_000),
+            cgt_annual_exclusion_amount: Some(dec!(30_000)),
+            cgt_inclusion_rate_natural_person: dec!(0.333),
+            cgt_inclusion_rate_company_and_trust: dec!(0.666),
+            primary_residence_exclusion_amount: dec!(2_000_000),
+            disallowed_contributions_in_estate: true,
+            abatement_portable: true,
+        },
+        SouthAfricaHistoricalRates {
+            // Runs up to the 1 March 2018 estate duty and donations tax changes.
+            version_id: "ZA-ESTATE-2017",
+            tax_year_from: 2017,
+            tax_year_to: 2018,
+            effective_from: "2016-03-01",
+            effective_to: "2018-02-28",
+            estate_duty_abatement_amount: dec!(3_500_000),
+            donations_annual_exemption_amount: dec!(100_000),
+            cgt_annual_exclusion_in_year_of_death_amount: dec!(300_000),
+            cgt_annual_exclusion_amount: Some(dec!(40_000)),
+            cgt_inclusion_rate_natural_person: dec!(0.40),
+            cgt_inclusion_rate_company_and_trust: dec!(0.80),
+            primary_residence_exclusion_amount: dec!(2_000_000),
+            disallowed_contributions_in_estate: true,
+            abatement_portable: true,
+        },
+    ]
+}
+
+// Historical versions start from the 2019 rule set and replace the figures that differed. Income
+// tax tables are only carried from 2018; earlier years need the scenario to supply the marginal
+// rate and the final income tax.
+fn south_africa_historical_tax_rules(
+    historical: SouthAfricaHistoricalRates,
+) -> VersionedJurisdictionTaxRuleSet {
+    let mut rules = south_africa_tax_rules_2018_onwards().rules;
+
+    rules.estate_duty.exemption_amount = historical.estate_duty_abatement_amount;
+    rules.estate_duty.rate_schedule = vec![RateBracket {
+        from_amount: Decimal::ZERO,
+        rate: dec!(0.20),
+    }];
+    if !historical.abatement_portable {
+        rules.estate_duty.exemption_portable_from = None;
+    }
+    rules.estate_duty.effective_from = historical.effective_from;
+    rules.estate_duty.source = "Estate Duty Act 45 of 1955, sections 4A and 24; SARS Estate Duty";
+    rules.estate_duty.source_url = "https://www.gov.za/documents/estate-duty-act-19-may-2015-1254";
+
+    rules.donations_tax.annual_exemption_natural_person_amount =
+        historical.donations_annual_exemption_amount;
+    rules.donations_tax.secondary_rate = rules.donations_tax.primary_rate;
+    rules.donations_tax.effective_from = historical.effective_from;
+    rules.donations_tax.source = "Income Tax Act 58 of 1962, sections 56(2)(b) and 64";
+    rules.donations_tax.source_url = "https://www.gov.za/documents/income-tax-act-29-may-1962-0000";
+
+    rules.cgt_on_death.annual_exclusion_in_year_of_death_amount =
+        historical.cgt_annual_exclusion_in_year_of_death_amount;
+    rules.cgt_on_death.annual_exclusion_amount = historical.cgt_annual_exclusion_amount;
+    rules.cgt_on_death.inclusion_rate_natural_person = historical.cgt_inclusion_rate_natural_person;
+    rules.cgt_on_death.inclusion_rate_company = historical.cgt_inclusion_rate_company_and_trust;
+    rules.cgt_on_death.inclusion_rate_trust = historical.cgt_inclusion_rate_company_and_trust;
+    rules.cgt_on_death.primary_residence_exclusion_amount =
+        historical.primary_residence_exclusion_amount;
+    rules.cgt_on_death.effective_from = historical.effective_from;
+    rules.cgt_on_death.source =
+        "Income Tax Act 58 of 1962, Eighth Schedule paragraphs 5, 10 and 45; SARS CGT history";
+    rules.cgt_on_death.source_url = "https://www.gov.za/documents/income-tax-act-29-may-1962-0000";
+
+    rules.retirement_funds.disallowed_contributions_in_estate =
+        historical.disallowed_contributions_in_estate;
+
+    // VAT was 14% from 7 April 1993 until 31 March 2018.
+    rules.vat = Some(VatRule {
+        rates: vec![VatRate {
+            tax_year_from: historical.tax_year_from,
+            tax_year_to: Some(historical.tax_year_to),
+            standard_rate: dec!(0.14),
+        }],
+        effective_from: "1993-04-07",
+        source: "Value-Added Tax Act 89 of 1991, section 7(1)",
+        source_url: "https://www.sars.gov.za/types-of-tax/value-added-tax/",
+    });
+
+    VersionedJurisdictionTaxRuleSet {
+        version: TaxRuleVersion {
+            version_id: historical.version_id,
+            tax_year_from: historical.tax_year_from,
+            tax_year_to: Some(historical.tax_year_to),
+            effective_from: historical.effective_from,
+            effective_to: Some(historical.effective_to),
+            source_last_verified_on: "2026-10-17",
+        },
+        rules,
     }
 }
 
 pub fn south_africa_tax_rules_catalog() -> Vec<VersionedJurisdictionTaxRuleSet> {
-    vec![south_africa_tax_rules_2018_onwards()]
+    let mut catalog: Vec<VersionedJurisdictionTaxRuleSet> = south_africa_historical_rates()
+        .into_iter()
+        .map(south_africa_historical_tax_rules)
+        .collect();
+    catalog.push(south_africa_tax_rules_2018_onwards());
+    catalog
 }
 
 pub fn south_africa_tax_rules_for_year(
@@ -117,6 +589,15 @@ pub fn south_africa_tax_rules_for_year(
     })
 }
 
+pub fn south_africa_tax_year_start_month() -> u8 {
+    // Year of assessment runs 1 March to the end of February; tax year N ends in February N.
+    3
+}
+
+pub fn south_africa_reporting_currency() -> Currency {
+    Currency::Zar
+}
+
 pub fn south_africa_latest_tax_rules() -> VersionedJurisdictionTaxRuleSet {
     south_africa_tax_rules_2018_onwards()
 }
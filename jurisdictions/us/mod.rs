@@ -1,7 +1,11 @@
 use crate::core::rules::tax_rules::{
-    CapitalGainsAtDeathRule, DonationsTaxRule, EstateDutyRule, Jurisdiction,
-    JurisdictionTaxRuleSet, TaxRuleSelectionError, TaxRuleVersion, VersionedJurisdictionTaxRuleSet,
+    CapitalGainsAtDeathRule, Currency, DonationsTaxRule, EstateDutyRule, EstateTaxApportionment,
+    ExecutorFeeRule, IncomeTaxRule, IncomeTaxTable, Jurisdiction, JurisdictionTaxRuleSet,
+    LifePolicyInclusion, RateBracket, RetirementFundRule, ReturnRounding, RoundingRule,
+    StateEstateTaxRule, TaxRuleSelectionError, TaxRuleVersion, VersionedJurisdictionTaxRuleSet,
 };
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
 #[derive(Debug, Clone)]
 pub struct LegalInstrument {
@@ -15,10 +19,12 @@ pub struct LegalInstrument {
 pub struct UsStateTaxSummary {
     pub tax_year_context: &'static str,
     pub rates_last_verified_on: &'static str,
-    pub estate_tax_exemption_usd: f64,
-    pub estate_tax_effective_top_rate: f64,
-    pub annual_gift_exclusion_usd: f64,
-    pub cgt_at_death_inclusion_rate_natural_person: f64,
+    pub federal_estate_tax_exemption_usd: Decimal,
+    pub federal_estate_tax_top_rate: Decimal,
+    pub state_estate_tax_exemption_usd: Option<Decimal>,
+    pub state_estate_tax_top_rate: Option<Decimal>,
+    pub annual_gift_exclusion_usd: Decimal,
+    pub cgt_at_death_inclusion_rate_natural_person: Decimal,
 }
 
 #[derive(Debug, Clone)]
@@ -36,10 +42,8 @@ struct UsStatePolicy {
     jurisdiction_code: &'static str,
     jurisdiction_name: &'static str,
     version_id: &'static str,
-    estate_tax_exemption_usd: f64,
-    estate_tax_effective_top_rate: f64,
-    estate_tax_source: &'static str,
-    estate_tax_source_url: &'static str,
+    state_estate_tax_source: &'static str,
+    state_estate_tax_source_url: &'static str,
 }
 
 fn us_state_policy(jurisdiction: Jurisdiction) -> Option<UsStatePolicy> {
@@ -48,54 +52,232 @@ fn us_state_policy(jurisdiction: Jurisdiction) -> Option<UsStatePolicy> {
             jurisdiction_code: "US-NY",
             jurisdiction_name: "United States - New York",
             version_id: "US-NY-ESTATE-BASELINE-2026+",
-            estate_tax_exemption_usd: 7_000_000.0,
-            estate_tax_effective_top_rate: 0.56,
-            estate_tax_source: "IRS + New York combined estate-tax planning baseline",
-            estate_tax_source_url: "https://www.tax.ny.gov/bus/estate/",
+            state_estate_tax_source: "New York Tax Law Article 26, sections 952 and 952(c)",
+            state_estate_tax_source_url: "https://www.tax.ny.gov/bus/estate/",
         }),
         Jurisdiction::UsTexas => Some(UsStatePolicy {
             jurisdiction_code: "US-TX",
             jurisdiction_name: "United States - Texas",
             version_id: "US-TX-ESTATE-BASELINE-2026+",
-            estate_tax_exemption_usd: 7_000_000.0,
-            estate_tax_effective_top_rate: 0.40,
-            estate_tax_source: "IRS federal estate-tax baseline (no Texas estate tax)",
-            estate_tax_source_url:
+            state_estate_tax_source: "No Texas estate tax",
+            state_estate_tax_source_url:
                 "https://www.irs.gov/businesses/small-businesses-self-employed/estate-tax",
         }),
         Jurisdiction::UsCalifornia => Some(UsStatePolicy {
             jurisdiction_code: "US-CA",
             jurisdiction_name: "United States - California",
             version_id: "US-CA-ESTATE-BASELINE-2026+",
-            estate_tax_exemption_usd: 7_000_000.0,
-            estate_tax_effective_top_rate: 0.40,
-            estate_tax_source: "IRS federal estate-tax baseline (no California estate tax)",
-            estate_tax_source_url:
+            state_estate_tax_source: "No California estate tax",
+            state_estate_tax_source_url:
                 "https://www.irs.gov/businesses/small-businesses-self-employed/estate-tax",
         }),
         Jurisdiction::UsFlorida => Some(UsStatePolicy {
             jurisdiction_code: "US-FL",
             jurisdiction_name: "United States - Florida",
             version_id: "US-FL-ESTATE-BASELINE-2026+",
-            estate_tax_exemption_usd: 7_000_000.0,
-            estate_tax_effective_top_rate: 0.40,
-            estate_tax_source: "IRS federal estate-tax baseline (no Florida estate tax)",
-            estate_tax_source_url:
+            state_estate_tax_source: "No Florida estate tax",
+            state_estate_tax_source_url:
                 "https://www.irs.gov/businesses/small-businesses-self-employed/estate-tax",
         }),
         Jurisdiction::UsMinnesota => Some(UsStatePolicy {
             jurisdiction_code: "US-MN",
             jurisdiction_name: "United States - Minnesota",
             version_id: "US-MN-ESTATE-BASELINE-2026+",
-            estate_tax_exemption_usd: 3_000_000.0,
-            estate_tax_effective_top_rate: 0.56,
-            estate_tax_source: "IRS + Minnesota combined estate-tax planning baseline",
-            estate_tax_source_url: "https://www.revenue.state.mn.us/estate-tax",
+            state_estate_tax_source: "Minnesota Statutes section 291.03",
+            state_estate_tax_source_url: "https://www.revenue.state.mn.us/estate-tax",
         }),
         Jurisdiction::SouthAfrica => None,
     }
 }
 
+fn brackets(schedule: &[(Decimal, Decimal)]) -> Vec<RateBracket> {
+    schedule
+        .iter()
+        .map(|&(from_amount, rate)| RateBracket { from_amount, rate })
+        .collect()
+}
+
+// 26 U.S.C. section 2001(c) rate schedule; the basic exclusion amount is applied as the
+// unified credit (section 2010) against the tentative tax.
+fn us_federal_estate_tax_rule_2026_onwards() -> EstateDutyRule {
+    EstateDutyRule {
+        exemption_amount: dec!(15_000_000),
+        rate_schedule: brackets(&[
+            (Decimal::ZERO, dec!(0.18)),
+            (dec!(10_000), dec!(0.20)),
+            (dec!(20_000), dec!(0.22)),
+            (dec!(40_000), dec!(0.24)),
+            (dec!(60_000), dec!(0.26)),
+            (dec!(80_000), dec!(0.28)),
+            (dec!(100_000), dec!(0.30)),
+            (dec!(150_000), dec!(0.32)),
+            (dec!(250_000), dec!(0.34)),
+            (dec!(500_000), dec!(0.37)),
+            (dec!(750_000), dec!(0.39)),
+            (dec!(1_000_000), dec!(0.40)),
+        ]),
+        exemption_as_unified_credit: true,
+        // Section 2102(b)(1): a $13,000 credit for nonresident aliens, the tax on $60,000.
+        non_resident_exemption_amount: Some(dec!(60_000)),
+        spouse_deduction_unlimited: true,
+        life_policy_inclusion: LifePolicyInclusion::IncidentsOfOwnership, // section 2042
+        // State apportionment statutes (e.g. NY EPTL 2-1.8) default to pro-rata recovery.
+        default_tax_apportionment: EstateTaxApportionment::ProRataAmongDutiableHeirs,
+        foreign_death_duty_credit: true, // section 2014
+        // Section 2010(c)(4) DSUE, for decedents dying after 31 December 2010.
+        exemption_portable_from: Some("2011-01-01"),
+        effective_from: "2026-01-01",
+        source: "26 U.S.C. sections 2001(c) and 2010(c) (basic exclusion amount per P.L. 119-21)",
+        source_url: "https://www.irs.gov/businesses/small-businesses-self-employed/estate-tax",
+    }
+}
+
+// Section 1(c) single-filer brackets and section 1(e) estate and trust brackets for 2026, as
+// adjusted by Rev. Proc. 2025-32. The final return takes the standard deduction, so taxable
+// income is supplied net of it and no rebates apply.
+fn us_federal_income_tax_rule_2026_onwards() -> IncomeTaxRule {
+    IncomeTaxRule {
+        tables: vec![IncomeTaxTable {
+            tax_year_from: 2026,
+            tax_year_to: None,
+            rate_schedule: brackets(&[
+                (Decimal::ZERO, dec!(0.10)),
+                (dec!(12_400), dec!(0.12)),
+                (dec!(50_400), dec!(0.22)),
+                (dec!(105_700), dec!(0.24)),
+                (dec!(201_775), dec!(0.32)),
+                (dec!(256_225), dec!(0.35)),
+                (dec!(640_600), dec!(0.37)),
+            ]),
+            rebates: Vec::new(),
+            trust_rate_schedule: brackets(&[
+                (Decimal::ZERO, dec!(0.10)),
+                (dec!(3_300), dec!(0.24)),
+                (dec!(11_700), dec!(0.35)),
+                (dec!(16_000), dec!(0.37)),
+            ]),
+            company_rate_schedule: brackets(&[(Decimal::ZERO, dec!(0.21))]), // section 11(b)
+        }],
+        effective_from: "2026-01-01",
+        source: "26 U.S.C. sections 1 and 11; Rev. Proc. 2025-32",
+        source_url: "https://www.irs.gov/filing/federal-income-tax-rates-and-brackets",
+    }
+}
+
+// Statutory personal representative commissions. The commission base is the estate accounted for,
+// which includes income collected during administration.
+fn us_state_executor_fee_rule(jurisdiction: Jurisdiction) -> Option<ExecutorFeeRule> {
+    let (asset_rate_schedule, source, source_url) = match jurisdiction {
+        // Above $25 million the court fixes a reasonable amount; the 0.5% band is carried on.
+        Jurisdiction::UsCalifornia => (
+            brackets(&[
+                (Decimal::ZERO, dec!(0.04)),
+                (dec!(100_000), dec!(0.03)),
+                (dec!(200_000), dec!(0.02)),
+                (dec!(1_000_000), dec!(0.01)),
+                (dec!(10_000_000), dec!(0.005)),
+            ]),
+            "California Probate Code section 10800",
+            "https://leginfo.legislature.ca.gov/faces/codes_displaySection.xhtml?lawCode=PROB&sectionNum=10800",
+        ),
+        Jurisdiction::UsFlorida => (
+            brackets(&[
+                (Decimal::ZERO, dec!(0.03)),
+                (dec!(1_000_000), dec!(0.025)),
+                (dec!(5_000_000), dec!(0.02)),
+                (dec!(10_000_000), dec!(0.015)),
+                (dec!(20_000_000), dec!(0.01)),
+            ]),
+            "Florida Statutes section 733.617",
+            "https://www.flsenate.gov/Laws/Statutes/2025/733.617",
+        ),
+        Jurisdiction::UsNewYork => (
+            brackets(&[
+                (Decimal::ZERO, dec!(0.05)),
+                (dec!(100_000), dec!(0.04)),
+                (dec!(300_000), dec!(0.03)),
+                (dec!(1_000_000), dec!(0.025)),
+                (dec!(5_000_000), dec!(0.02)),
+            ]),
+            "New York Surrogate's Court Procedure Act section 2307",
+            "https://www.nysenate.gov/legislation/laws/SCP/2307",
+        ),
+        // Texas allows 5% of cash received and paid out, capped at 5% of the gross estate; the
+        // cap is used as the estimate.
+        Jurisdiction::UsTexas => (
+            brackets(&[(Decimal::ZERO, dec!(0.05))]),
+            "Texas Estates Code section 352.002",
+            "https://statutes.capitol.texas.gov/Docs/ES/htm/ES.352.htm",
+        ),
+        // Minnesota leaves compensation to what is reasonable (section 524.3-719).
+        Jurisdiction::UsMinnesota | Jurisdiction::SouthAfrica => return None,
+    };
+
+    Some(ExecutorFeeRule {
+        asset_rate_schedule,
+        income_rate: None,
+        minimum_fee_amount: Decimal::ZERO,
+        vat_applies: false,
+        effective_from: "2026-01-01",
+        source,
+        source_url,
+    })
+}
+
+fn us_state_estate_tax_rule_2026_onwards(
+    jurisdiction: Jurisdiction,
+    policy: &UsStatePolicy,
+) -> Option<StateEstateTaxRule> {
+    match jurisdiction {
+        // Basic exclusion amount for deaths in 2026; the credit is lost entirely once the
+        // taxable estate exceeds 105% of it.
+        Jurisdiction::UsNewYork => Some(StateEstateTaxRule {
+            exemption_amount: dec!(7_350_000),
+            rate_schedule: brackets(&[
+                (Decimal::ZERO, dec!(0.0306)),
+                (dec!(500_000), dec!(0.05)),
+                (dec!(1_000_000), dec!(0.055)),
+                (dec!(1_500_000), dec!(0.065)),
+                (dec!(2_100_000), dec!(0.08)),
+                (dec!(2_600_000), dec!(0.088)),
+                (dec!(3_100_000), dec!(0.096)),
+                (dec!(3_600_000), dec!(0.104)),
+                (dec!(4_100_000), dec!(0.112)),
+                (dec!(5_100_000), dec!(0.12)),
+                (dec!(6_100_000), dec!(0.128)),
+                (dec!(7_100_000), dec!(0.136)),
+                (dec!(8_100_000), dec!(0.144)),
+                (dec!(9_100_000), dec!(0.152)),
+                (dec!(10_100_000), dec!(0.16)),
+            ]),
+            credit_phase_out_ceiling_ratio: Some(dec!(1.05)),
+            deductible_for_federal_estate_tax: true,
+            effective_from: "2026-01-01",
+            source: policy.state_estate_tax_source,
+            source_url: policy.state_estate_tax_source_url,
+        }),
+        Jurisdiction::UsMinnesota => Some(StateEstateTaxRule {
+            exemption_amount: dec!(3_000_000),
+            rate_schedule: brackets(&[
+                (Decimal::ZERO, dec!(0.13)),
+                (dec!(7_100_000), dec!(0.136)),
+                (dec!(8_100_000), dec!(0.144)),
+                (dec!(9_100_000), dec!(0.152)),
+                (dec!(10_100_000), dec!(0.16)),
+            ]),
+            credit_phase_out_ceiling_ratio: None,
+            deductible_for_federal_estate_tax: true,
+            effective_from: "2026-01-01",
+            source: policy.state_estate_tax_source,
+            source_url: policy.state_estate_tax_source_url,
+        }),
+        Jurisdiction::UsTexas
+        | Jurisdiction::UsCalifornia
+        | Jurisdiction::UsFlorida
+        | Jurisdiction::SouthAfrica => None,
+    }
+}
+
 fn us_state_tax_rules_2026_onwards(jurisdiction: Jurisdiction) -> VersionedJurisdictionTaxRuleSet {
     let policy = us_state_policy(jurisdiction)
         .expect("US state tax rules requested for non-US jurisdiction variant");
@@ -110,37 +292,58 @@ fn us_state_tax_rules_2026_onwards(jurisdiction: Jurisdiction) -> VersionedJuris
             source_last_verified_on: "2026-03-03",
         },
         rules: JurisdictionTaxRuleSet {
-            estate_duty: EstateDutyRule {
-                exemption_amount: policy.estate_tax_exemption_usd,
-                primary_rate: policy.estate_tax_effective_top_rate,
-                primary_rate_cap_amount: 1_000_000_000_000.0,
-                secondary_rate: policy.estate_tax_effective_top_rate,
-                spouse_deduction_unlimited: true,
-                effective_from: "2026-01-01",
-                source: policy.estate_tax_source,
-                source_url: policy.estate_tax_source_url,
-            },
+            estate_duty: us_federal_estate_tax_rule_2026_onwards(),
             donations_tax: DonationsTaxRule {
-                annual_exemption_natural_person_amount: 20_000.0,
-                annual_exemption_non_natural_casual_gifts_amount: 0.0,
-                primary_rate: 0.40,
-                primary_rate_cap_cumulative_amount: 1_000_000_000_000.0,
-                secondary_rate: 0.40,
+                annual_exemption_natural_person_amount: dec!(20_000),
+                annual_exemption_non_natural_casual_gifts_amount: Decimal::ZERO,
+                primary_rate: dec!(0.40),
+                primary_rate_cap_cumulative_amount: dec!(1_000_000_000_000),
+                secondary_rate: dec!(0.40),
                 effective_from: "2026-01-01",
                 source: "IRS gift-tax planning baseline",
                 source_url:
                     "https://www.irs.gov/businesses/small-businesses-self-employed/gift-tax",
             },
             cgt_on_death: CapitalGainsAtDeathRule {
-                annual_exclusion_in_year_of_death_amount: 0.0,
-                inclusion_rate_natural_person: 0.0,
-                inclusion_rate_company: 0.0,
-                inclusion_rate_trust: 0.0,
+                annual_exclusion_in_year_of_death_amount: Decimal::ZERO,
+                annual_exclusion_amount: None,
+                inclusion_rate_natural_person: Decimal::ZERO,
+                inclusion_rate_company: Decimal::ZERO,
+                inclusion_rate_trust: Decimal::ZERO,
+                // The section 121 exclusion is for a sale by the owner; the step-up leaves no
+                // gain at death.
+                primary_residence_exclusion_amount: Decimal::ZERO,
                 base_cost_step_up_to_market_value_on_death: true,
+                spousal_rollover_on_death: false,
+                pbo_bequest_gain_disregarded: false,
                 effective_from: "2026-01-01",
                 source: "IRS basis-of-assets step-up treatment at death",
                 source_url: "https://www.irs.gov/publications/p559#en_US_2024_publink10009920",
             },
+            // Retirement accounts and annuities are in the gross estate (section 2039) and pass
+            // by beneficiary designation; they are income in respect of a decedent, not stepped up.
+            retirement_funds: RetirementFundRule {
+                fund_benefits_in_estate: true,
+                living_annuity_in_estate: true,
+                disallowed_contributions_in_estate: false,
+                disregarded_for_cgt_on_death: true,
+                fund_benefits_allocated_by_trustees: false,
+                effective_from: "2026-01-01",
+                source: "26 U.S.C. section 2039",
+                source_url: "https://www.law.cornell.edu/uscode/text/26/2039",
+            },
+            income_tax: us_federal_income_tax_rule_2026_onwards(),
+            executor_fee: us_state_executor_fee_rule(jurisdiction),
+            // Sales taxes are state-level and do not reach executor commissions.
+            vat: None,
+            // Whole-dollar reporting: under 50 cents drops, 50 cents or more rounds up.
+            rounding: RoundingRule {
+                return_decimal_places: 0,
+                return_rounding: ReturnRounding::HalfUp,
+                source: "26 U.S.C. section 6102",
+                source_url: "https://www.law.cornell.edu/uscode/text/26/6102",
+            },
+            state_estate_tax: us_state_estate_tax_rule_2026_onwards(jurisdiction, &policy),
         },
     }
 }
@@ -176,6 +379,14 @@ pub fn us_state_tax_rules_for_year(
     })
 }
 
+pub fn us_tax_year_start_month() -> u8 {
+    1
+}
+
+pub fn us_reporting_currency() -> Currency {
+    Currency::Usd
+}
+
 pub fn us_state_latest_tax_rules(jurisdiction: Jurisdiction) -> VersionedJurisdictionTaxRuleSet {
     us_state_tax_rules_2026_onwards(jurisdiction)
 }
@@ -205,29 +416,40 @@ pub fn us_state_jurisdiction_baseline(
             },
             LegalInstrument {
                 name: "State Estate Tax",
-                citation: policy.estate_tax_source,
+                citation: policy.state_estate_tax_source,
                 planning_relevance:
-                    "State-level estate-tax overlay where applicable in New York and Minnesota.",
-                source_url: policy.estate_tax_source_url,
+                    "State-level estate tax computed separately from the federal estate tax where the state levies one (New York, Minnesota).",
+                source_url: policy.state_estate_tax_source_url,
             },
         ],
         baseline_tax_summary: UsStateTaxSummary {
             tax_year_context: "Tax years from 2026 onwards",
             rates_last_verified_on: "2026-03-03",
-            estate_tax_exemption_usd: rules.estate_duty.exemption_amount,
-            estate_tax_effective_top_rate: rules.estate_duty.primary_rate,
+            federal_estate_tax_exemption_usd: rules.estate_duty.exemption_amount,
+            federal_estate_tax_top_rate: rules.estate_duty.top_rate(),
+            state_estate_tax_exemption_usd: rules
+                .state_estate_tax
+                .as_ref()
+                .map(|rule| rule.exemption_amount),
+            state_estate_tax_top_rate: rules
+                .state_estate_tax
+                .as_ref()
+                .and_then(|rule| rule.rate_schedule.last())
+                .map(|bracket| bracket.rate),
             annual_gift_exclusion_usd: rules.donations_tax.annual_exemption_natural_person_amount,
             cgt_at_death_inclusion_rate_natural_person: rules.cgt_on_death.inclusion_rate_natural_person,
         },
         tax_source_urls: vec![
             rules.estate_duty.source_url,
+            policy.state_estate_tax_source_url,
             rules.donations_tax.source_url,
             rules.cgt_on_death.source_url,
         ],
         notes: vec![
-            "US baselines are implemented as planning assumptions using a combined federal+state effective estate-tax rate per state.",
-            "New York and Minnesota include a state estate-tax overlay in the effective estate-tax rate.",
-            "Texas, California, and Florida model federal estate-tax only in this baseline.",
+            "Federal estate tax applies the section 2001(c) graduated schedule (18%-40%) with the basic exclusion amount as a unified credit.",
+            "New York and Minnesota estate taxes are computed separately on their own exemptions and rate schedules and deducted in the federal computation (section 2058).",
+            "New York's exclusion credit phases out between 100% and 105% of the basic exclusion amount (the estate-tax cliff).",
+            "Texas, California, and Florida levy no state estate tax.",
             "Capital gains at death are modeled with a basis step-up (no immediate CGT realization in this rule set).",
             "Validate final filing positions with US-licensed tax counsel and state-specific guidance.",
         ],
@@ -1,29 +1,32 @@
 use crate::core::domain::models::{EstateScenarioInput, ScenarioResult};
 use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
-use crate::core::rules::tax_rules::TaxRuleSelectionError;
+use crate::core::errors::EngineError;
+use rust_decimal::Decimal;
 
 #[derive(Debug, Clone)]
 pub struct StressResult {
-    pub market_value_shock: f64,
-    pub liquid_asset_haircut: f64,
+    pub market_value_shock: Decimal,
+    pub liquid_asset_haircut: Decimal,
     pub outcome: ScenarioResult,
 }
 
 pub fn run_liquidity_stress_grid(
     base_input: &EstateScenarioInput,
-    market_value_shocks: &[f64],
-    liquid_asset_haircuts: &[f64],
-) -> Result<Vec<StressResult>, TaxRuleSelectionError> {
+    market_value_shocks: &[Decimal],
+    liquid_asset_haircuts: &[Decimal],
+) -> Result<Vec<StressResult>, EngineError> {
     let mut results = Vec::new();
 
     for shock in market_value_shocks {
         for haircut in liquid_asset_haircuts {
             let mut shocked_input = base_input.clone();
-            let applied_shock = (1.0 + shock).max(0.0);
-            let applied_liquid_haircut = (1.0 - haircut).clamp(0.0, 1.0);
+            let applied_shock = (Decimal::ONE + shock).max(Decimal::ZERO);
+            let applied_liquid_haircut =
+                (Decimal::ONE - haircut).clamp(Decimal::ZERO, Decimal::ONE);
 
             for asset in &mut shocked_input.assets {
-                asset.market_value_amount = (asset.market_value_amount * applied_shock).max(0.0);
+                asset.market_value_amount =
+                    (asset.market_value_amount * applied_shock).max(Decimal::ZERO);
                 if asset.is_liquid {
                     asset.market_value_amount *= applied_liquid_haircut;
                 }
@@ -4,22 +4,26 @@ use crate::api::handler::{
 };
 use crate::core::domain::models::EstateScenarioInput;
 use crate::core::rules::tax_rules::Jurisdiction;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
 fn valid_contract_input() -> ApiEstateScenarioInput {
     let mut input = ApiEstateScenarioInput::from(EstateScenarioInput::default());
     input.assets = vec![ApiEstateAssetInput {
         name: "Contract Asset".to_string(),
-        market_value_amount: 1_000_000.0,
-        base_cost_amount: 700_000.0,
+        market_value_amount: dec!(1_000_000),
+        base_cost_amount: dec!(700_000),
         is_liquid: true,
         situs_in_jurisdiction: true,
+        situs_jurisdiction: None,
         included_in_estate_duty: true,
         included_in_cgt_deemed_disposal: true,
         bequeathed_to_surviving_spouse: false,
         bequeathed_to_pbo: false,
         qualifies_primary_residence_exclusion: false,
+        currency: None,
     }];
-    input.explicit_executor_fee_amount = Some(0.0);
+    input.explicit_executor_fee_amount = Some(Decimal::ZERO);
     input
 }
 
@@ -29,19 +33,30 @@ fn contract_input_maps_to_domain_input() {
     let domain: EstateScenarioInput = input.clone().into();
 
     assert_eq!(domain.jurisdiction, Jurisdiction::from(input.jurisdiction));
-    assert_eq!(domain.tax_year, input.tax_year);
+    assert_eq!(Some(domain.tax_year), input.tax_year);
     assert_eq!(domain.assets.len(), 1);
     assert_eq!(domain.assets[0].name, "Contract Asset");
 }
 
+#[test]
+fn contract_input_leaves_an_omitted_tax_year_to_the_engine() {
+    let mut input = valid_contract_input();
+    input.tax_year = None;
+    input.date_of_death = Some("2025-03-01".to_string());
+
+    let domain: EstateScenarioInput = input.into();
+    assert_eq!(domain.tax_year, 0);
+    assert!(domain.validate().is_ok());
+}
+
 #[test]
 fn calculate_single_scenario_contract_returns_api_result() {
     let input = valid_contract_input();
     let result = calculate_single_scenario_contract(input)
         .expect("Expected contract scenario calculation to succeed");
 
-    assert!(result.combined_tax.total_tax_liability_amount >= 0.0);
-    assert!(result.liquidity.total_available_liquidity_amount >= 0.0);
+    assert!(result.combined_tax.total_tax_liability_amount >= Decimal::ZERO);
+    assert!(result.liquidity.total_available_liquidity_amount >= Decimal::ZERO);
 }
 
 #[test]
@@ -72,3 +87,27 @@ fn optimize_candidate_scenarios_contract_returns_api_validation_error() {
         .iter()
         .any(|issue| issue.field == "candidates[1].assets[0].name"));
 }
+
+#[test]
+fn currency_codes_deserialize_and_echo_in_contract_result() {
+    let mut input =
+        serde_json::to_value(valid_contract_input()).expect("Expected input to serialize");
+    input["assets"][0]["currency"] = serde_json::json!("USD");
+    input["fx_rates"] = serde_json::json!({
+        "valuation_date": "2025-11-14",
+        "rates": [{ "currency": "USD", "rate": 18.0 }]
+    });
+    let input: ApiEstateScenarioInput =
+        serde_json::from_value(input).expect("Expected currency-tagged input to deserialize");
+
+    let result = calculate_single_scenario_contract(input)
+        .expect("Expected contract scenario calculation to succeed");
+    let result = serde_json::to_value(result).expect("Expected result to serialize");
+    assert_eq!(result["currency"]["reporting_currency"], "ZAR");
+    assert_eq!(result["currency"]["lines"][0]["currency"], "USD");
+    // Amounts serialize as exact decimal numbers rather than binary floats.
+    assert_eq!(
+        result["currency"]["lines"][0]["reporting_amount"].to_string(),
+        "18000000.0"
+    );
+}
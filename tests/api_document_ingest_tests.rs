@@ -1,6 +1,6 @@
 use crate::api::contracts::{
-    ApiErrorCode, ApiEstateAssetInput, ApiEstateScenarioInput, ApiScenarioDocumentFormat,
-    ApiScenarioDocumentIngestRequest, ApiScenarioDocumentIngestResponse,
+    ApiErrorCode, ApiEstateAssetInput, ApiEstateScenarioInput, ApiJurisdiction,
+    ApiScenarioDocumentFormat, ApiScenarioDocumentIngestRequest, ApiScenarioDocumentIngestResponse,
 };
 use crate::api::handler::{
     calculate_scenario_document_contract, ingest_scenario_document_contract,
@@ -9,21 +9,25 @@ use crate::api::http::app;
 use crate::core::domain::models::EstateScenarioInput;
 use axum::body::{to_bytes, Body};
 use axum::http::{Request, StatusCode};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use tower::ServiceExt;
 
 fn valid_scenario() -> ApiEstateScenarioInput {
     let mut input = ApiEstateScenarioInput::from(EstateScenarioInput::default());
     input.assets = vec![ApiEstateAssetInput {
         name: "Liquidity Portfolio".to_string(),
-        market_value_amount: 5_000_000.0,
-        base_cost_amount: 3_000_000.0,
+        market_value_amount: dec!(5_000_000),
+        base_cost_amount: dec!(3_000_000),
         is_liquid: true,
         situs_in_jurisdiction: true,
+        situs_jurisdiction: None,
         included_in_estate_duty: true,
         included_in_cgt_deemed_disposal: true,
         bequeathed_to_surviving_spouse: false,
         bequeathed_to_pbo: false,
         qualifies_primary_residence_exclusion: false,
+        currency: None,
     }];
     input
 }
@@ -90,7 +94,7 @@ fn calculate_document_contract_returns_result_per_scenario() {
     let response = calculate_scenario_document_contract(request)
         .expect("Expected document calculate contract to succeed");
     assert_eq!(response.results.len(), 1);
-    assert!(response.results[0].combined_tax.total_tax_liability_amount >= 0.0);
+    assert!(response.results[0].combined_tax.total_tax_liability_amount >= Decimal::ZERO);
 }
 
 #[tokio::test]
@@ -173,12 +177,12 @@ Asset Qualifies Primary Residence Exclusion: false
     let response = ingest_scenario_document_contract(request)
         .expect("Expected structured TXT scenario document to parse successfully");
     assert_eq!(response.scenarios.len(), 1);
-    assert_eq!(response.scenarios[0].tax_year, 2026);
+    assert_eq!(response.scenarios[0].tax_year, Some(2026));
     assert_eq!(response.scenarios[0].assets.len(), 1);
     assert_eq!(response.scenarios[0].assets[0].name, "Liquidity Portfolio");
     assert_eq!(
         response.scenarios[0].assets[0].market_value_amount,
-        5_000_000.0
+        dec!(5_000_000)
     );
 }
 
@@ -251,9 +255,40 @@ Primary residence market value R 4,500,000
     let response = ingest_scenario_document_contract(request)
         .expect("Expected narrative TXT estate document to parse successfully");
     assert_eq!(response.scenarios.len(), 1);
-    assert_eq!(response.scenarios[0].tax_year, 2026);
+    assert_eq!(response.scenarios[0].tax_year, Some(2026));
     assert!(!response.scenarios[0].assets.is_empty());
-    assert!(response.scenarios[0].assets[0].market_value_amount > 0.0);
+    assert!(response.scenarios[0].assets[0].market_value_amount > Decimal::ZERO);
+}
+
+#[test]
+fn calculate_document_contract_defaults_narrative_us_estate_from_us_rules() {
+    let document = r#"
+Estate Plan Summary
+Jurisdiction New York
+Tax year 2026
+Primary residence market value $ 2,500,000
+"#
+    .to_string();
+
+    let request = ApiScenarioDocumentIngestRequest {
+        format: ApiScenarioDocumentFormat::Txt,
+        document_content: document,
+        document_content_base64: None,
+    };
+
+    let response = calculate_scenario_document_contract(request)
+        .expect("Expected narrative TXT estate document to calculate");
+    assert_eq!(
+        response.scenarios[0].jurisdiction,
+        ApiJurisdiction::UsNewYork
+    );
+    assert_eq!(response.scenarios[0].vat_rate, None);
+    let vat_default = response.results[0]
+        .defaulted_fields
+        .iter()
+        .find(|defaulted| defaulted.field == "vat_rate")
+        .expect("Expected VAT rate to be defaulted");
+    assert_eq!(vat_default.value, Decimal::ZERO);
 }
 
 #[test]
@@ -279,7 +314,7 @@ Savings account available balance R 1,245,300.50
         crate::api::contracts::ApiJurisdiction::SouthAfrica
     );
     assert!(!response.scenarios[0].assets.is_empty());
-    assert!(response.scenarios[0].assets[0].market_value_amount >= 1_245_300.0);
+    assert!(response.scenarios[0].assets[0].market_value_amount >= dec!(1_245_300));
     assert!(response.scenarios[0].assets[0].is_liquid);
 }
 
@@ -303,7 +338,7 @@ R 1,245,300.50
         .expect("Expected split-line balance TXT document to parse successfully");
     assert_eq!(response.scenarios.len(), 1);
     assert!(!response.scenarios[0].assets.is_empty());
-    assert!(response.scenarios[0].assets[0].market_value_amount >= 1_245_300.0);
+    assert!(response.scenarios[0].assets[0].market_value_amount >= dec!(1_245_300));
 }
 
 #[test]
@@ -329,8 +364,8 @@ Capitec Bank Limited shall have no liability whether in contract, delict (includ
     assert_eq!(response.scenarios.len(), 1);
     assert!(!response.scenarios[0].assets.is_empty());
     let first_asset = &response.scenarios[0].assets[0];
-    assert!(first_asset.market_value_amount >= 1_245_300.0);
-    assert!(first_asset.market_value_amount < 100_000_000.0);
+    assert!(first_asset.market_value_amount >= dec!(1_245_300));
+    assert!(first_asset.market_value_amount < dec!(100_000_000));
     assert!(!first_asset
         .name
         .to_ascii_lowercase()
@@ -404,25 +439,28 @@ Land Value (842 m2 @ R 1 200/m2)
     assert!(!response.scenarios[0].assets.is_empty());
 
     let assets = &response.scenarios[0].assets;
-    assert_eq!(response.scenarios[0].debts_and_loans_amount, 1_300_000.0);
+    assert_eq!(
+        response.scenarios[0].debts_and_loans_amount,
+        dec!(1_300_000)
+    );
     assert!(assets
         .iter()
-        .any(|asset| (asset.market_value_amount - 2_150_000.0).abs() < 0.5));
+        .any(|asset| (asset.market_value_amount - dec!(2_150_000)).abs() < dec!(0.5)));
     assert!(assets
         .iter()
         .all(|asset| !asset.name.to_ascii_lowercase().contains("mortgage bond")));
     assert!(assets
         .iter()
-        .all(|asset| (asset.market_value_amount - 48_291.0).abs() > 0.5));
+        .all(|asset| (asset.market_value_amount - dec!(48_291)).abs() > dec!(0.5)));
     assert!(assets
         .iter()
-        .all(|asset| (asset.market_value_amount - 4_427.0).abs() > 0.5));
+        .all(|asset| (asset.market_value_amount - dec!(4_427)).abs() > dec!(0.5)));
     assert!(assets
         .iter()
-        .all(|asset| (asset.market_value_amount - 1_200.0).abs() > 0.5));
+        .all(|asset| (asset.market_value_amount - dec!(1_200)).abs() > dec!(0.5)));
     assert!(assets
         .iter()
-        .all(|asset| (asset.market_value_amount - 5.0).abs() > 0.5));
+        .all(|asset| (asset.market_value_amount - dec!(5)).abs() > dec!(0.5)));
 }
 
 #[test]
@@ -24,12 +24,12 @@ fn api_scenario_wrapper_returns_validation_error_contract() {
 
 #[test]
 fn api_rule_resolution_wrapper_returns_rule_selection_error_contract() {
-    let err = resolve_tax_rules_for_year_api(Jurisdiction::SouthAfrica, 2017)
+    let err = resolve_tax_rules_for_year_api(Jurisdiction::SouthAfrica, 2001)
         .expect_err("Expected unsupported year to map to API error contract");
 
     assert_eq!(err.code, ApiErrorCode::RuleSelection);
     assert!(err.validation_issues.is_empty());
-    assert!(err.message.contains("2017"));
+    assert!(err.message.contains("2001"));
 }
 
 #[test]
@@ -1,10 +1,15 @@
 use crate::api::contracts::{
-    ApiErrorCode, ApiErrorResponse, ApiEstateScenarioInput, ApiHealthResponse,
+    ApiDonationsTaxInput, ApiDonationsTaxResult, ApiErrorCode, ApiErrorResponse,
+    ApiEstateScenarioInput, ApiHealthResponse, ApiJurisdiction, ApiLifetimeDonationInput,
+    ApiRankedStrategy, ApiStrategyLever, ApiStrategyOptimizationRequest, ApiTaxPayerClass,
+    ApiTaxRuleChangeKind, ApiTaxRuleVersionDiff,
 };
-use crate::api::http::app;
-use crate::core::domain::models::EstateScenarioInput;
+use crate::api::http::{app, app_with_state, AdminToken, AppState};
+use crate::core::domain::models::{EstateAsset, EstateScenarioInput};
 use axum::body::{to_bytes, Body};
 use axum::http::{Request, StatusCode};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use tower::ServiceExt;
 
 #[tokio::test]
@@ -49,6 +54,105 @@ async fn health_db_endpoint_returns_service_unavailable_without_pool() {
     assert_eq!(parsed.code, ApiErrorCode::Computation);
 }
 
+fn admin_app() -> axum::Router {
+    app_with_state(AppState {
+        db_pool: None,
+        rules_dir: None,
+        admin_tokens: vec![AdminToken {
+            principal: "publisher@example.com".to_string(),
+            token: "admin-token".to_string(),
+        }],
+    })
+}
+
+#[tokio::test]
+async fn admin_endpoints_require_a_known_bearer_token() {
+    for authorization in [None, Some("Bearer wrong-token"), Some("admin-token")] {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/v1/admin/rules/versions/1/retire");
+        if let Some(authorization) = authorization {
+            request = request.header("authorization", authorization);
+        }
+        let response = admin_app()
+            .oneshot(
+                request
+                    .body(Body::empty())
+                    .expect("Failed to build request"),
+            )
+            .await
+            .expect("Route call failed");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            "{authorization:?}"
+        );
+    }
+
+    // Without configured tokens every admin request is refused.
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .uri("/v1/admin/rules/versions/1")
+                .header("authorization", "Bearer admin-token")
+                .body(Body::empty())
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn rules_reload_endpoint_returns_service_unavailable_without_rules_dir() {
+    let response = admin_app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/admin/rules/reload")
+                .header("authorization", "Bearer admin-token")
+                .body(Body::empty())
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let parsed: ApiErrorResponse =
+        serde_json::from_slice(&body).expect("Failed to deserialize error response");
+    assert!(parsed.message.contains("TAX_RULES_DIR"));
+}
+
+#[tokio::test]
+async fn rule_version_endpoints_return_service_unavailable_without_database() {
+    for (method, uri) in [
+        (
+            "GET",
+            "/v1/admin/rules/versions?jurisdiction=za&status=Published",
+        ),
+        ("GET", "/v1/admin/rules/versions/1"),
+        ("POST", "/v1/admin/rules/versions/1/retire"),
+    ] {
+        let response = admin_app()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("authorization", "Bearer admin-token")
+                    .body(Body::empty())
+                    .expect("Failed to build request"),
+            )
+            .await
+            .expect("Route call failed");
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE, "{uri}");
+    }
+}
+
 #[tokio::test]
 async fn calculate_endpoint_returns_validation_error_for_empty_assets() {
     let payload = ApiEstateScenarioInput::from(EstateScenarioInput::default());
@@ -79,12 +183,227 @@ async fn calculate_endpoint_returns_validation_error_for_empty_assets() {
         .any(|issue| issue.field == "assets"));
 }
 
+#[tokio::test]
+async fn donations_endpoint_returns_per_year_breakdown() {
+    let payload = ApiDonationsTaxInput {
+        jurisdiction: ApiJurisdiction::SouthAfrica,
+        taxpayer_class: ApiTaxPayerClass::NaturalPerson,
+        prior_cumulative_taxable_donations_amount: Decimal::ZERO,
+        donations: vec![ApiLifetimeDonationInput {
+            donee: "Child".to_string(),
+            donated_on: "2025-07-01".to_string(),
+            amount: dec!(600_000),
+            exempt_from_donations_tax: false,
+        }],
+    };
+    let body = serde_json::to_vec(&payload).expect("Failed to serialize payload");
+
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/donations/calculate")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let parsed: ApiDonationsTaxResult =
+        serde_json::from_slice(&body).expect("Failed to deserialize donations response");
+    assert_eq!(parsed.tax_years.len(), 1);
+    assert_eq!(parsed.tax_years[0].tax_year, 2026);
+    assert!((parsed.total_tax_payable_amount - dec!(100_000)).abs() < dec!(0.01));
+}
+
+#[tokio::test]
+async fn strategies_endpoint_returns_ranked_strategies() {
+    let payload = ApiStrategyOptimizationRequest {
+        scenario: ApiEstateScenarioInput::from(EstateScenarioInput {
+            assets: vec![EstateAsset {
+                name: "Farm".to_string(),
+                market_value_amount: dec!(10_000_000),
+                base_cost_amount: dec!(2_000_000),
+                is_liquid: false,
+                situs_in_jurisdiction: true,
+                situs_jurisdiction: None,
+                included_in_estate_duty: true,
+                included_in_cgt_deemed_disposal: true,
+                bequeathed_to_surviving_spouse: false,
+                bequeathed_to_pbo: false,
+                qualifies_primary_residence_exclusion: false,
+                currency: None,
+            }],
+            ..EstateScenarioInput::default()
+        }),
+        annual_donation_years: Some(0),
+        planning_date: Some("2025-03-01".to_string()),
+    };
+    let body = serde_json::to_vec(&payload).expect("Failed to serialize payload");
+
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/scenario/optimize/strategies")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let parsed: Vec<ApiRankedStrategy> =
+        serde_json::from_slice(&body).expect("Failed to deserialize strategies response");
+    assert_eq!(parsed[0].rank, 1);
+    assert!(parsed
+        .iter()
+        .any(|strategy| strategy.name == "Current plan" && strategy.levers.is_empty()));
+    assert!(parsed
+        .iter()
+        .any(|strategy| strategy.levers == vec![ApiStrategyLever::LifeCover]));
+    assert!(parsed
+        .iter()
+        .flat_map(|strategy| &strategy.levers)
+        .all(|lever| *lever != ApiStrategyLever::AnnualDonations));
+}
+
+#[tokio::test]
+async fn strategies_endpoint_prefixes_scenario_validation_fields() {
+    let payload = ApiStrategyOptimizationRequest {
+        scenario: ApiEstateScenarioInput::from(EstateScenarioInput::default()),
+        annual_donation_years: None,
+        planning_date: None,
+    };
+    let body = serde_json::to_vec(&payload).expect("Failed to serialize payload");
+
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/scenario/optimize/strategies")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let parsed: ApiErrorResponse =
+        serde_json::from_slice(&body).expect("Failed to deserialize error response");
+    assert_eq!(parsed.code, ApiErrorCode::Validation);
+    assert!(parsed
+        .validation_issues
+        .iter()
+        .any(|issue| issue.field == "scenario.assets"));
+}
+
+#[tokio::test]
+async fn liquidation_account_pdf_endpoint_returns_pdf_document() {
+    let payload = ApiEstateScenarioInput::from(EstateScenarioInput {
+        assets: vec![EstateAsset {
+            name: "Portfolio".to_string(),
+            market_value_amount: dec!(5_000_000),
+            base_cost_amount: dec!(5_000_000),
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: false,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }],
+        ..EstateScenarioInput::default()
+    });
+    let body = serde_json::to_vec(&payload).expect("Failed to serialize payload");
+
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/scenario/liquidation-account/pdf")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/pdf");
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    assert!(body.starts_with(b"%PDF"));
+}
+
+#[tokio::test]
+async fn explain_endpoint_returns_plain_text_trace() {
+    let payload = ApiEstateScenarioInput::from(EstateScenarioInput {
+        assets: vec![EstateAsset {
+            name: "Portfolio".to_string(),
+            market_value_amount: dec!(5_000_000),
+            base_cost_amount: dec!(5_000_000),
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: false,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }],
+        ..EstateScenarioInput::default()
+    });
+    let body = serde_json::to_vec(&payload).expect("Failed to serialize payload");
+
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/scenario/calculate/explain")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/plain; charset=utf-8"
+    );
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let text = String::from_utf8(body.to_vec()).expect("Expected UTF-8 text");
+    assert!(text.starts_with("Calculation trace: SouthAfrica"));
+    assert!(text.contains("Rule: estate_duty.rate_schedule"));
+}
+
 #[tokio::test]
 async fn rules_endpoint_returns_rule_selection_error_for_unsupported_year() {
     let response = app()
         .oneshot(
             Request::builder()
-                .uri("/v1/rules/south-africa/2017")
+                .uri("/v1/rules/south-africa/2001")
                 .body(Body::empty())
                 .expect("Failed to build request"),
         )
@@ -98,7 +417,43 @@ async fn rules_endpoint_returns_rule_selection_error_for_unsupported_year() {
     let parsed: ApiErrorResponse =
         serde_json::from_slice(&body).expect("Failed to deserialize error response");
     assert_eq!(parsed.code, ApiErrorCode::RuleSelection);
-    assert!(parsed.message.contains("2017"));
+    assert!(parsed.message.contains("2001"));
+}
+
+#[tokio::test]
+async fn rules_diff_endpoint_lists_changes_between_versions() {
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .uri("/v1/rules/diff/za?from=ZA-ESTATE-2013-2015&to=ZA-ESTATE-2016")
+                .body(Body::empty())
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let parsed: ApiTaxRuleVersionDiff =
+        serde_json::from_slice(&body).expect("Failed to deserialize diff response");
+    assert_eq!(parsed.to_version_id, "ZA-ESTATE-2016");
+    assert!(parsed.changes.iter().any(|change| change.field
+        == "retirement_funds.disallowed_contributions_in_estate"
+        && change.kind == ApiTaxRuleChangeKind::Flag
+        && change.description.ends_with("false -> true")));
+
+    let response = app()
+        .oneshot(
+            Request::builder()
+                .uri("/v1/rules/diff/za?from=ZA-ESTATE-2016&to=ZA-UNKNOWN")
+                .body(Body::empty())
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Route call failed");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
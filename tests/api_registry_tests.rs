@@ -50,7 +50,7 @@ fn api_exposes_jurisdiction_registry_summary() {
         .expect("Expected registry summary for South Africa");
 
     assert_eq!(summary.jurisdiction, Jurisdiction::SouthAfrica);
-    assert_eq!(summary.supported_tax_year_from, 2018);
+    assert_eq!(summary.supported_tax_year_from, 2002);
     assert_eq!(summary.supported_tax_year_to, None);
     assert_eq!(summary.latest_version_id, "ZA-ESTATE-BASELINE-2018+");
     assert!(!summary.versions.is_empty());
@@ -8,13 +8,13 @@ fn api_resolves_tax_rules_for_supported_year() {
         .expect("Expected supported tax year to resolve");
 
     assert_eq!(selected.version.version_id, "ZA-ESTATE-BASELINE-2018+");
-    assert_eq!(selected.version.tax_year_from, 2018);
+    assert_eq!(selected.version.tax_year_from, 2019);
     assert_eq!(selected.version.tax_year_to, None);
 }
 
 #[test]
 fn api_rejects_unsupported_tax_year() {
-    let err = resolve_tax_rules_for_year(Jurisdiction::SouthAfrica, 2017)
+    let err = resolve_tax_rules_for_year(Jurisdiction::SouthAfrica, 2001)
         .expect_err("Expected unsupported tax year to fail");
 
     let EngineError::RuleSelection(selection_error) = err else {
@@ -23,7 +23,7 @@ fn api_rejects_unsupported_tax_year() {
 
     let rendered = selection_error.to_string();
     assert!(rendered.contains("SouthAfrica"));
-    assert!(rendered.contains("2017"));
+    assert!(rendered.contains("2001"));
 }
 
 #[test]
@@ -51,7 +51,7 @@ fn contract_exposes_jurisdiction_registry_summary() {
         .expect("Expected contract registry summary for South Africa");
 
     assert_eq!(summary.jurisdiction, ApiJurisdiction::SouthAfrica);
-    assert_eq!(summary.supported_tax_year_from, 2018);
+    assert_eq!(summary.supported_tax_year_from, 2002);
     assert_eq!(summary.supported_tax_year_to, None);
     assert_eq!(summary.latest_version_id, "ZA-ESTATE-BASELINE-2018+");
     assert!(!summary.versions.is_empty());
@@ -63,16 +63,16 @@ fn contract_resolves_rules_for_supported_year() {
         .expect("Expected contract rule resolution for supported tax year");
 
     assert_eq!(selected.version.version_id, "ZA-ESTATE-BASELINE-2018+");
-    assert_eq!(selected.version.tax_year_from, 2018);
+    assert_eq!(selected.version.tax_year_from, 2019);
     assert_eq!(selected.version.tax_year_to, None);
 }
 
 #[test]
 fn contract_rejects_unsupported_year() {
-    let err = resolve_tax_rules_for_year_contract(ApiJurisdiction::SouthAfrica, 2017)
+    let err = resolve_tax_rules_for_year_contract(ApiJurisdiction::SouthAfrica, 2001)
         .expect_err("Expected contract rule resolution to fail");
     assert_eq!(err.code, ApiErrorCode::RuleSelection);
-    assert!(err.message.contains("2017"));
+    assert!(err.message.contains("2001"));
 }
 
 #[test]
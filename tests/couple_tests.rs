@@ -0,0 +1,199 @@
+use crate::api::handler::calculate_couple_scenario;
+use crate::core::domain::models::{
+    CoupleScenarioInput, DeathOrder, EstateAsset, EstateScenarioInput, MaritalPropertyInput,
+    MaritalPropertyRegime,
+};
+use crate::core::engine::couple::calculate_couple_second_death;
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::Jurisdiction;
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn spouse_asset(
+    name: &str,
+    market_value_amount: Decimal,
+    base_cost_amount: Decimal,
+    to_spouse: bool,
+) -> EstateAsset {
+    EstateAsset {
+        base_cost_amount,
+        included_in_cgt_deemed_disposal: base_cost_amount < market_value_amount,
+        bequeathed_to_surviving_spouse: to_spouse,
+        ..asset(name, market_value_amount)
+    }
+}
+
+fn spouse_estate(assets: Vec<EstateAsset>) -> EstateScenarioInput {
+    EstateScenarioInput {
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        assets,
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn ports_unused_section_4a_abatement_and_spouse_bequests_to_survivor() {
+    let input = CoupleScenarioInput {
+        spouse_a: spouse_estate(vec![spouse_asset(
+            "Investment portfolio",
+            dec!(10_000_000),
+            dec!(10_000_000),
+            true,
+        )]),
+        spouse_b: spouse_estate(vec![spouse_asset(
+            "Savings",
+            dec!(2_000_000),
+            dec!(2_000_000),
+            false,
+        )]),
+        order_of_death: DeathOrder::SpouseAFirst,
+    };
+
+    let result = calculate_couple_second_death(&input).expect("Expected couple calculation");
+
+    assert_eq!(
+        result.first_death.combined_tax.total_tax_liability_amount,
+        Decimal::ZERO
+    );
+    assert!((result.ported_exemption_amount - dec!(3_500_000)).abs() < dec!(0.1));
+    assert!((result.rolled_over_to_survivor_amount - dec!(10_000_000)).abs() < dec!(0.1));
+    assert!(
+        (result.second_death.estate_duty.exemption_used_amount - dec!(7_000_000)).abs() < dec!(0.1)
+    );
+    assert!(
+        (result.second_death.estate_duty.tax_payable_amount - dec!(1_000_000)).abs() < dec!(0.1)
+    );
+    assert!((result.combined_tax_liability_amount - dec!(1_000_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn order_of_death_changes_ported_abatement_and_survivor_base_cost() {
+    let input = CoupleScenarioInput {
+        spouse_a: spouse_estate(vec![
+            spouse_asset("Shares", dec!(1_300_000), dec!(300_000), true),
+            spouse_asset("Holiday home", dec!(5_000_000), dec!(5_000_000), false),
+        ]),
+        spouse_b: spouse_estate(vec![spouse_asset(
+            "Savings",
+            dec!(1_000_000),
+            dec!(1_000_000),
+            true,
+        )]),
+        order_of_death: DeathOrder::SpouseAFirst,
+    };
+
+    let a_first = calculate_couple_second_death(&input).expect("Expected couple calculation");
+    // First estate nets R5m after the spousal deduction, so the whole abatement is used.
+    assert_eq!(a_first.ported_exemption_amount, Decimal::ZERO);
+    // Spousal roll-over hands the survivor the original R300k base cost.
+    assert!(
+        (a_first.second_death.cgt.gross_capital_gain_amount - dec!(1_000_000)).abs() < dec!(0.1)
+    );
+
+    let b_first = calculate_couple_second_death(&CoupleScenarioInput {
+        order_of_death: DeathOrder::SpouseBFirst,
+        ..input
+    })
+    .expect("Expected couple calculation");
+    assert!((b_first.ported_exemption_amount - dec!(3_500_000)).abs() < dec!(0.1));
+    assert!(b_first.combined_tax_liability_amount < a_first.combined_tax_liability_amount);
+}
+
+#[test]
+fn abatement_only_ports_from_first_deaths_on_or_after_1_january_2010() {
+    let ported_for = |date_of_death: Option<&str>, tax_year: u16| {
+        let mut spouse_a = spouse_estate(vec![spouse_asset(
+            "Investment portfolio",
+            dec!(10_000_000),
+            dec!(10_000_000),
+            true,
+        )]);
+        spouse_a.tax_year = tax_year;
+        spouse_a.date_of_death = date_of_death.map(str::to_string);
+        calculate_couple_second_death(&CoupleScenarioInput {
+            spouse_a,
+            spouse_b: spouse_estate(vec![spouse_asset(
+                "Savings",
+                dec!(2_000_000),
+                dec!(2_000_000),
+                false,
+            )]),
+            order_of_death: DeathOrder::SpouseAFirst,
+        })
+        .expect("Expected couple calculation")
+        .ported_exemption_amount
+    };
+
+    assert_eq!(ported_for(Some("2008-06-01"), 2009), Decimal::ZERO);
+    assert_eq!(ported_for(Some("2009-12-31"), 2010), Decimal::ZERO);
+    assert!((ported_for(Some("2010-01-01"), 2010) - dec!(3_500_000)).abs() < dec!(0.1));
+    // Without a date the 2010 tax year starts on 1 March 2009, before portability.
+    assert_eq!(ported_for(None, 2010), Decimal::ZERO);
+    assert!((ported_for(None, 2011) - dec!(3_500_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn only_the_deceased_half_of_a_joint_estate_rolls_over() {
+    let mut spouse_a = spouse_estate(vec![spouse_asset(
+        "Joint portfolio",
+        dec!(10_000_000),
+        dec!(10_000_000),
+        true,
+    )]);
+    spouse_a.marital_property = MaritalPropertyInput {
+        regime: MaritalPropertyRegime::InCommunity,
+        ..MaritalPropertyInput::default()
+    };
+    let result = calculate_couple_second_death(&CoupleScenarioInput {
+        spouse_a,
+        spouse_b: spouse_estate(vec![spouse_asset(
+            "Survivor's half of the joint portfolio",
+            dec!(5_000_000),
+            dec!(5_000_000),
+            false,
+        )]),
+        order_of_death: DeathOrder::SpouseAFirst,
+    })
+    .expect("Expected couple calculation");
+
+    assert!((result.rolled_over_to_survivor_amount - dec!(5_000_000)).abs() < dec!(0.1));
+    assert!(
+        (result
+            .second_death
+            .estate_duty
+            .gross_estate_for_transfer_tax_amount
+            - dec!(10_000_000))
+        .abs()
+            < dec!(0.1)
+    );
+}
+
+#[test]
+fn validation_requires_matching_jurisdictions_and_prefixes_spouse_fields() {
+    let mut spouse_b = spouse_estate(Vec::new());
+    spouse_b.jurisdiction = Jurisdiction::UsTexas;
+    let input = CoupleScenarioInput {
+        spouse_a: spouse_estate(vec![spouse_asset(
+            "Savings",
+            dec!(1_000_000),
+            dec!(1_000_000),
+            false,
+        )]),
+        spouse_b,
+        order_of_death: DeathOrder::SpouseAFirst,
+    };
+
+    let err = calculate_couple_scenario(&input).expect_err("Expected couple validation to fail");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected validation error");
+    };
+    assert!(validation
+        .issues
+        .iter()
+        .any(|issue| issue.field == "spouse_b.assets"));
+    assert!(validation
+        .issues
+        .iter()
+        .any(|issue| issue.field == "spouse_b.jurisdiction"));
+}
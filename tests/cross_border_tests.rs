@@ -0,0 +1,174 @@
+use crate::core::domain::models::{
+    CrossBorderScenarioInput, EstateAsset, EstateScenarioInput, ForeignJurisdictionFiling, FxRate,
+    FxRateTable, ResidencyStatus,
+};
+use crate::core::engine::cross_border::calculate_cross_border_scenario;
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{Currency, Jurisdiction};
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn sited_asset(
+    name: &str,
+    market_value_amount: Decimal,
+    situs_jurisdiction: Jurisdiction,
+    currency: Currency,
+) -> EstateAsset {
+    EstateAsset {
+        situs_jurisdiction: Some(situs_jurisdiction),
+        included_in_cgt_deemed_disposal: true,
+        currency: Some(currency),
+        ..asset(name, market_value_amount)
+    }
+}
+
+fn cross_border_input(us_market_value_amount: Decimal) -> CrossBorderScenarioInput {
+    CrossBorderScenarioInput {
+        estate: EstateScenarioInput {
+            assets: vec![
+                sited_asset(
+                    "JSE portfolio",
+                    dec!(40_000_000),
+                    Jurisdiction::SouthAfrica,
+                    Currency::Zar,
+                ),
+                sited_asset(
+                    "US brokerage account",
+                    us_market_value_amount,
+                    Jurisdiction::UsFlorida,
+                    Currency::Usd,
+                ),
+            ],
+            fx_rates: Some(FxRateTable {
+                valuation_date: "2025-11-14".to_string(),
+                rates: vec![FxRate {
+                    currency: Currency::Usd,
+                    rate: dec!(18),
+                }],
+            }),
+            ..EstateScenarioInput::default()
+        },
+        foreign_filings: vec![ForeignJurisdictionFiling {
+            jurisdiction: Jurisdiction::UsFlorida,
+            tax_year: 2026,
+            administration_costs_amount: Decimal::ZERO,
+        }],
+    }
+}
+
+#[test]
+fn situs_jurisdiction_taxes_non_resident_estate_on_its_own_base() {
+    let result = calculate_cross_border_scenario(&cross_border_input(dec!(2_000_000)))
+        .expect("Expected calculation to succeed for supported tax years");
+
+    let us = &result.foreign[0];
+    assert_eq!(us.jurisdiction, Jurisdiction::UsFlorida);
+    assert_eq!(us.reporting_currency, Currency::Usd);
+    assert!((us.rate_to_home_currency - dec!(18)).abs() < dec!(0.000000001));
+    // Only the US-situs asset, with the $60,000 non-resident exemption: $745,800 tentative tax
+    // less the $13,000 section 2102 credit.
+    assert!(
+        (us.result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(2_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((us.result.combined_tax.estate_transfer_tax_amount - dec!(732_800)).abs() < dec!(0.1));
+    assert!((us.foreign_death_duty_amount - dec!(732_800) * dec!(18)).abs() < dec!(0.1));
+
+    // South Africa taxes the worldwide estate in rand.
+    assert!(
+        (result.home.estate_duty.gross_estate_for_transfer_tax_amount - dec!(76_000_000)).abs()
+            < dec!(0.1)
+    );
+}
+
+#[test]
+fn section_16_rebate_is_capped_at_sa_duty_on_foreign_assets() {
+    let result = calculate_cross_border_scenario(&cross_border_input(dec!(2_000_000)))
+        .expect("Expected calculation to succeed for supported tax years");
+
+    let us = &result.foreign[0];
+    let expected_home_duty_amount =
+        result.home.combined_tax.estate_transfer_tax_amount * dec!(36_000_000) / dec!(76_000_000);
+    assert!((us.home_duty_on_situs_assets_amount - expected_home_duty_amount).abs() < dec!(0.1));
+    assert!(us.foreign_death_duty_amount > us.home_duty_on_situs_assets_amount);
+    assert!((us.death_duty_credit_amount - expected_home_duty_amount).abs() < dec!(0.1));
+
+    let consolidated = &result.consolidated;
+    assert_eq!(consolidated.reporting_currency, Currency::Zar);
+    assert!(
+        (consolidated.total_tax_liability_amount
+            - (result.home.combined_tax.total_tax_liability_amount - us.death_duty_credit_amount
+                + us.foreign_tax_liability_amount))
+            .abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (consolidated.immediate_cash_requirements_amount
+            - (result.home.liquidity.immediate_cash_requirements_amount
+                - us.death_duty_credit_amount
+                + us.foreign_tax_liability_amount))
+            .abs()
+            < dec!(0.1)
+    );
+}
+
+#[test]
+fn rebate_is_limited_to_foreign_duty_paid() {
+    let result = calculate_cross_border_scenario(&cross_border_input(dec!(100_000)))
+        .expect("Expected calculation to succeed for supported tax years");
+
+    let us = &result.foreign[0];
+    // $23,800 tentative tax on $100,000 less the $13,000 credit.
+    assert!((us.foreign_death_duty_amount - dec!(10_800) * dec!(18)).abs() < dec!(0.1));
+    assert!(us.home_duty_on_situs_assets_amount > us.foreign_death_duty_amount);
+    assert!((us.death_duty_credit_amount - us.foreign_death_duty_amount).abs() < dec!(0.1));
+}
+
+#[test]
+fn foreign_filing_uses_the_estate_rates_and_leaves_out_cgt() {
+    let mut input = cross_border_input(dec!(2_000_000));
+    input.estate.assets[1].base_cost_amount = dec!(500_000);
+
+    let result = calculate_cross_border_scenario(&input)
+        .expect("Expected calculation to succeed for supported tax years");
+    let us = &result.foreign[0];
+    assert_eq!(
+        us.result.currency.valuation_date.as_deref(),
+        Some("2025-11-14")
+    );
+    assert_eq!(us.result.cgt.tax_payable_amount, Decimal::ZERO);
+    assert!((us.foreign_tax_liability_amount - us.foreign_death_duty_amount).abs() < dec!(0.1));
+
+    input.estate.fx_rates = None;
+    let err = calculate_cross_border_scenario(&input)
+        .expect_err("Expected the missing USD rate to fail the calculation");
+    assert!(matches!(err, EngineError::Validation(_)), "{err:?}");
+}
+
+#[test]
+fn rejects_incomplete_cross_border_input() {
+    let mut input = cross_border_input(dec!(2_000_000));
+    input.estate.residency_status = ResidencyStatus::NonResident;
+    input.estate.fx_rates = None;
+    input.estate.assets[1].situs_jurisdiction = Some(Jurisdiction::UsNewYork);
+    input.foreign_filings.push(ForeignJurisdictionFiling {
+        jurisdiction: Jurisdiction::SouthAfrica,
+        tax_year: 2026,
+        administration_costs_amount: Decimal::ZERO,
+    });
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    for field in [
+        "estate.residency_status",
+        "estate.assets[1].currency",
+        "estate.assets[1].situs_jurisdiction",
+        "foreign_filings[0].jurisdiction",
+        "foreign_filings[1].jurisdiction",
+    ] {
+        assert!(
+            err.issues.iter().any(|issue| issue.field == field),
+            "Expected issue for {field}"
+        );
+    }
+}
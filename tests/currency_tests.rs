@@ -0,0 +1,260 @@
+use crate::core::domain::models::{
+    CurrencyLiability, EstateAsset, EstateScenarioInput, FxRate, FxRateTable,
+};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{Currency, Jurisdiction};
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn foreign_asset(
+    name: &str,
+    market_value_amount: Decimal,
+    base_cost_amount: Decimal,
+    currency: Option<Currency>,
+) -> EstateAsset {
+    EstateAsset {
+        base_cost_amount,
+        included_in_cgt_deemed_disposal: true,
+        currency,
+        ..asset(name, market_value_amount)
+    }
+}
+
+fn fx_rates() -> FxRateTable {
+    FxRateTable {
+        valuation_date: "2025-11-14".to_string(),
+        rates: vec![
+            FxRate {
+                currency: Currency::Usd,
+                rate: dec!(18),
+            },
+            FxRate {
+                currency: Currency::Gbp,
+                rate: dec!(23),
+            },
+        ],
+    }
+}
+
+#[test]
+fn foreign_currency_amounts_match_pre_converted_scenario() {
+    let foreign = EstateScenarioInput {
+        assets: vec![
+            foreign_asset("Local portfolio", dec!(5_000_000), dec!(3_000_000), None),
+            foreign_asset(
+                "Offshore brokerage",
+                dec!(500_000),
+                dec!(300_000),
+                Some(Currency::Usd),
+            ),
+        ],
+        debts_and_loans_amount: dec!(100_000),
+        liabilities: vec![CurrencyLiability {
+            description: "London overdraft".to_string(),
+            currency: Currency::Gbp,
+            amount: dec!(10_000),
+        }],
+        fx_rates: Some(fx_rates()),
+        ..EstateScenarioInput::default()
+    };
+    let converted = EstateScenarioInput {
+        assets: vec![
+            foreign_asset("Local portfolio", dec!(5_000_000), dec!(3_000_000), None),
+            foreign_asset("Offshore brokerage", dec!(9_000_000), dec!(5_400_000), None),
+        ],
+        debts_and_loans_amount: dec!(330_000),
+        ..EstateScenarioInput::default()
+    };
+
+    assert!(foreign.validate().is_ok());
+    let foreign_result = calculate_combined_tax_and_liquidity(&foreign)
+        .expect("Expected calculation to succeed for supported tax year");
+    let converted_result = calculate_combined_tax_and_liquidity(&converted)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert!(
+        (foreign_result.cgt.gross_capital_gain_amount
+            - converted_result.cgt.gross_capital_gain_amount)
+            .abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (foreign_result.combined_tax.total_tax_liability_amount
+            - converted_result.combined_tax.total_tax_liability_amount)
+            .abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (foreign_result.liquidity.immediate_cash_requirements_amount
+            - converted_result
+                .liquidity
+                .immediate_cash_requirements_amount)
+            .abs()
+            < dec!(0.1)
+    );
+}
+
+#[test]
+fn result_echoes_original_currency_figures() {
+    let input = EstateScenarioInput {
+        assets: vec![
+            foreign_asset("Local portfolio", dec!(5_000_000), dec!(3_000_000), None),
+            foreign_asset(
+                "Offshore brokerage",
+                dec!(500_000),
+                dec!(300_000),
+                Some(Currency::Usd),
+            ),
+        ],
+        liabilities: vec![CurrencyLiability {
+            description: "London overdraft".to_string(),
+            currency: Currency::Gbp,
+            amount: dec!(10_000),
+        }],
+        fx_rates: Some(fx_rates()),
+        ..EstateScenarioInput::default()
+    };
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    let conversion = &result.currency;
+    assert_eq!(conversion.reporting_currency, Currency::Zar);
+    assert_eq!(conversion.valuation_date.as_deref(), Some("2025-11-14"));
+    assert_eq!(conversion.lines.len(), 3);
+    let market_value = conversion
+        .lines
+        .iter()
+        .find(|line| line.item_name == "Offshore brokerage" && line.field == "market_value_amount")
+        .expect("Expected market value conversion line");
+    assert_eq!(market_value.currency, Currency::Usd);
+    assert!((market_value.original_amount - dec!(500_000)).abs() < dec!(0.1));
+    assert!((market_value.reporting_amount - dec!(9_000_000)).abs() < dec!(0.1));
+    let overdraft = conversion
+        .lines
+        .iter()
+        .find(|line| line.item_name == "London overdraft")
+        .expect("Expected liability conversion line");
+    assert!((overdraft.reporting_amount - dec!(230_000)).abs() < dec!(0.1));
+
+    let brokerage = result
+        .assets
+        .iter()
+        .find(|line| line.asset_name == "Offshore brokerage")
+        .expect("Expected attribution line for foreign asset");
+    assert!((brokerage.market_value_amount - dec!(9_000_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn reporting_currency_follows_jurisdiction() {
+    let input = EstateScenarioInput {
+        jurisdiction: Jurisdiction::UsNewYork,
+        assets: vec![
+            foreign_asset(
+                "Brokerage account",
+                dec!(2_000_000),
+                dec!(1_000_000),
+                Some(Currency::Usd),
+            ),
+            foreign_asset(
+                "Cape Town flat",
+                dec!(9_000_000),
+                dec!(9_000_000),
+                Some(Currency::Zar),
+            ),
+        ],
+        fx_rates: Some(FxRateTable {
+            valuation_date: "2026-03-31".to_string(),
+            rates: vec![FxRate {
+                currency: Currency::Zar,
+                rate: dec!(0.055),
+            }],
+        }),
+        ..EstateScenarioInput::default()
+    };
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(result.currency.reporting_currency, Currency::Usd);
+    assert_eq!(result.currency.lines.len(), 2);
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(2_495_000)).abs()
+            < dec!(0.1)
+    );
+}
+
+#[test]
+fn rejects_foreign_currency_without_usable_rate() {
+    let input = EstateScenarioInput {
+        assets: vec![foreign_asset(
+            "Paris apartment",
+            dec!(400_000),
+            dec!(250_000),
+            Some(Currency::Eur),
+        )],
+        liabilities: vec![CurrencyLiability {
+            description: " ".to_string(),
+            currency: Currency::Usd,
+            amount: -dec!(5),
+        }],
+        fx_rates: Some(FxRateTable {
+            valuation_date: "2025-02-30".to_string(),
+            rates: vec![
+                FxRate {
+                    currency: Currency::Usd,
+                    rate: Decimal::ZERO,
+                },
+                FxRate {
+                    currency: Currency::Usd,
+                    rate: dec!(18),
+                },
+            ],
+        }),
+        ..EstateScenarioInput::default()
+    };
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    for field in [
+        "assets[0].currency",
+        "liabilities[0].description",
+        "liabilities[0].amount",
+        "fx_rates.valuation_date",
+        "fx_rates.rates[0].rate",
+        "fx_rates.rates[1].currency",
+    ] {
+        assert!(
+            err.issues.iter().any(|issue| issue.field == field),
+            "Expected issue for {field}"
+        );
+    }
+}
+
+#[test]
+fn calculation_without_a_rate_fails_instead_of_converting_at_par() {
+    let input = EstateScenarioInput {
+        assets: vec![foreign_asset(
+            "Offshore brokerage",
+            dec!(500_000),
+            dec!(300_000),
+            Some(Currency::Usd),
+        )],
+        fx_rates: Some(FxRateTable {
+            valuation_date: "2025-11-14".to_string(),
+            rates: vec![FxRate {
+                currency: Currency::Gbp,
+                rate: dec!(23),
+            }],
+        }),
+        ..EstateScenarioInput::default()
+    };
+
+    // Library callers can skip `validate()`; the engine still refuses to value USD 1:1 in rand.
+    let err = calculate_combined_tax_and_liquidity(&input)
+        .expect_err("Expected the missing USD rate to fail the calculation");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected a validation error, got {err:?}");
+    };
+    assert_eq!(validation.issues[0].field, "fx_rates");
+    assert!(validation.issues[0].message.contains("USD"));
+}
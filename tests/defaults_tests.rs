@@ -0,0 +1,164 @@
+use crate::core::domain::models::{EstateAsset, EstateScenarioInput};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::rules::tax_rules::{Jurisdiction, TaxPayerClass};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn input_for(jurisdiction: Jurisdiction, tax_year: u16) -> EstateScenarioInput {
+    EstateScenarioInput {
+        jurisdiction,
+        tax_year,
+        assets: vec![EstateAsset {
+            name: "Family home".to_string(),
+            market_value_amount: dec!(4_000_000),
+            base_cost_amount: dec!(1_000_000),
+            is_liquid: false,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: true,
+            currency: None,
+        }],
+        ..EstateScenarioInput::default()
+    }
+}
+
+fn defaulted_value(
+    result: &crate::core::domain::models::ScenarioResult,
+    field: &str,
+) -> Option<Decimal> {
+    result
+        .defaulted_fields
+        .iter()
+        .find(|defaulted| defaulted.field == field)
+        .map(|defaulted| defaulted.value)
+}
+
+#[test]
+fn south_african_defaults_come_from_the_rule_version() {
+    let result = calculate_combined_tax_and_liquidity(&input_for(Jurisdiction::SouthAfrica, 2026))
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(
+        defaulted_value(&result, "primary_residence_cgt_exclusion_cap_amount"),
+        Some(dec!(2_000_000))
+    );
+    assert_eq!(defaulted_value(&result, "vat_rate"), Some(dec!(0.15)));
+    assert_eq!(
+        result.cgt.primary_residence_exclusion_used_amount,
+        dec!(2_000_000)
+    );
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.vat_amount, fee.fee_before_vat_amount * dec!(0.15));
+}
+
+#[test]
+fn defaults_follow_the_tax_year_and_taxpayer_class() {
+    let result = calculate_combined_tax_and_liquidity(&input_for(Jurisdiction::SouthAfrica, 2018))
+        .expect("Expected calculation to succeed for supported tax year");
+    assert_eq!(defaulted_value(&result, "vat_rate"), Some(dec!(0.14)));
+
+    let trust = EstateScenarioInput {
+        taxpayer_class: TaxPayerClass::Trust,
+        ..input_for(Jurisdiction::SouthAfrica, 2026)
+    };
+    let result = calculate_combined_tax_and_liquidity(&trust)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert_eq!(
+        defaulted_value(&result, "primary_residence_cgt_exclusion_cap_amount"),
+        Some(Decimal::ZERO)
+    );
+}
+
+#[test]
+fn us_scenario_gets_no_south_african_vat_or_exclusion() {
+    let result = calculate_combined_tax_and_liquidity(&input_for(Jurisdiction::UsNewYork, 2026))
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(defaulted_value(&result, "vat_rate"), Some(Decimal::ZERO));
+    assert_eq!(
+        defaulted_value(&result, "primary_residence_cgt_exclusion_cap_amount"),
+        Some(Decimal::ZERO)
+    );
+    let vat_default = result
+        .defaulted_fields
+        .iter()
+        .find(|defaulted| defaulted.field == "vat_rate")
+        .expect("Expected VAT rate to be defaulted");
+    assert!(vat_default.rule.is_none());
+    assert_eq!(result.estate_duty.executor_fee.vat_amount, Decimal::ZERO);
+}
+
+#[test]
+fn executor_fee_from_the_tariff_is_reported_as_defaulted() {
+    let result = calculate_combined_tax_and_liquidity(&input_for(Jurisdiction::SouthAfrica, 2026))
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(
+        defaulted_value(&result, "explicit_executor_fee_amount"),
+        Some(result.estate_duty.executor_fee_amount)
+    );
+    assert!(result.estate_duty.executor_fee_amount > Decimal::ZERO);
+    let fee_default = result
+        .defaulted_fields
+        .iter()
+        .find(|defaulted| defaulted.field == "explicit_executor_fee_amount")
+        .and_then(|defaulted| defaulted.rule.as_ref())
+        .expect("Expected the executor fee to cite the tariff");
+    assert_eq!(fee_default.rule_field, "executor_fee.asset_rate_schedule");
+
+    let flat_rate = EstateScenarioInput {
+        executor_fee_rate: Some(dec!(0.02)),
+        ..input_for(Jurisdiction::SouthAfrica, 2026)
+    };
+    let result = calculate_combined_tax_and_liquidity(&flat_rate)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert_eq!(
+        defaulted_value(&result, "explicit_executor_fee_amount"),
+        None
+    );
+}
+
+#[test]
+fn marginal_rate_from_the_income_tax_table_is_reported_as_defaulted() {
+    let result = calculate_combined_tax_and_liquidity(&input_for(Jurisdiction::SouthAfrica, 2026))
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(
+        defaulted_value(&result, "marginal_income_tax_rate"),
+        Some(result.income_tax.marginal_rate)
+    );
+    assert!(result.income_tax.marginal_rate > Decimal::ZERO);
+
+    // The 2010 tax year has no income tax table, so there is no rate to default to.
+    let without_table = EstateScenarioInput {
+        assets: Vec::new(),
+        ..input_for(Jurisdiction::SouthAfrica, 2010)
+    };
+    let result = calculate_combined_tax_and_liquidity(&without_table)
+        .expect("Expected calculation to succeed without a capital gain");
+    assert_eq!(defaulted_value(&result, "marginal_income_tax_rate"), None);
+}
+
+#[test]
+fn supplied_fields_are_not_reported_as_defaulted() {
+    let input = EstateScenarioInput {
+        vat_rate: Some(Decimal::ZERO),
+        primary_residence_cgt_exclusion_cap_amount: Some(dec!(1_000_000)),
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        marginal_income_tax_rate: Some(dec!(0.45)),
+        ..input_for(Jurisdiction::SouthAfrica, 2026)
+    };
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert!(result.defaulted_fields.is_empty());
+    assert_eq!(
+        result.cgt.primary_residence_exclusion_used_amount,
+        dec!(1_000_000)
+    );
+    assert_eq!(result.estate_duty.executor_fee.vat_amount, Decimal::ZERO);
+}
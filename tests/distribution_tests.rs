@@ -0,0 +1,222 @@
+use crate::core::domain::models::{Beneficiary, EstateScenarioInput, HeirCategory};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::engine::scoring::score_scenario;
+use crate::core::rules::tax_rules::{EstateTaxApportionment, Jurisdiction};
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn beneficiary(
+    name: &str,
+    category: HeirCategory,
+    specific_bequest_asset_names: &[&str],
+    legacy_amount: Decimal,
+    residue_share: Decimal,
+) -> Beneficiary {
+    Beneficiary {
+        name: name.to_string(),
+        category,
+        specific_bequest_asset_names: specific_bequest_asset_names
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+        legacy_amount,
+        residue_share,
+    }
+}
+
+fn will_input() -> EstateScenarioInput {
+    EstateScenarioInput {
+        assets: vec![
+            asset("Family home", dec!(4_000_000)),
+            asset("Portfolio", dec!(6_000_000)),
+        ],
+        debts_and_loans_amount: dec!(500_000),
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        beneficiaries: vec![
+            beneficiary(
+                "Thandi",
+                HeirCategory::OtherHeirs,
+                &["Family home"],
+                Decimal::ZERO,
+                Decimal::ZERO,
+            ),
+            beneficiary(
+                "Sipho",
+                HeirCategory::OtherHeirs,
+                &[],
+                dec!(1_000_000),
+                Decimal::ZERO,
+            ),
+            beneficiary(
+                "Lerato",
+                HeirCategory::OtherHeirs,
+                &[],
+                Decimal::ZERO,
+                Decimal::ONE,
+            ),
+        ],
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn south_african_residue_bears_estate_duty_by_default() {
+    let result = calculate_combined_tax_and_liquidity(&will_input())
+        .expect("Expected calculation to succeed for supported tax year");
+    let distribution = &result.distribution;
+    assert_eq!(
+        distribution.tax_apportionment,
+        EstateTaxApportionment::BorneByResidue
+    );
+    assert!((distribution.estate_taxes_amount - dec!(1_200_000)).abs() < dec!(0.1));
+    assert!((distribution.residue_amount - dec!(4_500_000)).abs() < dec!(0.1));
+
+    let heirs = &distribution.heirs;
+    assert!((heirs[0].net_inheritance_amount - dec!(4_000_000)).abs() < dec!(0.1));
+    assert!((heirs[1].net_inheritance_amount - dec!(1_000_000)).abs() < dec!(0.1));
+    assert!((heirs[2].estate_tax_borne_amount - dec!(1_200_000)).abs() < dec!(0.1));
+    assert!((heirs[2].net_inheritance_amount - dec!(3_300_000)).abs() < dec!(0.1));
+    assert!((distribution.total_net_inheritance_amount - dec!(8_300_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn will_can_direct_pro_rata_apportionment() {
+    let mut input = will_input();
+    input.tax_apportionment = Some(EstateTaxApportionment::ProRataAmongDutiableHeirs);
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let heirs = &result.distribution.heirs;
+    assert!((heirs[0].estate_tax_borne_amount - dec!(505_263.16)).abs() < dec!(0.1));
+    assert!((heirs[1].estate_tax_borne_amount - dec!(126_315.79)).abs() < dec!(0.1));
+    assert!((heirs[2].estate_tax_borne_amount - dec!(568_421.05)).abs() < dec!(0.1));
+    assert!((result.distribution.total_net_inheritance_amount - dec!(8_300_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn us_default_apportionment_spares_the_surviving_spouse() {
+    let input = EstateScenarioInput {
+        jurisdiction: Jurisdiction::UsTexas,
+        assets: vec![asset("US brokerage account", dec!(20_000_000))],
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        beneficiaries: vec![
+            beneficiary(
+                "Spouse",
+                HeirCategory::SurvivingSpouse,
+                &[],
+                dec!(5_000_000),
+                Decimal::ZERO,
+            ),
+            beneficiary(
+                "Child",
+                HeirCategory::OtherHeirs,
+                &[],
+                Decimal::ZERO,
+                Decimal::ONE,
+            ),
+        ],
+        ..EstateScenarioInput::default()
+    };
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected US federal calculation to succeed");
+    let distribution = &result.distribution;
+    assert_eq!(
+        distribution.tax_apportionment,
+        EstateTaxApportionment::ProRataAmongDutiableHeirs
+    );
+    assert_eq!(distribution.heirs[0].estate_tax_borne_amount, Decimal::ZERO);
+    assert!((distribution.heirs[0].net_inheritance_amount - dec!(5_000_000)).abs() < dec!(0.1));
+    assert!((distribution.heirs[1].estate_tax_borne_amount - dec!(2_000_000)).abs() < dec!(0.1));
+    assert!((distribution.heirs[1].net_inheritance_amount - dec!(13_000_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn legacies_abate_when_residue_cannot_cover_debts() {
+    let input = EstateScenarioInput {
+        assets: vec![asset("Cash", dec!(2_000_000))],
+        debts_and_loans_amount: dec!(1_500_000),
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        beneficiaries: vec![
+            beneficiary(
+                "Sipho",
+                HeirCategory::OtherHeirs,
+                &[],
+                dec!(1_000_000),
+                Decimal::ZERO,
+            ),
+            beneficiary(
+                "Lerato",
+                HeirCategory::OtherHeirs,
+                &[],
+                Decimal::ZERO,
+                Decimal::ONE,
+            ),
+        ],
+        ..EstateScenarioInput::default()
+    };
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let distribution = &result.distribution;
+    assert!((distribution.abatement_amount - dec!(500_000)).abs() < dec!(0.1));
+    assert!((distribution.heirs[0].net_inheritance_amount - dec!(500_000)).abs() < dec!(0.1));
+    assert_eq!(distribution.heirs[1].net_inheritance_amount, Decimal::ZERO);
+
+    let score = score_scenario(&result);
+    assert!((score.bequest_shortfall_ratio - 0.5).abs() < 1e-9);
+    assert!((score.net_inheritance_ratio - 0.25).abs() < 1e-9);
+    let tax_and_liquidity_penalty = score.tax_burden_ratio * 100.0
+        + if score.liquidity_cover_ratio >= 1.0 {
+            0.0
+        } else {
+            (1.0 - score.liquidity_cover_ratio) * 200.0
+        };
+    assert!(
+        (score.composite_score - tax_and_liquidity_penalty - 0.5 * 100.0 - 0.75 * 50.0).abs()
+            < 1e-9
+    );
+}
+
+#[test]
+fn residue_split_in_thirds_awards_exactly_the_residue() {
+    let third = Decimal::ONE / Decimal::from(3);
+    let input = EstateScenarioInput {
+        assets: vec![asset("Cash", dec!(1_000_000.01))],
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        beneficiaries: ["Thandi", "Sipho", "Lerato"]
+            .into_iter()
+            .zip([third, third, Decimal::ONE - third - third])
+            .map(|(name, residue_share)| {
+                beneficiary(
+                    name,
+                    HeirCategory::OtherHeirs,
+                    &[],
+                    Decimal::ZERO,
+                    residue_share,
+                )
+            })
+            .collect(),
+        ..EstateScenarioInput::default()
+    };
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let distribution = &result.distribution;
+    assert_eq!(distribution.residue_amount, dec!(1_000_000.01));
+    assert_eq!(
+        distribution
+            .heirs
+            .iter()
+            .map(|heir| heir.residue_amount)
+            .sum::<Decimal>(),
+        dec!(1_000_000.01)
+    );
+    assert_eq!(distribution.heirs[0].residue_amount, dec!(333_333.34));
+    assert_eq!(distribution.undistributed_residue_amount, Decimal::ZERO);
+    assert_eq!(
+        distribution.total_net_inheritance_amount,
+        dec!(1_000_000.01)
+    );
+}
@@ -0,0 +1,134 @@
+use crate::api::handler::calculate_lifetime_donations_tax;
+use crate::core::domain::models::{DonationsTaxInput, LifetimeDonation};
+use crate::core::engine::donations::calculate_donations_tax;
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{Jurisdiction, TaxPayerClass};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn donation(donee: &str, donated_on: &str, amount: Decimal) -> LifetimeDonation {
+    LifetimeDonation {
+        donee: donee.to_string(),
+        donated_on: donated_on.to_string(),
+        amount,
+        exempt_from_donations_tax: false,
+    }
+}
+
+fn baseline_input(donations: Vec<LifetimeDonation>) -> DonationsTaxInput {
+    DonationsTaxInput {
+        jurisdiction: Jurisdiction::SouthAfrica,
+        taxpayer_class: TaxPayerClass::NaturalPerson,
+        prior_cumulative_taxable_donations_amount: Decimal::ZERO,
+        donations,
+    }
+}
+
+#[test]
+fn applies_annual_exemption_once_per_sa_tax_year() {
+    let input = baseline_input(vec![
+        donation("Child A", "2025-10-01", dec!(80_000)),
+        donation("Child B", "2025-04-01", dec!(150_000)),
+        donation("Child A", "2026-03-05", dec!(100_000)),
+    ]);
+
+    let result = calculate_donations_tax(&input).expect("Expected donations tax to calculate");
+
+    assert_eq!(result.tax_years.len(), 2);
+    assert_eq!(result.tax_years[0].tax_year, 2026);
+    assert!((result.tax_years[0].annual_exemption_used_amount - dec!(100_000)).abs() < dec!(0.01));
+    assert!((result.tax_years[0].taxable_donations_amount - dec!(130_000)).abs() < dec!(0.01));
+    assert!((result.tax_years[0].tax_payable_amount - dec!(26_000)).abs() < dec!(0.01));
+    assert_eq!(result.tax_years[1].tax_year, 2027);
+    assert_eq!(result.tax_years[1].tax_payable_amount, Decimal::ZERO);
+
+    assert_eq!(result.donations[0].donation_index, 1);
+    assert!((result.donations[0].annual_exemption_used_amount - dec!(100_000)).abs() < dec!(0.01));
+    assert!((result.donations[1].taxable_amount - dec!(80_000)).abs() < dec!(0.01));
+    assert!((result.total_tax_payable_amount - dec!(26_000)).abs() < dec!(0.01));
+}
+
+#[test]
+fn splits_gift_across_cumulative_primary_and_secondary_bands() {
+    let mut input = baseline_input(vec![donation("Trust", "2025-06-30", dec!(1_100_000))]);
+    input.prior_cumulative_taxable_donations_amount = dec!(29_900_000);
+
+    let result = calculate_donations_tax(&input).expect("Expected donations tax to calculate");
+    let line = &result.donations[0];
+
+    assert!((line.primary_band_amount - dec!(100_000)).abs() < dec!(0.01));
+    assert!((line.secondary_band_amount - dec!(900_000)).abs() < dec!(0.01));
+    assert!((line.tax_payable_amount - dec!(245_000)).abs() < dec!(0.01));
+    assert!((result.cumulative_taxable_donations_amount - dec!(30_900_000)).abs() < dec!(0.01));
+}
+
+#[test]
+fn exempt_donations_do_not_consume_annual_exemption() {
+    let mut spouse_gift = donation("Spouse", "2025-05-01", dec!(5_000_000));
+    spouse_gift.exempt_from_donations_tax = true;
+    let input = baseline_input(vec![
+        spouse_gift,
+        donation("Child", "2025-05-02", dec!(100_000)),
+    ]);
+
+    let result = calculate_donations_tax(&input).expect("Expected donations tax to calculate");
+
+    assert!((result.donations[0].exempt_amount - dec!(5_000_000)).abs() < dec!(0.01));
+    assert_eq!(
+        result.donations[0].annual_exemption_used_amount,
+        Decimal::ZERO
+    );
+    assert!((result.donations[1].annual_exemption_used_amount - dec!(100_000)).abs() < dec!(0.01));
+    assert_eq!(result.total_tax_payable_amount, Decimal::ZERO);
+}
+
+#[test]
+fn company_donor_only_receives_casual_gift_exemption() {
+    let mut input = baseline_input(vec![donation("Charity", "2025-08-01", dec!(50_000))]);
+    input.taxpayer_class = TaxPayerClass::Company;
+
+    let result = calculate_donations_tax(&input).expect("Expected donations tax to calculate");
+
+    assert!(
+        (result.tax_years[0].annual_exemption_available_amount - dec!(10_000)).abs() < dec!(0.01)
+    );
+    assert!((result.total_tax_payable_amount - dec!(8_000)).abs() < dec!(0.01));
+}
+
+#[test]
+fn validation_rejects_invalid_and_unsupported_donation_dates() {
+    let input = baseline_input(vec![
+        donation("Child", "2025-02-30", dec!(10_000)),
+        donation("Child", "2000-05-01", dec!(10_000)),
+    ]);
+
+    let err = calculate_lifetime_donations_tax(&input)
+        .expect_err("Expected donation date validation to fail");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected validation error");
+    };
+    assert!(validation
+        .issues
+        .iter()
+        .any(|issue| issue.field == "donations[0].donated_on"));
+    assert!(validation
+        .issues
+        .iter()
+        .any(|issue| issue.field == "donations[1].donated_on" && issue.message.contains("2001")));
+}
+
+#[test]
+fn validation_rejects_us_gift_tax() {
+    let mut input = baseline_input(vec![donation("Child", "2025-05-01", dec!(20_000_000))]);
+    input.jurisdiction = Jurisdiction::UsTexas;
+
+    let err =
+        calculate_lifetime_donations_tax(&input).expect_err("Expected US donations to be rejected");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected validation error");
+    };
+    assert!(validation
+        .issues
+        .iter()
+        .any(|issue| issue.field == "jurisdiction" && issue.message.contains("UsTexas")));
+}
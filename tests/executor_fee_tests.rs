@@ -0,0 +1,106 @@
+use crate::core::domain::models::{EstateAsset, EstateScenarioInput, ExecutorFeeBasis};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::rules::tax_rules::Jurisdiction;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn input_with_assets(
+    jurisdiction: Jurisdiction,
+    market_value_amount: Decimal,
+) -> EstateScenarioInput {
+    EstateScenarioInput {
+        jurisdiction,
+        assets: vec![EstateAsset {
+            name: "Share portfolio".to_string(),
+            market_value_amount,
+            base_cost_amount: market_value_amount,
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }],
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn south_african_tariff_charges_assets_and_income_plus_vat() {
+    let mut input = input_with_assets(Jurisdiction::SouthAfrica, dec!(10_000_000));
+    input.income_accrued_after_death_amount = dec!(200_000);
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.basis, ExecutorFeeBasis::Tariff);
+    assert!((fee.asset_fee_amount - dec!(350_000)).abs() < dec!(0.1));
+    assert!((fee.income_fee_amount - dec!(12_000)).abs() < dec!(0.1));
+    assert!((fee.vat_amount - dec!(54_300)).abs() < dec!(0.1));
+    assert!((fee.executor_fee_amount - dec!(416_300)).abs() < dec!(0.1));
+    assert!((result.estate_duty.executor_fee_amount - dec!(416_300)).abs() < dec!(0.1));
+    assert!((result.liquidity.executor_fee_amount - dec!(416_300)).abs() < dec!(0.1));
+}
+
+#[test]
+fn south_african_tariff_minimum_applies_to_small_estates() {
+    let input = input_with_assets(Jurisdiction::SouthAfrica, dec!(5_000));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    // 3.5% of R5,000 is R175, below the R350 minimum.
+    let fee = &result.estate_duty.executor_fee;
+    assert!((fee.fee_before_vat_amount - dec!(350)).abs() < dec!(0.1));
+    assert!((fee.executor_fee_amount - dec!(402.5)).abs() < dec!(0.1));
+}
+
+#[test]
+fn california_commission_runs_on_sliding_scale_without_vat() {
+    let mut input = input_with_assets(Jurisdiction::UsCalifornia, dec!(1_500_000));
+    input.income_accrued_after_death_amount = dec!(100_000);
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    // Income joins the $1.6m base: 4% of $100,000, 3% of $100,000, 2% of $800,000 and 1% of
+    // $600,000.
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.basis, ExecutorFeeBasis::Tariff);
+    assert!((fee.asset_fee_amount - dec!(29_000)).abs() < dec!(0.1));
+    assert_eq!(fee.income_fee_amount, Decimal::ZERO);
+    assert_eq!(fee.vat_amount, Decimal::ZERO);
+    assert!((fee.executor_fee_amount - dec!(29_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn flat_rate_and_explicit_fee_override_the_tariff() {
+    let mut input = input_with_assets(Jurisdiction::SouthAfrica, dec!(10_000_000));
+    input.executor_fee_rate = Some(dec!(0.02));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.basis, ExecutorFeeBasis::FlatRateOverride);
+    assert!((fee.executor_fee_amount - dec!(230_000)).abs() < dec!(0.1));
+
+    input.explicit_executor_fee_amount = Some(dec!(100_000));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.basis, ExecutorFeeBasis::ExplicitAmount);
+    assert!((fee.executor_fee_amount - dec!(100_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn no_fee_without_a_statutory_tariff_or_override() {
+    let input = input_with_assets(Jurisdiction::UsMinnesota, dec!(2_000_000));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(
+        result.estate_duty.executor_fee.basis,
+        ExecutorFeeBasis::NoTariff
+    );
+    assert_eq!(result.estate_duty.executor_fee_amount, Decimal::ZERO);
+}
@@ -0,0 +1,109 @@
+use crate::core::domain::models::{EstateAsset, EstateScenarioInput, ExplainStage};
+use crate::core::engine::explain::render_explain_text;
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::rules::tax_rules::{latest_tax_rules_for, Jurisdiction};
+use rust_decimal_macros::dec;
+
+fn explained_input() -> EstateScenarioInput {
+    EstateScenarioInput {
+        assets: vec![EstateAsset {
+            name: "Share portfolio".to_string(),
+            market_value_amount: dec!(40_000_000),
+            base_cost_amount: dec!(10_000_000),
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }],
+        explain: true,
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn explain_trace_is_ordered_and_cites_rules() {
+    let result = calculate_combined_tax_and_liquidity(&explained_input())
+        .expect("Expected calculation to succeed for supported tax year");
+    let trace = result.explain.as_ref().expect("Expected an explain trace");
+    let rules = latest_tax_rules_for(Jurisdiction::SouthAfrica);
+
+    assert_eq!(trace.rule_version_id, rules.version.version_id);
+    assert!(trace
+        .steps
+        .iter()
+        .enumerate()
+        .all(|(index, step)| step.step == index + 1));
+
+    let first_cgt = trace
+        .steps
+        .iter()
+        .position(|step| step.stage == ExplainStage::CapitalGainsTax)
+        .expect("Expected CGT steps");
+    let first_estate_duty = trace
+        .steps
+        .iter()
+        .position(|step| step.stage == ExplainStage::EstateDuty)
+        .expect("Expected estate duty steps");
+    assert!(first_cgt < first_estate_duty);
+
+    let duty_payable = trace
+        .steps
+        .iter()
+        .find(|step| step.description == "Estate duty payable")
+        .expect("Expected the estate duty payable step");
+    assert_eq!(duty_payable.value, result.estate_duty.tax_payable_amount);
+    let citation = duty_payable
+        .rule
+        .as_ref()
+        .expect("Expected a rule citation");
+    assert_eq!(citation.rule_field, "estate_duty.rate_schedule");
+    assert_eq!(citation.source, rules.rules.estate_duty.source);
+    assert_eq!(citation.source_url, rules.rules.estate_duty.source_url);
+
+    let taxable_gain = trace
+        .steps
+        .iter()
+        .find(|step| step.description == "Taxable capital gain included in income")
+        .expect("Expected the taxable capital gain step");
+    assert_eq!(
+        taxable_gain.value,
+        result.cgt.taxable_capital_gain_in_income_amount
+    );
+    assert_eq!(
+        taxable_gain
+            .rule
+            .as_ref()
+            .map(|citation| citation.rule_field.as_str()),
+        Some("cgt_on_death.inclusion_rate_natural_person")
+    );
+}
+
+#[test]
+fn explain_trace_is_omitted_unless_requested() {
+    let input = EstateScenarioInput {
+        explain: false,
+        ..explained_input()
+    };
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert!(result.explain.is_none());
+}
+
+#[test]
+fn explain_trace_renders_as_plain_text() {
+    let result = calculate_combined_tax_and_liquidity(&explained_input())
+        .expect("Expected calculation to succeed for supported tax year");
+    let trace = result.explain.as_ref().expect("Expected an explain trace");
+    let text = render_explain_text(trace);
+
+    assert!(text.starts_with("Calculation trace: SouthAfrica"));
+    assert!(text.contains("[EstateDuty] Estate duty payable"));
+    assert!(text.contains(&format!("   -> {}", result.estate_duty.tax_payable_amount)));
+    assert!(text.contains("Rule: estate_duty.rate_schedule"));
+}
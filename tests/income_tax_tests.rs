@@ -0,0 +1,111 @@
+use crate::core::domain::models::{EstateAsset, EstateScenarioInput};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::rules::tax_rules::{tax_rules_for, Jurisdiction, TaxPayerClass};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn input_with_gain(capital_gain_amount: Decimal) -> EstateScenarioInput {
+    EstateScenarioInput {
+        assets: vec![EstateAsset {
+            name: "Share portfolio".to_string(),
+            market_value_amount: dec!(2_000_000) + capital_gain_amount,
+            base_cost_amount: dec!(2_000_000),
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }],
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn gain_is_taxed_in_the_brackets_it_pushes_income_into() {
+    let mut input = input_with_gain(dec!(1_300_000));
+    input.taxable_income_in_year_of_death_amount = dec!(500_000);
+    input.age_at_tax_year_end = Some(70);
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    // R1m gain after the R300,000 exclusion, 40% included: R900,000 of taxable income.
+    let income_tax = &result.income_tax;
+    assert!((income_tax.taxable_capital_gain_amount - dec!(400_000)).abs() < dec!(0.1));
+    assert!((income_tax.taxable_income_amount - dec!(900_000)).abs() < dec!(0.1));
+    assert!((income_tax.tax_before_rebates_amount - dec!(268_519)).abs() < dec!(0.1));
+    // Primary and secondary rebates.
+    assert!((income_tax.rebates_amount - dec!(26_679)).abs() < dec!(0.1));
+    assert!(
+        (income_tax.tax_on_income_excluding_capital_gain_amount - dec!(90_828)).abs() < dec!(0.1)
+    );
+    assert!((income_tax.tax_on_capital_gain_amount - dec!(151_012)).abs() < dec!(0.1));
+    assert!((income_tax.marginal_rate - dec!(0.41)).abs() < dec!(0.000000001));
+
+    assert!((result.cgt.tax_payable_amount - dec!(151_012)).abs() < dec!(0.1));
+    assert!((result.combined_tax.final_income_tax_amount - dec!(90_828)).abs() < dec!(0.1));
+    assert!((result.assets[0].cgt_attributed_amount - dec!(151_012)).abs() < dec!(0.1));
+}
+
+#[test]
+fn rebates_shelter_small_gains() {
+    let input = input_with_gain(dec!(400_000));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    // R40,000 taxable gain attracts R7,200, less than the R17,235 primary rebate.
+    assert!((result.income_tax.tax_before_rebates_amount - dec!(7_200)).abs() < dec!(0.1));
+    assert_eq!(result.cgt.tax_payable_amount, Decimal::ZERO);
+    assert_eq!(result.combined_tax.final_income_tax_amount, Decimal::ZERO);
+}
+
+#[test]
+fn trusts_pay_the_flat_trust_rate_without_rebates() {
+    let mut input = input_with_gain(dec!(1_000_000));
+    input.taxpayer_class = TaxPayerClass::Trust;
+    input.primary_residence_cgt_exclusion_cap_amount = Some(Decimal::ZERO);
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(result.income_tax.rebates_amount, Decimal::ZERO);
+    assert!((result.cgt.tax_payable_amount - dec!(800_000) * dec!(0.45)).abs() < dec!(0.1));
+}
+
+#[test]
+fn supplied_rate_and_final_tax_override_the_tables() {
+    let mut input = input_with_gain(dec!(1_300_000));
+    input.taxable_income_in_year_of_death_amount = dec!(500_000);
+    input.marginal_income_tax_rate = Some(dec!(0.45));
+    input.final_income_tax_due_amount = Some(dec!(120_000));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert!((result.cgt.tax_payable_amount - dec!(180_000)).abs() < dec!(0.1));
+    assert!((result.combined_tax.final_income_tax_amount - dec!(120_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn selects_the_income_tax_table_for_the_tax_year() {
+    let rules = tax_rules_for(Jurisdiction::SouthAfrica, 2021)
+        .expect("Expected rules for supported tax year")
+        .rules;
+    let table = rules
+        .income_tax
+        .table_for(2021)
+        .expect("Expected an income tax table for 2021");
+    assert!((table.rate_schedule[1].from_amount - dec!(205_900)).abs() < dec!(0.1));
+    assert!(
+        (table.rebates_amount_for(TaxPayerClass::NaturalPerson, Some(76)) - dec!(25_893)).abs()
+            < dec!(0.1)
+    );
+
+    let latest = rules
+        .income_tax
+        .table_for(2026)
+        .expect("Expected an income tax table for 2026");
+    assert!((latest.rate_schedule[1].from_amount - dec!(237_100)).abs() < dec!(0.1));
+}
@@ -0,0 +1,184 @@
+use crate::api::pdf::render_liquidation_account_pdf;
+use crate::core::domain::models::{
+    Beneficiary, EstateScenarioInput, HeirCategory, MaritalPropertyInput, MaritalPropertyRegime,
+};
+use crate::core::engine::liquidation_account::draft_liquidation_and_distribution_account;
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::rules::tax_rules::Jurisdiction;
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn beneficiary(name: &str, legacy_amount: Decimal, residue_share: Decimal) -> Beneficiary {
+    Beneficiary {
+        name: name.to_string(),
+        category: HeirCategory::OtherHeirs,
+        specific_bequest_asset_names: Vec::new(),
+        legacy_amount,
+        residue_share,
+    }
+}
+
+fn estate_input() -> EstateScenarioInput {
+    EstateScenarioInput {
+        assets: vec![
+            asset("Family home", dec!(4_000_000)),
+            asset("Portfolio", dec!(6_000_000)),
+        ],
+        debts_and_loans_amount: dec!(500_000),
+        funeral_costs_amount: dec!(50_000),
+        final_income_tax_due_amount: Some(dec!(80_000)),
+        ongoing_estate_income_tax_provision_amount: dec!(20_000),
+        beneficiaries: vec![
+            beneficiary("Sipho", dec!(1_000_000), Decimal::ZERO),
+            beneficiary("Lerato", Decimal::ZERO, Decimal::ONE),
+        ],
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn account_balances_and_reconciles_to_scenario_result() {
+    let input = estate_input();
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let account = draft_liquidation_and_distribution_account(&input)
+        .expect("Expected account to be drafted for supported tax year");
+
+    assert!(account.balanced);
+    assert!(account.reconciliation.reconciled);
+    assert!((account.capital_account.total_receipts_amount - dec!(10_000_000)).abs() < dec!(0.1));
+    assert!(
+        (account.capital_account.total_payments_amount
+            - result.liquidity.immediate_cash_requirements_amount)
+            .abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (account.reconciliation.estate_duty_per_account_amount
+            - result.estate_duty.tax_payable_amount)
+            .abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (account.distribution_account.total_awarded_amount
+            - result.distribution.total_net_inheritance_amount)
+            .abs()
+            < dec!(0.1)
+    );
+
+    // Post-death income tax is funded from capital through the income account.
+    assert!((account.income_account.total_receipts_amount - dec!(20_000)).abs() < dec!(0.1));
+    assert!(account.income_account.balance_amount.abs() < dec!(0.1));
+}
+
+#[test]
+fn community_of_property_account_lists_half_shares() {
+    let input = EstateScenarioInput {
+        marital_property: MaritalPropertyInput {
+            regime: MaritalPropertyRegime::InCommunity,
+            ..MaritalPropertyInput::default()
+        },
+        ..estate_input()
+    };
+    let account = draft_liquidation_and_distribution_account(&input)
+        .expect("Expected account to be drafted for supported tax year");
+
+    let home = account
+        .capital_account
+        .receipts
+        .iter()
+        .find(|line| line.description == "Family home (half share of joint estate)")
+        .expect("Expected half-share asset line");
+    assert!((home.amount - dec!(2_000_000)).abs() < dec!(0.1));
+    assert!(account.balanced);
+    assert!(account.reconciliation.reconciled);
+}
+
+#[test]
+fn pro_rata_apportionment_recovers_estate_tax_from_heirs() {
+    let input = EstateScenarioInput {
+        jurisdiction: Jurisdiction::UsFlorida,
+        tax_year: 2026,
+        assets: vec![asset("Brokerage account", dec!(30_000_000))],
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        beneficiaries: vec![
+            beneficiary("Alex", Decimal::ZERO, dec!(0.5)),
+            beneficiary("Jordan", Decimal::ZERO, dec!(0.5)),
+        ],
+        ..EstateScenarioInput::default()
+    };
+    let account = draft_liquidation_and_distribution_account(&input)
+        .expect("Expected account to be drafted for supported tax year");
+
+    assert!(account
+        .capital_account
+        .payments
+        .iter()
+        .any(|line| line.description == "Federal estate tax" && line.amount > Decimal::ZERO));
+    let recovered_amount = account
+        .distribution_account
+        .awards
+        .iter()
+        .filter(|line| line.description.ends_with("estate tax recovered"))
+        .map(|line| line.amount)
+        .sum::<Decimal>();
+    assert!(
+        (recovered_amount + account.reconciliation.estate_duty_per_breakdown_amount).abs()
+            < dec!(0.1)
+    );
+    assert!(account.balanced);
+}
+
+#[test]
+fn residue_split_three_ways_balances_to_the_cent() {
+    let third = Decimal::ONE / Decimal::from(3);
+    let input = EstateScenarioInput {
+        debts_and_loans_amount: dec!(500_000.02),
+        beneficiaries: vec![
+            beneficiary("Sipho", dec!(1_000_000), third),
+            beneficiary("Lerato", Decimal::ZERO, third),
+            beneficiary("Thandi", Decimal::ZERO, Decimal::ONE - third - third),
+        ],
+        ..estate_input()
+    };
+    let account = draft_liquidation_and_distribution_account(&input)
+        .expect("Expected account to be drafted for supported tax year");
+
+    assert!(account.balanced);
+    assert_eq!(
+        account.distribution_account.total_awarded_amount,
+        account.distribution_account.balance_for_distribution_amount
+    );
+}
+
+#[test]
+fn insolvent_estate_account_does_not_balance() {
+    let input = EstateScenarioInput {
+        debts_and_loans_amount: dec!(12_000_000),
+        ..estate_input()
+    };
+    let account = draft_liquidation_and_distribution_account(&input)
+        .expect("Expected account to be drafted for supported tax year");
+
+    assert!(account.distribution_account.balance_for_distribution_amount < Decimal::ZERO);
+    assert!(!account.balanced);
+    assert!(account.reconciliation.reconciled);
+}
+
+#[test]
+fn account_renders_to_pdf_with_extractable_text() {
+    let account = draft_liquidation_and_distribution_account(&estate_input())
+        .expect("Expected account to be drafted for supported tax year");
+    let bytes = render_liquidation_account_pdf(&account).expect("Expected PDF to render");
+
+    assert!(bytes.starts_with(b"%PDF-1.5"));
+    let document = lopdf::Document::load_mem(&bytes).expect("Expected rendered PDF to parse");
+    let page_numbers = document.get_pages().keys().copied().collect::<Vec<_>>();
+    let text = document
+        .extract_text(&page_numbers)
+        .expect("Expected text to be extractable");
+    assert!(text.contains("CAPITAL ACCOUNT"));
+    assert!(text.contains("DISTRIBUTION ACCOUNT"));
+    assert!(text.contains("10,000,000.00"));
+}
@@ -6,6 +6,22 @@ mod api_http_tests;
 mod api_registry_tests;
 mod api_rule_selection_tests;
 mod api_rules_contract_tests;
+mod couple_tests;
+mod cross_border_tests;
+mod currency_tests;
+mod defaults_tests;
+mod distribution_tests;
+mod donations_tests;
+mod executor_fee_tests;
+mod explain_tests;
+mod income_tax_tests;
+mod liquidation_account_tests;
+mod rounding_tests;
+mod rule_catalog_tests;
+mod rule_diff_tests;
+mod rule_publishing_tests;
 mod rule_registry_tests;
 mod scenario_tests;
+mod strategy_tests;
+mod support;
 mod validation_tests;
@@ -0,0 +1,89 @@
+use crate::core::domain::models::{EstateAsset, EstateScenarioInput, FxRate, FxRateTable};
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::rules::tax_rules::{latest_tax_rules_for, Currency, Jurisdiction};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn input_with_asset(
+    jurisdiction: Jurisdiction,
+    market_value_amount: Decimal,
+) -> EstateScenarioInput {
+    EstateScenarioInput {
+        jurisdiction,
+        assets: vec![EstateAsset {
+            name: "Share portfolio".to_string(),
+            market_value_amount,
+            base_cost_amount: market_value_amount,
+            is_liquid: true,
+            situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
+            included_in_estate_duty: true,
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_surviving_spouse: false,
+            bequeathed_to_pbo: false,
+            qualifies_primary_residence_exclusion: false,
+            currency: None,
+        }],
+        ..EstateScenarioInput::default()
+    }
+}
+
+#[test]
+fn return_rounding_follows_jurisdiction() {
+    let south_africa = latest_tax_rules_for(Jurisdiction::SouthAfrica)
+        .rules
+        .rounding;
+    assert_eq!(
+        south_africa.round_return_amount(dec!(1_234.99)),
+        dec!(1_234)
+    );
+
+    let us = latest_tax_rules_for(Jurisdiction::UsNewYork).rules.rounding;
+    assert_eq!(us.round_return_amount(dec!(1_234.49)), dec!(1_234));
+    assert_eq!(us.round_return_amount(dec!(1_234.50)), dec!(1_235));
+}
+
+#[test]
+fn fees_are_carried_to_the_cent_and_duty_to_the_rand() {
+    let input = input_with_asset(Jurisdiction::SouthAfrica, dec!(35_123_456.78));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    // 3.5% of R35,123,456.78 is R1,229,320.9873; VAT on the rounded fee is R184,398.1485.
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.fee_before_vat_amount, dec!(1_229_320.99));
+    assert_eq!(fee.vat_amount, dec!(184_398.15));
+    assert_eq!(fee.executor_fee_amount, dec!(1_413_719.14));
+
+    let estate_duty = &result.estate_duty;
+    assert_eq!(
+        estate_duty.dutiable_estate_after_exemption_amount.fract(),
+        Decimal::ZERO
+    );
+    assert_eq!(estate_duty.tax_payable_amount.fract(), Decimal::ZERO);
+    assert_eq!(
+        result
+            .assets
+            .iter()
+            .map(|line| line.estate_duty_attributed_amount)
+            .sum::<Decimal>(),
+        estate_duty.tax_payable_amount
+    );
+}
+
+#[test]
+fn decimal_amounts_convert_without_binary_drift() {
+    let mut input = input_with_asset(Jurisdiction::SouthAfrica, dec!(0.1));
+    input.assets[0].currency = Some(Currency::Usd);
+    input.fx_rates = Some(FxRateTable {
+        valuation_date: "2025-11-14".to_string(),
+        rates: vec![FxRate {
+            currency: Currency::Usd,
+            rate: dec!(0.3),
+        }],
+    });
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+
+    assert_eq!(result.currency.lines[0].reporting_amount, dec!(0.03));
+}
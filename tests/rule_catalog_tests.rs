@@ -0,0 +1,218 @@
+use crate::api::contracts::ApiTaxRuleCatalog;
+use crate::api::handler::{load_tax_rule_catalogs, parse_tax_rule_catalog, TaxRuleCatalogFormat};
+use crate::core::domain::models::CalendarDate;
+use crate::core::errors::EngineError;
+use crate::core::rules::rule_catalog::{
+    latest_tax_rule_version, merge_tax_rule_versions, select_tax_rule_version,
+    select_tax_rule_version_in_effect, TaxRuleCatalog,
+};
+use crate::core::rules::tax_rules::{supported_jurisdictions, Jurisdiction, TaxRuleSelectionError};
+use crate::jurisdictions::south_africa::south_africa_tax_rules_catalog;
+use crate::jurisdictions::us::us_state_tax_rules_catalog;
+use rust_decimal_macros::dec;
+use std::path::PathBuf;
+
+fn compiled_in_catalog(jurisdiction: Jurisdiction) -> TaxRuleCatalog {
+    let versions = match jurisdiction {
+        Jurisdiction::SouthAfrica => south_africa_tax_rules_catalog(),
+        _ => us_state_tax_rules_catalog(jurisdiction),
+    };
+    TaxRuleCatalog {
+        jurisdiction,
+        versions,
+    }
+}
+
+fn catalog_json(catalog: TaxRuleCatalog) -> String {
+    serde_json::to_string(&ApiTaxRuleCatalog::from(catalog)).expect("Expected catalog JSON")
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rule-catalogs-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("Expected scratch directory");
+    dir
+}
+
+#[test]
+fn compiled_in_catalogs_pass_catalog_validation() {
+    for jurisdiction in supported_jurisdictions() {
+        let catalog = compiled_in_catalog(jurisdiction);
+        assert!(
+            catalog.validate().is_ok(),
+            "{jurisdiction:?}: {:?}",
+            catalog.validate()
+        );
+    }
+}
+
+#[test]
+fn json_catalog_loads_the_same_rules() {
+    let json = catalog_json(compiled_in_catalog(Jurisdiction::SouthAfrica));
+
+    let loaded = parse_tax_rule_catalog(&json, TaxRuleCatalogFormat::Json)
+        .expect("Expected the JSON catalog to load");
+    assert_eq!(loaded.jurisdiction, Jurisdiction::SouthAfrica);
+    assert_eq!(
+        loaded.versions.len(),
+        south_africa_tax_rules_catalog().len()
+    );
+
+    let rules_2014 = select_tax_rule_version(Jurisdiction::SouthAfrica, loaded.versions, 2014)
+        .expect("Expected a 2014 version")
+        .rules;
+    assert_eq!(
+        rules_2014.cgt_on_death.inclusion_rate_natural_person,
+        dec!(0.333)
+    );
+    assert_eq!(
+        rules_2014.estate_duty.source,
+        "Estate Duty Act 45 of 1955, sections 4A and 24; SARS Estate Duty"
+    );
+}
+
+#[test]
+fn toml_example_catalog_matches_compiled_in_rules() {
+    let loaded = parse_tax_rule_catalog(
+        include_str!("../docs/rule-catalogs/us_texas.toml"),
+        TaxRuleCatalogFormat::Toml,
+    )
+    .expect("Expected the example TOML catalog to load");
+
+    assert_eq!(
+        ApiTaxRuleCatalog::from(loaded),
+        ApiTaxRuleCatalog::from(compiled_in_catalog(Jurisdiction::UsTexas))
+    );
+}
+
+#[test]
+fn rejects_overlapping_tax_year_windows() {
+    let mut catalog = compiled_in_catalog(Jurisdiction::SouthAfrica);
+    catalog.versions[1].version.tax_year_from = 2006;
+
+    let err = catalog
+        .validate()
+        .expect_err("Expected overlapping windows to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|issue| issue.field == "versions[1].tax_year_from"
+            && issue.message.contains("versions[0]")));
+}
+
+#[test]
+fn rejects_effective_windows_that_overlap_or_leave_a_gap() {
+    let mut catalog = compiled_in_catalog(Jurisdiction::SouthAfrica);
+    catalog.versions[1].version.effective_from = "2006-01-01";
+
+    let err = catalog
+        .validate()
+        .expect_err("Expected overlapping effective windows to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|issue| issue.field == "versions[1].version.effective_from"
+            && issue.message.contains("overlaps versions[0]")));
+
+    catalog.versions[1].version.effective_from = "2006-04-01";
+    let err = catalog
+        .validate()
+        .expect_err("Expected a gap between effective windows to fail");
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(err.issues[0].field, "versions[1].version.effective_from");
+    assert!(err.issues[0]
+        .message
+        .contains("gap after versions[0], which ends on 2006-02-28"));
+}
+
+#[test]
+fn rejects_catalogs_that_do_not_match_the_schema() {
+    let err = parse_tax_rule_catalog(
+        r#"{"jurisdiction": "SouthAfrica", "versions": [{"version": {}}]}"#,
+        TaxRuleCatalogFormat::Json,
+    )
+    .expect_err("Expected schema mismatch to fail");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected validation error");
+    };
+    assert_eq!(validation.issues[0].field, "catalog");
+
+    let mut catalog = compiled_in_catalog(Jurisdiction::UsFlorida);
+    catalog.versions[0].rules.estate_duty.rate_schedule[1].rate = dec!(1.5);
+    let err = parse_tax_rule_catalog(&catalog_json(catalog), TaxRuleCatalogFormat::Json)
+        .expect_err("Expected an out-of-range rate to fail");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected validation error");
+    };
+    assert!(validation
+        .issues
+        .iter()
+        .any(|issue| issue.field == "versions[0].rules.estate_duty.rate_schedule[1].rate"));
+}
+
+#[test]
+fn directory_load_rejects_two_files_for_one_jurisdiction() {
+    let dir = scratch_dir("duplicate");
+    let json = catalog_json(compiled_in_catalog(Jurisdiction::UsTexas));
+    std::fs::write(dir.join("a_texas.json"), &json).expect("Expected file write");
+    std::fs::write(dir.join("notes.txt"), "ignored").expect("Expected file write");
+
+    let loaded = load_tax_rule_catalogs(&dir).expect("Expected the directory to load");
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].0, "a_texas.json");
+
+    std::fs::write(
+        dir.join("b_texas.toml"),
+        include_str!("../docs/rule-catalogs/us_texas.toml"),
+    )
+    .expect("Expected file write");
+    let err = load_tax_rule_catalogs(&dir).expect_err("Expected duplicate jurisdiction to fail");
+    let EngineError::Validation(validation) = err else {
+        panic!("Expected validation error");
+    };
+    assert_eq!(validation.issues[0].field, "b_texas.toml/jurisdiction");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn selects_loaded_versions_by_tax_year_and_latest_window() {
+    let versions = south_africa_tax_rules_catalog();
+
+    let latest = latest_tax_rule_version(versions.clone()).expect("Expected a latest version");
+    assert_eq!(latest.version.version_id, "ZA-ESTATE-BASELINE-2018+");
+
+    let err = select_tax_rule_version(Jurisdiction::SouthAfrica, versions, 1999)
+        .expect_err("Expected years before the catalog to fail");
+    assert_eq!(
+        err,
+        TaxRuleSelectionError::UnsupportedTaxYear {
+            jurisdiction: Jurisdiction::SouthAfrica,
+            tax_year: 1999,
+        }
+    );
+}
+
+#[test]
+fn database_versions_replace_only_the_tax_years_they_cover() {
+    let base = south_africa_tax_rules_catalog();
+    let mut published = base.last().expect("Expected a baseline version").clone();
+    published.version.version_id = "ZA-ESTATE-2028+";
+    published.version.tax_year_from = 2028;
+    published.version.effective_from = "2027-03-01";
+
+    let merged = merge_tax_rule_versions(base.clone(), vec![published]);
+    assert_eq!(merged.len(), base.len() + 1);
+    let baseline = &merged[merged.len() - 2].version;
+    assert_eq!(baseline.version_id, "ZA-ESTATE-BASELINE-2018+");
+    assert_eq!(baseline.tax_year_to, Some(2027));
+    assert_eq!(baseline.effective_to, Some("2027-02-28"));
+
+    let historical = select_tax_rule_version(Jurisdiction::SouthAfrica, merged.clone(), 2010)
+        .expect("Expected the historical versions to stay in the catalog");
+    assert_eq!(historical.version.version_id, "ZA-ESTATE-2009-2011");
+    let date = CalendarDate::parse("2027-03-01").expect("Expected a valid date");
+    let in_effect = select_tax_rule_version_in_effect(Jurisdiction::SouthAfrica, merged, date)
+        .expect("Expected the published version to be in effect");
+    assert_eq!(in_effect.version.version_id, "ZA-ESTATE-2028+");
+}
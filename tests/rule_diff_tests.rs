@@ -0,0 +1,114 @@
+use crate::core::rules::rule_diff::{diff_tax_rule_versions, TaxRuleChangeKind};
+use crate::core::rules::tax_rules::{Jurisdiction, RateBracket, VersionedJurisdictionTaxRuleSet};
+use crate::jurisdictions::south_africa::south_africa_tax_rules_catalog;
+use crate::jurisdictions::us::us_state_tax_rules_catalog;
+use rust_decimal_macros::dec;
+
+fn south_africa_version(version_id: &str) -> VersionedJurisdictionTaxRuleSet {
+    south_africa_tax_rules_catalog()
+        .into_iter()
+        .find(|versioned| versioned.version.version_id == version_id)
+        .expect("Expected the version to be registered")
+}
+
+#[test]
+fn identical_versions_have_no_changes() {
+    let version = south_africa_version("ZA-ESTATE-BASELINE-2018+");
+
+    let diff = diff_tax_rule_versions(&version, &version);
+    assert_eq!(diff.from_version_id, "ZA-ESTATE-BASELINE-2018+");
+    assert!(diff.changes.is_empty(), "{:?}", diff.changes);
+}
+
+#[test]
+fn reports_changed_rates_thresholds_flags_and_dates() {
+    let from = south_africa_version("ZA-ESTATE-2012");
+    let to = south_africa_version("ZA-ESTATE-2013-2015");
+    let diff = diff_tax_rule_versions(&from, &to);
+    let change = |field: &str| {
+        diff.changes
+            .iter()
+            .find(|change| change.field == field)
+            .unwrap_or_else(|| panic!("Expected a change to {field}: {:?}", diff.changes))
+    };
+
+    let inclusion = change("cgt_on_death.inclusion_rate_natural_person");
+    assert_eq!(inclusion.kind, TaxRuleChangeKind::Rate);
+    assert_eq!(inclusion.from.as_deref(), Some("0.25"));
+    assert_eq!(inclusion.to.as_deref(), Some("0.333"));
+
+    let exclusion = change("cgt_on_death.annual_exclusion_in_year_of_death_amount");
+    assert_eq!(exclusion.kind, TaxRuleChangeKind::Threshold);
+    assert_eq!(
+        exclusion.description(),
+        "cgt_on_death.annual_exclusion_in_year_of_death_amount: 200000 -> 300000"
+    );
+
+    assert_eq!(
+        change("version.tax_year_to").kind,
+        TaxRuleChangeKind::EffectiveDate
+    );
+    assert!(diff
+        .changes
+        .iter()
+        .all(|change| !change.field.starts_with("retirement_funds.")));
+
+    let diff = diff_tax_rule_versions(&to, &south_africa_version("ZA-ESTATE-2016"));
+    let flag = diff
+        .changes
+        .iter()
+        .find(|change| change.field == "retirement_funds.disallowed_contributions_in_estate")
+        .expect("Expected the disallowed contributions flag to change");
+    assert_eq!(flag.kind, TaxRuleChangeKind::Flag);
+    assert_eq!(
+        (flag.from.as_deref(), flag.to.as_deref()),
+        (Some("false"), Some("true"))
+    );
+}
+
+#[test]
+fn reports_added_brackets_and_removed_rules_on_one_side() {
+    let from = us_state_tax_rules_catalog(Jurisdiction::UsFlorida)
+        .pop()
+        .expect("Expected a Florida version");
+    let mut to = from.clone();
+    // Same rate at a different scale is not a change.
+    to.rules.estate_duty.rate_schedule[0].rate = dec!(0.180);
+    to.rules.estate_duty.rate_schedule.push(RateBracket {
+        from_amount: dec!(20000000),
+        rate: dec!(0.45),
+    });
+    to.rules.estate_duty.source = "Revised source";
+    to.rules.executor_fee = None;
+
+    let diff = diff_tax_rule_versions(&from, &to);
+    let added_index = from.rules.estate_duty.rate_schedule.len();
+    let added_rate = diff
+        .changes
+        .iter()
+        .find(|change| change.field == format!("estate_duty.rate_schedule[{added_index}].rate"))
+        .expect("Expected the added bracket");
+    assert_eq!(
+        (added_rate.from.as_deref(), added_rate.to.as_deref()),
+        (None, Some("0.45"))
+    );
+    assert!(diff
+        .changes
+        .iter()
+        .all(|change| change.field != "estate_duty.rate_schedule[0].rate"));
+    assert!(diff
+        .changes
+        .iter()
+        .any(|change| change.field == "estate_duty.source"
+            && change.kind == TaxRuleChangeKind::Source));
+
+    let removed = diff
+        .changes
+        .iter()
+        .filter(|change| change.field.starts_with("executor_fee."))
+        .collect::<Vec<_>>();
+    assert!(removed
+        .iter()
+        .any(|change| change.field == "executor_fee.source"));
+    assert!(removed.iter().all(|change| change.to.is_none()));
+}
@@ -0,0 +1,225 @@
+use crate::api::contracts::ApiVersionedJurisdictionTaxRuleSet;
+use crate::core::rules::rule_catalog::uncovered_tax_rule_versions;
+use crate::core::rules::rule_publishing::{
+    published_tax_rule_catalogs, TaxRuleVersionRecord, TaxRuleVersionReview, TaxRuleVersionStatus,
+};
+use crate::core::rules::tax_rules::{Jurisdiction, VersionedJurisdictionTaxRuleSet};
+use crate::jurisdictions::us::us_state_tax_rules_catalog;
+use rust_decimal_macros::dec;
+
+fn texas_record(id: i64, index: usize, status: TaxRuleVersionStatus) -> TaxRuleVersionRecord {
+    TaxRuleVersionRecord {
+        id,
+        jurisdiction: Jurisdiction::UsTexas,
+        status,
+        author: "author@example.com".to_string(),
+        created_at: "2026-10-01T09:00:00Z".to_string(),
+        review: None,
+        published_by: None,
+        published_at: None,
+        retired_by: None,
+        retired_at: None,
+        versioned: us_state_tax_rules_catalog(Jurisdiction::UsTexas)[index].clone(),
+    }
+}
+
+fn approved(mut record: TaxRuleVersionRecord) -> TaxRuleVersionRecord {
+    record.review = Some(TaxRuleVersionReview {
+        reviewer: "reviewer@example.com".to_string(),
+        approved: true,
+        notes: None,
+        reviewed_at: "2026-10-02T09:00:00Z".to_string(),
+    });
+    record
+}
+
+// A corrected copy of the latest published Texas version under a new id.
+fn corrected_latest_draft(published: &[TaxRuleVersionRecord]) -> TaxRuleVersionRecord {
+    let latest = published.last().expect("Expected a published version");
+    let mut draft = texas_record(100, 0, TaxRuleVersionStatus::Draft);
+    draft.versioned = latest.versioned.clone();
+    draft.versioned.version.version_id = "US-TX-CORRECTED";
+    draft
+}
+
+fn published_texas() -> Vec<TaxRuleVersionRecord> {
+    (0..us_state_tax_rules_catalog(Jurisdiction::UsTexas).len())
+        .map(|index| texas_record(index as i64 + 1, index, TaxRuleVersionStatus::Published))
+        .collect()
+}
+
+#[test]
+fn draft_validation_reports_rule_set_fields() {
+    let mut versioned = us_state_tax_rules_catalog(Jurisdiction::UsTexas)[0].clone();
+    versioned.rules.estate_duty.rate_schedule[0].rate = dec!(1.2);
+
+    let err = versioned
+        .validate_draft(Jurisdiction::UsTexas, " ")
+        .expect_err("Expected draft validation to fail");
+    assert!(err.issues.iter().any(|issue| issue.field == "author"));
+    assert!(err
+        .issues
+        .iter()
+        .any(|issue| issue.field == "rule_set.rules.estate_duty.rate_schedule[0].rate"));
+}
+
+#[test]
+fn review_requires_a_draft_and_a_second_person() {
+    let draft = texas_record(1, 0, TaxRuleVersionStatus::Draft);
+    assert!(draft.validate_review("reviewer@example.com").is_ok());
+
+    let err = draft
+        .validate_review("author@example.com")
+        .expect_err("Expected self-review to fail");
+    assert_eq!(err.issues[0].field, "reviewer");
+
+    let published = texas_record(1, 0, TaxRuleVersionStatus::Published);
+    let err = published
+        .validate_review("reviewer@example.com")
+        .expect_err("Expected reviewing a published version to fail");
+    assert_eq!(err.issues[0].field, "status");
+}
+
+#[test]
+fn publish_requires_approval_and_supersedes_overlapping_versions() {
+    let published = published_texas();
+    let draft = corrected_latest_draft(&published);
+    let latest_id = published
+        .last()
+        .map(|record| record.versioned.version.version_id.to_string())
+        .expect("Expected a published version");
+
+    let err = draft
+        .validate_publish(
+            "publisher@example.com",
+            &published,
+            std::slice::from_ref(&latest_id),
+        )
+        .expect_err("Expected an unreviewed draft to fail");
+    assert!(err.issues.iter().any(|issue| issue.field == "review"));
+
+    let draft = approved(draft);
+    let err = draft
+        .validate_publish("publisher@example.com", &published, &[])
+        .expect_err("Expected an overlapping window to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|issue| issue.field == "rule_set.version.tax_year_from"
+            && issue.message.contains(&latest_id)));
+
+    let err = draft
+        .validate_publish(
+            "publisher@example.com",
+            &published,
+            &[latest_id.clone(), "US-TX-UNKNOWN".to_string()],
+        )
+        .expect_err("Expected an unknown superseded version to fail");
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(err.issues[0].field, "supersedes[1]");
+
+    assert!(draft
+        .validate_publish("publisher@example.com", &published, &[latest_id])
+        .is_ok());
+}
+
+#[test]
+fn publishing_a_successor_closes_the_predecessor_window() {
+    let published = published_texas();
+    let latest = published.last().expect("Expected a published version");
+    let latest_id = latest.versioned.version.version_id.to_string();
+    let mut draft = approved(corrected_latest_draft(&published));
+    draft.versioned.version.tax_year_from = 2028;
+    draft.versioned.version.effective_from = "2028-01-01";
+
+    assert!(draft
+        .validate_publish(
+            "publisher@example.com",
+            &published,
+            std::slice::from_ref(&latest_id),
+        )
+        .is_ok());
+    let closed = uncovered_tax_rule_versions(&latest.versioned, &draft.versioned.version);
+    assert_eq!(closed.len(), 1);
+    assert_eq!(closed[0].version.version_id, latest_id);
+    assert_eq!(closed[0].version.tax_year_from, 2026);
+    assert_eq!(closed[0].version.tax_year_to, Some(2027));
+    assert_eq!(closed[0].version.effective_to, Some("2027-12-31"));
+
+    // A version covering only 2027 would leave the predecessor on both sides of it.
+    draft.versioned.version.tax_year_from = 2027;
+    draft.versioned.version.tax_year_to = Some(2027);
+    draft.versioned.version.effective_from = "2027-01-01";
+    draft.versioned.version.effective_to = Some("2027-12-31");
+    let err = draft
+        .validate_publish("publisher@example.com", &published, &[latest_id])
+        .expect_err("Expected a version inside the predecessor window to fail");
+    assert_eq!(err.issues[0].field, "supersedes[0]");
+}
+
+#[test]
+fn retire_rejects_retired_versions() {
+    assert!(texas_record(1, 0, TaxRuleVersionStatus::Published)
+        .validate_retire("publisher@example.com")
+        .is_ok());
+    assert!(texas_record(1, 0, TaxRuleVersionStatus::Draft)
+        .validate_retire("publisher@example.com")
+        .is_ok());
+    assert!(texas_record(1, 0, TaxRuleVersionStatus::Retired)
+        .validate_retire("publisher@example.com")
+        .is_err());
+    let err = texas_record(1, 0, TaxRuleVersionStatus::Published)
+        .validate_retire(" ")
+        .expect_err("Expected a blank retirer to fail");
+    assert_eq!(err.issues[0].field, "retired_by");
+}
+
+#[test]
+fn stored_records_are_checked_without_converting_their_rule_set() {
+    let stored = approved(texas_record(7, 0, TaxRuleVersionStatus::Draft))
+        .map_rule_set(ApiVersionedJurisdictionTaxRuleSet::from);
+    assert!(stored.validate_retire("publisher@example.com").is_ok());
+    assert_eq!(
+        stored
+            .validate_review("author@example.com")
+            .unwrap_err()
+            .issues[0]
+            .field,
+        "reviewer"
+    );
+
+    let installed = stored.map_rule_set(VersionedJurisdictionTaxRuleSet::from);
+    assert_eq!(installed.id, 7);
+    assert!(installed.review.is_some_and(|review| review.approved));
+    assert_eq!(
+        installed.versioned.version.version_id,
+        us_state_tax_rules_catalog(Jurisdiction::UsTexas)[0]
+            .version
+            .version_id
+    );
+}
+
+#[test]
+fn only_published_records_become_catalogs() {
+    let mut records = published_texas();
+    records.push(texas_record(90, 0, TaxRuleVersionStatus::Draft));
+    let mut retired = texas_record(91, 0, TaxRuleVersionStatus::Retired);
+    retired.jurisdiction = Jurisdiction::UsFlorida;
+    records.push(retired);
+
+    let catalogs = published_tax_rule_catalogs(records);
+    assert_eq!(catalogs.len(), 1);
+    assert_eq!(catalogs[0].jurisdiction, Jurisdiction::UsTexas);
+    assert_eq!(
+        catalogs[0].versions.len(),
+        us_state_tax_rules_catalog(Jurisdiction::UsTexas).len()
+    );
+
+    for status in [
+        TaxRuleVersionStatus::Draft,
+        TaxRuleVersionStatus::Published,
+        TaxRuleVersionStatus::Retired,
+    ] {
+        assert_eq!(TaxRuleVersionStatus::from_code(status.code()), Some(status));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::core::rules::tax_rules::{
     latest_tax_rules_for, supported_jurisdictions, supported_tax_year_window, tax_rule_registry,
-    tax_rule_registry_for, Jurisdiction,
+    tax_rule_registry_for, tax_rules_for, Jurisdiction,
 };
+use rust_decimal_macros::dec;
 
 #[test]
 fn registry_exposes_south_africa_versions() {
@@ -31,7 +32,50 @@ fn supported_jurisdictions_contains_south_africa() {
 #[test]
 fn tax_year_window_matches_catalog_bounds() {
     let window = supported_tax_year_window(Jurisdiction::SouthAfrica);
-    assert_eq!(window, Some((2018, None)));
+    assert_eq!(window, Some((2002, None)));
+}
+
+#[test]
+fn south_africa_versions_cover_every_tax_year_from_2002() {
+    let versions = tax_rule_registry_for(Jurisdiction::SouthAfrica);
+    for pair in versions.windows(2) {
+        assert_eq!(
+            pair[0].tax_year_to.map(|year| year + 1),
+            Some(pair[1].tax_year_from)
+        );
+    }
+    assert!(versions
+        .iter()
+        .all(|version| !version.source_last_verified_on.is_empty()));
+
+    let pre_2013 = tax_rules_for(Jurisdiction::SouthAfrica, 2012)
+        .expect("Expected rules for 2012")
+        .rules;
+    assert_eq!(
+        pre_2013.cgt_on_death.inclusion_rate_natural_person,
+        dec!(0.25)
+    );
+    assert_eq!(
+        pre_2013
+            .cgt_on_death
+            .annual_exclusion_in_year_of_death_amount,
+        dec!(200_000)
+    );
+
+    let rules_2014 = tax_rules_for(Jurisdiction::SouthAfrica, 2014)
+        .expect("Expected rules for 2014")
+        .rules;
+    assert_eq!(
+        rules_2014.cgt_on_death.inclusion_rate_natural_person,
+        dec!(0.333)
+    );
+    assert_eq!(rules_2014.cgt_on_death.inclusion_rate_trust, dec!(0.666));
+    assert_eq!(rules_2014.estate_duty.top_rate(), dec!(0.20));
+
+    let rules_2005 = tax_rules_for(Jurisdiction::SouthAfrica, 2005)
+        .expect("Expected rules for 2005")
+        .rules;
+    assert_eq!(rules_2005.estate_duty.exemption_amount, dec!(1_500_000));
 }
 
 #[test]
@@ -58,3 +102,28 @@ fn registry_exposes_us_state_versions() {
         .iter()
         .any(|version| version.version_id == "US-CA-ESTATE-BASELINE-2026+"));
 }
+
+#[test]
+fn only_new_york_and_minnesota_carry_state_estate_tax_rules() {
+    for jurisdiction in supported_jurisdictions() {
+        let rules = latest_tax_rules_for(jurisdiction).rules;
+        let expects_state_tax = matches!(
+            jurisdiction,
+            Jurisdiction::UsNewYork | Jurisdiction::UsMinnesota
+        );
+        assert_eq!(rules.state_estate_tax.is_some(), expects_state_tax);
+    }
+
+    let federal = latest_tax_rules_for(Jurisdiction::UsFlorida)
+        .rules
+        .estate_duty;
+    assert!(federal.exemption_as_unified_credit);
+    assert_eq!(
+        federal.rate_schedule.first().map(|b| b.rate),
+        Some(dec!(0.18))
+    );
+    assert_eq!(
+        federal.rate_schedule.last().map(|b| b.rate),
+        Some(dec!(0.40))
+    );
+}
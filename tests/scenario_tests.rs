@@ -1,14 +1,27 @@
-use crate::core::domain::models::{EstateAsset, EstateScenarioInput, ResidencyStatus};
-use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
-use crate::core::rules::tax_rules::{Jurisdiction, TaxPayerClass, TaxRuleSelectionError};
+use crate::core::domain::models::{
+    CalendarDate, EstateAsset, EstateScenarioInput, HeirCategory, LifeInsurancePolicy,
+    LifePolicyTreatment, MaritalPropertyInput, MaritalPropertyRegime, PolicyParty, ResidencyStatus,
+    RetirementBenefitRecipient, RetirementFundInterest, RetirementFundType, ScenarioResult,
+};
+use crate::core::engine::scenario::{
+    calculate_combined_tax_and_liquidity, JurisdictionScenarioCalculator, ScenarioCalculator,
+};
+use crate::core::errors::EngineError;
+use crate::core::rules::tax_rules::{
+    graduated_tax_amount, graduated_tax_lines, tax_rules_for, tax_rules_in_effect_on,
+    tax_year_for_date, Jurisdiction, RateBracket, TaxPayerClass, TaxRuleSelectionError,
+};
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
 fn baseline_input() -> EstateScenarioInput {
     EstateScenarioInput {
         jurisdiction: Jurisdiction::SouthAfrica,
         taxpayer_class: TaxPayerClass::NaturalPerson,
         residency_status: ResidencyStatus::Resident,
-        marginal_income_tax_rate: 0.45,
-        explicit_executor_fee_amount: Some(0.0),
+        marginal_income_tax_rate: Some(dec!(0.45)),
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
         ..EstateScenarioInput::default()
     }
 }
@@ -18,21 +31,68 @@ fn applies_estate_duty_primary_and_secondary_bands() {
     let mut input = baseline_input();
     input.assets.push(EstateAsset {
         name: "Illiquid asset".to_string(),
-        market_value_amount: 40_000_000.0,
-        base_cost_amount: 40_000_000.0,
+        market_value_amount: dec!(40_000_000),
+        base_cost_amount: dec!(40_000_000),
         is_liquid: false,
         situs_in_jurisdiction: true,
+        situs_jurisdiction: None,
         included_in_estate_duty: true,
         included_in_cgt_deemed_disposal: false,
         bequeathed_to_surviving_spouse: false,
         bequeathed_to_pbo: false,
         qualifies_primary_residence_exclusion: false,
+        currency: None,
     });
 
     let result = calculate_combined_tax_and_liquidity(&input)
         .expect("Expected calculation to succeed for supported tax year");
-    assert!((result.estate_duty.tax_payable_amount - 7_625_000.0).abs() < 0.1);
-    assert!((result.liquidity.liquidity_gap_amount - 7_625_000.0).abs() < 0.1);
+    assert!((result.estate_duty.tax_payable_amount - dec!(7_625_000)).abs() < dec!(0.1));
+    assert!((result.liquidity.liquidity_gap_amount - dec!(7_625_000)).abs() < dec!(0.1));
+
+    let brackets = &result.estate_duty.bracket_taxes;
+    assert_eq!(brackets.len(), 2);
+    assert_eq!(brackets[0].to_amount, Some(dec!(30_000_000)));
+    assert!((brackets[0].tax_amount - dec!(6_000_000)).abs() < dec!(0.1));
+    assert!((brackets[1].taxable_amount - dec!(6_500_000)).abs() < dec!(0.1));
+    assert!((brackets[1].tax_amount - dec!(1_625_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn graduated_schedule_slices_amount_across_every_bracket() {
+    let schedule = [
+        RateBracket {
+            from_amount: Decimal::ZERO,
+            rate: dec!(0.10),
+        },
+        RateBracket {
+            from_amount: dec!(100),
+            rate: dec!(0.20),
+        },
+        RateBracket {
+            from_amount: dec!(300),
+            rate: dec!(0.30),
+        },
+        RateBracket {
+            from_amount: dec!(600),
+            rate: dec!(0.40),
+        },
+        RateBracket {
+            from_amount: dec!(1_000),
+            rate: dec!(0.50),
+        },
+    ];
+
+    let lines = graduated_tax_lines(&schedule, dec!(700));
+    let taxable = lines
+        .iter()
+        .map(|line| line.taxable_amount)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        taxable,
+        vec![dec!(100), dec!(200), dec!(300), dec!(100), Decimal::ZERO]
+    );
+    assert_eq!(lines[4].to_amount, None);
+    assert!((graduated_tax_amount(&schedule, dec!(700)) - dec!(180)).abs() < dec!(0.1));
 }
 
 #[test]
@@ -41,34 +101,38 @@ fn applies_section_4q_spousal_deduction() {
     input.assets.extend([
         EstateAsset {
             name: "Spouse bequest".to_string(),
-            market_value_amount: 20_000_000.0,
-            base_cost_amount: 20_000_000.0,
+            market_value_amount: dec!(20_000_000),
+            base_cost_amount: dec!(20_000_000),
             is_liquid: false,
             situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
             included_in_estate_duty: true,
             included_in_cgt_deemed_disposal: false,
             bequeathed_to_surviving_spouse: true,
             bequeathed_to_pbo: false,
             qualifies_primary_residence_exclusion: false,
+            currency: None,
         },
         EstateAsset {
             name: "Non-spouse bequest".to_string(),
-            market_value_amount: 5_000_000.0,
-            base_cost_amount: 5_000_000.0,
+            market_value_amount: dec!(5_000_000),
+            base_cost_amount: dec!(5_000_000),
             is_liquid: false,
             situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
             included_in_estate_duty: true,
             included_in_cgt_deemed_disposal: false,
             bequeathed_to_surviving_spouse: false,
             bequeathed_to_pbo: false,
             qualifies_primary_residence_exclusion: false,
+            currency: None,
         },
     ]);
 
     let result = calculate_combined_tax_and_liquidity(&input)
         .expect("Expected calculation to succeed for supported tax year");
-    assert!((result.estate_duty.spousal_deduction_amount - 20_000_000.0).abs() < 0.1);
-    assert!((result.estate_duty.tax_payable_amount - 300_000.0).abs() < 0.1);
+    assert!((result.estate_duty.spousal_deduction_amount - dec!(20_000_000)).abs() < dec!(0.1));
+    assert!((result.estate_duty.tax_payable_amount - dec!(300_000)).abs() < dec!(0.1));
 }
 
 #[test]
@@ -78,49 +142,216 @@ fn excludes_foreign_assets_for_non_resident_estate_duty_scope() {
     input.assets.extend([
         EstateAsset {
             name: "SA situs asset".to_string(),
-            market_value_amount: 10_000_000.0,
-            base_cost_amount: 10_000_000.0,
+            market_value_amount: dec!(10_000_000),
+            base_cost_amount: dec!(10_000_000),
             is_liquid: false,
             situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
             included_in_estate_duty: true,
             included_in_cgt_deemed_disposal: false,
             bequeathed_to_surviving_spouse: false,
             bequeathed_to_pbo: false,
             qualifies_primary_residence_exclusion: false,
+            currency: None,
         },
         EstateAsset {
             name: "Foreign situs asset".to_string(),
-            market_value_amount: 50_000_000.0,
-            base_cost_amount: 50_000_000.0,
+            market_value_amount: dec!(50_000_000),
+            base_cost_amount: dec!(50_000_000),
             is_liquid: false,
             situs_in_jurisdiction: false,
+            situs_jurisdiction: None,
             included_in_estate_duty: true,
             included_in_cgt_deemed_disposal: false,
             bequeathed_to_surviving_spouse: false,
             bequeathed_to_pbo: false,
             qualifies_primary_residence_exclusion: false,
+            currency: None,
         },
     ]);
 
     let result = calculate_combined_tax_and_liquidity(&input)
         .expect("Expected calculation to succeed for supported tax year");
-    assert!((result.estate_duty.gross_estate_for_transfer_tax_amount - 10_000_000.0).abs() < 0.1);
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(10_000_000)).abs()
+            < dec!(0.1)
+    );
 }
 
 #[test]
 fn calculation_rejects_unsupported_tax_year() {
     let mut input = baseline_input();
-    input.tax_year = 2017;
+    input.tax_year = 2001;
 
     let err = calculate_combined_tax_and_liquidity(&input)
         .expect_err("Expected unsupported tax year to fail rule selection");
     assert_eq!(
         err,
-        TaxRuleSelectionError::UnsupportedTaxYear {
+        EngineError::RuleSelection(TaxRuleSelectionError::UnsupportedTaxYear {
+            jurisdiction: Jurisdiction::SouthAfrica,
+            tax_year: 2001,
+        })
+    );
+}
+
+#[test]
+fn historical_tax_year_applies_flat_estate_duty_and_older_cgt_rates() {
+    let mut input = baseline_input();
+    input.tax_year = 2010;
+    input.assets.extend([
+        EstateAsset {
+            is_liquid: false,
+            ..asset("Illiquid asset", dec!(40_000_000))
+        },
+        EstateAsset {
+            base_cost_amount: dec!(300_000),
+            included_in_estate_duty: false,
+            included_in_cgt_deemed_disposal: true,
+            ..asset("Appreciated shares", dec!(1_300_000))
+        },
+    ]);
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for a historical tax year");
+    // R40m less the R99,000 CGT liability and the R3.5m abatement, at a flat 20%.
+    assert!((result.estate_duty.tax_payable_amount - dec!(7_280_200)).abs() < dec!(0.1));
+    assert_eq!(result.estate_duty.bracket_taxes.len(), 1);
+    // R1m gain less the R120,000 exclusion on death, included at 25%.
+    assert!((result.cgt.taxable_capital_gain_in_income_amount - dec!(220_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn date_of_death_selects_the_version_in_effect_on_that_date() {
+    let mut input = baseline_input();
+    input.assets.push(EstateAsset {
+        is_liquid: false,
+        ..asset("Illiquid asset", dec!(40_000_000))
+    });
+    input.tax_year = 2019;
+    input.date_of_death = Some("2018-03-01".to_string());
+
+    let on_first_day = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed on the first day of the 25% band");
+    assert_eq!(on_first_day.estate_duty.bracket_taxes.len(), 2);
+
+    // The 25% band took effect on 1 March 2018, the first day of the 2019 tax year.
+    input.tax_year = 2018;
+    input.date_of_death = Some("2018-02-28".to_string());
+    let on_last_day = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed on the last day of the flat rate");
+    assert_eq!(on_last_day.estate_duty.bracket_taxes.len(), 1);
+    assert!((on_last_day.estate_duty.tax_payable_amount - dec!(7_300_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn death_in_september_2017_uses_the_2017_version_for_the_2018_tax_year() {
+    let date = CalendarDate::parse("2017-09-15").expect("Expected a valid date");
+    let selected = tax_rules_in_effect_on(Jurisdiction::SouthAfrica, date)
+        .expect("Expected a version in effect in September 2017");
+    assert_eq!(selected.version.version_id, "ZA-ESTATE-2017");
+    let by_tax_year = tax_rules_for(Jurisdiction::SouthAfrica, 2018)
+        .expect("Expected the 2018 tax year to be supported");
+    assert_eq!(by_tax_year.version.version_id, "ZA-ESTATE-2017");
+
+    let mut input = baseline_input();
+    input.tax_year = 2018;
+    input.date_of_death = Some("2017-09-15".to_string());
+    input.marginal_income_tax_rate = None;
+    input.explicit_executor_fee_amount = None;
+    input.assets.push(EstateAsset {
+        is_liquid: false,
+        ..asset("Illiquid asset", dec!(40_000_000))
+    });
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for a 2017-09 death");
+    // R40m less the 3.5% executor fee with 14% VAT and the R3.5m abatement, at a flat 20%.
+    let fee = &result.estate_duty.executor_fee;
+    assert_eq!(fee.executor_fee_amount, dec!(1_596_000));
+    assert_eq!(result.estate_duty.bracket_taxes.len(), 1);
+    assert!((result.estate_duty.tax_payable_amount - dec!(6_980_800)).abs() < dec!(0.1));
+
+    // Without a tax year the engine derives 2018 from the date, including the 14% VAT rate.
+    input.tax_year = 0;
+    assert!(input.validate().is_ok());
+    let derived = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed with the tax year derived");
+    assert_eq!(
+        derived.estate_duty.executor_fee.executor_fee_amount,
+        dec!(1_596_000)
+    );
+    assert_eq!(
+        derived
+            .income_tax
+            .tax_on_income_excluding_capital_gain_amount,
+        result
+            .income_tax
+            .tax_on_income_excluding_capital_gain_amount
+    );
+}
+
+#[test]
+fn tax_year_without_an_income_tax_table_needs_the_income_tax_figures() {
+    let mut input = baseline_input();
+    input.tax_year = 2010;
+    input.marginal_income_tax_rate = None;
+    input.assets.push(EstateAsset {
+        base_cost_amount: dec!(300_000),
+        included_in_cgt_deemed_disposal: true,
+        ..asset("Appreciated shares", dec!(1_300_000))
+    });
+
+    let err = calculate_combined_tax_and_liquidity(&input)
+        .expect_err("Expected no fallback to a later income tax table");
+    assert_eq!(
+        err,
+        EngineError::RuleSelection(TaxRuleSelectionError::MissingIncomeTaxTable {
             jurisdiction: Jurisdiction::SouthAfrica,
-            tax_year: 2017,
+            tax_year: 2010,
+        })
+    );
+
+    input.marginal_income_tax_rate = Some(dec!(0.40));
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected the supplied marginal rate to stand in for the table");
+    assert!(result.cgt.tax_payable_amount > Decimal::ZERO);
+}
+
+#[test]
+fn first_version_is_selected_alike_by_date_and_tax_year_at_both_edges() {
+    for date in ["2002-01-01", "2006-02-28"] {
+        let date = CalendarDate::parse(date).expect("Expected a valid date");
+        let by_date = tax_rules_in_effect_on(Jurisdiction::SouthAfrica, date)
+            .expect("Expected a version in effect on the date");
+        let by_tax_year = tax_rules_for(
+            Jurisdiction::SouthAfrica,
+            tax_year_for_date(Jurisdiction::SouthAfrica, date),
+        )
+        .expect("Expected the tax year to be supported");
+        assert_eq!(by_date.version.version_id, "ZA-ESTATE-2002-2006");
+        assert_eq!(by_tax_year.version.version_id, by_date.version.version_id);
+        assert_eq!(by_date.rules.estate_duty.exemption_amount, dec!(1_500_000));
+        assert_eq!(by_date.rules.estate_duty.effective_from, "2002-01-01");
+    }
+}
+
+#[test]
+fn rule_selection_by_date_rejects_dates_before_the_first_version() {
+    let date = CalendarDate::parse("2001-12-31").expect("Expected a valid date");
+    let err = tax_rules_in_effect_on(Jurisdiction::SouthAfrica, date)
+        .expect_err("Expected no version before the R1.5 million abatement applied");
+    assert_eq!(
+        err,
+        TaxRuleSelectionError::NoVersionInEffect {
+            jurisdiction: Jurisdiction::SouthAfrica,
+            date,
         }
     );
+
+    let date = CalendarDate::parse("2018-03-01").expect("Expected a valid date");
+    let selected = tax_rules_in_effect_on(Jurisdiction::SouthAfrica, date)
+        .expect("Expected the baseline version to be in effect");
+    assert_eq!(selected.version.version_id, "ZA-ESTATE-BASELINE-2018+");
 }
 
 #[test]
@@ -128,25 +359,60 @@ fn us_state_baseline_applies_step_up_and_no_cgt_at_death() {
     let mut input = baseline_input();
     input.jurisdiction = Jurisdiction::UsCalifornia;
     input.tax_year = 2026;
-    input.marginal_income_tax_rate = 0.37;
-    input.primary_residence_cgt_exclusion_cap_amount = 0.0;
+    input.marginal_income_tax_rate = Some(dec!(0.37));
+    input.primary_residence_cgt_exclusion_cap_amount = Some(Decimal::ZERO);
     input.assets.push(EstateAsset {
         name: "US appreciated asset".to_string(),
-        market_value_amount: 9_000_000.0,
-        base_cost_amount: 1_000_000.0,
+        market_value_amount: dec!(9_000_000),
+        base_cost_amount: dec!(1_000_000),
         is_liquid: false,
         situs_in_jurisdiction: true,
+        situs_jurisdiction: None,
         included_in_estate_duty: true,
         included_in_cgt_deemed_disposal: true,
         bequeathed_to_surviving_spouse: false,
         bequeathed_to_pbo: false,
         qualifies_primary_residence_exclusion: false,
+        currency: None,
     });
 
     let result = calculate_combined_tax_and_liquidity(&input)
         .expect("Expected US state baseline calculation to succeed");
-    assert_eq!(result.cgt.tax_payable_amount, 0.0);
-    assert_eq!(result.cgt.taxable_capital_gain_in_income_amount, 0.0);
+    assert_eq!(result.cgt.tax_payable_amount, Decimal::ZERO);
+    assert_eq!(
+        result.cgt.taxable_capital_gain_in_income_amount,
+        Decimal::ZERO
+    );
+    assert!(!result.cgt.deemed_disposal_applies);
+    assert_eq!(result.cgt.stepped_up_basis.len(), 1);
+    assert_eq!(
+        result.cgt.stepped_up_basis[0].heir,
+        HeirCategory::OtherHeirs
+    );
+    assert!(
+        (result.cgt.stepped_up_basis[0].stepped_up_basis_amount - dec!(9_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (result.cgt.stepped_up_basis[0].basis_step_up_amount - dec!(8_000_000)).abs() < dec!(0.1)
+    );
+    assert!((result.cgt.stepped_up_basis_total_amount - dec!(9_000_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn south_africa_applies_deemed_disposal_without_basis_step_up() {
+    let mut input = baseline_input();
+    input.assets.push(EstateAsset {
+        base_cost_amount: dec!(300_000),
+        included_in_cgt_deemed_disposal: true,
+        ..asset("Appreciated shares", dec!(1_300_000))
+    });
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!(result.cgt.deemed_disposal_applies);
+    assert!(result.cgt.stepped_up_basis.is_empty());
+    assert!(result.cgt.tax_payable_amount > Decimal::ZERO);
 }
 
 #[test]
@@ -159,9 +425,582 @@ fn us_state_baseline_rejects_pre_2026_tax_year() {
         .expect_err("Expected pre-2026 year to fail US state rule selection");
     assert_eq!(
         err,
-        TaxRuleSelectionError::UnsupportedTaxYear {
+        EngineError::RuleSelection(TaxRuleSelectionError::UnsupportedTaxYear {
             jurisdiction: Jurisdiction::UsTexas,
             tax_year: 2025,
-        }
+        })
+    );
+}
+
+#[test]
+fn rolls_over_spouse_gains_and_disregards_pbo_gains_on_death() {
+    let mut input = baseline_input();
+    input.assets.extend([
+        EstateAsset {
+            base_cost_amount: dec!(2_000_000),
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_surviving_spouse: true,
+            ..asset("Shares to spouse", dec!(6_000_000))
+        },
+        EstateAsset {
+            base_cost_amount: dec!(400_000),
+            included_in_cgt_deemed_disposal: true,
+            bequeathed_to_pbo: true,
+            ..asset("Shares to PBO", dec!(1_000_000))
+        },
+        EstateAsset {
+            base_cost_amount: dec!(300_000),
+            included_in_cgt_deemed_disposal: true,
+            ..asset("Shares to children", dec!(1_300_000))
+        },
+    ]);
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!((result.cgt.spousal_rollover_gain_amount - dec!(4_000_000)).abs() < dec!(0.1));
+    assert!((result.cgt.spouse_inherited_base_cost_amount - dec!(2_000_000)).abs() < dec!(0.1));
+    assert!((result.cgt.pbo_disregarded_gain_amount - dec!(600_000)).abs() < dec!(0.1));
+    assert!((result.cgt.gross_capital_gain_amount - dec!(1_000_000)).abs() < dec!(0.1));
+    // (1m gain - 300k annual exclusion) x 40% inclusion x 45% marginal rate.
+    assert!((result.cgt.tax_payable_amount - dec!(126_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn attributes_cgt_and_estate_duty_to_each_asset() {
+    let mut input = baseline_input();
+    for (name, market_value_amount, base_cost_amount, in_cgt, to_spouse) in [
+        (
+            "Shares to spouse",
+            dec!(6_000_000),
+            dec!(2_000_000),
+            true,
+            true,
+        ),
+        (
+            "Shares to children",
+            dec!(1_300_000),
+            dec!(300_000),
+            true,
+            false,
+        ),
+        ("Farm", dec!(10_000_000), dec!(10_000_000), false, false),
+    ] {
+        input.assets.push(EstateAsset {
+            base_cost_amount,
+            is_liquid: false,
+            included_in_cgt_deemed_disposal: in_cgt,
+            bequeathed_to_surviving_spouse: to_spouse,
+            ..asset(name, market_value_amount)
+        });
+    }
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let [spouse_shares, children_shares, farm] = &result.assets[..] else {
+        panic!("Expected one attribution line per asset");
+    };
+
+    assert!((spouse_shares.gain_disregarded_amount - dec!(4_000_000)).abs() < dec!(0.1));
+    assert_eq!(spouse_shares.cgt_attributed_amount, Decimal::ZERO);
+    assert!((spouse_shares.deductions_attributable_amount - dec!(6_000_000)).abs() < dec!(0.1));
+    assert_eq!(spouse_shares.estate_duty_attributed_amount, Decimal::ZERO);
+
+    assert!((children_shares.annual_exclusion_used_amount - dec!(300_000)).abs() < dec!(0.1));
+    assert!((children_shares.cgt_attributed_amount - dec!(126_000)).abs() < dec!(0.1));
+    assert!((children_shares.deductions_attributable_amount - dec!(126_000)).abs() < dec!(0.1));
+
+    // Net estate 11.174m less the 3.5m abatement, at 20%; shared by net contribution.
+    assert!((result.estate_duty.tax_payable_amount - dec!(1_534_800)).abs() < dec!(0.1));
+    assert!(
+        (farm.estate_duty_attributed_amount - dec!(1_534_800) * dec!(10) / dec!(11.174)).abs()
+            < dec!(0.1)
+    );
+    let attributed_duty_amount = result
+        .assets
+        .iter()
+        .map(|line| line.estate_duty_attributed_amount)
+        .sum::<Decimal>();
+    assert!((attributed_duty_amount - result.estate_duty.tax_payable_amount).abs() < dec!(0.1));
+    assert!(
+        (children_shares.total_tax_attributed_amount
+            - dec!(126_000)
+            - children_shares.estate_duty_attributed_amount)
+            .abs()
+            < dec!(0.1)
+    );
+}
+
+fn us_estate_input(
+    jurisdiction: Jurisdiction,
+    market_value_amount: Decimal,
+) -> EstateScenarioInput {
+    let mut input = baseline_input();
+    input.jurisdiction = jurisdiction;
+    input.tax_year = 2026;
+    input
+        .assets
+        .push(asset("US brokerage account", market_value_amount));
+    input
+}
+
+#[test]
+fn us_federal_estate_tax_applies_unified_credit_to_graduated_schedule() {
+    let below_exclusion = us_estate_input(Jurisdiction::UsTexas, dec!(14_000_000));
+    let result = calculate_combined_tax_and_liquidity(&below_exclusion)
+        .expect("Expected US federal calculation to succeed");
+    assert_eq!(result.estate_duty.tax_payable_amount, Decimal::ZERO);
+    assert!(result.state_estate_tax.is_none());
+
+    let above_exclusion = us_estate_input(Jurisdiction::UsTexas, dec!(20_000_000));
+    let result = calculate_combined_tax_and_liquidity(&above_exclusion)
+        .expect("Expected US federal calculation to succeed");
+    assert!((result.estate_duty.tax_payable_amount - dec!(2_000_000)).abs() < dec!(0.1));
+    assert!((result.combined_tax.estate_transfer_tax_amount - dec!(2_000_000)).abs() < dec!(0.1));
+    assert_eq!(result.estate_duty.bracket_taxes.len(), 12);
+    assert!((result.estate_duty.unified_credit_amount - dec!(5_945_800)).abs() < dec!(0.1));
+    assert_eq!(result.combined_tax.state_estate_tax_amount, Decimal::ZERO);
+}
+
+#[test]
+fn new_york_estate_tax_phases_out_credit_up_to_cliff() {
+    let within_phase_out = us_estate_input(Jurisdiction::UsNewYork, dec!(7_500_000));
+    let result = calculate_combined_tax_and_liquidity(&within_phase_out)
+        .expect("Expected New York calculation to succeed");
+    let state = result
+        .state_estate_tax
+        .expect("Expected New York estate tax breakdown");
+    assert!((state.tentative_tax_amount - dec!(705_200)).abs() < dec!(0.1));
+    // $299,910.20 is reported in whole dollars.
+    assert_eq!(state.tax_payable_amount, dec!(299_910));
+
+    let over_cliff = us_estate_input(Jurisdiction::UsNewYork, dec!(8_000_000));
+    let result = calculate_combined_tax_and_liquidity(&over_cliff)
+        .expect("Expected New York calculation to succeed");
+    let state = result
+        .state_estate_tax
+        .expect("Expected New York estate tax breakdown");
+    assert_eq!(state.credit_amount, Decimal::ZERO);
+    assert!((state.tax_payable_amount - dec!(773_200)).abs() < dec!(0.1));
+    assert_eq!(
+        result.combined_tax.estate_transfer_tax_amount,
+        Decimal::ZERO
+    );
+    assert!((result.combined_tax.state_estate_tax_amount - dec!(773_200)).abs() < dec!(0.1));
+    assert!((result.combined_tax.total_tax_liability_amount - dec!(773_200)).abs() < dec!(0.1));
+}
+
+#[test]
+fn minnesota_estate_tax_is_deducted_in_federal_computation() {
+    let input = us_estate_input(Jurisdiction::UsMinnesota, dec!(20_000_000));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected Minnesota calculation to succeed");
+    assert!((result.combined_tax.state_estate_tax_amount - dec!(2_549_000)).abs() < dec!(0.1));
+    assert!(
+        (result.estate_duty.state_estate_tax_deduction_amount - dec!(2_549_000)).abs() < dec!(0.1)
+    );
+    assert!((result.combined_tax.estate_transfer_tax_amount - dec!(980_400)).abs() < dec!(0.1));
+    assert!((result.combined_tax.total_tax_liability_amount - dec!(3_529_400)).abs() < dec!(0.1));
+}
+
+#[test]
+fn state_estate_tax_is_attributed_alongside_federal_tax() {
+    let mut input = us_estate_input(Jurisdiction::UsMinnesota, dec!(20_000_000));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::RetirementAnnuityFund,
+        dec!(5_000_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::Dependants,
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected Minnesota calculation to succeed");
+    let line = &result.assets[0];
+    assert_eq!(line.capital_gain_amount, Decimal::ZERO);
+    assert!((line.included_in_estate_amount - dec!(20_000_000)).abs() < dec!(0.1));
+    // The brokerage account is 20m of the 25m net estate; the fund carries the rest.
+    assert!(
+        (line.estate_duty_attributed_amount - result.estate_duty.tax_payable_amount * dec!(0.8))
+            .abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (line.state_estate_tax_attributed_amount
+            - result.combined_tax.state_estate_tax_amount * dec!(0.8))
+        .abs()
+            < dec!(0.1)
+    );
+}
+
+fn married_input(
+    regime: MaritalPropertyRegime,
+    market_value_amount: Decimal,
+) -> EstateScenarioInput {
+    let mut input = baseline_input();
+    input.marital_property = MaritalPropertyInput {
+        regime,
+        ..MaritalPropertyInput::default()
+    };
+    input
+        .assets
+        .push(asset("Investment portfolio", market_value_amount));
+    input
+}
+
+#[test]
+fn community_of_property_counts_half_the_joint_estate() {
+    let mut input = married_input(MaritalPropertyRegime::InCommunity, dec!(10_000_000));
+    input.debts_and_loans_amount = dec!(1_000_000);
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!(
+        (result.marital_property.spouse_share_of_joint_estate_amount - dec!(5_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(5_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((result.estate_duty.tax_payable_amount - dec!(200_000)).abs() < dec!(0.1));
+    assert!((result.liquidity.liquid_assets_in_estate_amount - dec!(5_000_000)).abs() < dec!(0.1));
+}
+
+#[test]
+fn larger_deceased_accrual_is_deducted_and_needs_cash() {
+    let mut input = married_input(MaritalPropertyRegime::Accrual, dec!(10_000_000));
+    input.marital_property.deceased_commencement_value_amount = dec!(2_000_000);
+    input.marital_property.spouse_net_estate_amount = dec!(4_000_000);
+    input.marital_property.spouse_commencement_value_amount = dec!(1_000_000);
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!((result.marital_property.deceased_accrual_amount - dec!(8_000_000)).abs() < dec!(0.1));
+    assert!((result.marital_property.spouse_accrual_amount - dec!(3_000_000)).abs() < dec!(0.1));
+    assert!(
+        (result.estate_duty.accrual_claim_deduction_amount - dec!(2_500_000)).abs() < dec!(0.1)
+    );
+    assert!((result.estate_duty.tax_payable_amount - dec!(800_000)).abs() < dec!(0.1));
+    assert!((result.liquidity.accrual_claim_payable_amount - dec!(2_500_000)).abs() < dec!(0.1));
+    assert!(
+        (result.liquidity.immediate_cash_requirements_amount - dec!(3_300_000)).abs() < dec!(0.1)
+    );
+}
+
+#[test]
+fn larger_spouse_accrual_becomes_an_estate_asset() {
+    let mut input = married_input(MaritalPropertyRegime::Accrual, dec!(2_000_000));
+    input.marital_property.spouse_net_estate_amount = dec!(10_000_000);
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!(
+        (result.marital_property.accrual_claim_by_estate_amount - dec!(4_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((result.estate_duty.accrual_claim_asset_amount - dec!(4_000_000)).abs() < dec!(0.1));
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(6_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((result.estate_duty.tax_payable_amount - dec!(500_000)).abs() < dec!(0.1));
+    assert_eq!(result.liquidity.accrual_claim_payable_amount, Decimal::ZERO);
+}
+
+fn policy(
+    name: &str,
+    owner: PolicyParty,
+    beneficiary: PolicyParty,
+    sum_assured_amount: Decimal,
+) -> LifeInsurancePolicy {
+    LifeInsurancePolicy {
+        name: name.to_string(),
+        owner,
+        life_insured: PolicyParty::Deceased,
+        beneficiary,
+        sum_assured_amount,
+        premiums_paid_by: owner,
+        held_under_buy_and_sell_agreement: false,
+    }
+}
+
+#[test]
+fn policy_payable_to_estate_is_deemed_property_and_funds_liquidity() {
+    let mut input = married_input(MaritalPropertyRegime::NotMarried, dec!(3_500_000));
+    input.life_policies.push(policy(
+        "Estate liquidity policy",
+        PolicyParty::Deceased,
+        PolicyParty::Estate,
+        dec!(2_000_000),
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let line = &result.estate_duty.life_policies[0];
+    assert_eq!(line.treatment, LifePolicyTreatment::DeemedProperty);
+    assert!(
+        (result.estate_duty.life_policy_deemed_property_amount - dec!(2_000_000)).abs() < dec!(0.1)
+    );
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(5_500_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((result.estate_duty.tax_payable_amount - dec!(400_000)).abs() < dec!(0.1));
+    assert!(
+        (result.liquidity.life_policy_proceeds_to_estate_amount - dec!(2_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (result.liquidity.total_available_liquidity_amount - dec!(5_500_000)).abs() < dec!(0.1)
+    );
+}
+
+#[test]
+fn buy_and_sell_and_third_party_owned_policies_are_exempt() {
+    let mut input = married_input(MaritalPropertyRegime::NotMarried, dec!(3_500_000));
+    let mut buy_and_sell = policy(
+        "Buy-and-sell cover",
+        PolicyParty::NamedPerson,
+        PolicyParty::NamedPerson,
+        dec!(5_000_000),
+    );
+    buy_and_sell.held_under_buy_and_sell_agreement = true;
+    input.life_policies.push(buy_and_sell);
+    input.life_policies.push(policy(
+        "Policy owned by child",
+        PolicyParty::NamedPerson,
+        PolicyParty::NamedPerson,
+        dec!(1_000_000),
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let treatments = result
+        .estate_duty
+        .life_policies
+        .iter()
+        .map(|line| line.treatment)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        treatments,
+        vec![
+            LifePolicyTreatment::ExemptBuyAndSell,
+            LifePolicyTreatment::ExemptThirdPartyOwned
+        ]
+    );
+    assert_eq!(
+        result.estate_duty.life_policy_deemed_property_amount,
+        Decimal::ZERO
+    );
+    assert_eq!(result.estate_duty.tax_payable_amount, Decimal::ZERO);
+    assert_eq!(
+        result.liquidity.life_policy_proceeds_to_estate_amount,
+        Decimal::ZERO
+    );
+}
+
+#[test]
+fn policy_for_surviving_spouse_is_included_then_deducted() {
+    let mut input = married_input(MaritalPropertyRegime::OutOfCommunity, dec!(3_500_000));
+    input.life_policies.push(policy(
+        "Spouse cover",
+        PolicyParty::Deceased,
+        PolicyParty::SurvivingSpouse,
+        dec!(2_000_000),
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!(
+        (result.estate_duty.life_policy_deemed_property_amount - dec!(2_000_000)).abs() < dec!(0.1)
+    );
+    assert!((result.estate_duty.spousal_deduction_amount - dec!(2_000_000)).abs() < dec!(0.1));
+    assert_eq!(result.estate_duty.tax_payable_amount, Decimal::ZERO);
+    assert_eq!(
+        result.liquidity.life_policy_proceeds_to_estate_amount,
+        Decimal::ZERO
+    );
+}
+
+#[test]
+fn us_policy_is_included_only_with_incidents_of_ownership() {
+    let mut input = us_estate_input(Jurisdiction::UsTexas, dec!(20_000_000));
+    input.life_policies.push(policy(
+        "ILIT policy",
+        PolicyParty::NamedPerson,
+        PolicyParty::NamedPerson,
+        dec!(5_000_000),
+    ));
+    input.life_policies.push(policy(
+        "Personally owned policy",
+        PolicyParty::Deceased,
+        PolicyParty::NamedPerson,
+        dec!(5_000_000),
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected US federal calculation to succeed");
+    assert_eq!(
+        result.estate_duty.life_policies[0].treatment,
+        LifePolicyTreatment::ExemptThirdPartyOwned
+    );
+    assert_eq!(
+        result.estate_duty.life_policies[1].treatment,
+        LifePolicyTreatment::DeemedProperty
+    );
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(25_000_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((result.estate_duty.tax_payable_amount - dec!(4_000_000)).abs() < dec!(0.1));
+}
+
+fn retirement_fund(
+    fund_type: RetirementFundType,
+    value_amount: Decimal,
+    disallowed_contributions_amount: Decimal,
+    nominated_recipient: RetirementBenefitRecipient,
+) -> RetirementFundInterest {
+    RetirementFundInterest {
+        name: format!("{fund_type:?}"),
+        fund_type,
+        value_amount,
+        disallowed_contributions_amount,
+        nominated_recipient,
+    }
+}
+
+#[test]
+fn south_african_fund_benefits_stay_outside_estate_except_disallowed_contributions() {
+    let mut input = married_input(MaritalPropertyRegime::NotMarried, dec!(3_500_000));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::RetirementAnnuityFund,
+        dec!(4_000_000),
+        dec!(1_000_000),
+        RetirementBenefitRecipient::Dependants,
+    ));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::LivingAnnuity,
+        dec!(6_000_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::Dependants,
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert!(
+        (result.estate_duty.retirement_fund_included_amount - dec!(1_000_000)).abs() < dec!(0.1)
+    );
+    assert!(
+        (result.estate_duty.gross_estate_for_transfer_tax_amount - dec!(4_500_000)).abs()
+            < dec!(0.1)
+    );
+    assert!((result.estate_duty.tax_payable_amount - dec!(200_000)).abs() < dec!(0.1));
+    assert!((result.cgt.retirement_fund_disregarded_amount - dec!(10_000_000)).abs() < dec!(0.1));
+    assert_eq!(result.cgt.tax_payable_amount, Decimal::ZERO);
+    assert_eq!(
+        result.liquidity.retirement_fund_proceeds_to_estate_amount,
+        Decimal::ZERO
+    );
+}
+
+#[test]
+fn asset_and_fund_cgt_lines_add_up_to_the_cgt_total() {
+    let mut input = baseline_input();
+    input.assets.push(EstateAsset {
+        base_cost_amount: dec!(300_000),
+        included_in_cgt_deemed_disposal: true,
+        ..asset("Shares", dec!(1_300_000))
+    });
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::PreservationFund,
+        dec!(700_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::Dependants,
+    ));
+    let attributed_cgt_amount = |result: &ScenarioResult| {
+        result
+            .assets
+            .iter()
+            .map(|line| line.cgt_attributed_amount)
+            .sum::<Decimal>()
+            + result.cgt.retirement_fund_cgt_attributed_amount
+    };
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    assert_eq!(result.cgt.retirement_fund_gain_amount, Decimal::ZERO);
+    assert!((result.cgt.tax_payable_amount - dec!(126_000)).abs() < dec!(0.1));
+    assert!((attributed_cgt_amount(&result) - result.cgt.tax_payable_amount).abs() < dec!(0.1));
+
+    // A rule version that taxes fund interests gives the fund its own share of the CGT.
+    let mut selected =
+        tax_rules_for(Jurisdiction::SouthAfrica, input.tax_year).expect("Expected SA rules");
+    selected.rules.retirement_funds.disregarded_for_cgt_on_death = false;
+    let result =
+        JurisdictionScenarioCalculator::from_rule_version(Jurisdiction::SouthAfrica, selected)
+            .calculate(&input)
+            .expect("Expected calculation to succeed with custom rules");
+    assert_eq!(result.cgt.retirement_fund_gain_amount, dec!(700_000));
+    // (1.7m - 0.3m exclusion) * 40% inclusion * 45%, with 0.7m/1.7m of it on the fund.
+    assert!((result.cgt.tax_payable_amount - dec!(252_000)).abs() < dec!(0.1));
+    assert!(
+        (result.cgt.retirement_fund_cgt_attributed_amount - dec!(103_764.71)).abs() < dec!(0.1)
+    );
+    assert!((attributed_cgt_amount(&result) - result.cgt.tax_payable_amount).abs() < dec!(0.1));
+}
+
+#[test]
+fn only_living_annuity_nominated_to_estate_counts_as_liquidity_under_section_37c() {
+    let mut input = married_input(MaritalPropertyRegime::NotMarried, dec!(1_000_000));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::PensionFund,
+        dec!(2_000_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::Estate,
+    ));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::LivingAnnuity,
+        dec!(500_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::Estate,
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected calculation to succeed for supported tax year");
+    let lines = &result.estate_duty.retirement_funds;
+    assert_eq!(lines[0].liquidity_to_estate_amount, Decimal::ZERO);
+    assert!((lines[1].liquidity_to_estate_amount - dec!(500_000)).abs() < dec!(0.1));
+    assert!(
+        (result.liquidity.retirement_fund_proceeds_to_estate_amount - dec!(500_000)).abs()
+            < dec!(0.1)
+    );
+    assert!(
+        (result.liquidity.total_available_liquidity_amount - dec!(1_500_000)).abs() < dec!(0.1)
+    );
+}
+
+#[test]
+fn us_retirement_accounts_are_in_gross_estate_with_marital_deduction() {
+    let mut input = us_estate_input(Jurisdiction::UsTexas, dec!(20_000_000));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::RetirementAnnuityFund,
+        dec!(5_000_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::Dependants,
+    ));
+    input.retirement_funds.push(retirement_fund(
+        RetirementFundType::PensionFund,
+        dec!(3_000_000),
+        Decimal::ZERO,
+        RetirementBenefitRecipient::SurvivingSpouse,
+    ));
+
+    let result = calculate_combined_tax_and_liquidity(&input)
+        .expect("Expected US federal calculation to succeed");
+    assert!(
+        (result.estate_duty.retirement_fund_included_amount - dec!(8_000_000)).abs() < dec!(0.1)
     );
+    assert!((result.estate_duty.spousal_deduction_amount - dec!(3_000_000)).abs() < dec!(0.1));
+    assert!((result.estate_duty.tax_payable_amount - dec!(4_000_000)).abs() < dec!(0.1));
 }
@@ -0,0 +1,291 @@
+use crate::core::domain::models::{
+    Beneficiary, EstateAsset, EstateScenarioInput, HeirCategory, MaritalPropertyInput,
+    MaritalPropertyRegime,
+};
+use crate::core::engine::optimizer::rank_candidate_strategies;
+use crate::core::engine::scenario::calculate_combined_tax_and_liquidity;
+use crate::core::engine::strategies::{
+    generate_candidate_strategies, CandidateStrategy, StrategyGeneratorOptions, StrategyLever,
+};
+use crate::core::rules::tax_rules::Jurisdiction;
+use crate::tests::support::asset;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn holding(name: &str, market_value_amount: Decimal, base_cost_amount: Decimal) -> EstateAsset {
+    EstateAsset {
+        base_cost_amount,
+        is_liquid: false,
+        included_in_cgt_deemed_disposal: true,
+        ..asset(name, market_value_amount)
+    }
+}
+
+fn married_estate() -> EstateScenarioInput {
+    let mut shares = holding("Share portfolio", dec!(3_000_000), dec!(1_000_000));
+    shares.is_liquid = true;
+    let mut home = holding("Family home", dec!(4_000_000), dec!(1_500_000));
+    home.qualifies_primary_residence_exclusion = true;
+    EstateScenarioInput {
+        assets: vec![
+            holding("Farm", dec!(20_000_000), dec!(5_000_000)),
+            shares,
+            home,
+        ],
+        marital_property: MaritalPropertyInput {
+            regime: MaritalPropertyRegime::OutOfCommunity,
+            ..MaritalPropertyInput::default()
+        },
+        beneficiaries: vec![Beneficiary {
+            name: "Children".to_string(),
+            category: HeirCategory::OtherHeirs,
+            specific_bequest_asset_names: Vec::new(),
+            legacy_amount: Decimal::ZERO,
+            residue_share: Decimal::ONE,
+        }],
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
+        ..EstateScenarioInput::default()
+    }
+}
+
+fn generated(input: &EstateScenarioInput) -> Vec<CandidateStrategy> {
+    generate_candidate_strategies(input, StrategyGeneratorOptions::default())
+        .expect("Expected strategies to be generated")
+}
+
+#[test]
+fn generates_the_current_plan_and_each_applicable_lever() {
+    let strategies = generated(&married_estate());
+
+    assert_eq!(strategies[0].name, "Current plan");
+    assert!(strategies[0].changes.is_empty());
+    assert_eq!(strategies[0].input.assets.len(), 3);
+    assert_eq!(
+        strategies[1..]
+            .iter()
+            .flat_map(CandidateStrategy::levers)
+            .collect::<Vec<_>>(),
+        vec![
+            StrategyLever::ResidueToSpouse,
+            StrategyLever::PboBequest,
+            StrategyLever::LifeCover,
+            StrategyLever::TrustTransfer,
+            StrategyLever::AnnualDonations,
+        ]
+    );
+    for strategy in &strategies {
+        assert!(
+            strategy.input.validate().is_ok(),
+            "{} produced an invalid scenario",
+            strategy.name
+        );
+    }
+}
+
+#[test]
+fn strategies_apply_their_changes_to_the_scenario() {
+    let strategies = generated(&married_estate());
+    let strategy = |lever: StrategyLever| {
+        strategies
+            .iter()
+            .find(|strategy| strategy.levers() == vec![lever])
+            .unwrap_or_else(|| panic!("Expected a {lever:?} strategy"))
+    };
+
+    let spouse = &strategy(StrategyLever::ResidueToSpouse).input;
+    assert!(spouse
+        .assets
+        .iter()
+        .all(|asset| asset.bequeathed_to_surviving_spouse));
+    assert_eq!(spouse.beneficiaries[0].residue_share, Decimal::ZERO);
+    assert_eq!(
+        spouse.beneficiaries[1].category,
+        HeirCategory::SurvivingSpouse
+    );
+    assert_eq!(spouse.beneficiaries[1].residue_share, Decimal::ONE);
+
+    // The farm has the largest gain; the home is excluded as a primary residence.
+    let pbo = &strategy(StrategyLever::PboBequest).input;
+    assert!(pbo.assets[0].bequeathed_to_pbo);
+    assert_eq!(
+        pbo.beneficiaries[1].specific_bequest_asset_names,
+        vec!["Farm".to_string()]
+    );
+
+    // R19.9m donated above the R100,000 exemption at 20%. The farm's R15m gain less the R40,000
+    // annual exclusion is included at 40%, and R5,984,000 of taxable income carries R2,502,404 of
+    // tax after the primary rebate.
+    let trust = &strategy(StrategyLever::TrustTransfer).input;
+    assert!(trust.assets.iter().all(|asset| asset.name != "Farm"));
+    assert_eq!(trust.assets.len(), 2);
+    assert_eq!(
+        trust.debts_and_loans_amount,
+        dec!(3_980_000) + dec!(2_502_404)
+    );
+
+    // R100,000 a year for five years comes out of the liquid share portfolio.
+    let donations = &strategy(StrategyLever::AnnualDonations).input;
+    assert_eq!(donations.assets[1].market_value_amount, dec!(2_500_000));
+    assert_eq!(
+        donations.assets[1].base_cost_amount.round_dp(2),
+        dec!(833_333.33)
+    );
+}
+
+#[test]
+fn life_cover_is_sized_to_close_the_liquidity_gap() {
+    let base_gap = calculate_combined_tax_and_liquidity(&married_estate())
+        .expect("Expected the scenario to calculate")
+        .liquidity
+        .liquidity_gap_amount;
+    assert!(base_gap > Decimal::ZERO);
+
+    let strategies = generated(&married_estate());
+    let cover = strategies
+        .iter()
+        .find(|strategy| strategy.levers() == vec![StrategyLever::LifeCover])
+        .expect("Expected a life cover strategy");
+    let policy = cover
+        .input
+        .life_policies
+        .last()
+        .expect("Expected a policy to be added");
+    // The policy itself attracts duty, so the gap is grossed up at the 20% band, to the rand.
+    assert!((policy.sum_assured_amount - base_gap / dec!(0.8)).abs() <= Decimal::ONE);
+    assert!(policy.sum_assured_amount > base_gap);
+    assert_eq!(
+        calculate_combined_tax_and_liquidity(&cover.input)
+            .expect("Expected the scenario to calculate")
+            .liquidity
+            .liquidity_gap_amount,
+        Decimal::ZERO
+    );
+}
+
+#[test]
+fn levers_that_do_not_apply_are_left_out() {
+    let mut single = married_estate();
+    single.marital_property = MaritalPropertyInput::default();
+    single.assets[1].market_value_amount = dec!(30_000_000);
+    let strategies = generate_candidate_strategies(
+        &single,
+        StrategyGeneratorOptions {
+            annual_donation_years: 0,
+            ..StrategyGeneratorOptions::default()
+        },
+    )
+    .expect("Expected strategies to be generated");
+
+    let levers = strategies
+        .iter()
+        .flat_map(CandidateStrategy::levers)
+        .collect::<Vec<_>>();
+    assert!(!levers.contains(&StrategyLever::ResidueToSpouse));
+    assert!(!levers.contains(&StrategyLever::LifeCover));
+    assert!(!levers.contains(&StrategyLever::AnnualDonations));
+    assert!(levers.contains(&StrategyLever::PboBequest));
+}
+
+#[test]
+fn trust_transfer_taxes_the_gain_as_a_lifetime_disposal() {
+    let input = EstateScenarioInput {
+        marginal_income_tax_rate: Some(dec!(0.45)),
+        ..married_estate()
+    };
+    let strategies = generated(&input);
+    let trust = strategies
+        .iter()
+        .find(|strategy| strategy.levers() == vec![StrategyLever::TrustTransfer])
+        .expect("Expected a trust transfer strategy");
+
+    // (R15m - R40,000) * 40% * 45%, not the farm's share of the CGT on death.
+    assert_eq!(
+        trust.input.debts_and_loans_amount,
+        dec!(3_980_000) + dec!(2_692_800)
+    );
+}
+
+#[test]
+fn trust_transfer_is_left_out_where_it_cannot_be_priced() {
+    let levers = |input: &EstateScenarioInput| {
+        generated(input)
+            .iter()
+            .flat_map(CandidateStrategy::levers)
+            .collect::<Vec<_>>()
+    };
+
+    // US gift tax is not modelled.
+    let us = EstateScenarioInput {
+        jurisdiction: Jurisdiction::UsTexas,
+        tax_year: 2026,
+        ..married_estate()
+    };
+    assert!(!levers(&us).contains(&StrategyLever::TrustTransfer));
+
+    // The 2010 version does not record the annual exclusion.
+    let historical = EstateScenarioInput {
+        tax_year: 2010,
+        marginal_income_tax_rate: Some(dec!(0.40)),
+        ..married_estate()
+    };
+    assert!(!levers(&historical).contains(&StrategyLever::TrustTransfer));
+}
+
+#[test]
+fn ranks_strategies_by_score_against_the_current_plan() {
+    let ranked = rank_candidate_strategies(&married_estate(), StrategyGeneratorOptions::default())
+        .expect("Expected strategies to be ranked");
+
+    assert_eq!(ranked.len(), 6);
+    assert_eq!(
+        ranked.iter().map(|ranked| ranked.rank).collect::<Vec<_>>(),
+        (1..=6).collect::<Vec<_>>()
+    );
+    assert!(ranked
+        .windows(2)
+        .all(|pair| pair[0].score.composite_score <= pair[1].score.composite_score));
+    assert_eq!(ranked[0].strategy.name, "Residue to surviving spouse");
+    assert!(ranked[0].tax_saving_amount > Decimal::ZERO);
+
+    let current = ranked
+        .iter()
+        .find(|ranked| ranked.strategy.name == "Current plan")
+        .expect("Expected the current plan to be ranked");
+    assert_eq!(current.tax_saving_amount, Decimal::ZERO);
+    assert_eq!(current.combined_tax_saving_amount, Decimal::ZERO);
+    assert!(ranked
+        .iter()
+        .all(|ranked| ranked.second_death_tax_amount.is_some() && !ranked.deferral_only));
+}
+
+#[test]
+fn strategies_that_only_defer_tax_to_the_survivor_rank_last() {
+    let mut input = married_estate();
+    input.marital_property.spouse_net_estate_amount = dec!(20_000_000);
+    let ranked = rank_candidate_strategies(&input, StrategyGeneratorOptions::default())
+        .expect("Expected strategies to be ranked");
+
+    // Leaving R27m to a spouse who already has R20m saves duty now but costs more on the
+    // survivor's death, once the second estate reaches the 25% band.
+    let last = ranked.last().expect("Expected ranked strategies");
+    assert_eq!(last.strategy.name, "Residue to surviving spouse");
+    assert!(last.deferral_only);
+    assert!(last.tax_saving_amount > Decimal::ZERO);
+    assert!(last.combined_tax_saving_amount <= Decimal::ZERO);
+    assert!(ranked[..ranked.len() - 1]
+        .iter()
+        .all(|ranked| !ranked.deferral_only));
+}
+
+#[test]
+fn unmarried_scenarios_have_no_second_death() {
+    let mut single = married_estate();
+    single.marital_property = MaritalPropertyInput::default();
+    let ranked = rank_candidate_strategies(&single, StrategyGeneratorOptions::default())
+        .expect("Expected strategies to be ranked");
+
+    assert!(ranked.iter().all(|ranked| {
+        ranked.second_death_tax_amount.is_none()
+            && ranked.combined_tax_saving_amount == ranked.tax_saving_amount
+    }));
+}
@@ -0,0 +1,21 @@
+use crate::core::domain::models::EstateAsset;
+use rust_decimal::Decimal;
+
+// A liquid asset held at cost in the reporting currency, in the estate and outside the CGT deemed
+// disposal. Tests override the fields they exercise with struct update syntax.
+pub fn asset(name: &str, market_value_amount: Decimal) -> EstateAsset {
+    EstateAsset {
+        name: name.to_string(),
+        market_value_amount,
+        base_cost_amount: market_value_amount,
+        is_liquid: true,
+        situs_in_jurisdiction: true,
+        situs_jurisdiction: None,
+        included_in_estate_duty: true,
+        included_in_cgt_deemed_disposal: false,
+        bequeathed_to_surviving_spouse: false,
+        bequeathed_to_pbo: false,
+        qualifies_primary_residence_exclusion: false,
+        currency: None,
+    }
+}
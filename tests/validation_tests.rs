@@ -1,27 +1,38 @@
-use crate::api::handler::{calculate_single_scenario, optimize_candidate_scenarios};
-use crate::core::domain::models::{EstateAsset, EstateScenarioInput, ResidencyStatus};
+use crate::api::handler::{
+    calculate_single_scenario, optimize_candidate_scenarios, rank_planning_strategies,
+};
+use crate::core::domain::models::{
+    Beneficiary, CalendarDate, EstateAsset, EstateScenarioInput, FxRateTable, HeirCategory,
+    LifeInsurancePolicy, PolicyParty, ResidencyStatus, RetirementBenefitRecipient,
+    RetirementFundInterest, RetirementFundType,
+};
+use crate::core::engine::strategies::StrategyGeneratorOptions;
 use crate::core::errors::EngineError;
 use crate::core::rules::tax_rules::{Jurisdiction, TaxPayerClass};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
 fn valid_input() -> EstateScenarioInput {
     EstateScenarioInput {
         jurisdiction: Jurisdiction::SouthAfrica,
         taxpayer_class: TaxPayerClass::NaturalPerson,
         residency_status: ResidencyStatus::Resident,
-        marginal_income_tax_rate: 0.45,
+        marginal_income_tax_rate: Some(dec!(0.45)),
         assets: vec![EstateAsset {
             name: "Test Asset".to_string(),
-            market_value_amount: 1_000_000.0,
-            base_cost_amount: 700_000.0,
+            market_value_amount: dec!(1_000_000),
+            base_cost_amount: dec!(700_000),
             is_liquid: true,
             situs_in_jurisdiction: true,
+            situs_jurisdiction: None,
             included_in_estate_duty: true,
             included_in_cgt_deemed_disposal: true,
             bequeathed_to_surviving_spouse: false,
             bequeathed_to_pbo: false,
             qualifies_primary_residence_exclusion: false,
+            currency: None,
         }],
-        explicit_executor_fee_amount: Some(0.0),
+        explicit_executor_fee_amount: Some(Decimal::ZERO),
         ..EstateScenarioInput::default()
     }
 }
@@ -39,8 +50,8 @@ fn rejects_empty_assets() {
 #[test]
 fn rejects_out_of_range_rates() {
     let mut input = valid_input();
-    input.marginal_income_tax_rate = 1.5;
-    input.vat_rate = -0.1;
+    input.marginal_income_tax_rate = Some(dec!(1.5));
+    input.vat_rate = Some(-dec!(0.1));
 
     let err = input.validate().expect_err("Expected validation to fail");
     assert!(err
@@ -53,17 +64,65 @@ fn rejects_out_of_range_rates() {
 #[test]
 fn rejects_unsupported_tax_year() {
     let mut input = valid_input();
-    input.tax_year = 2017;
+    input.tax_year = 2001;
 
     let err = input.validate().expect_err("Expected validation to fail");
     assert!(err.issues.iter().any(|i| i.field == "tax_year"));
 }
 
+#[test]
+fn date_of_death_must_fall_in_the_tax_year() {
+    let mut input = valid_input();
+    input.date_of_death = Some("2025-03-01".to_string());
+    input.tax_year = 2025;
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|i| i.field == "tax_year" && i.message.contains("tax year 2026")));
+
+    input.tax_year = 2026;
+    assert!(input.validate().is_ok());
+
+    input.date_of_death = Some("2025-02-30".to_string());
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert_eq!(err.issues.len(), 1);
+    assert_eq!(err.issues[0].field, "date_of_death");
+}
+
+#[test]
+fn rejects_valuation_date_before_death_or_without_it() {
+    let mut input = valid_input();
+    input.valuation_date = Some("2025-06-01".to_string());
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert!(err.issues.iter().any(|i| i.field == "valuation_date"));
+
+    input.date_of_death = Some("2025-07-01".to_string());
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|i| i.field == "valuation_date" && i.message.contains("before the date of death")));
+
+    input.valuation_date = Some("2025-09-01".to_string());
+    input.fx_rates = Some(FxRateTable {
+        valuation_date: "2025-07-01".to_string(),
+        rates: Vec::new(),
+    });
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|i| i.field == "fx_rates.valuation_date"));
+}
+
 #[test]
 fn rejects_negative_amounts() {
     let mut input = valid_input();
-    input.funeral_costs_amount = -10.0;
-    input.assets[0].market_value_amount = -100.0;
+    input.funeral_costs_amount = -dec!(10);
+    input.assets[0].market_value_amount = -dec!(100);
 
     let err = input.validate().expect_err("Expected validation to fail");
     assert!(err.issues.iter().any(|i| i.field == "funeral_costs_amount"));
@@ -76,7 +135,7 @@ fn rejects_negative_amounts() {
 #[test]
 fn api_calculate_rejects_invalid_input() {
     let mut input = valid_input();
-    input.executor_fee_rate = 2.0;
+    input.executor_fee_rate = Some(dec!(2));
 
     let err = calculate_single_scenario(&input).expect_err("Expected API validation error");
     let EngineError::Validation(err) = err else {
@@ -132,7 +191,7 @@ fn rejects_primary_residence_flag_without_cgt_inclusion() {
 fn rejects_primary_residence_exclusion_for_company() {
     let mut input = valid_input();
     input.taxpayer_class = TaxPayerClass::Company;
-    input.primary_residence_cgt_exclusion_cap_amount = 2_000_000.0;
+    input.primary_residence_cgt_exclusion_cap_amount = Some(dec!(2_000_000));
     input.assets[0].qualifies_primary_residence_exclusion = true;
 
     let err = input.validate().expect_err("Expected validation to fail");
@@ -159,3 +218,88 @@ fn rejects_non_resident_foreign_asset_marked_for_estate_duty() {
         .iter()
         .any(|i| i.field == "assets[0].situs_in_jurisdiction"));
 }
+
+#[test]
+fn rejects_estate_owned_policy_and_negative_sum_assured() {
+    let mut input = valid_input();
+    input.life_policies.push(LifeInsurancePolicy {
+        name: "Key person cover".to_string(),
+        owner: PolicyParty::Estate,
+        life_insured: PolicyParty::Company,
+        beneficiary: PolicyParty::Estate,
+        sum_assured_amount: -Decimal::ONE,
+        premiums_paid_by: PolicyParty::Deceased,
+        held_under_buy_and_sell_agreement: false,
+    });
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    for field in [
+        "life_policies[0].owner",
+        "life_policies[0].life_insured",
+        "life_policies[0].sum_assured_amount",
+    ] {
+        assert!(err.issues.iter().any(|i| i.field == field));
+    }
+}
+
+#[test]
+fn rejects_negative_retirement_fund_amounts() {
+    let mut input = valid_input();
+    input.retirement_funds.push(RetirementFundInterest {
+        name: "Pension fund".to_string(),
+        fund_type: RetirementFundType::PensionFund,
+        value_amount: -Decimal::ONE,
+        disallowed_contributions_amount: -Decimal::ONE,
+        nominated_recipient: RetirementBenefitRecipient::Dependants,
+    });
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|i| i.field == "retirement_funds[0].value_amount"));
+    assert!(err
+        .issues
+        .iter()
+        .any(|i| i.field == "retirement_funds[0].disallowed_contributions_amount"));
+}
+
+#[test]
+fn rejects_unknown_bequest_asset_and_residue_over_100_percent() {
+    let mut input = valid_input();
+    for share in [dec!(0.6), dec!(0.6)] {
+        input.beneficiaries.push(Beneficiary {
+            name: "Heir".to_string(),
+            category: HeirCategory::OtherHeirs,
+            specific_bequest_asset_names: vec!["Missing asset".to_string()],
+            legacy_amount: Decimal::ZERO,
+            residue_share: share,
+        });
+    }
+
+    let err = input.validate().expect_err("Expected validation to fail");
+    assert!(err
+        .issues
+        .iter()
+        .any(|i| i.field == "beneficiaries[0].specific_bequest_asset_names[0]"));
+    assert!(err.issues.iter().any(|i| i.field == "beneficiaries"));
+}
+
+#[test]
+fn rejects_a_planning_date_after_the_date_of_death() {
+    let mut input = valid_input();
+    input.tax_year = 2026;
+    input.date_of_death = Some("2025-07-01".to_string());
+    let options = StrategyGeneratorOptions {
+        planning_date: CalendarDate::parse("2025-08-01"),
+        ..StrategyGeneratorOptions::default()
+    };
+
+    match rank_planning_strategies(&input, options) {
+        Err(EngineError::Validation(err)) => {
+            assert_eq!(err.issues.len(), 1);
+            assert_eq!(err.issues[0].field, "planning_date");
+        }
+        other => panic!("Expected a planning date validation error, got {other:?}"),
+    }
+}
@@ -131,31 +131,69 @@ function ScenarioDraftEditor({
         </label>
 
         <label className="field-stack">
-          <span>Marginal Tax Rate</span>
+          <span>Taxable Income in Year of Death</span>
+          <input
+            className="control-input"
+            value={draft.taxableIncomeInYearOfDeathAmount}
+            inputMode="decimal"
+            onChange={(event) =>
+              onDraftChange('taxableIncomeInYearOfDeathAmount', event.target.value)
+            }
+          />
+        </label>
+
+        <label className="field-stack">
+          <span>Age at Tax Year End</span>
+          <input
+            className="control-input"
+            value={draft.ageAtTaxYearEnd}
+            inputMode="numeric"
+            placeholder="Optional"
+            onChange={(event) => onDraftChange('ageAtTaxYearEnd', event.target.value)}
+          />
+        </label>
+
+        <label className="field-stack">
+          <span>Marginal Tax Rate Override</span>
           <input
             className="control-input"
             value={draft.marginalIncomeTaxRate}
             inputMode="decimal"
+            placeholder="From tax tables"
             onChange={(event) => onDraftChange('marginalIncomeTaxRate', event.target.value)}
           />
         </label>
 
         <label className="field-stack">
-          <span>Executor Fee Rate</span>
+          <span>Executor Fee Rate Override</span>
           <input
             className="control-input"
             value={draft.executorFeeRate}
             inputMode="decimal"
+            placeholder="From fee tariff"
             onChange={(event) => onDraftChange('executorFeeRate', event.target.value)}
           />
         </label>
 
+        <label className="field-stack">
+          <span>Income Accrued After Death</span>
+          <input
+            className="control-input"
+            value={draft.incomeAccruedAfterDeathAmount}
+            inputMode="decimal"
+            onChange={(event) =>
+              onDraftChange('incomeAccruedAfterDeathAmount', event.target.value)
+            }
+          />
+        </label>
+
         <label className="field-stack">
           <span>VAT Rate</span>
           <input
             className="control-input"
             value={draft.vatRate}
             inputMode="decimal"
+            placeholder="From tax rules"
             onChange={(event) => onDraftChange('vatRate', event.target.value)}
           />
         </label>
@@ -191,8 +229,8 @@ function ScenarioDraftEditor({
           <input className="control-input" value={draft.otherSettlementCostsAmount} inputMode="decimal" onChange={(event) => onDraftChange('otherSettlementCostsAmount', event.target.value)} />
         </label>
         <label className="field-stack">
-          <span>Final Income Tax Due</span>
-          <input className="control-input" value={draft.finalIncomeTaxDueAmount} inputMode="decimal" onChange={(event) => onDraftChange('finalIncomeTaxDueAmount', event.target.value)} />
+          <span>Final Income Tax Override</span>
+          <input className="control-input" value={draft.finalIncomeTaxDueAmount} inputMode="decimal" placeholder="From tax tables" onChange={(event) => onDraftChange('finalIncomeTaxDueAmount', event.target.value)} />
         </label>
         <label className="field-stack">
           <span>Ongoing Estate Income Tax Provision</span>
@@ -208,7 +246,7 @@ function ScenarioDraftEditor({
         </label>
         <label className="field-stack">
           <span>Primary Residence CGT Cap</span>
-          <input className="control-input" value={draft.primaryResidenceCgtExclusionCapAmount} inputMode="decimal" onChange={(event) => onDraftChange('primaryResidenceCgtExclusionCapAmount', event.target.value)} />
+          <input className="control-input" value={draft.primaryResidenceCgtExclusionCapAmount} inputMode="decimal" placeholder="From tax rules" onChange={(event) => onDraftChange('primaryResidenceCgtExclusionCapAmount', event.target.value)} />
         </label>
         <label className="field-stack">
           <span>Explicit Executor Fee</span>
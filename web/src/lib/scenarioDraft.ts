@@ -40,20 +40,23 @@ export function createScenarioDraft(
     taxYear: '2025',
     taxpayerClass: 'NaturalPerson',
     residencyStatus: 'Resident',
-    marginalIncomeTaxRate: '0.45',
+    taxableIncomeInYearOfDeathAmount: '0',
+    ageAtTaxYearEnd: '',
+    marginalIncomeTaxRate: '',
     debtsAndLoansAmount: '350000',
     funeralCostsAmount: '30000',
     administrationCostsAmount: '55000',
     mastersOfficeFeesAmount: '8000',
     conveyancingCostsAmount: '24000',
     otherSettlementCostsAmount: '12000',
-    finalIncomeTaxDueAmount: '0',
+    finalIncomeTaxDueAmount: '',
     ongoingEstateIncomeTaxProvisionAmount: '0',
     additionalAllowableEstateTransferTaxDeductionsAmount: '0',
     portedEstateTaxExemptionAmount: '0',
-    primaryResidenceCgtExclusionCapAmount: '2000000',
-    executorFeeRate: '0.035',
-    vatRate: '0.15',
+    primaryResidenceCgtExclusionCapAmount: '',
+    executorFeeRate: '',
+    incomeAccruedAfterDeathAmount: '0',
+    vatRate: '',
     explicitExecutorFeeAmount: '',
     externalLiquidityProceedsAmount: '300000',
     cashReserveAmount: '150000',
@@ -74,6 +77,10 @@ export function createScenarioDraft(
   }
 }
 
+function parseOptionalNumber(value: string): number | null {
+  return value.trim().length > 0 ? parseNumber(value) : null
+}
+
 function parseNumber(value: string): number {
   const normalized = value.trim().replace(/,/g, '')
   if (normalized.length === 0) {
@@ -89,7 +96,12 @@ export function scenarioDraftToApiPayload(draft: ScenarioBuilderDraft) {
     tax_year: Math.max(0, Math.trunc(parseNumber(draft.taxYear))),
     taxpayer_class: draft.taxpayerClass,
     residency_status: draft.residencyStatus,
-    marginal_income_tax_rate: parseNumber(draft.marginalIncomeTaxRate),
+    taxable_income_in_year_of_death_amount: parseNumber(draft.taxableIncomeInYearOfDeathAmount),
+    age_at_tax_year_end:
+      draft.ageAtTaxYearEnd.trim().length > 0
+        ? Math.max(0, Math.trunc(parseNumber(draft.ageAtTaxYearEnd)))
+        : null,
+    marginal_income_tax_rate: parseOptionalNumber(draft.marginalIncomeTaxRate),
     assets: draft.assets.map((asset) => ({
       name: asset.name.trim() || 'Unnamed Asset',
       market_value_amount: parseNumber(asset.marketValueAmount),
@@ -108,7 +120,7 @@ export function scenarioDraftToApiPayload(draft: ScenarioBuilderDraft) {
     masters_office_fees_amount: parseNumber(draft.mastersOfficeFeesAmount),
     conveyancing_costs_amount: parseNumber(draft.conveyancingCostsAmount),
     other_settlement_costs_amount: parseNumber(draft.otherSettlementCostsAmount),
-    final_income_tax_due_amount: parseNumber(draft.finalIncomeTaxDueAmount),
+    final_income_tax_due_amount: parseOptionalNumber(draft.finalIncomeTaxDueAmount),
     ongoing_estate_income_tax_provision_amount: parseNumber(
       draft.ongoingEstateIncomeTaxProvisionAmount,
     ),
@@ -116,11 +128,12 @@ export function scenarioDraftToApiPayload(draft: ScenarioBuilderDraft) {
       draft.additionalAllowableEstateTransferTaxDeductionsAmount,
     ),
     ported_estate_tax_exemption_amount: parseNumber(draft.portedEstateTaxExemptionAmount),
-    primary_residence_cgt_exclusion_cap_amount: parseNumber(
+    primary_residence_cgt_exclusion_cap_amount: parseOptionalNumber(
       draft.primaryResidenceCgtExclusionCapAmount,
     ),
-    executor_fee_rate: parseNumber(draft.executorFeeRate),
-    vat_rate: parseNumber(draft.vatRate),
+    executor_fee_rate: parseOptionalNumber(draft.executorFeeRate),
+    income_accrued_after_death_amount: parseNumber(draft.incomeAccruedAfterDeathAmount),
+    vat_rate: parseOptionalNumber(draft.vatRate),
     explicit_executor_fee_amount:
       draft.explicitExecutorFeeAmount.trim().length > 0
         ? parseNumber(draft.explicitExecutorFeeAmount)
@@ -28,6 +28,8 @@ export type ScenarioBuilderDraft = {
   taxYear: string
   taxpayerClass: ScenarioBuilderTaxpayerClass
   residencyStatus: ScenarioBuilderResidencyStatus
+  taxableIncomeInYearOfDeathAmount: string
+  ageAtTaxYearEnd: string
   marginalIncomeTaxRate: string
   debtsAndLoansAmount: string
   funeralCostsAmount: string
@@ -41,6 +43,7 @@ export type ScenarioBuilderDraft = {
   portedEstateTaxExemptionAmount: string
   primaryResidenceCgtExclusionCapAmount: string
   executorFeeRate: string
+  incomeAccruedAfterDeathAmount: string
   vatRate: string
   explicitExecutorFeeAmount: string
   externalLiquidityProceedsAmount: string
@@ -73,7 +73,7 @@ fn safe_join_dist(relative_path: &str) -> Option<PathBuf> {
     Some(full_path)
 }
 
-fn guess_content_type(path: &PathBuf) -> &'static str {
+fn guess_content_type(path: &std::path::Path) -> &'static str {
     match path.extension().and_then(|ext| ext.to_str()) {
         Some("js") => "application/javascript; charset=utf-8",
         Some("mjs") => "application/javascript; charset=utf-8",